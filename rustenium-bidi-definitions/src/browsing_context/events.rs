@@ -39,6 +39,9 @@ impl ContextCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ContextCreated {
+    const IDENTIFIER: &'static str = "browsingContext.contextCreated";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContextDestroyedParams {
     #[serde(rename = "children")]
@@ -79,6 +82,9 @@ impl ContextDestroyed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ContextDestroyed {
+    const IDENTIFIER: &'static str = "browsingContext.contextDestroyed";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NavigationStartedParams {
     #[serde(flatten)]
@@ -102,6 +108,9 @@ impl NavigationStarted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for NavigationStarted {
+    const IDENTIFIER: &'static str = "browsingContext.navigationStarted";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FragmentNavigatedParams {
     #[serde(flatten)]
@@ -125,6 +134,9 @@ impl FragmentNavigated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FragmentNavigated {
+    const IDENTIFIER: &'static str = "browsingContext.fragmentNavigated";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HistoryUpdatedParams {
     #[serde(rename = "context")]
@@ -151,6 +163,9 @@ impl HistoryUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for HistoryUpdated {
+    const IDENTIFIER: &'static str = "browsingContext.historyUpdated";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DomContentLoadedParams {
     #[serde(flatten)]
@@ -174,6 +189,9 @@ impl DomContentLoaded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DomContentLoaded {
+    const IDENTIFIER: &'static str = "browsingContext.domContentLoaded";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoadParams {
     #[serde(flatten)]
@@ -197,6 +215,9 @@ impl Load {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for Load {
+    const IDENTIFIER: &'static str = "browsingContext.load";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DownloadWillBeginParams {
     #[serde(rename = "suggestedFilename")]
@@ -222,6 +243,9 @@ impl DownloadWillBegin {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DownloadWillBegin {
+    const IDENTIFIER: &'static str = "browsingContext.downloadWillBegin";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DownloadEndParams {
     #[serde(flatten)]
@@ -246,6 +270,9 @@ impl DownloadEnd {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DownloadEnd {
+    const IDENTIFIER: &'static str = "browsingContext.downloadEnd";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NavigationAbortedParams {
     #[serde(flatten)]
@@ -269,6 +296,9 @@ impl NavigationAborted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for NavigationAborted {
+    const IDENTIFIER: &'static str = "browsingContext.navigationAborted";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NavigationCommittedParams {
     #[serde(flatten)]
@@ -292,6 +322,9 @@ impl NavigationCommitted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for NavigationCommitted {
+    const IDENTIFIER: &'static str = "browsingContext.navigationCommitted";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NavigationFailedParams {
     #[serde(flatten)]
@@ -315,6 +348,9 @@ impl NavigationFailed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for NavigationFailed {
+    const IDENTIFIER: &'static str = "browsingContext.navigationFailed";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserPromptClosedParams {
     #[serde(rename = "context")]
@@ -345,6 +381,9 @@ impl UserPromptClosed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for UserPromptClosed {
+    const IDENTIFIER: &'static str = "browsingContext.userPromptClosed";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserPromptOpenedParams {
     #[serde(rename = "context")]
@@ -377,4 +416,7 @@ impl UserPromptOpened {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for UserPromptOpened {
+    const IDENTIFIER: &'static str = "browsingContext.userPromptOpened";
+}
 group_enum ! (BrowsingContextEvent { ContextCreated (ContextCreated) , ContextDestroyed (ContextDestroyed) , NavigationStarted (NavigationStarted) , FragmentNavigated (FragmentNavigated) , HistoryUpdated (HistoryUpdated) , DomContentLoaded (DomContentLoaded) , Load (Load) , DownloadWillBegin (DownloadWillBegin) , DownloadEnd (DownloadEnd) , NavigationAborted (NavigationAborted) , NavigationCommitted (NavigationCommitted) , NavigationFailed (NavigationFailed) , UserPromptClosed (UserPromptClosed) , UserPromptOpened (UserPromptOpened) } + identifiable);
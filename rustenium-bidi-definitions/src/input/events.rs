@@ -27,4 +27,7 @@ impl FileDialogOpened {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FileDialogOpened {
+    const IDENTIFIER: &'static str = "input.fileDialogOpened";
+}
 group_enum ! (InputEvent { FileDialogOpened (FileDialogOpened) } + identifiable);
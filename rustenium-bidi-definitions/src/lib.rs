@@ -36,6 +36,9 @@ pub trait CommandResult {
         serde_json::from_value(result)
     }
 }
+pub trait EventType {
+    const IDENTIFIER: &'static str;
+}
 pub mod browser;
 pub mod browsing_context;
 pub mod emulation;
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct EntryAddedParams {}
+pub struct EntryAddedParams {
+    #[serde(flatten)]
+    pub entry: super::types::Entry,
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EntryAddedMethod {
     #[serde(rename = "log.entryAdded")]
@@ -18,4 +21,7 @@ impl EntryAdded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for EntryAdded {
+    const IDENTIFIER: &'static str = "log.entryAdded";
+}
 group_enum ! (LogEvent { EntryAdded (EntryAdded) } + identifiable);
@@ -13,9 +13,9 @@ pub enum Level {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Entry {
-    GenericLogEntry(GenericLogEntry),
     ConsoleLogEntry(ConsoleLogEntry),
     JavascriptLogEntry(JavascriptLogEntry),
+    GenericLogEntry(GenericLogEntry),
 }
 impl From<GenericLogEntry> for Entry {
     fn from(v: GenericLogEntry) -> Self {
@@ -24,6 +24,9 @@ impl AuthRequired {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AuthRequired {
+    const IDENTIFIER: &'static str = "network.authRequired";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BeforeRequestSentParams {
     #[serde(flatten)]
@@ -51,6 +54,9 @@ impl BeforeRequestSent {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for BeforeRequestSent {
+    const IDENTIFIER: &'static str = "network.beforeRequestSent";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FetchErrorParams {
     #[serde(flatten)]
@@ -76,6 +82,9 @@ impl FetchError {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FetchError {
+    const IDENTIFIER: &'static str = "network.fetchError";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResponseCompletedParams {
     #[serde(flatten)]
@@ -101,6 +110,9 @@ impl ResponseCompleted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ResponseCompleted {
+    const IDENTIFIER: &'static str = "network.responseCompleted";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResponseStartedParams {
     #[serde(flatten)]
@@ -126,4 +138,7 @@ impl ResponseStarted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ResponseStarted {
+    const IDENTIFIER: &'static str = "network.responseStarted";
+}
 group_enum ! (NetworkEvent { AuthRequired (AuthRequired) , BeforeRequestSent (BeforeRequestSent) , FetchError (FetchError) , ResponseCompleted (ResponseCompleted) , ResponseStarted (ResponseStarted) } + identifiable);
@@ -25,8 +25,14 @@ impl Message {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for Message {
+    const IDENTIFIER: &'static str = "script.message";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct RealmCreatedParams {}
+pub struct RealmCreatedParams {
+    #[serde(flatten)]
+    pub realm_info: super::types::RealmInfo,
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RealmCreatedMethod {
     #[serde(rename = "script.realmCreated")]
@@ -44,6 +50,9 @@ impl RealmCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for RealmCreated {
+    const IDENTIFIER: &'static str = "script.realmCreated";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RealmDestroyedParams {
     #[serde(rename = "realm")]
@@ -66,4 +75,7 @@ impl RealmDestroyed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for RealmDestroyed {
+    const IDENTIFIER: &'static str = "script.realmDestroyed";
+}
 group_enum ! (ScriptEvent { Message (Message) , RealmCreated (RealmCreated) , RealmDestroyed (RealmDestroyed) } + identifiable);
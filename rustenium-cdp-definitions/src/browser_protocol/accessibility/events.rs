@@ -23,6 +23,9 @@ impl LoadComplete {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for LoadComplete {
+    const IDENTIFIER: &'static str = "Accessibility.loadComplete";
+}
 #[doc = "The nodesUpdated event is sent every time a previously requested node has changed the in tree.\n[nodesUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Accessibility/#event-nodesUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NodesUpdatedParams {
@@ -48,4 +51,7 @@ impl NodesUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for NodesUpdated {
+    const IDENTIFIER: &'static str = "Accessibility.nodesUpdated";
+}
 group_enum ! (AccessibilityEvents { LoadComplete (LoadComplete) , NodesUpdated (NodesUpdated) } + identifiable);
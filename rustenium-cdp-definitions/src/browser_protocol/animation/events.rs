@@ -23,6 +23,9 @@ impl AnimationCanceled {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AnimationCanceled {
+    const IDENTIFIER: &'static str = "Animation.animationCanceled";
+}
 #[doc = "Event for each animation that has been created.\n[animationCreated](https://chromedevtools.github.io/devtools-protocol/tot/Animation/#event-animationCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AnimationCreatedParams {
@@ -47,6 +50,9 @@ impl AnimationCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AnimationCreated {
+    const IDENTIFIER: &'static str = "Animation.animationCreated";
+}
 #[doc = "Event for animation that has been started.\n[animationStarted](https://chromedevtools.github.io/devtools-protocol/tot/Animation/#event-animationStarted)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AnimationStartedParams {
@@ -71,6 +77,9 @@ impl AnimationStarted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AnimationStarted {
+    const IDENTIFIER: &'static str = "Animation.animationStarted";
+}
 #[doc = "Event for animation that has been updated.\n[animationUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Animation/#event-animationUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AnimationUpdatedParams {
@@ -95,4 +104,7 @@ impl AnimationUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AnimationUpdated {
+    const IDENTIFIER: &'static str = "Animation.animationUpdated";
+}
 group_enum ! (AnimationEvents { AnimationCanceled (AnimationCanceled) , AnimationCreated (AnimationCreated) , AnimationStarted (AnimationStarted) , AnimationUpdated (AnimationUpdated) } + identifiable);
@@ -20,4 +20,7 @@ impl IssueAdded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for IssueAdded {
+    const IDENTIFIER: &'static str = "Audits.issueAdded";
+}
 group_enum ! (AuditsEvents { IssueAdded (IssueAdded) } + identifiable);
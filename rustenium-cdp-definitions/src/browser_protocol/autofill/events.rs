@@ -27,4 +27,7 @@ impl AddressFormFilled {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AddressFormFilled {
+    const IDENTIFIER: &'static str = "Autofill.addressFormFilled";
+}
 group_enum ! (AutofillEvents { AddressFormFilled (AddressFormFilled) } + identifiable);
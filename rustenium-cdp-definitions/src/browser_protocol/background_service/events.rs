@@ -24,6 +24,9 @@ impl RecordingStateChanged {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for RecordingStateChanged {
+    const IDENTIFIER: &'static str = "BackgroundService.recordingStateChanged";
+}
 #[doc = "Called with all existing backgroundServiceEvents when enabled, and all new\nevents afterwards if enabled and recording.\n[backgroundServiceEventReceived](https://chromedevtools.github.io/devtools-protocol/tot/BackgroundService/#event-backgroundServiceEventReceived)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BackgroundServiceEventReceivedParams {
@@ -47,4 +50,7 @@ impl BackgroundServiceEventReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for BackgroundServiceEventReceived {
+    const IDENTIFIER: &'static str = "BackgroundService.backgroundServiceEventReceived";
+}
 group_enum ! (BackgroundServiceEvents { RecordingStateChanged (RecordingStateChanged) , BackgroundServiceEventReceived (BackgroundServiceEventReceived) } + identifiable);
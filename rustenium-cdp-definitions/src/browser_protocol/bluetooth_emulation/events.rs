@@ -24,6 +24,9 @@ impl GattOperationReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for GattOperationReceived {
+    const IDENTIFIER: &'static str = "BluetoothEmulation.gattOperationReceived";
+}
 #[doc = "Event for when a characteristic operation of |type| to the characteristic\nrespresented by |characteristicId| happened. |data| and |writeType| is\nexpected to exist when |type| is write.\n[characteristicOperationReceived](https://chromedevtools.github.io/devtools-protocol/tot/BluetoothEmulation/#event-characteristicOperationReceived)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CharacteristicOperationReceivedParams {
@@ -57,6 +60,9 @@ impl CharacteristicOperationReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for CharacteristicOperationReceived {
+    const IDENTIFIER: &'static str = "BluetoothEmulation.characteristicOperationReceived";
+}
 #[doc = "Event for when a descriptor operation of |type| to the descriptor\nrespresented by |descriptorId| happened. |data| is expected to exist when\n|type| is write.\n[descriptorOperationReceived](https://chromedevtools.github.io/devtools-protocol/tot/BluetoothEmulation/#event-descriptorOperationReceived)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DescriptorOperationReceivedParams {
@@ -86,4 +92,7 @@ impl DescriptorOperationReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DescriptorOperationReceived {
+    const IDENTIFIER: &'static str = "BluetoothEmulation.descriptorOperationReceived";
+}
 group_enum ! (BluetoothEmulationEvents { GattOperationReceived (GattOperationReceived) , CharacteristicOperationReceived (CharacteristicOperationReceived) , DescriptorOperationReceived (DescriptorOperationReceived) } + identifiable);
@@ -32,6 +32,9 @@ impl DownloadWillBegin {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DownloadWillBegin {
+    const IDENTIFIER: &'static str = "Browser.downloadWillBegin";
+}
 #[doc = "Fired when download makes progress. Last call has |done| == true.\n[downloadProgress](https://chromedevtools.github.io/devtools-protocol/tot/Browser/#event-downloadProgress)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DownloadProgressParams {
@@ -80,4 +83,7 @@ impl DownloadProgress {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DownloadProgress {
+    const IDENTIFIER: &'static str = "Browser.downloadProgress";
+}
 group_enum ! (BrowserEvents { DownloadWillBegin (DownloadWillBegin) , DownloadProgress (DownloadProgress) } + identifiable);
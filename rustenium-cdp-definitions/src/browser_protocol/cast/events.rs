@@ -23,6 +23,9 @@ impl SinksUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for SinksUpdated {
+    const IDENTIFIER: &'static str = "Cast.sinksUpdated";
+}
 #[doc = "This is fired whenever the outstanding issue/error message changes.\n|issueMessage| is empty if there is no issue.\n[issueUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Cast/#event-issueUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IssueUpdatedParams {
@@ -46,4 +49,7 @@ impl IssueUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for IssueUpdated {
+    const IDENTIFIER: &'static str = "Cast.issueUpdated";
+}
 group_enum ! (CastEvents { SinksUpdated (SinksUpdated) , IssueUpdated (IssueUpdated) } + identifiable);
@@ -25,6 +25,9 @@ impl FontsUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FontsUpdated {
+    const IDENTIFIER: &'static str = "CSS.fontsUpdated";
+}
 #[doc = "Fires whenever a MediaQuery result changes (for example, after a browser window has been\nresized.) The current implementation considers only viewport-dependent media features.\n[mediaQueryResultChanged](https://chromedevtools.github.io/devtools-protocol/tot/CSS/#event-mediaQueryResultChanged)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MediaQueryResultChangedParams {}
@@ -45,6 +48,9 @@ impl MediaQueryResultChanged {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for MediaQueryResultChanged {
+    const IDENTIFIER: &'static str = "CSS.mediaQueryResultChanged";
+}
 #[doc = "Fired whenever an active document stylesheet is added.\n[styleSheetAdded](https://chromedevtools.github.io/devtools-protocol/tot/CSS/#event-styleSheetAdded)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StyleSheetAddedParams {
@@ -69,6 +75,9 @@ impl StyleSheetAdded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for StyleSheetAdded {
+    const IDENTIFIER: &'static str = "CSS.styleSheetAdded";
+}
 #[doc = "Fired whenever a stylesheet is changed as a result of the client operation.\n[styleSheetChanged](https://chromedevtools.github.io/devtools-protocol/tot/CSS/#event-styleSheetChanged)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StyleSheetChangedParams {
@@ -92,6 +101,9 @@ impl StyleSheetChanged {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for StyleSheetChanged {
+    const IDENTIFIER: &'static str = "CSS.styleSheetChanged";
+}
 #[doc = "Fired whenever an active document stylesheet is removed.\n[styleSheetRemoved](https://chromedevtools.github.io/devtools-protocol/tot/CSS/#event-styleSheetRemoved)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StyleSheetRemovedParams {
@@ -116,6 +128,9 @@ impl StyleSheetRemoved {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for StyleSheetRemoved {
+    const IDENTIFIER: &'static str = "CSS.styleSheetRemoved";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ComputedStyleUpdatedParams {
     #[doc = "The node id that has updated computed styles."]
@@ -138,4 +153,7 @@ impl ComputedStyleUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ComputedStyleUpdated {
+    const IDENTIFIER: &'static str = "CSS.computedStyleUpdated";
+}
 group_enum ! (CssEvents { FontsUpdated (FontsUpdated) , MediaQueryResultChanged (MediaQueryResultChanged) , StyleSheetAdded (StyleSheetAdded) , StyleSheetChanged (StyleSheetChanged) , StyleSheetRemoved (StyleSheetRemoved) , ComputedStyleUpdated (ComputedStyleUpdated) } + identifiable);
@@ -25,4 +25,7 @@ impl DeviceRequestPrompted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DeviceRequestPrompted {
+    const IDENTIFIER: &'static str = "DeviceAccess.deviceRequestPrompted";
+}
 group_enum ! (DeviceAccessEvents { DeviceRequestPrompted (DeviceRequestPrompted) } + identifiable);
@@ -29,6 +29,9 @@ impl AttributeModified {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AttributeModified {
+    const IDENTIFIER: &'static str = "DOM.attributeModified";
+}
 #[doc = "Fired when `Element`'s adoptedStyleSheets are modified.\n[adoptedStyleSheetsModified](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-adoptedStyleSheetsModified)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AdoptedStyleSheetsModifiedParams {
@@ -57,6 +60,9 @@ impl AdoptedStyleSheetsModified {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AdoptedStyleSheetsModified {
+    const IDENTIFIER: &'static str = "DOM.adoptedStyleSheetsModified";
+}
 #[doc = "Fired when `Element`'s attribute is removed.\n[attributeRemoved](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-attributeRemoved)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributeRemovedParams {
@@ -84,6 +90,9 @@ impl AttributeRemoved {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AttributeRemoved {
+    const IDENTIFIER: &'static str = "DOM.attributeRemoved";
+}
 #[doc = "Mirrors `DOMCharacterDataModified` event.\n[characterDataModified](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-characterDataModified)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CharacterDataModifiedParams {
@@ -111,6 +120,9 @@ impl CharacterDataModified {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for CharacterDataModified {
+    const IDENTIFIER: &'static str = "DOM.characterDataModified";
+}
 #[doc = "Fired when `Container`'s child node count has changed.\n[childNodeCountUpdated](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-childNodeCountUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChildNodeCountUpdatedParams {
@@ -138,6 +150,9 @@ impl ChildNodeCountUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ChildNodeCountUpdated {
+    const IDENTIFIER: &'static str = "DOM.childNodeCountUpdated";
+}
 #[doc = "Mirrors `DOMNodeInserted` event.\n[childNodeInserted](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-childNodeInserted)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChildNodeInsertedParams {
@@ -168,6 +183,9 @@ impl ChildNodeInserted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ChildNodeInserted {
+    const IDENTIFIER: &'static str = "DOM.childNodeInserted";
+}
 #[doc = "Mirrors `DOMNodeRemoved` event.\n[childNodeRemoved](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-childNodeRemoved)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChildNodeRemovedParams {
@@ -195,6 +213,9 @@ impl ChildNodeRemoved {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ChildNodeRemoved {
+    const IDENTIFIER: &'static str = "DOM.childNodeRemoved";
+}
 #[doc = "Called when distribution is changed.\n[distributedNodesUpdated](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-distributedNodesUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DistributedNodesUpdatedParams {
@@ -223,6 +244,9 @@ impl DistributedNodesUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DistributedNodesUpdated {
+    const IDENTIFIER: &'static str = "DOM.distributedNodesUpdated";
+}
 #[doc = "Fired when `Document` has been totally updated. Node ids are no longer valid.\n[documentUpdated](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-documentUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DocumentUpdatedParams {}
@@ -243,6 +267,9 @@ impl DocumentUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DocumentUpdated {
+    const IDENTIFIER: &'static str = "DOM.documentUpdated";
+}
 #[doc = "Fired when `Element`'s inline style is modified via a CSS property modification.\n[inlineStyleInvalidated](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-inlineStyleInvalidated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InlineStyleInvalidatedParams {
@@ -268,6 +295,9 @@ impl InlineStyleInvalidated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for InlineStyleInvalidated {
+    const IDENTIFIER: &'static str = "DOM.inlineStyleInvalidated";
+}
 #[doc = "Called when a pseudo element is added to an element.\n[pseudoElementAdded](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-pseudoElementAdded)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PseudoElementAddedParams {
@@ -295,6 +325,9 @@ impl PseudoElementAdded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PseudoElementAdded {
+    const IDENTIFIER: &'static str = "DOM.pseudoElementAdded";
+}
 #[doc = "Called when top layer elements are changed.\n[topLayerElementsUpdated](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-topLayerElementsUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TopLayerElementsUpdatedParams {}
@@ -315,6 +348,9 @@ impl TopLayerElementsUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for TopLayerElementsUpdated {
+    const IDENTIFIER: &'static str = "DOM.topLayerElementsUpdated";
+}
 #[doc = "Fired when a node's scrollability state changes.\n[scrollableFlagUpdated](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-scrollableFlagUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScrollableFlagUpdatedParams {
@@ -342,6 +378,9 @@ impl ScrollableFlagUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ScrollableFlagUpdated {
+    const IDENTIFIER: &'static str = "DOM.scrollableFlagUpdated";
+}
 #[doc = "Fired when a node's ad related state changes.\n[adRelatedStateUpdated](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-adRelatedStateUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AdRelatedStateUpdatedParams {
@@ -369,6 +408,9 @@ impl AdRelatedStateUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AdRelatedStateUpdated {
+    const IDENTIFIER: &'static str = "DOM.adRelatedStateUpdated";
+}
 #[doc = "Fired when a node's starting styles changes.\n[affectedByStartingStylesFlagUpdated](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-affectedByStartingStylesFlagUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AffectedByStartingStylesFlagUpdatedParams {
@@ -396,6 +438,9 @@ impl AffectedByStartingStylesFlagUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AffectedByStartingStylesFlagUpdated {
+    const IDENTIFIER: &'static str = "DOM.affectedByStartingStylesFlagUpdated";
+}
 #[doc = "Called when a pseudo element is removed from an element.\n[pseudoElementRemoved](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-pseudoElementRemoved)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PseudoElementRemovedParams {
@@ -423,6 +468,9 @@ impl PseudoElementRemoved {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PseudoElementRemoved {
+    const IDENTIFIER: &'static str = "DOM.pseudoElementRemoved";
+}
 #[doc = "Fired when backend wants to provide client with the missing DOM structure. This happens upon\nmost of the calls requesting node ids.\n[setChildNodes](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-setChildNodes)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SetChildNodesParams {
@@ -451,6 +499,9 @@ impl SetChildNodes {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for SetChildNodes {
+    const IDENTIFIER: &'static str = "DOM.setChildNodes";
+}
 #[doc = "Called when shadow root is popped from the element.\n[shadowRootPopped](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-shadowRootPopped)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ShadowRootPoppedParams {
@@ -478,6 +529,9 @@ impl ShadowRootPopped {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ShadowRootPopped {
+    const IDENTIFIER: &'static str = "DOM.shadowRootPopped";
+}
 #[doc = "Called when shadow root is pushed into the element.\n[shadowRootPushed](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#event-shadowRootPushed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ShadowRootPushedParams {
@@ -505,4 +559,7 @@ impl ShadowRootPushed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ShadowRootPushed {
+    const IDENTIFIER: &'static str = "DOM.shadowRootPushed";
+}
 group_enum ! (DomEvents { AttributeModified (AttributeModified) , AdoptedStyleSheetsModified (AdoptedStyleSheetsModified) , AttributeRemoved (AttributeRemoved) , CharacterDataModified (CharacterDataModified) , ChildNodeCountUpdated (ChildNodeCountUpdated) , ChildNodeInserted (ChildNodeInserted) , ChildNodeRemoved (ChildNodeRemoved) , DistributedNodesUpdated (DistributedNodesUpdated) , DocumentUpdated (DocumentUpdated) , InlineStyleInvalidated (InlineStyleInvalidated) , PseudoElementAdded (PseudoElementAdded) , TopLayerElementsUpdated (TopLayerElementsUpdated) , ScrollableFlagUpdated (ScrollableFlagUpdated) , AdRelatedStateUpdated (AdRelatedStateUpdated) , AffectedByStartingStylesFlagUpdated (AffectedByStartingStylesFlagUpdated) , PseudoElementRemoved (PseudoElementRemoved) , SetChildNodes (SetChildNodes) , ShadowRootPopped (ShadowRootPopped) , ShadowRootPushed (ShadowRootPushed) } + identifiable);
@@ -24,6 +24,9 @@ impl DomStorageItemAdded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DomStorageItemAdded {
+    const IDENTIFIER: &'static str = "DOMStorage.domStorageItemAdded";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DomStorageItemRemovedParams {
     #[serde(rename = "storageId")]
@@ -47,6 +50,9 @@ impl DomStorageItemRemoved {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DomStorageItemRemoved {
+    const IDENTIFIER: &'static str = "DOMStorage.domStorageItemRemoved";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DomStorageItemUpdatedParams {
     #[serde(rename = "storageId")]
@@ -74,6 +80,9 @@ impl DomStorageItemUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DomStorageItemUpdated {
+    const IDENTIFIER: &'static str = "DOMStorage.domStorageItemUpdated";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DomStorageItemsClearedParams {
     #[serde(rename = "storageId")]
@@ -95,4 +104,7 @@ impl DomStorageItemsCleared {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DomStorageItemsCleared {
+    const IDENTIFIER: &'static str = "DOMStorage.domStorageItemsCleared";
+}
 group_enum ! (DomStorageEvents { DomStorageItemAdded (DomStorageItemAdded) , DomStorageItemRemoved (DomStorageItemRemoved) , DomStorageItemUpdated (DomStorageItemUpdated) , DomStorageItemsCleared (DomStorageItemsCleared) } + identifiable);
@@ -19,4 +19,7 @@ impl VirtualTimeBudgetExpired {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for VirtualTimeBudgetExpired {
+    const IDENTIFIER: &'static str = "Emulation.virtualTimeBudgetExpired";
+}
 group_enum ! (EmulationEvents { VirtualTimeBudgetExpired (VirtualTimeBudgetExpired) } + identifiable);
@@ -32,6 +32,9 @@ impl DialogShown {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DialogShown {
+    const IDENTIFIER: &'static str = "FedCm.dialogShown";
+}
 #[doc = "Triggered when a dialog is closed, either by user action, JS abort,\nor a command below.\n[dialogClosed](https://chromedevtools.github.io/devtools-protocol/tot/FedCm/#event-dialogClosed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DialogClosedParams {
@@ -55,4 +58,7 @@ impl DialogClosed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DialogClosed {
+    const IDENTIFIER: &'static str = "FedCm.dialogClosed";
+}
 group_enum ! (FedCmEvents { DialogShown (DialogShown) , DialogClosed (DialogClosed) } + identifiable);
@@ -62,6 +62,9 @@ impl RequestPaused {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for RequestPaused {
+    const IDENTIFIER: &'static str = "Fetch.requestPaused";
+}
 #[doc = "Issued when the domain is enabled with handleAuthRequests set to true.\nThe request is paused until client responds with continueWithAuth.\n[authRequired](https://chromedevtools.github.io/devtools-protocol/tot/Fetch/#event-authRequired)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AuthRequiredParams {
@@ -98,4 +101,7 @@ impl AuthRequired {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AuthRequired {
+    const IDENTIFIER: &'static str = "Fetch.authRequired";
+}
 group_enum ! (FetchEvents { RequestPaused (RequestPaused) , AuthRequired (AuthRequired) } + identifiable);
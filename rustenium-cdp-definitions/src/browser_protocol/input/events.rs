@@ -22,4 +22,7 @@ impl DragIntercepted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DragIntercepted {
+    const IDENTIFIER: &'static str = "Input.dragIntercepted";
+}
 group_enum ! (InputEvents { DragIntercepted (DragIntercepted) } + identifiable);
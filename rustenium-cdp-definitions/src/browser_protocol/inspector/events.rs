@@ -23,6 +23,9 @@ impl Detached {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for Detached {
+    const IDENTIFIER: &'static str = "Inspector.detached";
+}
 #[doc = "Fired when debugging target has crashed\n[targetCrashed](https://chromedevtools.github.io/devtools-protocol/tot/Inspector/#event-targetCrashed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TargetCrashedParams {}
@@ -43,6 +46,9 @@ impl TargetCrashed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for TargetCrashed {
+    const IDENTIFIER: &'static str = "Inspector.targetCrashed";
+}
 #[doc = "Fired when debugging target has reloaded after crash\n[targetReloadedAfterCrash](https://chromedevtools.github.io/devtools-protocol/tot/Inspector/#event-targetReloadedAfterCrash)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TargetReloadedAfterCrashParams {}
@@ -63,6 +69,9 @@ impl TargetReloadedAfterCrash {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for TargetReloadedAfterCrash {
+    const IDENTIFIER: &'static str = "Inspector.targetReloadedAfterCrash";
+}
 #[doc = "Fired on worker targets when main worker script and any imported scripts have been evaluated.\n[workerScriptLoaded](https://chromedevtools.github.io/devtools-protocol/tot/Inspector/#event-workerScriptLoaded)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WorkerScriptLoadedParams {}
@@ -83,4 +92,7 @@ impl WorkerScriptLoaded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WorkerScriptLoaded {
+    const IDENTIFIER: &'static str = "Inspector.workerScriptLoaded";
+}
 group_enum ! (InspectorEvents { Detached (Detached) , TargetCrashed (TargetCrashed) , TargetReloadedAfterCrash (TargetReloadedAfterCrash) , WorkerScriptLoaded (WorkerScriptLoaded) } + identifiable);
@@ -24,6 +24,9 @@ impl LayerPainted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for LayerPainted {
+    const IDENTIFIER: &'static str = "LayerTree.layerPainted";
+}
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct LayerTreeDidChangeParams {
     #[doc = "Layer tree, absent if not in the compositing mode."]
@@ -48,4 +51,7 @@ impl LayerTreeDidChange {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for LayerTreeDidChange {
+    const IDENTIFIER: &'static str = "LayerTree.layerTreeDidChange";
+}
 group_enum ! (LayerTreeEvents { LayerPainted (LayerPainted) , LayerTreeDidChange (LayerTreeDidChange) } + identifiable);
@@ -23,4 +23,7 @@ impl EntryAdded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for EntryAdded {
+    const IDENTIFIER: &'static str = "Log.entryAdded";
+}
 group_enum ! (LogEvents { EntryAdded (EntryAdded) } + identifiable);
@@ -25,6 +25,9 @@ impl PlayerPropertiesChanged {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PlayerPropertiesChanged {
+    const IDENTIFIER: &'static str = "Media.playerPropertiesChanged";
+}
 #[doc = "Send events as a list, allowing them to be batched on the browser for less\ncongestion. If batched, events must ALWAYS be in chronological order.\n[playerEventsAdded](https://chromedevtools.github.io/devtools-protocol/tot/Media/#event-playerEventsAdded)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlayerEventsAddedParams {
@@ -51,6 +54,9 @@ impl PlayerEventsAdded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PlayerEventsAdded {
+    const IDENTIFIER: &'static str = "Media.playerEventsAdded";
+}
 #[doc = "Send a list of any messages that need to be delivered.\n[playerMessagesLogged](https://chromedevtools.github.io/devtools-protocol/tot/Media/#event-playerMessagesLogged)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlayerMessagesLoggedParams {
@@ -77,6 +83,9 @@ impl PlayerMessagesLogged {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PlayerMessagesLogged {
+    const IDENTIFIER: &'static str = "Media.playerMessagesLogged";
+}
 #[doc = "Send a list of any errors that need to be delivered.\n[playerErrorsRaised](https://chromedevtools.github.io/devtools-protocol/tot/Media/#event-playerErrorsRaised)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlayerErrorsRaisedParams {
@@ -103,6 +112,9 @@ impl PlayerErrorsRaised {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PlayerErrorsRaised {
+    const IDENTIFIER: &'static str = "Media.playerErrorsRaised";
+}
 #[doc = "Called whenever a player is created, or when a new agent joins and receives\na list of active players. If an agent is restored, it will receive one\nevent for each active player.\n[playerCreated](https://chromedevtools.github.io/devtools-protocol/tot/Media/#event-playerCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlayerCreatedParams {
@@ -126,4 +138,7 @@ impl PlayerCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PlayerCreated {
+    const IDENTIFIER: &'static str = "Media.playerCreated";
+}
 group_enum ! (MediaEvents { PlayerPropertiesChanged (PlayerPropertiesChanged) , PlayerEventsAdded (PlayerEventsAdded) , PlayerMessagesLogged (PlayerMessagesLogged) , PlayerErrorsRaised (PlayerErrorsRaised) , PlayerCreated (PlayerCreated) } + identifiable);
@@ -37,6 +37,9 @@ impl DataReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DataReceived {
+    const IDENTIFIER: &'static str = "Network.dataReceived";
+}
 #[doc = "Fired when EventSource message is received.\n[eventSourceMessageReceived](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-eventSourceMessageReceived)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EventSourceMessageReceivedParams {
@@ -73,6 +76,9 @@ impl EventSourceMessageReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for EventSourceMessageReceived {
+    const IDENTIFIER: &'static str = "Network.eventSourceMessageReceived";
+}
 #[doc = "Fired when HTTP request has failed to load.\n[loadingFailed](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-loadingFailed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoadingFailedParams {
@@ -121,6 +127,9 @@ impl LoadingFailed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for LoadingFailed {
+    const IDENTIFIER: &'static str = "Network.loadingFailed";
+}
 #[doc = "Fired when HTTP request has finished loading.\n[loadingFinished](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-loadingFinished)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoadingFinishedParams {
@@ -151,6 +160,9 @@ impl LoadingFinished {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for LoadingFinished {
+    const IDENTIFIER: &'static str = "Network.loadingFinished";
+}
 #[doc = "Fired if request ended up loading from cache.\n[requestServedFromCache](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-requestServedFromCache)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RequestServedFromCacheParams {
@@ -175,6 +187,9 @@ impl RequestServedFromCache {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for RequestServedFromCache {
+    const IDENTIFIER: &'static str = "Network.requestServedFromCache";
+}
 #[doc = "Fired when page is about to send HTTP request.\n[requestWillBeSent](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-requestWillBeSent)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RequestWillBeSentParams {
@@ -245,6 +260,9 @@ impl RequestWillBeSent {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for RequestWillBeSent {
+    const IDENTIFIER: &'static str = "Network.requestWillBeSent";
+}
 #[doc = "Fired when resource loading priority is changed\n[resourceChangedPriority](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-resourceChangedPriority)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceChangedPriorityParams {
@@ -275,6 +293,9 @@ impl ResourceChangedPriority {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ResourceChangedPriority {
+    const IDENTIFIER: &'static str = "Network.resourceChangedPriority";
+}
 #[doc = "Fired when a signed exchange was received over the network\n[signedExchangeReceived](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-signedExchangeReceived)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SignedExchangeReceivedParams {
@@ -302,6 +323,9 @@ impl SignedExchangeReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for SignedExchangeReceived {
+    const IDENTIFIER: &'static str = "Network.signedExchangeReceived";
+}
 #[doc = "Fired when HTTP response is available.\n[responseReceived](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-responseReceived)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResponseReceivedParams {
@@ -346,6 +370,9 @@ impl ResponseReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ResponseReceived {
+    const IDENTIFIER: &'static str = "Network.responseReceived";
+}
 #[doc = "Fired when WebSocket is closed.\n[webSocketClosed](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-webSocketClosed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebSocketClosedParams {
@@ -373,6 +400,9 @@ impl WebSocketClosed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WebSocketClosed {
+    const IDENTIFIER: &'static str = "Network.webSocketClosed";
+}
 #[doc = "Fired upon WebSocket creation.\n[webSocketCreated](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-webSocketCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebSocketCreatedParams {
@@ -405,6 +435,9 @@ impl WebSocketCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WebSocketCreated {
+    const IDENTIFIER: &'static str = "Network.webSocketCreated";
+}
 #[doc = "Fired when WebSocket message error occurs.\n[webSocketFrameError](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-webSocketFrameError)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebSocketFrameErrorParams {
@@ -435,6 +468,9 @@ impl WebSocketFrameError {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WebSocketFrameError {
+    const IDENTIFIER: &'static str = "Network.webSocketFrameError";
+}
 #[doc = "Fired when WebSocket message is received.\n[webSocketFrameReceived](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-webSocketFrameReceived)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebSocketFrameReceivedParams {
@@ -465,6 +501,9 @@ impl WebSocketFrameReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WebSocketFrameReceived {
+    const IDENTIFIER: &'static str = "Network.webSocketFrameReceived";
+}
 #[doc = "Fired when WebSocket message is sent.\n[webSocketFrameSent](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-webSocketFrameSent)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebSocketFrameSentParams {
@@ -495,6 +534,9 @@ impl WebSocketFrameSent {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WebSocketFrameSent {
+    const IDENTIFIER: &'static str = "Network.webSocketFrameSent";
+}
 #[doc = "Fired when WebSocket handshake response becomes available.\n[webSocketHandshakeResponseReceived](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-webSocketHandshakeResponseReceived)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebSocketHandshakeResponseReceivedParams {
@@ -525,6 +567,9 @@ impl WebSocketHandshakeResponseReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WebSocketHandshakeResponseReceived {
+    const IDENTIFIER: &'static str = "Network.webSocketHandshakeResponseReceived";
+}
 #[doc = "Fired when WebSocket is about to initiate handshake.\n[webSocketWillSendHandshakeRequest](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-webSocketWillSendHandshakeRequest)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebSocketWillSendHandshakeRequestParams {
@@ -558,6 +603,9 @@ impl WebSocketWillSendHandshakeRequest {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WebSocketWillSendHandshakeRequest {
+    const IDENTIFIER: &'static str = "Network.webSocketWillSendHandshakeRequest";
+}
 #[doc = "Fired upon WebTransport creation.\n[webTransportCreated](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-webTransportCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebTransportCreatedParams {
@@ -593,6 +641,9 @@ impl WebTransportCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WebTransportCreated {
+    const IDENTIFIER: &'static str = "Network.webTransportCreated";
+}
 #[doc = "Fired when WebTransport handshake is finished.\n[webTransportConnectionEstablished](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-webTransportConnectionEstablished)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebTransportConnectionEstablishedParams {
@@ -620,6 +671,9 @@ impl WebTransportConnectionEstablished {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WebTransportConnectionEstablished {
+    const IDENTIFIER: &'static str = "Network.webTransportConnectionEstablished";
+}
 #[doc = "Fired when WebTransport is disposed.\n[webTransportClosed](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-webTransportClosed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebTransportClosedParams {
@@ -647,6 +701,9 @@ impl WebTransportClosed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WebTransportClosed {
+    const IDENTIFIER: &'static str = "Network.webTransportClosed";
+}
 #[doc = "Fired upon direct_socket.TCPSocket creation.\n[directTCPSocketCreated](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directTCPSocketCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectTcpSocketCreatedParams {
@@ -683,6 +740,9 @@ impl DirectTcpSocketCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectTcpSocketCreated {
+    const IDENTIFIER: &'static str = "Network.directTCPSocketCreated";
+}
 #[doc = "Fired when direct_socket.TCPSocket connection is opened.\n[directTCPSocketOpened](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directTCPSocketOpened)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectTcpSocketOpenedParams {
@@ -722,6 +782,9 @@ impl DirectTcpSocketOpened {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectTcpSocketOpened {
+    const IDENTIFIER: &'static str = "Network.directTCPSocketOpened";
+}
 #[doc = "Fired when direct_socket.TCPSocket is aborted.\n[directTCPSocketAborted](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directTCPSocketAborted)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectTcpSocketAbortedParams {
@@ -749,6 +812,9 @@ impl DirectTcpSocketAborted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectTcpSocketAborted {
+    const IDENTIFIER: &'static str = "Network.directTCPSocketAborted";
+}
 #[doc = "Fired when direct_socket.TCPSocket is closed.\n[directTCPSocketClosed](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directTCPSocketClosed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectTcpSocketClosedParams {
@@ -774,6 +840,9 @@ impl DirectTcpSocketClosed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectTcpSocketClosed {
+    const IDENTIFIER: &'static str = "Network.directTCPSocketClosed";
+}
 #[doc = "Fired when data is sent to tcp direct socket stream.\n[directTCPSocketChunkSent](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directTCPSocketChunkSent)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectTcpSocketChunkSentParams {
@@ -801,6 +870,9 @@ impl DirectTcpSocketChunkSent {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectTcpSocketChunkSent {
+    const IDENTIFIER: &'static str = "Network.directTCPSocketChunkSent";
+}
 #[doc = "Fired when data is received from tcp direct socket stream.\n[directTCPSocketChunkReceived](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directTCPSocketChunkReceived)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectTcpSocketChunkReceivedParams {
@@ -828,6 +900,9 @@ impl DirectTcpSocketChunkReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectTcpSocketChunkReceived {
+    const IDENTIFIER: &'static str = "Network.directTCPSocketChunkReceived";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectUdpSocketJoinedMulticastGroupParams {
     #[serde(rename = "identifier")]
@@ -851,6 +926,9 @@ impl DirectUdpSocketJoinedMulticastGroup {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectUdpSocketJoinedMulticastGroup {
+    const IDENTIFIER: &'static str = "Network.directUDPSocketJoinedMulticastGroup";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectUdpSocketLeftMulticastGroupParams {
     #[serde(rename = "identifier")]
@@ -874,6 +952,9 @@ impl DirectUdpSocketLeftMulticastGroup {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectUdpSocketLeftMulticastGroup {
+    const IDENTIFIER: &'static str = "Network.directUDPSocketLeftMulticastGroup";
+}
 #[doc = "Fired upon direct_socket.UDPSocket creation.\n[directUDPSocketCreated](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directUDPSocketCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectUdpSocketCreatedParams {
@@ -905,6 +986,9 @@ impl DirectUdpSocketCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectUdpSocketCreated {
+    const IDENTIFIER: &'static str = "Network.directUDPSocketCreated";
+}
 #[doc = "Fired when direct_socket.UDPSocket connection is opened.\n[directUDPSocketOpened](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directUDPSocketOpened)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectUdpSocketOpenedParams {
@@ -944,6 +1028,9 @@ impl DirectUdpSocketOpened {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectUdpSocketOpened {
+    const IDENTIFIER: &'static str = "Network.directUDPSocketOpened";
+}
 #[doc = "Fired when direct_socket.UDPSocket is aborted.\n[directUDPSocketAborted](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directUDPSocketAborted)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectUdpSocketAbortedParams {
@@ -971,6 +1058,9 @@ impl DirectUdpSocketAborted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectUdpSocketAborted {
+    const IDENTIFIER: &'static str = "Network.directUDPSocketAborted";
+}
 #[doc = "Fired when direct_socket.UDPSocket is closed.\n[directUDPSocketClosed](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directUDPSocketClosed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectUdpSocketClosedParams {
@@ -996,6 +1086,9 @@ impl DirectUdpSocketClosed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectUdpSocketClosed {
+    const IDENTIFIER: &'static str = "Network.directUDPSocketClosed";
+}
 #[doc = "Fired when message is sent to udp direct socket stream.\n[directUDPSocketChunkSent](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directUDPSocketChunkSent)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectUdpSocketChunkSentParams {
@@ -1023,6 +1116,9 @@ impl DirectUdpSocketChunkSent {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectUdpSocketChunkSent {
+    const IDENTIFIER: &'static str = "Network.directUDPSocketChunkSent";
+}
 #[doc = "Fired when message is received from udp direct socket stream.\n[directUDPSocketChunkReceived](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-directUDPSocketChunkReceived)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectUdpSocketChunkReceivedParams {
@@ -1050,6 +1146,9 @@ impl DirectUdpSocketChunkReceived {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DirectUdpSocketChunkReceived {
+    const IDENTIFIER: &'static str = "Network.directUDPSocketChunkReceived";
+}
 #[doc = "Fired when additional information about a requestWillBeSent event is available from the\nnetwork stack. Not every requestWillBeSent event will have an additional\nrequestWillBeSentExtraInfo fired for it, and there is no guarantee whether requestWillBeSent\nor requestWillBeSentExtraInfo will be fired first for the same request.\n[requestWillBeSentExtraInfo](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-requestWillBeSentExtraInfo)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RequestWillBeSentExtraInfoParams {
@@ -1104,6 +1203,9 @@ impl RequestWillBeSentExtraInfo {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for RequestWillBeSentExtraInfo {
+    const IDENTIFIER: &'static str = "Network.requestWillBeSentExtraInfo";
+}
 #[doc = "Fired when additional information about a responseReceived event is available from the network\nstack. Not every responseReceived event will have an additional responseReceivedExtraInfo for\nit, and responseReceivedExtraInfo may be fired before or after responseReceived.\n[responseReceivedExtraInfo](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-responseReceivedExtraInfo)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResponseReceivedExtraInfoParams {
@@ -1161,6 +1263,9 @@ impl ResponseReceivedExtraInfo {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ResponseReceivedExtraInfo {
+    const IDENTIFIER: &'static str = "Network.responseReceivedExtraInfo";
+}
 #[doc = "Fired when 103 Early Hints headers is received in addition to the common response.\nNot every responseReceived event will have an responseReceivedEarlyHints fired.\nOnly one responseReceivedEarlyHints may be fired for eached responseReceived event.\n[responseReceivedEarlyHints](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-responseReceivedEarlyHints)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResponseReceivedEarlyHintsParams {
@@ -1188,6 +1293,9 @@ impl ResponseReceivedEarlyHints {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ResponseReceivedEarlyHints {
+    const IDENTIFIER: &'static str = "Network.responseReceivedEarlyHints";
+}
 #[doc = "Fired exactly once for each Trust Token operation. Depending on\nthe type of the operation and whether the operation succeeded or\nfailed, the event is fired before the corresponding request was sent\nor after the response was received.\n[trustTokenOperationDone](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-trustTokenOperationDone)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TrustTokenOperationDoneParams {
@@ -1261,6 +1369,9 @@ impl TrustTokenOperationDone {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for TrustTokenOperationDone {
+    const IDENTIFIER: &'static str = "Network.trustTokenOperationDone";
+}
 #[doc = "Fired once security policy has been updated.\n[policyUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-policyUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PolicyUpdatedParams {}
@@ -1281,6 +1392,9 @@ impl PolicyUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PolicyUpdated {
+    const IDENTIFIER: &'static str = "Network.policyUpdated";
+}
 #[doc = "Is sent whenever a new report is added.\nAnd after 'enableReportingApi' for all existing reports.\n[reportingApiReportAdded](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-reportingApiReportAdded)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReportingApiReportAddedParams {
@@ -1304,6 +1418,9 @@ impl ReportingApiReportAdded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ReportingApiReportAdded {
+    const IDENTIFIER: &'static str = "Network.reportingApiReportAdded";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReportingApiReportUpdatedParams {
     #[serde(rename = "report")]
@@ -1325,6 +1442,9 @@ impl ReportingApiReportUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ReportingApiReportUpdated {
+    const IDENTIFIER: &'static str = "Network.reportingApiReportUpdated";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReportingApiEndpointsChangedForOriginParams {
     #[doc = "Origin of the document(s) which configured the endpoints."]
@@ -1350,6 +1470,9 @@ impl ReportingApiEndpointsChangedForOrigin {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ReportingApiEndpointsChangedForOrigin {
+    const IDENTIFIER: &'static str = "Network.reportingApiEndpointsChangedForOrigin";
+}
 #[doc = "Triggered when the initial set of device bound sessions is added.\n[deviceBoundSessionsAdded](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-deviceBoundSessionsAdded)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeviceBoundSessionsAddedParams {
@@ -1375,6 +1498,9 @@ impl DeviceBoundSessionsAdded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DeviceBoundSessionsAdded {
+    const IDENTIFIER: &'static str = "Network.deviceBoundSessionsAdded";
+}
 #[doc = "Triggered when a device bound session event occurs.\n[deviceBoundSessionEventOccurred](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-deviceBoundSessionEventOccurred)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeviceBoundSessionEventOccurredParams {
@@ -1427,4 +1553,7 @@ impl DeviceBoundSessionEventOccurred {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DeviceBoundSessionEventOccurred {
+    const IDENTIFIER: &'static str = "Network.deviceBoundSessionEventOccurred";
+}
 group_enum ! (NetworkEvents { DataReceived (DataReceived) , EventSourceMessageReceived (EventSourceMessageReceived) , LoadingFailed (LoadingFailed) , LoadingFinished (LoadingFinished) , RequestServedFromCache (RequestServedFromCache) , RequestWillBeSent (RequestWillBeSent) , ResourceChangedPriority (ResourceChangedPriority) , SignedExchangeReceived (SignedExchangeReceived) , ResponseReceived (ResponseReceived) , WebSocketClosed (WebSocketClosed) , WebSocketCreated (WebSocketCreated) , WebSocketFrameError (WebSocketFrameError) , WebSocketFrameReceived (WebSocketFrameReceived) , WebSocketFrameSent (WebSocketFrameSent) , WebSocketHandshakeResponseReceived (WebSocketHandshakeResponseReceived) , WebSocketWillSendHandshakeRequest (WebSocketWillSendHandshakeRequest) , WebTransportCreated (WebTransportCreated) , WebTransportConnectionEstablished (WebTransportConnectionEstablished) , WebTransportClosed (WebTransportClosed) , DirectTcpSocketCreated (DirectTcpSocketCreated) , DirectTcpSocketOpened (DirectTcpSocketOpened) , DirectTcpSocketAborted (DirectTcpSocketAborted) , DirectTcpSocketClosed (DirectTcpSocketClosed) , DirectTcpSocketChunkSent (DirectTcpSocketChunkSent) , DirectTcpSocketChunkReceived (DirectTcpSocketChunkReceived) , DirectUdpSocketJoinedMulticastGroup (DirectUdpSocketJoinedMulticastGroup) , DirectUdpSocketLeftMulticastGroup (DirectUdpSocketLeftMulticastGroup) , DirectUdpSocketCreated (DirectUdpSocketCreated) , DirectUdpSocketOpened (DirectUdpSocketOpened) , DirectUdpSocketAborted (DirectUdpSocketAborted) , DirectUdpSocketClosed (DirectUdpSocketClosed) , DirectUdpSocketChunkSent (DirectUdpSocketChunkSent) , DirectUdpSocketChunkReceived (DirectUdpSocketChunkReceived) , RequestWillBeSentExtraInfo (RequestWillBeSentExtraInfo) , ResponseReceivedExtraInfo (ResponseReceivedExtraInfo) , ResponseReceivedEarlyHints (ResponseReceivedEarlyHints) , TrustTokenOperationDone (TrustTokenOperationDone) , PolicyUpdated (PolicyUpdated) , ReportingApiReportAdded (ReportingApiReportAdded) , ReportingApiReportUpdated (ReportingApiReportUpdated) , ReportingApiEndpointsChangedForOrigin (ReportingApiEndpointsChangedForOrigin) , DeviceBoundSessionsAdded (DeviceBoundSessionsAdded) , DeviceBoundSessionEventOccurred (DeviceBoundSessionEventOccurred) } + identifiable);
@@ -23,6 +23,9 @@ impl InspectNodeRequested {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for InspectNodeRequested {
+    const IDENTIFIER: &'static str = "Overlay.inspectNodeRequested";
+}
 #[doc = "Fired when the node should be highlighted. This happens after call to `setInspectMode`.\n[nodeHighlightRequested](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#event-nodeHighlightRequested)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NodeHighlightRequestedParams {
@@ -46,6 +49,9 @@ impl NodeHighlightRequested {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for NodeHighlightRequested {
+    const IDENTIFIER: &'static str = "Overlay.nodeHighlightRequested";
+}
 #[doc = "Fired when user asks to capture screenshot of some area on the page.\n[screenshotRequested](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#event-screenshotRequested)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScreenshotRequestedParams {
@@ -70,6 +76,9 @@ impl ScreenshotRequested {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ScreenshotRequested {
+    const IDENTIFIER: &'static str = "Overlay.screenshotRequested";
+}
 #[doc = "Fired when user asks to show the Inspect panel.\n[inspectPanelShowRequested](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#event-inspectPanelShowRequested)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InspectPanelShowRequestedParams {
@@ -94,6 +103,9 @@ impl InspectPanelShowRequested {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for InspectPanelShowRequested {
+    const IDENTIFIER: &'static str = "Overlay.inspectPanelShowRequested";
+}
 #[doc = "Fired when user asks to restore the Inspected Element floating window.\n[inspectedElementWindowRestored](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#event-inspectedElementWindowRestored)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InspectedElementWindowRestoredParams {
@@ -118,6 +130,9 @@ impl InspectedElementWindowRestored {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for InspectedElementWindowRestored {
+    const IDENTIFIER: &'static str = "Overlay.inspectedElementWindowRestored";
+}
 #[doc = "Fired when user cancels the inspect mode.\n[inspectModeCanceled](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#event-inspectModeCanceled)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InspectModeCanceledParams {}
@@ -138,4 +153,7 @@ impl InspectModeCanceled {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for InspectModeCanceled {
+    const IDENTIFIER: &'static str = "Overlay.inspectModeCanceled";
+}
 group_enum ! (OverlayEvents { InspectNodeRequested (InspectNodeRequested) , NodeHighlightRequested (NodeHighlightRequested) , ScreenshotRequested (ScreenshotRequested) , InspectPanelShowRequested (InspectPanelShowRequested) , InspectedElementWindowRestored (InspectedElementWindowRestored) , InspectModeCanceled (InspectModeCanceled) } + identifiable);
@@ -20,6 +20,9 @@ impl DomContentEventFired {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DomContentEventFired {
+    const IDENTIFIER: &'static str = "Page.domContentEventFired";
+}
 #[doc = "Emitted only when `page.interceptFileChooser` is enabled.\n[fileChooserOpened](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-fileChooserOpened)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FileChooserOpenedParams {
@@ -60,6 +63,9 @@ impl FileChooserOpened {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FileChooserOpened {
+    const IDENTIFIER: &'static str = "Page.fileChooserOpened";
+}
 #[doc = "Fired when frame has been attached to its parent.\n[frameAttached](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-frameAttached)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameAttachedParams {
@@ -92,6 +98,9 @@ impl FrameAttached {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FrameAttached {
+    const IDENTIFIER: &'static str = "Page.frameAttached";
+}
 #[doc = "Fired when frame has been detached from its parent.\n[frameDetached](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-frameDetached)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameDetachedParams {
@@ -127,6 +136,9 @@ impl FrameDetached {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FrameDetached {
+    const IDENTIFIER: &'static str = "Page.frameDetached";
+}
 #[doc = "Fired before frame subtree is detached. Emitted before any frame of the\nsubtree is actually detached.\n[frameSubtreeWillBeDetached](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-frameSubtreeWillBeDetached)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameSubtreeWillBeDetachedParams {
@@ -151,6 +163,9 @@ impl FrameSubtreeWillBeDetached {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FrameSubtreeWillBeDetached {
+    const IDENTIFIER: &'static str = "Page.frameSubtreeWillBeDetached";
+}
 #[doc = "Fired once navigation of the frame has completed. Frame is now associated with the new loader.\n[frameNavigated](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-frameNavigated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameNavigatedParams {
@@ -177,6 +192,9 @@ impl FrameNavigated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FrameNavigated {
+    const IDENTIFIER: &'static str = "Page.frameNavigated";
+}
 #[doc = "Fired when opening document to write to.\n[documentOpened](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-documentOpened)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DocumentOpenedParams {
@@ -201,6 +219,9 @@ impl DocumentOpened {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DocumentOpened {
+    const IDENTIFIER: &'static str = "Page.documentOpened";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameResizedParams {}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -219,6 +240,9 @@ impl FrameResized {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FrameResized {
+    const IDENTIFIER: &'static str = "Page.frameResized";
+}
 #[doc = "Fired when a navigation starts. This event is fired for both\nrenderer-initiated and browser-initiated navigations. For renderer-initiated\nnavigations, the event is fired after `frameRequestedNavigation`.\nNavigation may still be cancelled after the event is issued. Multiple events\ncan be fired for a single navigation, for example, when a same-document\nnavigation becomes a cross-document navigation (such as in the case of a\nframeset).\n[frameStartedNavigating](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-frameStartedNavigating)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameStartedNavigatingParams {
@@ -270,6 +294,9 @@ impl FrameStartedNavigating {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FrameStartedNavigating {
+    const IDENTIFIER: &'static str = "Page.frameStartedNavigating";
+}
 #[doc = "Fired when a renderer-initiated navigation is requested.\nNavigation may still be cancelled after the event is issued.\n[frameRequestedNavigation](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-frameRequestedNavigation)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameRequestedNavigationParams {
@@ -303,6 +330,9 @@ impl FrameRequestedNavigation {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FrameRequestedNavigation {
+    const IDENTIFIER: &'static str = "Page.frameRequestedNavigation";
+}
 #[doc = "Fired when frame has started loading.\n[frameStartedLoading](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-frameStartedLoading)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameStartedLoadingParams {
@@ -327,6 +357,9 @@ impl FrameStartedLoading {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FrameStartedLoading {
+    const IDENTIFIER: &'static str = "Page.frameStartedLoading";
+}
 #[doc = "Fired when frame has stopped loading.\n[frameStoppedLoading](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-frameStoppedLoading)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameStoppedLoadingParams {
@@ -351,6 +384,9 @@ impl FrameStoppedLoading {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for FrameStoppedLoading {
+    const IDENTIFIER: &'static str = "Page.frameStoppedLoading";
+}
 #[doc = "Fired when interstitial page was hidden\n[interstitialHidden](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-interstitialHidden)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InterstitialHiddenParams {}
@@ -371,6 +407,9 @@ impl InterstitialHidden {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for InterstitialHidden {
+    const IDENTIFIER: &'static str = "Page.interstitialHidden";
+}
 #[doc = "Fired when interstitial page was shown\n[interstitialShown](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-interstitialShown)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InterstitialShownParams {}
@@ -391,6 +430,9 @@ impl InterstitialShown {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for InterstitialShown {
+    const IDENTIFIER: &'static str = "Page.interstitialShown";
+}
 #[doc = "Fired when a JavaScript initiated dialog (alert, confirm, prompt, or onbeforeunload) has been\nclosed.\n[javascriptDialogClosed](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-javascriptDialogClosed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JavascriptDialogClosedParams {
@@ -421,6 +463,9 @@ impl JavascriptDialogClosed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for JavascriptDialogClosed {
+    const IDENTIFIER: &'static str = "Page.javascriptDialogClosed";
+}
 #[doc = "Fired when a JavaScript initiated dialog (alert, confirm, prompt, or onbeforeunload) is about to\nopen.\n[javascriptDialogOpening](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-javascriptDialogOpening)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JavascriptDialogOpeningParams {
@@ -462,6 +507,9 @@ impl JavascriptDialogOpening {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for JavascriptDialogOpening {
+    const IDENTIFIER: &'static str = "Page.javascriptDialogOpening";
+}
 #[doc = "Fired for lifecycle events (navigation, load, paint, etc) in the current\ntarget (including local frames).\n[lifecycleEvent](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-lifecycleEvent)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LifecycleEventParams {
@@ -493,6 +541,9 @@ impl LifecycleEvent {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for LifecycleEvent {
+    const IDENTIFIER: &'static str = "Page.lifecycleEvent";
+}
 #[doc = "Fired for failed bfcache history navigations if BackForwardCache feature is enabled. Do\nnot assume any ordering with the Page.frameNavigated event. This event is fired only for\nmain-frame history navigation where the document changes (non-same-document navigations),\nwhen bfcache navigation fails.\n[backForwardCacheNotUsed](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-backForwardCacheNotUsed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BackForwardCacheNotUsedParams {
@@ -530,6 +581,9 @@ impl BackForwardCacheNotUsed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for BackForwardCacheNotUsed {
+    const IDENTIFIER: &'static str = "Page.backForwardCacheNotUsed";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoadEventFiredParams {
     #[serde(rename = "timestamp")]
@@ -551,6 +605,9 @@ impl LoadEventFired {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for LoadEventFired {
+    const IDENTIFIER: &'static str = "Page.loadEventFired";
+}
 #[doc = "Fired when same-document navigation happens, e.g. due to history API usage or anchor navigation.\n[navigatedWithinDocument](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-navigatedWithinDocument)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NavigatedWithinDocumentParams {
@@ -594,6 +651,9 @@ impl NavigatedWithinDocument {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for NavigatedWithinDocument {
+    const IDENTIFIER: &'static str = "Page.navigatedWithinDocument";
+}
 #[doc = "Compressed image data requested by the `startScreencast`.\n[screencastFrame](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-screencastFrame)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScreencastFrameParams {
@@ -624,6 +684,9 @@ impl ScreencastFrame {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ScreencastFrame {
+    const IDENTIFIER: &'static str = "Page.screencastFrame";
+}
 #[doc = "Fired when the page with currently enabled screencast was shown or hidden `.\n[screencastVisibilityChanged](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-screencastVisibilityChanged)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScreencastVisibilityChangedParams {
@@ -648,6 +711,9 @@ impl ScreencastVisibilityChanged {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ScreencastVisibilityChanged {
+    const IDENTIFIER: &'static str = "Page.screencastVisibilityChanged";
+}
 #[doc = "Fired when a new window is going to be opened, via window.open(), link click, form submission,\netc.\n[windowOpen](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-windowOpen)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WindowOpenParams {
@@ -682,6 +748,9 @@ impl WindowOpen {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WindowOpen {
+    const IDENTIFIER: &'static str = "Page.windowOpen";
+}
 #[doc = "Issued for every compilation cache generated.\n[compilationCacheProduced](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-compilationCacheProduced)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CompilationCacheProducedParams {
@@ -708,4 +777,7 @@ impl CompilationCacheProduced {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for CompilationCacheProduced {
+    const IDENTIFIER: &'static str = "Page.compilationCacheProduced";
+}
 group_enum ! (PageEvents { DomContentEventFired (DomContentEventFired) , FileChooserOpened (FileChooserOpened) , FrameAttached (FrameAttached) , FrameDetached (FrameDetached) , FrameSubtreeWillBeDetached (FrameSubtreeWillBeDetached) , FrameNavigated (FrameNavigated) , DocumentOpened (DocumentOpened) , FrameResized (FrameResized) , FrameStartedNavigating (FrameStartedNavigating) , FrameRequestedNavigation (FrameRequestedNavigation) , FrameStartedLoading (FrameStartedLoading) , FrameStoppedLoading (FrameStoppedLoading) , InterstitialHidden (InterstitialHidden) , InterstitialShown (InterstitialShown) , JavascriptDialogClosed (JavascriptDialogClosed) , JavascriptDialogOpening (JavascriptDialogOpening) , LifecycleEvent (LifecycleEvent) , BackForwardCacheNotUsed (BackForwardCacheNotUsed) , LoadEventFired (LoadEventFired) , NavigatedWithinDocument (NavigatedWithinDocument) , ScreencastFrame (ScreencastFrame) , ScreencastVisibilityChanged (ScreencastVisibilityChanged) , WindowOpen (WindowOpen) , CompilationCacheProduced (CompilationCacheProduced) } + identifiable);
@@ -27,4 +27,7 @@ impl Metrics {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for Metrics {
+    const IDENTIFIER: &'static str = "Performance.metrics";
+}
 group_enum ! (PerformanceEvents { Metrics (Metrics) } + identifiable);
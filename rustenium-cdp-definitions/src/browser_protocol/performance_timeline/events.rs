@@ -22,4 +22,7 @@ impl TimelineEventAdded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for TimelineEventAdded {
+    const IDENTIFIER: &'static str = "PerformanceTimeline.timelineEventAdded";
+}
 group_enum ! (PerformanceTimelineEvents { TimelineEventAdded (TimelineEventAdded) } + identifiable);
@@ -22,6 +22,9 @@ impl RuleSetUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for RuleSetUpdated {
+    const IDENTIFIER: &'static str = "Preload.ruleSetUpdated";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RuleSetRemovedParams {
     #[serde(rename = "id")]
@@ -43,6 +46,9 @@ impl RuleSetRemoved {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for RuleSetRemoved {
+    const IDENTIFIER: &'static str = "Preload.ruleSetRemoved";
+}
 #[doc = "Fired when a preload enabled state is updated.\n[preloadEnabledStateUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Preload/#event-preloadEnabledStateUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PreloadEnabledStateUpdatedParams {
@@ -74,6 +80,9 @@ impl PreloadEnabledStateUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PreloadEnabledStateUpdated {
+    const IDENTIFIER: &'static str = "Preload.preloadEnabledStateUpdated";
+}
 #[doc = "Fired when a prefetch attempt is updated.\n[prefetchStatusUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Preload/#event-prefetchStatusUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PrefetchStatusUpdatedParams {
@@ -110,6 +119,9 @@ impl PrefetchStatusUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PrefetchStatusUpdated {
+    const IDENTIFIER: &'static str = "Preload.prefetchStatusUpdated";
+}
 #[doc = "Fired when a prerender attempt is updated.\n[prerenderStatusUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Preload/#event-prerenderStatusUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PrerenderStatusUpdatedParams {
@@ -150,6 +162,9 @@ impl PrerenderStatusUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PrerenderStatusUpdated {
+    const IDENTIFIER: &'static str = "Preload.prerenderStatusUpdated";
+}
 #[doc = "Send a list of sources for all preloading attempts in a document.\n[preloadingAttemptSourcesUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Preload/#event-preloadingAttemptSourcesUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PreloadingAttemptSourcesUpdatedParams {
@@ -176,4 +191,7 @@ impl PreloadingAttemptSourcesUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PreloadingAttemptSourcesUpdated {
+    const IDENTIFIER: &'static str = "Preload.preloadingAttemptSourcesUpdated";
+}
 group_enum ! (PreloadEvents { RuleSetUpdated (RuleSetUpdated) , RuleSetRemoved (RuleSetRemoved) , PreloadEnabledStateUpdated (PreloadEnabledStateUpdated) , PrefetchStatusUpdated (PrefetchStatusUpdated) , PrerenderStatusUpdated (PrerenderStatusUpdated) , PreloadingAttemptSourcesUpdated (PreloadingAttemptSourcesUpdated) } + identifiable);
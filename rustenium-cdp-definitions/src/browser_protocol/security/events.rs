@@ -23,4 +23,7 @@ impl VisibleSecurityStateChanged {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for VisibleSecurityStateChanged {
+    const IDENTIFIER: &'static str = "Security.visibleSecurityStateChanged";
+}
 group_enum ! (SecurityEvents { VisibleSecurityStateChanged (VisibleSecurityStateChanged) } + identifiable);
@@ -20,6 +20,9 @@ impl WorkerErrorReported {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WorkerErrorReported {
+    const IDENTIFIER: &'static str = "ServiceWorker.workerErrorReported";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WorkerRegistrationUpdatedParams {
     #[serde(rename = "registrations")]
@@ -42,6 +45,9 @@ impl WorkerRegistrationUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WorkerRegistrationUpdated {
+    const IDENTIFIER: &'static str = "ServiceWorker.workerRegistrationUpdated";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WorkerVersionUpdatedParams {
     #[serde(rename = "versions")]
@@ -64,4 +70,7 @@ impl WorkerVersionUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for WorkerVersionUpdated {
+    const IDENTIFIER: &'static str = "ServiceWorker.workerVersionUpdated";
+}
 group_enum ! (ServiceWorkerEvents { WorkerErrorReported (WorkerErrorReported) , WorkerRegistrationUpdated (WorkerRegistrationUpdated) , WorkerVersionUpdated (WorkerVersionUpdated) } + identifiable);
@@ -32,6 +32,9 @@ impl CacheStorageContentUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for CacheStorageContentUpdated {
+    const IDENTIFIER: &'static str = "Storage.cacheStorageContentUpdated";
+}
 #[doc = "A cache has been added/deleted.\n[cacheStorageListUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Storage/#event-cacheStorageListUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CacheStorageListUpdatedParams {
@@ -62,6 +65,9 @@ impl CacheStorageListUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for CacheStorageListUpdated {
+    const IDENTIFIER: &'static str = "Storage.cacheStorageListUpdated";
+}
 #[doc = "The origin's IndexedDB object store has been modified.\n[indexedDBContentUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Storage/#event-indexedDBContentUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IndexedDbContentUpdatedParams {
@@ -98,6 +104,9 @@ impl IndexedDbContentUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for IndexedDbContentUpdated {
+    const IDENTIFIER: &'static str = "Storage.indexedDBContentUpdated";
+}
 #[doc = "The origin's IndexedDB database list has been modified.\n[indexedDBListUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Storage/#event-indexedDBListUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IndexedDbListUpdatedParams {
@@ -128,6 +137,9 @@ impl IndexedDbListUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for IndexedDbListUpdated {
+    const IDENTIFIER: &'static str = "Storage.indexedDBListUpdated";
+}
 #[doc = "One of the interest groups was accessed. Note that these events are global\nto all targets sharing an interest group store.\n[interestGroupAccessed](https://chromedevtools.github.io/devtools-protocol/tot/Storage/#event-interestGroupAccessed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InterestGroupAccessedParams {
@@ -176,6 +188,9 @@ impl InterestGroupAccessed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for InterestGroupAccessed {
+    const IDENTIFIER: &'static str = "Storage.interestGroupAccessed";
+}
 #[doc = "An auction involving interest groups is taking place. These events are\ntarget-specific.\n[interestGroupAuctionEventOccurred](https://chromedevtools.github.io/devtools-protocol/tot/Storage/#event-interestGroupAuctionEventOccurred)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InterestGroupAuctionEventOccurredParams {
@@ -213,6 +228,9 @@ impl InterestGroupAuctionEventOccurred {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for InterestGroupAuctionEventOccurred {
+    const IDENTIFIER: &'static str = "Storage.interestGroupAuctionEventOccurred";
+}
 #[doc = "Specifies which auctions a particular network fetch may be related to, and\nin what role. Note that it is not ordered with respect to\nNetwork.requestWillBeSent (but will happen before loadingFinished\nloadingFailed).\n[interestGroupAuctionNetworkRequestCreated](https://chromedevtools.github.io/devtools-protocol/tot/Storage/#event-interestGroupAuctionNetworkRequestCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InterestGroupAuctionNetworkRequestCreatedParams {
@@ -242,6 +260,9 @@ impl InterestGroupAuctionNetworkRequestCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for InterestGroupAuctionNetworkRequestCreated {
+    const IDENTIFIER: &'static str = "Storage.interestGroupAuctionNetworkRequestCreated";
+}
 #[doc = "Shared storage was accessed by the associated page.\nThe following parameters are included in all events.\n[sharedStorageAccessed](https://chromedevtools.github.io/devtools-protocol/tot/Storage/#event-sharedStorageAccessed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SharedStorageAccessedParams {
@@ -284,6 +305,9 @@ impl SharedStorageAccessed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for SharedStorageAccessed {
+    const IDENTIFIER: &'static str = "Storage.sharedStorageAccessed";
+}
 #[doc = "A shared storage run or selectURL operation finished its execution.\nThe following parameters are included in all events.\n[sharedStorageWorkletOperationExecutionFinished](https://chromedevtools.github.io/devtools-protocol/tot/Storage/#event-sharedStorageWorkletOperationExecutionFinished)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SharedStorageWorkletOperationExecutionFinishedParams {
@@ -326,6 +350,9 @@ impl SharedStorageWorkletOperationExecutionFinished {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for SharedStorageWorkletOperationExecutionFinished {
+    const IDENTIFIER: &'static str = "Storage.sharedStorageWorkletOperationExecutionFinished";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StorageBucketCreatedOrUpdatedParams {
     #[serde(rename = "bucketInfo")]
@@ -347,6 +374,9 @@ impl StorageBucketCreatedOrUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for StorageBucketCreatedOrUpdated {
+    const IDENTIFIER: &'static str = "Storage.storageBucketCreatedOrUpdated";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StorageBucketDeletedParams {
     #[serde(rename = "bucketId")]
@@ -368,6 +398,9 @@ impl StorageBucketDeleted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for StorageBucketDeleted {
+    const IDENTIFIER: &'static str = "Storage.storageBucketDeleted";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributionReportingSourceRegisteredParams {
     #[serde(rename = "registration")]
@@ -391,6 +424,9 @@ impl AttributionReportingSourceRegistered {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AttributionReportingSourceRegistered {
+    const IDENTIFIER: &'static str = "Storage.attributionReportingSourceRegistered";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributionReportingTriggerRegisteredParams {
     #[serde(rename = "registration")]
@@ -416,6 +452,9 @@ impl AttributionReportingTriggerRegistered {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AttributionReportingTriggerRegistered {
+    const IDENTIFIER: &'static str = "Storage.attributionReportingTriggerRegistered";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributionReportingReportSentParams {
     #[serde(rename = "url")]
@@ -454,6 +493,9 @@ impl AttributionReportingReportSent {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AttributionReportingReportSent {
+    const IDENTIFIER: &'static str = "Storage.attributionReportingReportSent";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributionReportingVerboseDebugReportSentParams {
     #[serde(rename = "url")]
@@ -491,4 +533,7 @@ impl AttributionReportingVerboseDebugReportSent {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AttributionReportingVerboseDebugReportSent {
+    const IDENTIFIER: &'static str = "Storage.attributionReportingVerboseDebugReportSent";
+}
 group_enum ! (StorageEvents { CacheStorageContentUpdated (CacheStorageContentUpdated) , CacheStorageListUpdated (CacheStorageListUpdated) , IndexedDbContentUpdated (IndexedDbContentUpdated) , IndexedDbListUpdated (IndexedDbListUpdated) , InterestGroupAccessed (InterestGroupAccessed) , InterestGroupAuctionEventOccurred (InterestGroupAuctionEventOccurred) , InterestGroupAuctionNetworkRequestCreated (InterestGroupAuctionNetworkRequestCreated) , SharedStorageAccessed (SharedStorageAccessed) , SharedStorageWorkletOperationExecutionFinished (SharedStorageWorkletOperationExecutionFinished) , StorageBucketCreatedOrUpdated (StorageBucketCreatedOrUpdated) , StorageBucketDeleted (StorageBucketDeleted) , AttributionReportingSourceRegistered (AttributionReportingSourceRegistered) , AttributionReportingTriggerRegistered (AttributionReportingTriggerRegistered) , AttributionReportingReportSent (AttributionReportingReportSent) , AttributionReportingVerboseDebugReportSent (AttributionReportingVerboseDebugReportSent) } + identifiable);
@@ -27,6 +27,9 @@ impl AttachedToTarget {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AttachedToTarget {
+    const IDENTIFIER: &'static str = "Target.attachedToTarget";
+}
 #[doc = "Issued when detached from target for any reason (including `detachFromTarget` command). Can be\nissued multiple times per target if multiple sessions have been attached to it.\n[detachedFromTarget](https://chromedevtools.github.io/devtools-protocol/tot/Target/#event-detachedFromTarget)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DetachedFromTargetParams {
@@ -51,6 +54,9 @@ impl DetachedFromTarget {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DetachedFromTarget {
+    const IDENTIFIER: &'static str = "Target.detachedFromTarget";
+}
 #[doc = "Notifies about a new protocol message received from the session (as reported in\n`attachedToTarget` event).\n[receivedMessageFromTarget](https://chromedevtools.github.io/devtools-protocol/tot/Target/#event-receivedMessageFromTarget)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReceivedMessageFromTargetParams {
@@ -77,6 +83,9 @@ impl ReceivedMessageFromTarget {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ReceivedMessageFromTarget {
+    const IDENTIFIER: &'static str = "Target.receivedMessageFromTarget";
+}
 #[doc = "Issued when a possible inspection target is created.\n[targetCreated](https://chromedevtools.github.io/devtools-protocol/tot/Target/#event-targetCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TargetCreatedParams {
@@ -100,6 +109,9 @@ impl TargetCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for TargetCreated {
+    const IDENTIFIER: &'static str = "Target.targetCreated";
+}
 #[doc = "Issued when a target is destroyed.\n[targetDestroyed](https://chromedevtools.github.io/devtools-protocol/tot/Target/#event-targetDestroyed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TargetDestroyedParams {
@@ -123,6 +135,9 @@ impl TargetDestroyed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for TargetDestroyed {
+    const IDENTIFIER: &'static str = "Target.targetDestroyed";
+}
 #[doc = "Issued when a target has crashed.\n[targetCrashed](https://chromedevtools.github.io/devtools-protocol/tot/Target/#event-targetCrashed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TargetCrashedParams {
@@ -152,6 +167,9 @@ impl TargetCrashed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for TargetCrashed {
+    const IDENTIFIER: &'static str = "Target.targetCrashed";
+}
 #[doc = "Issued when some information about a target has changed. This only happens between\n`targetCreated` and `targetDestroyed`.\n[targetInfoChanged](https://chromedevtools.github.io/devtools-protocol/tot/Target/#event-targetInfoChanged)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TargetInfoChangedParams {
@@ -175,4 +193,7 @@ impl TargetInfoChanged {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for TargetInfoChanged {
+    const IDENTIFIER: &'static str = "Target.targetInfoChanged";
+}
 group_enum ! (TargetEvents { AttachedToTarget (AttachedToTarget) , DetachedFromTarget (DetachedFromTarget) , ReceivedMessageFromTarget (ReceivedMessageFromTarget) , TargetCreated (TargetCreated) , TargetDestroyed (TargetDestroyed) , TargetCrashed (TargetCrashed) , TargetInfoChanged (TargetInfoChanged) } + identifiable);
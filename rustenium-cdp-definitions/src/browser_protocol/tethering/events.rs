@@ -26,4 +26,7 @@ impl Accepted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for Accepted {
+    const IDENTIFIER: &'static str = "Tethering.accepted";
+}
 group_enum ! (TetheringEvents { Accepted (Accepted) } + identifiable);
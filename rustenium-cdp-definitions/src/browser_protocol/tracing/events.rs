@@ -33,6 +33,9 @@ impl BufferUsage {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for BufferUsage {
+    const IDENTIFIER: &'static str = "Tracing.bufferUsage";
+}
 #[doc = "Contains a bucket of collected trace events. When tracing is stopped collected events will be\nsent as a sequence of dataCollected events followed by tracingComplete event.\n[dataCollected](https://chromedevtools.github.io/devtools-protocol/tot/Tracing/#event-dataCollected)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataCollectedParams {
@@ -57,6 +60,9 @@ impl DataCollected {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for DataCollected {
+    const IDENTIFIER: &'static str = "Tracing.dataCollected";
+}
 #[doc = "Signals that tracing is stopped and there is no trace buffers pending flush, all data were\ndelivered via dataCollected events.\n[tracingComplete](https://chromedevtools.github.io/devtools-protocol/tot/Tracing/#event-tracingComplete)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TracingCompleteParams {
@@ -96,4 +102,7 @@ impl TracingComplete {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for TracingComplete {
+    const IDENTIFIER: &'static str = "Tracing.tracingComplete";
+}
 group_enum ! (TracingEvents { BufferUsage (BufferUsage) , DataCollected (DataCollected) , TracingComplete (TracingComplete) } + identifiable);
@@ -22,6 +22,9 @@ impl ContextCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ContextCreated {
+    const IDENTIFIER: &'static str = "WebAudio.contextCreated";
+}
 #[doc = "Notifies that an existing BaseAudioContext will be destroyed.\n[contextWillBeDestroyed](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-contextWillBeDestroyed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContextWillBeDestroyedParams {
@@ -45,6 +48,9 @@ impl ContextWillBeDestroyed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ContextWillBeDestroyed {
+    const IDENTIFIER: &'static str = "WebAudio.contextWillBeDestroyed";
+}
 #[doc = "Notifies that existing BaseAudioContext has changed some properties (id stays the same)..\n[contextChanged](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-contextChanged)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContextChangedParams {
@@ -68,6 +74,9 @@ impl ContextChanged {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ContextChanged {
+    const IDENTIFIER: &'static str = "WebAudio.contextChanged";
+}
 #[doc = "Notifies that the construction of an AudioListener has finished.\n[audioListenerCreated](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-audioListenerCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioListenerCreatedParams {
@@ -91,6 +100,9 @@ impl AudioListenerCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AudioListenerCreated {
+    const IDENTIFIER: &'static str = "WebAudio.audioListenerCreated";
+}
 #[doc = "Notifies that a new AudioListener has been created.\n[audioListenerWillBeDestroyed](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-audioListenerWillBeDestroyed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioListenerWillBeDestroyedParams {
@@ -116,6 +128,9 @@ impl AudioListenerWillBeDestroyed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AudioListenerWillBeDestroyed {
+    const IDENTIFIER: &'static str = "WebAudio.audioListenerWillBeDestroyed";
+}
 #[doc = "Notifies that a new AudioNode has been created.\n[audioNodeCreated](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-audioNodeCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioNodeCreatedParams {
@@ -139,6 +154,9 @@ impl AudioNodeCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AudioNodeCreated {
+    const IDENTIFIER: &'static str = "WebAudio.audioNodeCreated";
+}
 #[doc = "Notifies that an existing AudioNode has been destroyed.\n[audioNodeWillBeDestroyed](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-audioNodeWillBeDestroyed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioNodeWillBeDestroyedParams {
@@ -164,6 +182,9 @@ impl AudioNodeWillBeDestroyed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AudioNodeWillBeDestroyed {
+    const IDENTIFIER: &'static str = "WebAudio.audioNodeWillBeDestroyed";
+}
 #[doc = "Notifies that a new AudioParam has been created.\n[audioParamCreated](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-audioParamCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioParamCreatedParams {
@@ -187,6 +208,9 @@ impl AudioParamCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AudioParamCreated {
+    const IDENTIFIER: &'static str = "WebAudio.audioParamCreated";
+}
 #[doc = "Notifies that an existing AudioParam has been destroyed.\n[audioParamWillBeDestroyed](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-audioParamWillBeDestroyed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioParamWillBeDestroyedParams {
@@ -214,6 +238,9 @@ impl AudioParamWillBeDestroyed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AudioParamWillBeDestroyed {
+    const IDENTIFIER: &'static str = "WebAudio.audioParamWillBeDestroyed";
+}
 #[doc = "Notifies that two AudioNodes are connected.\n[nodesConnected](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-nodesConnected)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NodesConnectedParams {
@@ -249,6 +276,9 @@ impl NodesConnected {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for NodesConnected {
+    const IDENTIFIER: &'static str = "WebAudio.nodesConnected";
+}
 #[doc = "Notifies that AudioNodes are disconnected. The destination can be null, and it means all the outgoing connections from the source are disconnected.\n[nodesDisconnected](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-nodesDisconnected)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NodesDisconnectedParams {
@@ -284,6 +314,9 @@ impl NodesDisconnected {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for NodesDisconnected {
+    const IDENTIFIER: &'static str = "WebAudio.nodesDisconnected";
+}
 #[doc = "Notifies that an AudioNode is connected to an AudioParam.\n[nodeParamConnected](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-nodeParamConnected)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NodeParamConnectedParams {
@@ -315,6 +348,9 @@ impl NodeParamConnected {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for NodeParamConnected {
+    const IDENTIFIER: &'static str = "WebAudio.nodeParamConnected";
+}
 #[doc = "Notifies that an AudioNode is disconnected to an AudioParam.\n[nodeParamDisconnected](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#event-nodeParamDisconnected)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NodeParamDisconnectedParams {
@@ -346,4 +382,7 @@ impl NodeParamDisconnected {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for NodeParamDisconnected {
+    const IDENTIFIER: &'static str = "WebAudio.nodeParamDisconnected";
+}
 group_enum ! (WebAudioEvents { ContextCreated (ContextCreated) , ContextWillBeDestroyed (ContextWillBeDestroyed) , ContextChanged (ContextChanged) , AudioListenerCreated (AudioListenerCreated) , AudioListenerWillBeDestroyed (AudioListenerWillBeDestroyed) , AudioNodeCreated (AudioNodeCreated) , AudioNodeWillBeDestroyed (AudioNodeWillBeDestroyed) , AudioParamCreated (AudioParamCreated) , AudioParamWillBeDestroyed (AudioParamWillBeDestroyed) , NodesConnected (NodesConnected) , NodesDisconnected (NodesDisconnected) , NodeParamConnected (NodeParamConnected) , NodeParamDisconnected (NodeParamDisconnected) } + identifiable);
@@ -24,6 +24,9 @@ impl CredentialAdded {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for CredentialAdded {
+    const IDENTIFIER: &'static str = "WebAuthn.credentialAdded";
+}
 #[doc = "Triggered when a credential is deleted, e.g. through\nPublicKeyCredential.signalUnknownCredential().\n[credentialDeleted](https://chromedevtools.github.io/devtools-protocol/tot/WebAuthn/#event-credentialDeleted)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CredentialDeletedParams {
@@ -49,6 +52,9 @@ impl CredentialDeleted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for CredentialDeleted {
+    const IDENTIFIER: &'static str = "WebAuthn.credentialDeleted";
+}
 #[doc = "Triggered when a credential is updated, e.g. through\nPublicKeyCredential.signalCurrentUserDetails().\n[credentialUpdated](https://chromedevtools.github.io/devtools-protocol/tot/WebAuthn/#event-credentialUpdated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CredentialUpdatedParams {
@@ -74,6 +80,9 @@ impl CredentialUpdated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for CredentialUpdated {
+    const IDENTIFIER: &'static str = "WebAuthn.credentialUpdated";
+}
 #[doc = "Triggered when a credential is used in a webauthn assertion.\n[credentialAsserted](https://chromedevtools.github.io/devtools-protocol/tot/WebAuthn/#event-credentialAsserted)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CredentialAssertedParams {
@@ -99,4 +108,7 @@ impl CredentialAsserted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for CredentialAsserted {
+    const IDENTIFIER: &'static str = "WebAuthn.credentialAsserted";
+}
 group_enum ! (WebAuthnEvents { CredentialAdded (CredentialAdded) , CredentialDeleted (CredentialDeleted) , CredentialUpdated (CredentialUpdated) , CredentialAsserted (CredentialAsserted) } + identifiable);
@@ -77,6 +77,9 @@ impl Paused {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for Paused {
+    const IDENTIFIER: &'static str = "Debugger.paused";
+}
 #[doc = "Fired when the virtual machine resumed execution.\n[resumed](https://chromedevtools.github.io/devtools-protocol/tot/Debugger/#event-resumed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResumedParams {}
@@ -97,6 +100,9 @@ impl Resumed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for Resumed {
+    const IDENTIFIER: &'static str = "Debugger.resumed";
+}
 #[doc = "Fired when virtual machine fails to parse the script.\n[scriptFailedToParse](https://chromedevtools.github.io/devtools-protocol/tot/Debugger/#event-scriptFailedToParse)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScriptFailedToParseParams {
@@ -190,6 +196,9 @@ impl ScriptFailedToParse {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ScriptFailedToParse {
+    const IDENTIFIER: &'static str = "Debugger.scriptFailedToParse";
+}
 #[doc = "Fired when virtual machine parses script. This event is also fired for all known and uncollected\nscripts upon enabling debugger.\n[scriptParsed](https://chromedevtools.github.io/devtools-protocol/tot/Debugger/#event-scriptParsed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScriptParsedParams {
@@ -298,4 +307,7 @@ impl ScriptParsed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ScriptParsed {
+    const IDENTIFIER: &'static str = "Debugger.scriptParsed";
+}
 group_enum ! (DebuggerEvents { Paused (Paused) , Resumed (Resumed) , ScriptFailedToParse (ScriptFailedToParse) , ScriptParsed (ScriptParsed) } + identifiable);
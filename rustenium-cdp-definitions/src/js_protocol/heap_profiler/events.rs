@@ -20,6 +20,9 @@ impl AddHeapSnapshotChunk {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for AddHeapSnapshotChunk {
+    const IDENTIFIER: &'static str = "HeapProfiler.addHeapSnapshotChunk";
+}
 #[doc = "If heap objects tracking has been started then backend may send update for one or more fragments\n[heapStatsUpdate](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#event-heapStatsUpdate)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HeapStatsUpdateParams {
@@ -45,6 +48,9 @@ impl HeapStatsUpdate {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for HeapStatsUpdate {
+    const IDENTIFIER: &'static str = "HeapProfiler.heapStatsUpdate";
+}
 #[doc = "If heap objects tracking has been started then backend regularly sends a current value for last\nseen object id and corresponding timestamp. If the were changes in the heap since last event\nthen one or more heapStatsUpdate events will be sent before a new lastSeenObjectId event.\n[lastSeenObjectId](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#event-lastSeenObjectId)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LastSeenObjectIdParams {
@@ -70,6 +76,9 @@ impl LastSeenObjectId {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for LastSeenObjectId {
+    const IDENTIFIER: &'static str = "HeapProfiler.lastSeenObjectId";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReportHeapSnapshotProgressParams {
     #[serde(rename = "done")]
@@ -97,6 +106,9 @@ impl ReportHeapSnapshotProgress {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ReportHeapSnapshotProgress {
+    const IDENTIFIER: &'static str = "HeapProfiler.reportHeapSnapshotProgress";
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResetProfilesParams {}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -115,4 +127,7 @@ impl ResetProfiles {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ResetProfiles {
+    const IDENTIFIER: &'static str = "HeapProfiler.resetProfiles";
+}
 group_enum ! (HeapProfilerEvents { AddHeapSnapshotChunk (AddHeapSnapshotChunk) , HeapStatsUpdate (HeapStatsUpdate) , LastSeenObjectId (LastSeenObjectId) , ReportHeapSnapshotProgress (ReportHeapSnapshotProgress) , ResetProfiles (ResetProfiles) } + identifiable);
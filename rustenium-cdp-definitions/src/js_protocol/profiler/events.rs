@@ -30,6 +30,9 @@ impl ConsoleProfileFinished {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ConsoleProfileFinished {
+    const IDENTIFIER: &'static str = "Profiler.consoleProfileFinished";
+}
 #[doc = "Sent when new profile recording is started using console.profile() call.\n[consoleProfileStarted](https://chromedevtools.github.io/devtools-protocol/tot/Profiler/#event-consoleProfileStarted)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConsoleProfileStartedParams {
@@ -61,6 +64,9 @@ impl ConsoleProfileStarted {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ConsoleProfileStarted {
+    const IDENTIFIER: &'static str = "Profiler.consoleProfileStarted";
+}
 #[doc = "Reports coverage delta since the last poll (either from an event like this, or from\n`takePreciseCoverage` for the current isolate. May only be sent if precise code\ncoverage has been started. This event can be trigged by the embedder to, for example,\ntrigger collection of coverage data immediately at a certain point in time.\n[preciseCoverageDeltaUpdate](https://chromedevtools.github.io/devtools-protocol/tot/Profiler/#event-preciseCoverageDeltaUpdate)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PreciseCoverageDeltaUpdateParams {
@@ -92,4 +98,7 @@ impl PreciseCoverageDeltaUpdate {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for PreciseCoverageDeltaUpdate {
+    const IDENTIFIER: &'static str = "Profiler.preciseCoverageDeltaUpdate";
+}
 group_enum ! (ProfilerEvents { ConsoleProfileFinished (ConsoleProfileFinished) , ConsoleProfileStarted (ConsoleProfileStarted) , PreciseCoverageDeltaUpdate (PreciseCoverageDeltaUpdate) } + identifiable);
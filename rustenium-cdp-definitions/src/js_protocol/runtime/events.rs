@@ -27,6 +27,9 @@ impl BindingCalled {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for BindingCalled {
+    const IDENTIFIER: &'static str = "Runtime.bindingCalled";
+}
 #[doc = "Issued when console API was called.\n[consoleAPICalled](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#event-consoleAPICalled)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConsoleApiCalledParams {
@@ -111,6 +114,9 @@ impl ConsoleApiCalled {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ConsoleApiCalled {
+    const IDENTIFIER: &'static str = "Runtime.consoleAPICalled";
+}
 #[doc = "Issued when unhandled exception was revoked.\n[exceptionRevoked](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#event-exceptionRevoked)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExceptionRevokedParams {
@@ -138,6 +144,9 @@ impl ExceptionRevoked {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ExceptionRevoked {
+    const IDENTIFIER: &'static str = "Runtime.exceptionRevoked";
+}
 #[doc = "Issued when exception was thrown and unhandled.\n[exceptionThrown](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#event-exceptionThrown)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExceptionThrownParams {
@@ -164,6 +173,9 @@ impl ExceptionThrown {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ExceptionThrown {
+    const IDENTIFIER: &'static str = "Runtime.exceptionThrown";
+}
 #[doc = "Issued when new execution context is created.\n[executionContextCreated](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#event-executionContextCreated)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExecutionContextCreatedParams {
@@ -188,6 +200,9 @@ impl ExecutionContextCreated {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ExecutionContextCreated {
+    const IDENTIFIER: &'static str = "Runtime.executionContextCreated";
+}
 #[doc = "Issued when execution context is destroyed.\n[executionContextDestroyed](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#event-executionContextDestroyed)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExecutionContextDestroyedParams {
@@ -212,6 +227,9 @@ impl ExecutionContextDestroyed {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ExecutionContextDestroyed {
+    const IDENTIFIER: &'static str = "Runtime.executionContextDestroyed";
+}
 #[doc = "Issued when all executionContexts were cleared in browser\n[executionContextsCleared](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#event-executionContextsCleared)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExecutionContextsClearedParams {}
@@ -232,6 +250,9 @@ impl ExecutionContextsCleared {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for ExecutionContextsCleared {
+    const IDENTIFIER: &'static str = "Runtime.executionContextsCleared";
+}
 #[doc = "Issued when object should be inspected (for example, as a result of inspect() command line API\ncall).\n[inspectRequested](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#event-inspectRequested)"]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InspectRequestedParams {
@@ -262,4 +283,7 @@ impl InspectRequested {
         Self::IDENTIFIER
     }
 }
+impl crate::EventType for InspectRequested {
+    const IDENTIFIER: &'static str = "Runtime.inspectRequested";
+}
 group_enum ! (RuntimeEvents { BindingCalled (BindingCalled) , ConsoleApiCalled (ConsoleApiCalled) , ExceptionRevoked (ExceptionRevoked) , ExceptionThrown (ExceptionThrown) , ExecutionContextCreated (ExecutionContextCreated) , ExecutionContextDestroyed (ExecutionContextDestroyed) , ExecutionContextsCleared (ExecutionContextsCleared) , InspectRequested (InspectRequested) } + identifiable);
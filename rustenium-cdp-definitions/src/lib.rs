@@ -36,6 +36,9 @@ pub trait CommandResult {
         serde_json::from_value(result)
     }
 }
+pub trait EventType {
+    const IDENTIFIER: &'static str;
+}
 group_enum ! (Type { JsProtocol (js_protocol :: JsProtocolTypes) , BrowserProtocol (browser_protocol :: BrowserProtocolTypes) });
 group_enum ! (Command { JsProtocol (js_protocol :: JsProtocolCommands) , BrowserProtocol (browser_protocol :: BrowserProtocolCommands) } + identifiable);
 group_enum ! (Event { JsProtocol (js_protocol :: JsProtocolEvents) , BrowserProtocol (browser_protocol :: BrowserProtocolEvents) } + other + identifiable);
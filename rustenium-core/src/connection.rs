@@ -3,7 +3,7 @@ use rustenium_cdp_definitions::base;
 use std::sync::Arc;
 use std::{collections::HashMap, net::TcpListener};
 use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
-use tokio::sync::{Mutex, oneshot};
+use tokio::sync::{Mutex, oneshot, watch};
 
 use crate::listeners::{
     CdpCommandResponseListener, CdpCommandResponseState, CdpEventListener, CdpListener,
@@ -68,6 +68,10 @@ impl<T: ConnectionTransport> BidiConnection<T> {
     pub async fn close(&self) {
         self.transport.close().await;
     }
+
+    pub fn on_close(&self) -> watch::Receiver<bool> {
+        self.transport.on_close()
+    }
 }
 
 // ── CDP Connection ───────────────────────────────────────────────────────────
@@ -121,4 +125,8 @@ impl<T: ConnectionTransport> CdpConnection<T> {
     pub async fn close(&self) {
         self.transport.close().await;
     }
+
+    pub fn on_close(&self) -> watch::Receiver<bool> {
+        self.transport.on_close()
+    }
 }
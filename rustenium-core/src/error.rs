@@ -46,6 +46,14 @@ pub enum CdpCommandResultError {
 #[error("Failed to kill process")]
 pub struct ProcessKillError;
 
+/// Returned by [`Process::detach`](crate::process::Process::detach) for a process whose
+/// output is piped into this one. Holds the process, which is still running.
+#[derive(Debug, Error)]
+#[error(
+    "Process output is piped into this process; spawn it with ProcessOutput::File or ProcessOutput::Null to detach"
+)]
+pub struct ProcessDetachError(pub crate::process::Process);
+
 #[derive(Debug, Error)]
 pub enum PostDataError {
     #[error("No POST data available")]
@@ -55,3 +63,23 @@ pub enum PostDataError {
     #[error("POST data is valid JSON but not a JSON object")]
     NotJsonObject,
 }
+
+#[derive(Debug, Error)]
+pub enum WebDriverHttpError {
+    #[error("Invalid WebDriver server URL: {0}")]
+    InvalidUrl(String),
+    #[error("Unsupported WebDriver server URL scheme (only http:// is supported): {0}")]
+    UnsupportedScheme(String),
+    #[error("HTTP request to WebDriver server failed: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid response from WebDriver server: {0}")]
+    InvalidResponse(String),
+    #[error("WebDriver server returned {status} ({error}): {message}")]
+    ErrorResponse {
+        status: u16,
+        error: String,
+        message: String,
+    },
+    #[error("WebDriver server did not return a webSocketUrl capability")]
+    MissingWebSocketUrl,
+}
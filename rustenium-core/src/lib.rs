@@ -2,7 +2,9 @@ mod connection;
 pub mod network;
 pub mod process;
 pub mod session;
+pub mod stats;
 pub mod transport;
+pub mod webdriver;
 
 pub mod error;
 pub mod events;
@@ -1,20 +1,98 @@
-use std::process::Stdio;
+use std::path::PathBuf;
+use std::process::{ExitStatus, Stdio};
+use std::sync::{Arc, Mutex};
 
+use crate::error::ProcessDetachError;
+use crate::stats::ProcessStats;
 use regex::Regex;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::{Child, Command};
 use tokio::time::{Duration, timeout};
 
-#[derive(Debug)]
+/// A spawned child process. `Process::default()` holds no child, for sessions
+/// whose browser and driver are managed elsewhere.
+#[derive(Debug, Default)]
 pub struct Process {
-    child: Option<Child>,
+    child: Arc<Mutex<Option<Child>>>,
+    piped: bool,
+}
+
+/// Where a spawned process writes its stdout and stderr.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ProcessOutput {
+    /// Piped into this process and logged through `tracing` at debug level (default).
+    /// Such a process cannot be [detached](Process::detach): once this process exits,
+    /// its writes would hit closed pipes.
+    #[default]
+    Piped,
+    /// Appended to a file, which stays valid after the process is detached.
+    File(PathBuf),
+    /// Discarded.
+    Null,
+}
+
+/// Cloneable handle for watching a [`Process`] without owning it.
+#[derive(Debug, Clone)]
+pub struct ProcessHandle {
+    child: Arc<Mutex<Option<Child>>>,
+}
+
+impl ProcessHandle {
+    pub fn id(&self) -> Option<u32> {
+        self.child.lock().unwrap().as_ref().and_then(Child::id)
+    }
+
+    /// Exit status of the process, or `None` while it is running or when there is no child.
+    pub fn try_wait(&self) -> Option<ExitStatus> {
+        self.child
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|child| child.try_wait().ok().flatten())
+    }
+
+    /// Resource usage of the process and its descendants. See [`ProcessStats`].
+    pub fn stats(&self) -> std::io::Result<ProcessStats> {
+        let pid = self
+            .id()
+            .ok_or_else(|| std::io::Error::other("process is not running"))?;
+        ProcessStats::collect(pid)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.child
+            .lock()
+            .unwrap()
+            .as_mut()
+            .is_some_and(|child| matches!(child.try_wait(), Ok(None)))
+    }
+
+    /// Moves the child of `process` into the watched process, e.g. after a relaunch.
+    /// The previous child is dropped (and killed if still running).
+    pub fn replace(&self, process: Process) {
+        let child = process.child.lock().unwrap().take();
+        *self.child.lock().unwrap() = child;
+    }
 }
 
 impl Process {
-    fn from_command(exe: &str, mut cmd: Command) -> Process {
+    fn from_command(exe: &str, mut cmd: Command, output: ProcessOutput) -> Process {
+        let (stdout, stderr) = match &output {
+            ProcessOutput::Piped => (Stdio::piped(), Stdio::piped()),
+            ProcessOutput::File(path) => {
+                let file = std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .unwrap_or_else(|e| panic!("Failed to open log file {:?}: {}", path, e));
+                let file_err = file.try_clone().expect("Failed to clone log file handle");
+                (Stdio::from(file), Stdio::from(file_err))
+            }
+            ProcessOutput::Null => (Stdio::null(), Stdio::null()),
+        };
         let mut child = cmd
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
+            .stdout(stdout)
+            .stderr(stderr)
             .kill_on_drop(true)
             .spawn()
             .expect("Failed to start process");
@@ -61,7 +139,22 @@ impl Process {
             });
         }
 
-        Self { child: Some(child) }
+        Self {
+            child: Arc::new(Mutex::new(Some(child))),
+            piped: output == ProcessOutput::Piped,
+        }
+    }
+
+    /// Resource usage of the process and its descendants. See [`ProcessStats`].
+    pub fn stats(&self) -> std::io::Result<ProcessStats> {
+        self.handle().stats()
+    }
+
+    /// Returns a handle for checking whether this process is still running.
+    pub fn handle(&self) -> ProcessHandle {
+        ProcessHandle {
+            child: self.child.clone(),
+        }
     }
 
     pub fn create<S, I>(exe_path: S, args: I) -> Process
@@ -69,12 +162,7 @@ impl Process {
         S: AsRef<str>,
         I: IntoIterator<Item = String>,
     {
-        let exe = exe_path.as_ref();
-        let mut cmd = Command::new(exe);
-        let args = args.into_iter().collect::<Vec<_>>();
-        tracing::info!("Starting process: '{}', args: {:?}", exe, args);
-        cmd.args(args);
-        Self::from_command(exe, cmd)
+        Self::spawn(exe_path, args, [], ProcessOutput::Piped)
     }
 
     pub fn create_with_env<S, I>(
@@ -82,24 +170,44 @@ impl Process {
         args: I,
         env: impl IntoIterator<Item = (String, String)>,
     ) -> Process
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = String>,
+    {
+        Self::spawn(exe_path, args, env, ProcessOutput::Piped)
+    }
+
+    /// Starts `exe_path` with `args` and extra `env`, sending its stdout and stderr to `output`.
+    pub fn spawn<S, I>(
+        exe_path: S,
+        args: I,
+        env: impl IntoIterator<Item = (String, String)>,
+        output: ProcessOutput,
+    ) -> Process
     where
         S: AsRef<str>,
         I: IntoIterator<Item = String>,
     {
         let exe = exe_path.as_ref();
         let mut cmd = Command::new(exe);
-        cmd.args(args.into_iter().collect::<Vec<_>>()).envs(env);
-        Self::from_command(exe, cmd)
+        let args = args.into_iter().collect::<Vec<_>>();
+        tracing::info!("Starting process: '{}', args: {:?}", exe, args);
+        cmd.args(args).envs(env);
+        Self::from_command(exe, cmd, output)
     }
 
     #[deprecated]
     pub async fn wait_for_pattern(&mut self, pattern: &str, timeout_secs: Option<u64>) -> String {
         let timeout_secs = timeout_secs.unwrap_or(20);
         let regex = Regex::new(pattern).expect("Invalid regex pattern");
-        let child = self.child.as_mut().unwrap();
-
-        let stdout = child.stdout.as_mut().expect("Failed to access stdout");
-        let stderr = child.stderr.as_mut().expect("Failed to access stderr");
+        let (stdout, stderr) = {
+            let mut child = self.child.lock().unwrap();
+            let child = child.as_mut().unwrap();
+            (
+                child.stdout.take().expect("Failed to access stdout"),
+                child.stderr.take().expect("Failed to access stderr"),
+            )
+        };
 
         let mut stdout_lines = BufReader::new(stdout).lines();
         let mut stderr_lines = BufReader::new(stderr).lines();
@@ -144,7 +252,38 @@ impl Process {
 
 impl Process {
     pub fn kill(&mut self) -> Result<(), crate::error::ProcessKillError> {
-        if let Some(mut child) = self.child.take() {
+        self.handle().kill()
+    }
+
+    /// Whether [`detach`](Self::detach) can release this process, i.e. it holds no child
+    /// or its output does not go through [`ProcessOutput::Piped`].
+    pub fn is_detachable(&self) -> bool {
+        !self.piped || self.child.lock().unwrap().is_none()
+    }
+
+    /// Releases the child so it keeps running after this `Process` is dropped and after
+    /// the current program exits. Returns its PID. Handles see no process afterwards.
+    ///
+    /// Fails, handing the process back, when its output is piped into this process.
+    pub fn detach(self) -> Result<Option<u32>, ProcessDetachError> {
+        if !self.is_detachable() {
+            return Err(ProcessDetachError(self));
+        }
+        let Some(child) = self.child.lock().unwrap().take() else {
+            return Ok(None);
+        };
+        let pid = child.id();
+        // The child was spawned with kill_on_drop; forgetting it is the only way to opt out
+        std::mem::forget(child);
+        Ok(pid)
+    }
+}
+
+impl ProcessHandle {
+    /// Kills the process and its direct children.
+    pub fn kill(&self) -> Result<(), crate::error::ProcessKillError> {
+        let child = self.child.lock().unwrap().take();
+        if let Some(mut child) = child {
             if let Some(pid) = child.id() {
                 let pid_str = pid.to_string();
                 tracing::debug!("[Process]: Killing process, PID: {}", pid_str);
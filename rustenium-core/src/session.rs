@@ -18,7 +18,7 @@ use serde_json;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, watch};
 use tokio::time::timeout;
 use tracing;
 
@@ -35,6 +35,32 @@ impl BidiSession<WebsocketConnectionTransport> {
         connection_config: &ConnectionTransportConfig,
         capabilities: CapabilitiesRequest,
     ) -> Self {
+        let mut session = Self::connect(connection_config, String::new()).await;
+
+        let command = NewBuilder::default()
+            .capabilities(capabilities)
+            .build()
+            .unwrap();
+        let command_result = session.send(command).await;
+        match command_result {
+            Ok(command_result) => {
+                let result: NewResult = command_result
+                    .result
+                    .clone()
+                    .try_into()
+                    .unwrap_or_else(|_| panic!("Invalid command result: {:?}", command_result));
+                session.id = result.session_id;
+            }
+            Err(e) => panic!("Error creating new session: {}", e),
+        }
+
+        session
+    }
+
+    /// Connects to the BiDi WebSocket of a session that already exists,
+    /// e.g. one created through the WebDriver HTTP `POST /session` endpoint.
+    /// No `session.new` command is sent.
+    pub async fn connect(connection_config: &ConnectionTransportConfig, id: String) -> Self {
         let transport = WebsocketConnectionTransport::new(connection_config)
             .await
             .unwrap();
@@ -46,7 +72,7 @@ impl BidiSession<WebsocketConnectionTransport> {
         connection.start_listeners();
 
         let mut session = Self {
-            id: String::new(),
+            id,
             connection,
             events: Arc::new(Mutex::new(Vec::new())),
             handled_network_requests: Arc::new(Mutex::new(HashMap::new())),
@@ -58,28 +84,15 @@ impl BidiSession<WebsocketConnectionTransport> {
             .register_event_listener_channel(event_tx)
             .await;
 
-        let command = NewBuilder::default()
-            .capabilities(capabilities)
-            .build()
-            .unwrap();
-        let command_result = session.send(command).await;
-        match command_result {
-            Ok(command_result) => {
-                let result: NewResult = command_result
-                    .result
-                    .clone()
-                    .try_into()
-                    .unwrap_or_else(|_| panic!("Invalid command result: {:?}", command_result));
-                session.id = result.session_id;
-            }
-            Err(e) => panic!("Error creating new session: {}", e),
-        }
-
         session
     }
 }
 
 impl<T: ConnectionTransport> BidiSession<T> {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
     /// Send a command and return the receiver to wait for response.
     /// This allows the caller to release locks before waiting for the response.
     pub async fn send_and_get_receiver(
@@ -147,6 +160,16 @@ impl<T: ConnectionTransport> BidiSession<T> {
         self.connection.close().await;
         result
     }
+
+    /// Closes the WebSocket without ending the session.
+    pub async fn close(&self) {
+        self.connection.close().await;
+    }
+
+    /// Receiver that flips to `true` once the WebSocket is closed.
+    pub fn on_close(&self) -> watch::Receiver<bool> {
+        self.connection.on_close()
+    }
 }
 
 impl<T: ConnectionTransport> BidiEventManagement for BidiSession<T> {
@@ -269,6 +292,11 @@ impl<T: ConnectionTransport> CdpSession<T> {
     pub async fn close(&self) {
         self.connection.close().await;
     }
+
+    /// Receiver that flips to `true` once the WebSocket is closed.
+    pub fn on_close(&self) -> watch::Receiver<bool> {
+        self.connection.on_close()
+    }
 }
 
 impl<T: ConnectionTransport> CdpEventManagement for CdpSession<T> {
@@ -0,0 +1,313 @@
+use crate::process::ProcessHandle;
+use std::io;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, watch};
+use tokio::task::JoinHandle;
+
+/// Resource usage of a process and all of its descendants (renderers, GPU and
+/// utility processes for a browser), read from `/proc`. Linux only.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProcessStats {
+    pub pid: u32,
+    /// Resident set size of the whole tree, in bytes.
+    pub rss_bytes: u64,
+    /// User plus system CPU time used by the processes currently in the tree.
+    pub cpu_time: Duration,
+    /// Threads across the whole tree.
+    pub threads: u64,
+    /// Number of descendant processes, not counting the root.
+    pub child_processes: u64,
+}
+
+impl ProcessStats {
+    /// Collects stats for `pid` and its descendants.
+    pub fn collect(pid: u32) -> io::Result<ProcessStats> {
+        collect(pid)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn collect(pid: u32) -> io::Result<ProcessStats> {
+    use std::collections::HashMap;
+
+    struct Stat {
+        ppid: u32,
+        ticks: u64,
+        threads: u64,
+    }
+
+    fn read_stat(pid: u32) -> Option<Stat> {
+        let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+        // The command name is parenthesized and may itself contain spaces or parentheses
+        let fields: Vec<&str> = stat[stat.rfind(')')? + 1..].split_whitespace().collect();
+        let field = |i: usize| fields.get(i).and_then(|f| f.parse::<u64>().ok());
+        Some(Stat {
+            ppid: field(1)? as u32,
+            ticks: field(11)? + field(12)?,
+            threads: field(17)?,
+        })
+    }
+
+    fn read_rss(pid: u32) -> u64 {
+        std::fs::read_to_string(format!("/proc/{}/status", pid))
+            .ok()
+            .and_then(|status| {
+                let line = status.lines().find(|l| l.starts_with("VmRSS:"))?;
+                line.split_whitespace().nth(1)?.parse::<u64>().ok()
+            })
+            .map_or(0, |kb| kb * 1024)
+    }
+
+    let root = read_stat(pid).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("process {} not found", pid),
+        )
+    })?;
+
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for entry in std::fs::read_dir("/proc")?.flatten() {
+        let Some(child) = entry.file_name().to_str().and_then(|n| n.parse().ok()) else {
+            continue;
+        };
+        if let Some(stat) = read_stat(child) {
+            children.entry(stat.ppid).or_default().push(child);
+        }
+    }
+
+    let mut stats = ProcessStats {
+        pid,
+        rss_bytes: read_rss(pid),
+        threads: root.threads,
+        ..ProcessStats::default()
+    };
+    let mut ticks = root.ticks;
+    let mut pending = children.remove(&pid).unwrap_or_default();
+    while let Some(child) = pending.pop() {
+        // Processes may exit while the tree is walked
+        let Some(stat) = read_stat(child) else {
+            continue;
+        };
+        stats.child_processes += 1;
+        stats.threads += stat.threads;
+        stats.rss_bytes += read_rss(child);
+        ticks += stat.ticks;
+        pending.extend(children.remove(&child).unwrap_or_default());
+    }
+    stats.cpu_time = Duration::from_secs_f64(ticks as f64 / clock_ticks_per_second() as f64);
+    Ok(stats)
+}
+
+#[cfg(target_os = "linux")]
+fn clock_ticks_per_second() -> u64 {
+    static CLK_TCK: std::sync::OnceLock<u64> = std::sync::OnceLock::new();
+    *CLK_TCK.get_or_init(|| {
+        std::process::Command::new("getconf")
+            .arg("CLK_TCK")
+            .output()
+            .ok()
+            .and_then(|out| String::from_utf8_lossy(&out.stdout).trim().parse().ok())
+            .filter(|&ticks| ticks > 0)
+            .unwrap_or(100)
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn collect(_pid: u32) -> io::Result<ProcessStats> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "process stats are only available on Linux",
+    ))
+}
+
+/// Limits checked by a [`ResourceSampler`]. `None` disables a check.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResourceThresholds {
+    pub max_rss_bytes: Option<u64>,
+    /// CPU usage between two samples, where 100.0 is one fully used core.
+    pub max_cpu_percent: Option<f64>,
+    pub max_threads: Option<u64>,
+    pub max_child_processes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resource {
+    Rss,
+    CpuPercent,
+    Threads,
+    ChildProcesses,
+}
+
+/// Events published by a [`ResourceSampler`]. Breaches are reported once when a value
+/// goes over its limit, and again only after it has recovered.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResourceEvent {
+    ThresholdExceeded {
+        resource: Resource,
+        value: f64,
+        limit: f64,
+        stats: ProcessStats,
+    },
+    Recovered {
+        resource: Resource,
+        stats: ProcessStats,
+    },
+    /// The process could not be sampled any more, usually because it exited.
+    /// The sampler stops.
+    Stopped(String),
+    /// The process could not be sampled, e.g. while it is being relaunched. Published once
+    /// per gap by samplers from [`ResourceSampler::start_following`], which keep sampling
+    /// and pick up the process that replaces it.
+    Interrupted(String),
+}
+
+/// Samples a process tree every `interval` in the background, starting one interval after
+/// [`start`](Self::start), and publishes [`ResourceEvent`]s when [`ResourceThresholds`]
+/// are crossed. The task is aborted on [`stop`](Self::stop) or drop.
+#[derive(Debug)]
+pub struct ResourceSampler {
+    events: broadcast::Sender<ResourceEvent>,
+    latest: watch::Receiver<Option<ProcessStats>>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl ResourceSampler {
+    /// Starts sampling `process`. Stops with [`ResourceEvent::Stopped`] on the first
+    /// sample that fails.
+    pub fn start(
+        process: ProcessHandle,
+        interval: Duration,
+        thresholds: ResourceThresholds,
+    ) -> ResourceSampler {
+        Self::spawn(process, interval, thresholds, false)
+    }
+
+    /// Like [`start`](Self::start), but keeps sampling through gaps where the process is
+    /// not running, such as while a supervisor relaunches it and [replaces](ProcessHandle::replace)
+    /// the handle's child. Each gap publishes [`ResourceEvent::Interrupted`]; the sampler
+    /// only ends on [`stop`](Self::stop) or drop.
+    pub fn start_following(
+        process: ProcessHandle,
+        interval: Duration,
+        thresholds: ResourceThresholds,
+    ) -> ResourceSampler {
+        Self::spawn(process, interval, thresholds, true)
+    }
+
+    fn spawn(
+        process: ProcessHandle,
+        interval: Duration,
+        thresholds: ResourceThresholds,
+        follow: bool,
+    ) -> ResourceSampler {
+        let events = broadcast::channel(16).0;
+        let (latest_tx, latest) = watch::channel(None);
+        let task_events = events.clone();
+        let task = tokio::spawn(async move {
+            let mut breached = Vec::new();
+            let mut previous: Option<(Instant, ProcessStats)> = None;
+            let mut interrupted = false;
+            loop {
+                tokio::time::sleep(interval).await;
+                // is_running reaps an exited child, whose zombie would otherwise still be in /proc
+                let stats = match process.id().filter(|_| process.is_running()) {
+                    Some(pid) => ProcessStats::collect(pid),
+                    None => Err(io::Error::other("process is not running")),
+                };
+                let stats = match stats {
+                    Ok(stats) => stats,
+                    Err(e) if follow => {
+                        if !interrupted {
+                            interrupted = true;
+                            let _ = task_events.send(ResourceEvent::Interrupted(e.to_string()));
+                        }
+                        // CPU time restarts from zero in the replacement process
+                        previous = None;
+                        continue;
+                    }
+                    Err(e) => {
+                        let _ = task_events.send(ResourceEvent::Stopped(e.to_string()));
+                        break;
+                    }
+                };
+                interrupted = false;
+                let now = Instant::now();
+                let cpu_percent = previous.map(|(at, prev)| {
+                    let elapsed = now.duration_since(at).as_secs_f64();
+                    let used = stats.cpu_time.saturating_sub(prev.cpu_time).as_secs_f64();
+                    used / elapsed * 100.0
+                });
+
+                let checks = [
+                    (
+                        Resource::Rss,
+                        Some(stats.rss_bytes as f64),
+                        thresholds.max_rss_bytes.map(|v| v as f64),
+                    ),
+                    (
+                        Resource::CpuPercent,
+                        cpu_percent,
+                        thresholds.max_cpu_percent,
+                    ),
+                    (
+                        Resource::Threads,
+                        Some(stats.threads as f64),
+                        thresholds.max_threads.map(|v| v as f64),
+                    ),
+                    (
+                        Resource::ChildProcesses,
+                        Some(stats.child_processes as f64),
+                        thresholds.max_child_processes.map(|v| v as f64),
+                    ),
+                ];
+                for (resource, value, limit) in checks {
+                    let (Some(value), Some(limit)) = (value, limit) else {
+                        continue;
+                    };
+                    let was_breached = breached.contains(&resource);
+                    if value > limit && !was_breached {
+                        breached.push(resource);
+                        let _ = task_events.send(ResourceEvent::ThresholdExceeded {
+                            resource,
+                            value,
+                            limit,
+                            stats,
+                        });
+                    } else if value <= limit && was_breached {
+                        breached.retain(|r| *r != resource);
+                        let _ = task_events.send(ResourceEvent::Recovered { resource, stats });
+                    }
+                }
+
+                let _ = latest_tx.send(Some(stats));
+                previous = Some((now, stats));
+            }
+        });
+        ResourceSampler {
+            events,
+            latest,
+            task: Some(task),
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ResourceEvent> {
+        self.events.subscribe()
+    }
+
+    /// The most recent sample, if one has been taken.
+    pub fn latest(&self) -> Option<ProcessStats> {
+        *self.latest.borrow()
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+impl Drop for ResourceSampler {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
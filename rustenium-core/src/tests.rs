@@ -277,7 +277,8 @@ mod listener_tests {
 }
 
 mod process_tests {
-    use crate::process::Process;
+    use crate::error::ProcessDetachError;
+    use crate::process::{Process, ProcessOutput};
 
     #[tokio::test]
     async fn process_create_and_drop() {
@@ -301,4 +302,451 @@ mod process_tests {
 
         drop(proc);
     }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn handle_reports_exit_status() {
+        let proc = Process::create("sh", vec!["-c".to_string(), "exit 3".to_string()]);
+        let handle = proc.handle();
+        assert!(handle.id().is_some());
+        for _ in 0..50 {
+            if !handle.is_running() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert!(!handle.is_running());
+        assert_eq!(handle.try_wait().and_then(|status| status.code()), Some(3));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn handle_kill_and_replace() {
+        let proc = Process::create("sleep", vec!["30".to_string()]);
+        let handle = proc.handle();
+        assert!(handle.is_running());
+
+        handle.kill().unwrap();
+        assert!(!handle.is_running());
+        assert!(handle.kill().is_err());
+
+        handle.replace(Process::create("sleep", vec!["30".to_string()]));
+        assert!(handle.is_running());
+        drop(proc);
+        assert!(!handle.is_running());
+    }
+
+    #[cfg(unix)]
+    fn alive(pid: u32) -> bool {
+        std::process::Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .status()
+            .unwrap()
+            .success()
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn detached_process_survives_drop() {
+        let proc = Process::spawn("sleep", vec!["30".to_string()], [], ProcessOutput::Null);
+        let handle = proc.handle();
+        let pid = proc.detach().unwrap().expect("detached process has a pid");
+        assert!(!handle.is_running());
+
+        assert!(alive(pid));
+        std::process::Command::new("kill")
+            .args(["-9", &pid.to_string()])
+            .status()
+            .unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn piped_process_is_not_detachable() {
+        let proc = Process::create("sleep", vec!["30".to_string()]);
+        assert!(!proc.is_detachable());
+        let ProcessDetachError(proc) = proc.detach().unwrap_err();
+        assert!(proc.handle().is_running());
+        drop(proc);
+    }
+
+    const DETACH_PARENT_LOG: &str = "RUSTENIUM_DETACH_PARENT_LOG";
+
+    /// Runs as the short-lived parent of [`detached_process_outlives_parent`]: spawns a child
+    /// that keeps writing output, detaches it, prints its PID and exits.
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn detach_parent_helper() {
+        let Some(log) = std::env::var_os(DETACH_PARENT_LOG) else {
+            return;
+        };
+        let proc = Process::spawn(
+            "sh",
+            vec![
+                "-c".to_string(),
+                "while true; do echo tick; echo tock >&2; sleep 0.05; done".to_string(),
+            ],
+            [],
+            ProcessOutput::File(log.into()),
+        );
+        let pid = proc.detach().unwrap().unwrap();
+        println!("detached-pid={}", pid);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn detached_process_outlives_parent() {
+        let log = std::env::temp_dir().join(format!(
+            "rustenium-detach-parent-{}.log",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&log);
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "tests::process_tests::detach_parent_helper",
+                "--nocapture",
+            ])
+            .env(DETACH_PARENT_LOG, &log)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let pid: u32 = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| Some(line.split_once("detached-pid=")?.1.to_string()))
+            .expect("helper printed the detached pid")
+            .parse()
+            .unwrap();
+
+        // The parent has exited; the child must keep running and writing
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        let before = std::fs::metadata(&log).unwrap().len();
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        let after = std::fs::metadata(&log).unwrap().len();
+        let survived = alive(pid);
+        std::process::Command::new("kill")
+            .args(["-9", &pid.to_string()])
+            .status()
+            .unwrap();
+        let _ = std::fs::remove_file(&log);
+
+        assert!(survived);
+        assert!(after > before);
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod stats_tests {
+    use crate::process::Process;
+    use crate::stats::{Resource, ResourceEvent, ResourceSampler, ResourceThresholds};
+    use std::time::Duration;
+
+    fn shell_with_two_children() -> Process {
+        Process::create(
+            "sh",
+            vec!["-c".to_string(), "sleep 30 & sleep 30 & wait".to_string()],
+        )
+    }
+
+    #[tokio::test]
+    async fn stats_include_descendants() {
+        let proc = shell_with_two_children();
+        let mut stats = proc.stats().unwrap();
+        for _ in 0..50 {
+            if stats.child_processes >= 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            stats = proc.stats().unwrap();
+        }
+        assert_eq!(Some(stats.pid), proc.handle().id());
+        assert!(stats.child_processes >= 2);
+        assert!(stats.threads >= 3);
+        assert!(stats.rss_bytes > 0);
+    }
+
+    #[tokio::test]
+    async fn stats_error_without_child() {
+        assert!(Process::default().stats().is_err());
+    }
+
+    #[tokio::test]
+    async fn sampler_reports_exceeded_threshold() {
+        let proc = shell_with_two_children();
+        let thresholds = ResourceThresholds {
+            max_child_processes: Some(0),
+            ..Default::default()
+        };
+        let sampler = ResourceSampler::start(proc.handle(), Duration::from_millis(50), thresholds);
+        let mut events = sampler.subscribe();
+        let event = tokio::time::timeout(Duration::from_secs(5), events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        match event {
+            ResourceEvent::ThresholdExceeded {
+                resource, limit, ..
+            } => {
+                assert_eq!(resource, Resource::ChildProcesses);
+                assert_eq!(limit, 0.0);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert!(sampler.latest().is_some());
+    }
+
+    #[tokio::test]
+    async fn sampler_stops_when_process_exits() {
+        let proc = Process::create("true", Vec::<String>::new());
+        let sampler = ResourceSampler::start(
+            proc.handle(),
+            Duration::from_millis(50),
+            ResourceThresholds::default(),
+        );
+        let mut events = sampler.subscribe();
+        let event = tokio::time::timeout(Duration::from_secs(5), events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(event, ResourceEvent::Stopped(_)));
+    }
+
+    #[tokio::test]
+    async fn following_sampler_resumes_after_replace() {
+        let proc = Process::create("sleep", vec!["30".to_string()]);
+        let handle = proc.handle();
+        let sampler = ResourceSampler::start_following(
+            handle.clone(),
+            Duration::from_millis(50),
+            ResourceThresholds::default(),
+        );
+        let mut events = sampler.subscribe();
+
+        handle.kill().unwrap();
+        let event = tokio::time::timeout(Duration::from_secs(5), events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(event, ResourceEvent::Interrupted(_)));
+
+        handle.replace(Process::create("sleep", vec!["30".to_string()]));
+        let new_pid = handle.id();
+        let mut resumed = false;
+        for _ in 0..100 {
+            if sampler.latest().map(|stats| stats.pid) == new_pid {
+                resumed = true;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(resumed);
+        drop(proc);
+    }
+}
+
+mod webdriver_tests {
+    use crate::error::WebDriverHttpError;
+    use crate::webdriver::{delete_session, new_session, parse_server_url};
+    use rustenium_bidi_definitions::session::types::CapabilitiesRequest;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::mpsc;
+
+    /// Serves one canned JSON response per connection and forwards each raw request.
+    async fn mock_server(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, mpsc::UnboundedReceiver<String>) {
+        let responses = responses
+            .into_iter()
+            .map(|(status, body)| {
+                format!(
+                    "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+            })
+            .collect();
+        mock_server_raw(responses).await
+    }
+
+    /// Serves one raw HTTP response per connection and forwards each raw request.
+    async fn mock_server_raw(responses: Vec<String>) -> (String, mpsc::UnboundedReceiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/wd/hub", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 8192];
+                let mut request = String::new();
+                loop {
+                    let n = stream.read(&mut buf).await.unwrap();
+                    request.push_str(&String::from_utf8_lossy(&buf[..n]));
+                    if let Some((head, body)) = request.split_once("\r\n\r\n") {
+                        let len = head
+                            .lines()
+                            .find_map(|l| {
+                                l.split_once(':')
+                                    .filter(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                                    .map(|(_, v)| v.trim())
+                            })
+                            .and_then(|v| v.parse::<usize>().ok())
+                            .unwrap_or(0);
+                        if body.len() >= len {
+                            break;
+                        }
+                    }
+                }
+                tx.send(request).unwrap();
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        (url, rx)
+    }
+
+    #[tokio::test]
+    async fn new_session_posts_capabilities_with_websocket_url() {
+        let (url, mut requests) = mock_server(vec![(
+            200,
+            r#"{"value":{"sessionId":"abc123","capabilities":{"browserName":"chrome","webSocketUrl":"ws://127.0.0.1:9515/session/abc123"}}}"#,
+        )])
+        .await;
+
+        let session = new_session(&url, &CapabilitiesRequest::default())
+            .await
+            .unwrap();
+        assert_eq!(session.session_id, "abc123");
+        assert_eq!(session.web_socket_url, "ws://127.0.0.1:9515/session/abc123");
+        let config = session.transport_config().unwrap();
+        assert_eq!(config.port, 9515);
+        assert_eq!(config.path, "/session/abc123");
+
+        let request = requests.recv().await.unwrap();
+        assert!(request.starts_with("POST /wd/hub/session HTTP/1.1"));
+        let body = request.split_once("\r\n\r\n").unwrap().1;
+        let body: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(body["capabilities"]["alwaysMatch"]["webSocketUrl"], true);
+    }
+
+    #[tokio::test]
+    async fn delete_session_sends_delete() {
+        let (url, mut requests) = mock_server(vec![
+            (
+                200,
+                r#"{"value":{"sessionId":"s1","capabilities":{"webSocketUrl":"ws://127.0.0.1:1/session/s1"}}}"#,
+            ),
+            (200, r#"{"value":null}"#),
+        ])
+        .await;
+
+        let session = new_session(&url, &CapabilitiesRequest::default())
+            .await
+            .unwrap();
+        delete_session(&session).await.unwrap();
+
+        requests.recv().await.unwrap();
+        let request = requests.recv().await.unwrap();
+        assert!(request.starts_with("DELETE /wd/hub/session/s1 HTTP/1.1"));
+    }
+
+    #[tokio::test]
+    async fn new_session_without_websocket_url_errors() {
+        let (url, _requests) = mock_server(vec![(
+            200,
+            r#"{"value":{"sessionId":"s1","capabilities":{"browserName":"chrome"}}}"#,
+        )])
+        .await;
+
+        let result = new_session(&url, &CapabilitiesRequest::default()).await;
+        assert!(matches!(
+            result,
+            Err(WebDriverHttpError::MissingWebSocketUrl)
+        ));
+    }
+
+    #[tokio::test]
+    async fn new_session_error_response_is_surfaced() {
+        let (url, _requests) = mock_server(vec![(
+            500,
+            r#"{"value":{"error":"session not created","message":"no browser"}}"#,
+        )])
+        .await;
+
+        let result = new_session(&url, &CapabilitiesRequest::default()).await;
+        match result {
+            Err(WebDriverHttpError::ErrorResponse {
+                status,
+                error,
+                message,
+            }) => {
+                assert_eq!(status, 500);
+                assert_eq!(error, "session not created");
+                assert_eq!(message, "no browser");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn new_session_rejects_non_http_url() {
+        let result = new_session("ftp://localhost:4444", &CapabilitiesRequest::default()).await;
+        assert!(matches!(result, Err(WebDriverHttpError::InvalidUrl(_))));
+    }
+
+    #[tokio::test]
+    async fn new_session_rejects_https_url() {
+        let result =
+            new_session("https://grid.example:4444", &CapabilitiesRequest::default()).await;
+        assert!(matches!(
+            result,
+            Err(WebDriverHttpError::UnsupportedScheme(_))
+        ));
+    }
+
+    #[test]
+    fn parse_server_url_splits_host_port_and_base() {
+        let (host, port, base) = parse_server_url("http://grid.example:4444/wd/hub/").unwrap();
+        assert_eq!(
+            (host.as_str(), port, base.as_str()),
+            ("grid.example", 4444, "/wd/hub")
+        );
+        let (host, port, base) = parse_server_url("http://localhost").unwrap();
+        assert_eq!((host.as_str(), port, base.as_str()), ("localhost", 80, ""));
+        assert!(matches!(
+            parse_server_url("http://localhost:port"),
+            Err(WebDriverHttpError::InvalidUrl(_))
+        ));
+    }
+
+    #[test]
+    fn parse_server_url_keeps_ipv6_brackets() {
+        let (host, port, base) = parse_server_url("http://[::1]:4444/wd/hub").unwrap();
+        assert_eq!(
+            (host.as_str(), port, base.as_str()),
+            ("[::1]", 4444, "/wd/hub")
+        );
+        let (host, port, _) = parse_server_url("http://[2001:db8::7]").unwrap();
+        assert_eq!((host.as_str(), port), ("[2001:db8::7]", 80));
+    }
+
+    #[tokio::test]
+    async fn new_session_decodes_chunked_response() {
+        let body = r#"{"value":{"sessionId":"c1","capabilities":{"webSocketUrl":"ws://127.0.0.1:1/session/c1"}}}"#;
+        let (head, tail) = body.split_at(20);
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n{:x}\r\n{}\r\n{:x}\r\n{}\r\n0\r\n\r\n",
+            head.len(),
+            head,
+            tail.len(),
+            tail
+        );
+        let (url, _requests) = mock_server_raw(vec![response]).await;
+
+        let session = new_session(&url, &CapabilitiesRequest::default())
+            .await
+            .unwrap();
+        assert_eq!(session.session_id, "c1");
+        assert_eq!(session.web_socket_url, "ws://127.0.0.1:1/session/c1");
+    }
 }
@@ -15,6 +15,7 @@ use tokio::io::{ReadHalf, WriteHalf};
 use tokio::net::TcpStream;
 use tokio::sync::Mutex;
 use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::watch;
 
 #[derive(Debug, Clone)]
 pub enum ConnectionTransportProtocol {
@@ -107,12 +108,14 @@ pub trait ConnectionTransport {
     fn send(&mut self, message: String) -> impl Future<Output = ()> + Send;
     fn listen(&self, listener: UnboundedSender<String>) -> ();
     fn close(&self) -> impl Future<Output = ()> + Send;
-    fn on_close(&self) -> ();
+    /// Receiver that flips to `true` once the connection is closed, by either side.
+    fn on_close(&self) -> watch::Receiver<bool>;
 }
 
 pub struct WebsocketConnectionTransport {
     client_tx: Arc<Mutex<WebSocketWrite<WriteHalf<TokioIo<Upgraded>>>>>,
     client_rx: Arc<Mutex<WebSocketRead<ReadHalf<TokioIo<Upgraded>>>>>,
+    closed: Arc<watch::Sender<bool>>,
 }
 
 impl ConnectionTransport for WebsocketConnectionTransport {
@@ -131,6 +134,7 @@ impl ConnectionTransport for WebsocketConnectionTransport {
             self.client_rx.clone(),
             self.client_tx.clone(),
             listener,
+            self.closed.clone(),
         )
         .unwrap();
     }
@@ -143,8 +147,8 @@ impl ConnectionTransport for WebsocketConnectionTransport {
         }
     }
 
-    fn on_close(&self) -> () {
-        todo!()
+    fn on_close(&self) -> watch::Receiver<bool> {
+        self.closed.subscribe()
     }
 }
 
@@ -201,6 +205,7 @@ impl WebsocketConnectionTransport {
         Ok(Self {
             client_rx: Arc::new(Mutex::new(rx)),
             client_tx: Arc::new(Mutex::new(tx)),
+            closed: Arc::new(watch::channel(false).0),
         })
     }
 
@@ -215,6 +220,7 @@ impl WebsocketConnectionTransport {
         ws_rx: Arc<Mutex<WebSocketRead<ReadHalf<TokioIo<Upgraded>>>>>,
         ws_tx: Arc<Mutex<WebSocketWrite<WriteHalf<TokioIo<Upgraded>>>>>,
         tx: UnboundedSender<String>,
+        closed: Arc<watch::Sender<bool>>,
     ) -> Result<(), WebSocketError> {
         tokio::spawn(async move {
             loop {
@@ -239,7 +245,8 @@ impl WebsocketConnectionTransport {
                         break;
                     }
                     Err(e) => {
-                        panic!("Unexpected WebSocket error: {:?}", e);
+                        tracing::warn!("WebSocket error: {:?}. Exiting listener loop.", e);
+                        break;
                     }
                 };
 
@@ -256,6 +263,7 @@ impl WebsocketConnectionTransport {
                     _ => {}
                 }
             }
+            closed.send_replace(true);
         });
         Ok(())
     }
@@ -0,0 +1,170 @@
+use crate::error::WebDriverHttpError;
+use crate::transport::ConnectionTransportConfig;
+use http_body_util::{BodyExt, Full};
+use hyper::body::Bytes;
+use hyper::header::{CONTENT_TYPE, HOST};
+use hyper::{Request, Uri};
+use hyper_util::rt::TokioIo;
+use rustenium_bidi_definitions::session::types::CapabilitiesRequest;
+use serde_json::{Value, json};
+use tokio::net::TcpStream;
+
+/// A session created through the classic WebDriver HTTP `POST /session` endpoint.
+#[derive(Debug, Clone)]
+pub struct WebDriverSession {
+    /// Base URL of the WebDriver server, e.g. `http://localhost:4444/wd/hub`.
+    pub server_url: String,
+    /// Session ID returned by the server.
+    pub session_id: String,
+    /// BiDi WebSocket URL returned in the `webSocketUrl` capability.
+    pub web_socket_url: String,
+    /// Capabilities the server matched for the session.
+    pub capabilities: Value,
+}
+
+impl WebDriverSession {
+    /// Transport config for the BiDi WebSocket of this session.
+    pub fn transport_config(&self) -> Result<ConnectionTransportConfig, WebDriverHttpError> {
+        ConnectionTransportConfig::from_ws_url(&self.web_socket_url)
+            .map_err(WebDriverHttpError::InvalidResponse)
+    }
+}
+
+/// Performs the classic WebDriver new-session handshake against `server_url`,
+/// requesting `webSocketUrl: true` so the session can be driven over BiDi.
+pub async fn new_session(
+    server_url: &str,
+    capabilities: &CapabilitiesRequest,
+) -> Result<WebDriverSession, WebDriverHttpError> {
+    let mut capabilities = serde_json::to_value(capabilities)
+        .map_err(|e| WebDriverHttpError::InvalidResponse(e.to_string()))?;
+    if !capabilities["alwaysMatch"].is_object() {
+        capabilities["alwaysMatch"] = json!({});
+    }
+    capabilities["alwaysMatch"]["webSocketUrl"] = Value::Bool(true);
+
+    let body = json!({ "capabilities": capabilities }).to_string();
+    let value = http_request(server_url, "POST", "/session", Some(&body)).await?;
+
+    let session_id = value["sessionId"]
+        .as_str()
+        .ok_or_else(|| {
+            WebDriverHttpError::InvalidResponse("sessionId not found in response".to_string())
+        })?
+        .to_string();
+    let capabilities = value["capabilities"].clone();
+    let web_socket_url = capabilities["webSocketUrl"]
+        .as_str()
+        .ok_or(WebDriverHttpError::MissingWebSocketUrl)?
+        .to_string();
+
+    tracing::info!("Created WebDriver session {} at {}", session_id, server_url);
+    Ok(WebDriverSession {
+        server_url: server_url.to_string(),
+        session_id,
+        web_socket_url,
+        capabilities,
+    })
+}
+
+/// Deletes a session created with [`new_session`] via `DELETE /session/{id}`.
+pub async fn delete_session(session: &WebDriverSession) -> Result<(), WebDriverHttpError> {
+    let path = format!("/session/{}", session.session_id);
+    http_request(&session.server_url, "DELETE", &path, None).await?;
+    tracing::info!("Deleted WebDriver session {}", session.session_id);
+    Ok(())
+}
+
+/// Splits `http://host[:port][/base]` into `(host, port, base_path)`. IPv6 hosts keep
+/// their brackets, e.g. `[::1]`, so `host:port` stays a valid socket address.
+///
+/// Only plain `http://` is supported; an `https://` server URL is rejected with
+/// [`WebDriverHttpError::UnsupportedScheme`] rather than failing mid-handshake.
+pub(crate) fn parse_server_url(url: &str) -> Result<(String, u16, String), WebDriverHttpError> {
+    let invalid = || WebDriverHttpError::InvalidUrl(url.to_string());
+    let uri: Uri = url.parse().map_err(|_| invalid())?;
+    match uri.scheme_str() {
+        Some("http") => {}
+        Some("https") => return Err(WebDriverHttpError::UnsupportedScheme(url.to_string())),
+        _ => return Err(invalid()),
+    }
+    let host = uri
+        .host()
+        .filter(|host| !host.is_empty())
+        .ok_or_else(invalid)?;
+    // `Uri::port` is `None` for a port that is not a number, so read it off the authority
+    let authority = uri.authority().map_or("", |authority| authority.as_str());
+    let port = match authority
+        .rsplit('@')
+        .next()
+        .and_then(|a| a.strip_prefix(host))
+    {
+        Some("") => 80,
+        Some(port) => port
+            .strip_prefix(':')
+            .and_then(|port| port.parse().ok())
+            .ok_or_else(invalid)?,
+        None => return Err(invalid()),
+    };
+    let base = uri.path().trim_end_matches('/');
+    Ok((host.to_string(), port, base.to_string()))
+}
+
+/// Sends a single HTTP/1.1 request and returns the `value` field of the JSON body.
+///
+/// Framing (including `Transfer-Encoding: chunked` responses) is handled by hyper.
+async fn http_request(
+    server_url: &str,
+    method: &str,
+    path: &str,
+    body: Option<&str>,
+) -> Result<Value, WebDriverHttpError> {
+    let (host, port, base) = parse_server_url(server_url)?;
+    let addr = format!("{}:{}", host, port);
+    let stream = TcpStream::connect(&addr).await?;
+
+    let (mut sender, conn) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+        .await
+        .map_err(|e| WebDriverHttpError::InvalidResponse(e.to_string()))?;
+    tokio::spawn(async move {
+        if let Err(e) = conn.await {
+            tracing::debug!("WebDriver HTTP connection closed: {}", e);
+        }
+    });
+
+    let request = Request::builder()
+        .method(method)
+        .uri(format!("{}{}", base, path))
+        .header(HOST, &addr)
+        .header(CONTENT_TYPE, "application/json; charset=utf-8")
+        .body(Full::new(Bytes::from(body.unwrap_or("").to_string())))
+        .map_err(|e| WebDriverHttpError::InvalidUrl(e.to_string()))?;
+
+    let response = sender
+        .send_request(request)
+        .await
+        .map_err(|e| WebDriverHttpError::InvalidResponse(e.to_string()))?;
+    let status = response.status().as_u16();
+    let body = response
+        .into_body()
+        .collect()
+        .await
+        .map_err(|e| WebDriverHttpError::InvalidResponse(e.to_string()))?
+        .to_bytes();
+
+    let response: Value = serde_json::from_slice(&body)
+        .map_err(|e| WebDriverHttpError::InvalidResponse(format!("parse JSON: {}", e)))?;
+    let value = response["value"].clone();
+
+    if !(200..300).contains(&status) {
+        return Err(WebDriverHttpError::ErrorResponse {
+            status,
+            error: value["error"]
+                .as_str()
+                .unwrap_or("unknown error")
+                .to_string(),
+            message: value["message"].as_str().unwrap_or_default().to_string(),
+        });
+    }
+    Ok(value)
+}
@@ -10,7 +10,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 
 use crate::backend::base_types::{
-    DomainDirection, Module, Param, Protocol, Type, TypeRef, Variant,
+    DomainDirection, Item, Module, Param, Protocol, Type, TypeRef, Variant,
 };
 use crate::backend::builder::Builder;
 use crate::backend::event;
@@ -314,6 +314,10 @@ impl Generator {
                     }
                 }
 
+                pub trait EventType {
+                    const IDENTIFIER: &'static str;
+                }
+
                 #proto_content
             }
         } else {
@@ -466,6 +470,10 @@ impl Generator {
                     }
                 }
 
+                pub trait EventType {
+                    const IDENTIFIER: &'static str;
+                }
+
                 #top_type_group
                 #top_cmd_group
                 #top_evt_group
@@ -535,6 +543,16 @@ impl Generator {
                 command_idents.push((ident.clone(), ident));
             } else {
                 events_stream.extend(def);
+
+                // Let generic code name the event's method through the type alone
+                let def_ident = format_ident!("{}", camel_name);
+                let identifier = dt.raw_name();
+                events_stream.extend(quote! {
+                    impl crate::EventType for #def_ident {
+                        const IDENTIFIER: &'static str = #identifier;
+                    }
+                });
+
                 let ident = format_ident!("{}", camel_name);
                 event_idents.push((ident.clone(), ident));
             }
@@ -624,6 +642,7 @@ impl Generator {
                         optional: param.optional,
                         deprecated: param.deprecated,
                         serde_skip: false,
+                        flattens_choice: false,
                     };
 
                     if let Some(default_fn) = field.generate_default_fn(param, &returns_name) {
@@ -893,6 +912,13 @@ impl Generator {
             );
             ty.needs_box = ty.needs_box || param.is_circular_dep;
 
+            let flattens_choice = param.flatten
+                && matches!(&param.r#type, Type::Ref(tr)
+                if tr.module.as_deref().is_none_or(|m| m == module.name.as_ref())
+                    && module.types.iter().any(|t| {
+                        t.name.as_ref() == tr.name.as_ref()
+                            && matches!(t.parameters, Some(Item::TypeChoice(_)))
+                    }));
             let field = FieldDefinition {
                 name: param.name.to_string(),
                 name_ident: field_name,
@@ -900,6 +926,7 @@ impl Generator {
                 optional: param.optional,
                 deprecated: param.deprecated,
                 serde_skip: false,
+                flattens_choice,
             };
 
             if let Some(default_fn) = field.generate_default_fn(param, &struct_ident) {
@@ -1086,7 +1113,20 @@ impl Generator {
         let mut variants: Vec<TokenStream> = Vec::new();
         let mut from_impls: Vec<TokenStream> = Vec::new();
 
-        for tr in type_refs {
+        // Serde tries untagged variants in order, so a variant whose `type` is any string
+        // (e.g. log.GenericLogEntry) would swallow the ones with a literal `type` after it
+        let catches_all = |tr: &TypeRef| {
+            tr.module.is_none()
+                && module.types.iter().any(|t| {
+                    t.name.as_ref() == tr.name.as_ref()
+                        && matches!(&t.parameters, Some(Item::Properties(params))
+                            if params.iter().any(|p| p.name == "type" && p.r#type.is_string()))
+                })
+        };
+        let (catch_alls, specific): (Vec<&TypeRef>, Vec<&TypeRef>) =
+            type_refs.iter().partition(|tr| catches_all(tr));
+
+        for tr in specific.into_iter().chain(catch_alls) {
             let variant_name = format_ident!("{}", tr.name.to_upper_camel_case());
             let is_empty = tr.module.is_none()
                 && !module
@@ -301,6 +301,8 @@ pub struct FieldDefinition {
     pub optional: bool,
     pub deprecated: bool,
     pub serde_skip: bool,
+    /// A flattened type choice: untagged enums have no `Default` to fall back on.
+    pub flattens_choice: bool,
 }
 
 impl FieldDefinition {
@@ -322,6 +324,10 @@ impl FieldDefinition {
 
         if self.serde_skip {
             serde_attr.extend(quote! {#[serde(skip)]})
+        } else if param.flatten && self.flattens_choice {
+            serde_attr.extend(quote! {
+                #[serde(flatten)]
+            });
         } else if param.flatten {
             serde_attr.extend(quote! {
                 #[serde(flatten)]
@@ -595,7 +595,9 @@ fn resolve_params(
         Some(pt) if pt != "EmptyParams" => {
             if let Some(body) = rule_map.get(pt.as_str()) {
                 let body = body.trim();
-                if body.starts_with('{') || body.starts_with('(') {
+                // A parenthesised choice of types, e.g. `log.Entry`, is not a group of fields
+                let is_choice = body.starts_with('(') && body.contains('/') && !body.contains(':');
+                if !is_choice && (body.starts_with('{') || body.starts_with('(')) {
                     parse_struct_fields(body, parent_name)
                 } else {
                     let field_snake =
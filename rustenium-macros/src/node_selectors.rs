@@ -21,3 +21,105 @@ macro_rules! xpath {
         )
     };
 }
+
+/// Locates elements by ARIA role and/or accessible name.
+///
+/// `role!("button")`, `role!("button", name = "Submit")` and `role!(name = "Submit")` are
+/// all accepted.
+#[macro_export]
+macro_rules! role {
+    (name = $name:expr) => {
+        $crate::__accessibility_locator!(
+            ::core::option::Option::None,
+            ::core::option::Option::Some($name.to_string())
+        )
+    };
+    ($role:expr, name = $name:expr) => {
+        $crate::__accessibility_locator!(
+            ::core::option::Option::Some($role.to_string()),
+            ::core::option::Option::Some($name.to_string())
+        )
+    };
+    ($role:expr) => {
+        $crate::__accessibility_locator!(
+            ::core::option::Option::Some($role.to_string()),
+            ::core::option::Option::None
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __accessibility_locator {
+    ($role:expr, $name:expr) => {
+        $crate::rustenium_bidi_definitions::browsing_context::types::Locator::AccessibilityLocator(
+            $crate::rustenium_bidi_definitions::browsing_context::types::AccessibilityLocator {
+                r#type: $crate::rustenium_bidi_definitions::browsing_context::types::AccessibilityLocatorType::Accessibility,
+                value: $crate::rustenium_bidi_definitions::browsing_context::types::AccessibilityLocatorValue {
+                    name: $name,
+                    role: $role,
+                },
+            }
+        )
+    };
+}
+
+/// Locates elements by their rendered text. Matches any element whose text contains the
+/// value unless `exact` is given; `ignore_case` and `max_depth = n` are also accepted, in
+/// any order: `text!("Sign in", exact, ignore_case)`.
+#[macro_export]
+macro_rules! text {
+    ($value:expr $(, $($option:tt)+)?) => {
+        $crate::__inner_text_locator!(
+            $value;
+            ::core::option::Option::Some($crate::rustenium_bidi_definitions::browsing_context::types::InnerTextLocatorMatchType::Partial),
+            ::core::option::Option::None,
+            ::core::option::Option::None;
+            $(, $($option)+)?
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __inner_text_locator {
+    ($value:expr; $match_type:expr, $ignore_case:expr, $max_depth:expr;) => {
+        $crate::rustenium_bidi_definitions::browsing_context::types::Locator::InnerTextLocator(
+            $crate::rustenium_bidi_definitions::browsing_context::types::InnerTextLocator {
+                r#type: $crate::rustenium_bidi_definitions::browsing_context::types::InnerTextLocatorType::InnerText,
+                value: $value.to_string(),
+                ignore_case: $ignore_case,
+                match_type: $match_type,
+                max_depth: $max_depth,
+            }
+        )
+    };
+    ($value:expr; $match_type:expr, $ignore_case:expr, $max_depth:expr; , exact $($rest:tt)*) => {
+        $crate::__inner_text_locator!(
+            $value;
+            ::core::option::Option::Some($crate::rustenium_bidi_definitions::browsing_context::types::InnerTextLocatorMatchType::Full),
+            $ignore_case,
+            $max_depth;
+            $($rest)*
+        )
+    };
+    ($value:expr; $match_type:expr, $ignore_case:expr, $max_depth:expr; , ignore_case $($rest:tt)*) => {
+        $crate::__inner_text_locator!($value; $match_type, ::core::option::Option::Some(true), $max_depth; $($rest)*)
+    };
+    ($value:expr; $match_type:expr, $ignore_case:expr, $max_depth:expr; , max_depth = $depth:literal $($rest:tt)*) => {
+        $crate::__inner_text_locator!($value; $match_type, $ignore_case, ::core::option::Option::Some($depth); $($rest)*)
+    };
+}
+
+/// Locates the `<iframe>` or `<frame>` element hosting a browsing context.
+#[macro_export]
+macro_rules! context {
+    ($context:expr) => {
+        $crate::rustenium_bidi_definitions::browsing_context::types::Locator::ContextLocator(
+            $crate::rustenium_bidi_definitions::browsing_context::types::ContextLocator {
+                r#type: $crate::rustenium_bidi_definitions::browsing_context::types::ContextLocatorType::Context,
+                value: $crate::rustenium_bidi_definitions::browsing_context::types::ContextLocatorValue::new($context),
+            }
+        )
+    };
+}
@@ -820,9 +820,8 @@ fn bench_end_to_end(c: &mut Criterion) {
             rt.block_on(async {
                 let mut contexts = vec![browser.get_active_context_id().unwrap()];
                 for _ in 1..urls.len() {
-                    let ctx = browser.create_context(true).await.unwrap();
-                    let id: String = ctx.into();
-                    contexts.push(id.into());
+                    let page = browser.create_context(true).await.unwrap();
+                    contexts.push(page.id().clone());
                 }
 
                 for (url, ctx) in urls.iter().zip(contexts.iter()) {
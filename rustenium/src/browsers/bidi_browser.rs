@@ -1,12 +1,23 @@
-use crate::conduit::bidi::drivers::BidiDriver;
+use crate::conduit::bidi::drivers::{self, BidiDriver};
+use crate::console::{ConsoleCollector, ConsoleMessage, PageError};
+use crate::dialog::{Dialog, FileChooser, HandlerRegistration};
 use crate::domain::context::BrowsingContext as DomainBrowsingContext;
+use crate::download::{Download, DownloadBehavior, DownloadEvent};
 use crate::error::bidi::{
-    BrowserCloseError, ContextCloseError, ContextCreationError, ContextIndexError, EmulationError,
-    EvaluateResultError, FindNodesError, InterceptNetworkError, NavigateError, ScreenshotError,
+    BrowserCloseError, ConsoleError, ContextCloseError, ContextCreationError, ContextIndexError,
+    EmulationError, EvaluateError, EvaluateResultError, ExposeFunctionError, FindNodesError,
+    InterceptNetworkError, NavigateError, ScreenshotError,
 };
+use crate::error::dialog::{DialogError, FileChooserError};
+use crate::error::download::FileDownloadError;
+use crate::error::pdf::PdfError;
+use crate::evaluate::EvaluateArgs;
 use crate::input::{BidiKeyboard, BidiMouse, HumanMouse, HumanTouchscreen, Touchscreen};
-use crate::nodes::Node;
+use crate::nodes::FromBidiNode;
+use crate::page::Page;
+use crate::pdf::PdfOptions;
 use rustenium_bidi_definitions::base::CommandResponse;
+use rustenium_bidi_definitions::browser::command_builders::SetDownloadBehaviorBuilder;
 use rustenium_bidi_definitions::browser::types::UserContext;
 use rustenium_bidi_definitions::browsing_context::command_builders::{
     CaptureScreenshotBuilder, CreateBuilder, LocateNodesBuilder, NavigateBuilder,
@@ -27,8 +38,11 @@ use rustenium_core::error::{CommandResultError, SessionSendError};
 use rustenium_core::events::BidiEventManagement;
 use rustenium_core::transport::ConnectionTransport;
 use rustenium_core::{BidiSession, NetworkRequest};
+use serde::de::DeserializeOwned;
 use std::collections::HashSet;
 use std::future::Future;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedReceiver;
 
 pub mod options {
     use rustenium_bidi_definitions::browsing_context::commands::CaptureScreenshotOrigin;
@@ -148,12 +162,24 @@ pub mod options {
         }
     }
 
+    /// Which shadow roots a shadow-piercing search walks into.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ShadowPierce {
+        /// Open shadow roots only, the ones scripts can reach through `element.shadowRoot`.
+        Open,
+        /// Open and closed shadow roots.
+        All,
+    }
+
     #[derive(Debug, Clone, Default)]
     pub struct FindNodesOptions {
         pub context_id: Option<BrowsingContext>,
         pub max_node_count: Option<u64>,
         pub serialization_options: Option<SerializationOptions>,
         pub start_nodes: Option<Vec<SharedReference>>,
+        /// Also search inside shadow roots, nested ones included. Matches in the light DOM
+        /// come first, followed by matches in each shadow root from the outermost inwards.
+        pub pierce_shadow: Option<ShadowPierce>,
     }
 
     #[derive(Default, Clone)]
@@ -162,6 +188,7 @@ pub mod options {
         max_node_count: Option<u64>,
         serialization_options: Option<SerializationOptions>,
         start_nodes: Option<Vec<SharedReference>>,
+        pierce_shadow: Option<ShadowPierce>,
     }
 
     impl FindNodesOptionsBuilder {
@@ -181,12 +208,17 @@ pub mod options {
             self.start_nodes = Some(v);
             self
         }
+        pub fn pierce_shadow(mut self, v: ShadowPierce) -> Self {
+            self.pierce_shadow = Some(v);
+            self
+        }
         pub fn build(self) -> FindNodesOptions {
             FindNodesOptions {
                 context_id: self.context_id,
                 max_node_count: self.max_node_count,
                 serialization_options: self.serialization_options,
                 start_nodes: self.start_nodes,
+                pierce_shadow: self.pierce_shadow,
             }
         }
     }
@@ -436,13 +468,22 @@ pub use options::{
     CreateContextOptions, CreateContextOptionsBuilder, EmulateTimezoneOptions,
     EmulateTimezoneOptionsBuilder, EvaluateScriptOptions, EvaluateScriptOptionsBuilder,
     FindNodesOptions, FindNodesOptionsBuilder, NavigateOptions, NavigateOptionsBuilder,
-    OnRequestOptions, OnRequestOptionsBuilder, SubscribeEventsOptions,
+    OnRequestOptions, OnRequestOptionsBuilder, ShadowPierce, SubscribeEventsOptions,
     SubscribeEventsOptionsBuilder, WaitForNodesOptions, WaitForNodesOptionsBuilder,
 };
 
+/// Serialization used when locating nodes without explicit options.
+pub(crate) fn default_node_serialization() -> SerializationOptions {
+    SerializationOptions {
+        max_dom_depth: Some(40),
+        max_object_depth: Some(0),
+        include_shadow_tree: Some(SerializationOptionsIncludeShadowTree::None),
+    }
+}
+
 pub trait BidiBrowser: Send + Sync {
     type Transport: ConnectionTransport + Send + Sync + 'static;
-    type BrowserNode: Node + Send;
+    type BrowserNode: FromBidiNode<Self::Transport> + Send;
 
     // ── Required methods ─────────────────────────────────────────────────────
     // These are the only methods a browser implementation must provide.
@@ -510,7 +551,8 @@ pub trait BidiBrowser: Send + Sync {
     fn create_context(
         &mut self,
         background: bool,
-    ) -> impl Future<Output = Result<DomainBrowsingContext, ContextCreationError>> + Send {
+    ) -> impl Future<Output = Result<Page<Self::Transport, Self::BrowserNode>, ContextCreationError>>
+    + Send {
         async move {
             self.create_context_with_options(background, CreateContextOptions::default())
                 .await
@@ -522,7 +564,8 @@ pub trait BidiBrowser: Send + Sync {
         &mut self,
         background: bool,
         options: CreateContextOptions,
-    ) -> impl Future<Output = Result<DomainBrowsingContext, ContextCreationError>> + Send {
+    ) -> impl Future<Output = Result<Page<Self::Transport, Self::BrowserNode>, ContextCreationError>>
+    + Send {
         async move {
             let context_type = options.context_type.unwrap_or(CreateType::Tab);
             let mut builder = CreateBuilder::default().r#type(context_type);
@@ -530,12 +573,44 @@ pub trait BidiBrowser: Send + Sync {
                 builder = builder.reference_context(ref_ctx);
             };
             builder = builder.background(background);
-            self.driver_mut()
+            let context = self
+                .driver_mut()
                 .create_context(builder.build().unwrap())
-                .await
+                .await?;
+            Ok(self.page(context.id().clone()))
         }
     }
 
+    // ── Pages ────────────────────────────────────────────────────────────────
+
+    /// Returns a [`Page`] bound to `context`.
+    fn page(&self, context: BrowsingContext) -> Page<Self::Transport, Self::BrowserNode> {
+        let driver = self.driver();
+        Page::with_exposed(driver.session.clone(), driver.exposed.clone(), context)
+    }
+
+    /// Returns the page of the active context.
+    fn active_page(&self) -> Result<Page<Self::Transport, Self::BrowserNode>, ContextIndexError> {
+        Ok(self.page(self.get_active_context_id()?))
+    }
+
+    /// Returns a page for every open top-level context, including popups, in the order
+    /// they were created.
+    fn pages(&self) -> Vec<Page<Self::Transport, Self::BrowserNode>> {
+        let contexts: Vec<_> = self
+            .driver()
+            .browsing_contexts
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|context| context.id().clone())
+            .collect();
+        contexts
+            .into_iter()
+            .map(|context| self.page(context))
+            .collect()
+    }
+
     // ── Node finding ─────────────────────────────────────────────────────────
 
     /// Finds all elements matching the given locator in the active context.
@@ -560,14 +635,9 @@ pub trait BidiBrowser: Send + Sync {
                 .context_id
                 .clone()
                 .unwrap_or_else(|| self.driver().get_active_context_id().unwrap());
-            let serialization_options =
-                options
-                    .serialization_options
-                    .unwrap_or(SerializationOptions {
-                        max_dom_depth: Some(40),
-                        max_object_depth: Some(0),
-                        include_shadow_tree: Some(SerializationOptionsIncludeShadowTree::None),
-                    });
+            let serialization_options = options
+                .serialization_options
+                .unwrap_or_else(default_node_serialization);
             let mut builder = LocateNodesBuilder::default()
                 .context(context.clone())
                 .locator(locator.clone())
@@ -578,10 +648,21 @@ pub trait BidiBrowser: Send + Sync {
             if let Some(start_nodes) = options.start_nodes {
                 builder = builder.start_nodes(start_nodes);
             }
-            let node_result = self
-                .driver_mut()
-                .find_nodes(builder.build().unwrap())
-                .await?;
+            let node_result = match options.pierce_shadow {
+                Some(pierce) => {
+                    drivers::find_nodes_piercing(
+                        &self.driver().session,
+                        builder.build().unwrap(),
+                        pierce,
+                    )
+                    .await?
+                }
+                None => {
+                    self.driver_mut()
+                        .find_nodes(builder.build().unwrap())
+                        .await?
+                }
+            };
             let mut nodes = Vec::new();
             for node in node_result.nodes.iter() {
                 nodes.push(self.build_node(node.clone(), locator.clone(), context.clone()));
@@ -620,6 +701,7 @@ pub trait BidiBrowser: Send + Sync {
     // ── Waiting ──────────────────────────────────────────────────────────────
 
     /// Waits for elements matching the locator to appear (default 4s timeout).
+    /// [`Page::wait_for_selector`] waits without polling.
     fn wait_for_nodes(
         &mut self,
         locator: Locator,
@@ -796,6 +878,134 @@ pub trait BidiBrowser: Send + Sync {
         async move { self.driver_mut().add_event_handler(events, handler).await }
     }
 
+    // ── Console ──────────────────────────────────────────────────────────────
+
+    /// Streams the console messages the active browsing context logs from now on. See
+    /// [`Page::console_messages`].
+    fn console_messages(
+        &mut self,
+    ) -> impl Future<Output = Result<UnboundedReceiver<ConsoleMessage>, ConsoleError>> + Send {
+        async move { self.active_page()?.console_messages().await }
+    }
+
+    /// Streams the uncaught errors in the active browsing context from now on. See
+    /// [`Page::page_errors`].
+    fn page_errors(
+        &mut self,
+    ) -> impl Future<Output = Result<UnboundedReceiver<PageError>, ConsoleError>> + Send {
+        async move { self.active_page()?.page_errors().await }
+    }
+
+    /// Buffers the console messages and uncaught errors of the active browsing context
+    /// from now on. See [`Page::collect_console`].
+    fn collect_console(
+        &mut self,
+    ) -> impl Future<Output = Result<ConsoleCollector, ConsoleError>> + Send {
+        async move { self.active_page()?.collect_console().await }
+    }
+
+    // ── Dialogs ──────────────────────────────────────────────────────────────
+
+    /// Calls `handler` with every JavaScript dialog the active browsing context opens from
+    /// now on. See [`Page::on_dialog`].
+    fn on_dialog<F, Fut>(
+        &mut self,
+        handler: F,
+    ) -> impl Future<Output = Result<HandlerRegistration, DialogError>> + Send
+    where
+        F: Fn(Dialog) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        async move { self.active_page()?.on_dialog(handler).await }
+    }
+
+    /// Runs `trigger` and returns the first JavaScript dialog the active browsing context
+    /// opens meanwhile. See [`Page::wait_for_dialog`].
+    fn wait_for_dialog<F>(
+        &mut self,
+        trigger: F,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<Dialog, DialogError>> + Send
+    where
+        F: Future + Send,
+    {
+        async move { self.active_page()?.wait_for_dialog(trigger, timeout).await }
+    }
+
+    // ── File choosers ────────────────────────────────────────────────────────
+
+    /// Calls `handler` with every native file chooser the active browsing context tries to
+    /// open from now on. See [`Page::on_file_chooser`].
+    fn on_file_chooser<F, Fut>(
+        &mut self,
+        handler: F,
+    ) -> impl Future<Output = Result<HandlerRegistration, FileChooserError>> + Send
+    where
+        F: Fn(FileChooser) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        async move { self.active_page()?.on_file_chooser(handler).await }
+    }
+
+    /// Runs `trigger` and returns the first native file chooser the active browsing context
+    /// tries to open meanwhile. See [`Page::wait_for_file_chooser`].
+    fn wait_for_file_chooser<F>(
+        &mut self,
+        trigger: F,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<FileChooser, FileChooserError>> + Send
+    where
+        F: Future + Send,
+    {
+        async move {
+            self.active_page()?
+                .wait_for_file_chooser(trigger, timeout)
+                .await
+        }
+    }
+
+    // ── Downloads ────────────────────────────────────────────────────────────
+
+    /// Sets what the browser does with downloads, in every browsing context.
+    fn set_download_behavior(
+        &mut self,
+        behavior: DownloadBehavior,
+    ) -> impl Future<Output = Result<(), FileDownloadError>> + Send {
+        async move {
+            let dir = behavior.prepare_dir()?;
+            let command = SetDownloadBehaviorBuilder::default()
+                .download_behavior(behavior.to_bidi(dir.as_deref()))
+                .build();
+            self.driver_mut().set_download_behavior(command).await
+        }
+    }
+
+    /// Streams what happens to the downloads the active browsing context starts from now
+    /// on. See [`Page::download_events`].
+    fn download_events(
+        &mut self,
+    ) -> impl Future<Output = Result<UnboundedReceiver<DownloadEvent>, FileDownloadError>> + Send
+    {
+        async move { self.active_page()?.download_events().await }
+    }
+
+    /// Runs `trigger` and waits for the first download the active browsing context starts
+    /// meanwhile to finish. See [`Page::wait_for_download`].
+    fn wait_for_download<F>(
+        &mut self,
+        trigger: F,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<Download, FileDownloadError>> + Send
+    where
+        F: Future + Send,
+    {
+        async move {
+            self.active_page()?
+                .wait_for_download(trigger, timeout)
+                .await
+        }
+    }
+
     // ── Script evaluation ────────────────────────────────────────────────────
 
     /// Evaluates a JavaScript expression in the active browsing context.
@@ -845,6 +1055,35 @@ pub trait BidiBrowser: Send + Sync {
         }
     }
 
+    /// Calls the JavaScript function `function` in the active browsing context with `args`
+    /// and deserializes its result into `R`. See [`Page::evaluate`].
+    fn evaluate<R, A>(
+        &mut self,
+        function: impl Into<String> + Send,
+        args: A,
+    ) -> impl Future<Output = Result<R, EvaluateError>> + Send
+    where
+        R: DeserializeOwned,
+        A: EvaluateArgs + Send,
+    {
+        async move { self.active_page()?.evaluate(function, args).await }
+    }
+
+    /// Exposes `function` to scripts in the active browsing context as `window[name]`. See
+    /// [`Page::expose_function`].
+    fn expose_function<F, Fut, E>(
+        &mut self,
+        name: impl Into<String> + Send,
+        function: F,
+    ) -> impl Future<Output = Result<(), ExposeFunctionError>> + Send
+    where
+        F: Fn(Vec<serde_json::Value>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<serde_json::Value, E>> + Send + 'static,
+        E: std::fmt::Display,
+    {
+        async move { self.active_page()?.expose_function(name, function).await }
+    }
+
     // ── Preload scripts ──────────────────────────────────────────────────────
 
     /// Adds a preload script that runs in every new browsing context.
@@ -943,6 +1182,16 @@ pub trait BidiBrowser: Send + Sync {
         }
     }
 
+    // ── PDF ──────────────────────────────────────────────────────────────────
+
+    /// Prints the active browsing context to PDF. See [`Page::pdf`].
+    fn pdf(
+        &mut self,
+        options: PdfOptions,
+    ) -> impl Future<Output = Result<Vec<u8>, PdfError>> + Send {
+        async move { self.active_page()?.pdf(options).await }
+    }
+
     // ── Emulation ────────────────────────────────────────────────────────────
 
     /// Emulates a timezone for the active browsing context.
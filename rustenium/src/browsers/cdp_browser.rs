@@ -1,11 +1,25 @@
+use crate::browsers::ShadowPierce;
 use crate::browsers::chrome::tab::ChromeTab;
 use crate::conduit::cdp::adapter::CdpAdapter;
+use crate::console::{ConsoleCollector, ConsoleMessage, PageError};
+use crate::dialog::{Dialog, FileChooser, HandlerRegistration};
+use crate::download::{Download, DownloadBehavior, DownloadEvent};
 use crate::error::cdp::{
-    CreateTabError, EmulateDeviceMetricsError, EvaluateScriptError, LocateError, NavigateError,
-    NodesFetchError, PreloadScriptError, ScreenshotError,
+    ConsoleError, CreateTabError, EmulateDeviceMetricsError, EvaluateError, EvaluateScriptError,
+    ExposeFunctionError, FrameError, LocateError, NavigateError, NodesFetchError,
+    PreloadScriptError, ScreenshotError, WaitError,
 };
+use crate::error::dialog::{DialogError, FileChooserError};
+use crate::error::download::FileDownloadError;
+use crate::error::pdf::PdfError;
+use crate::evaluate::{EvaluateArgs, exposed_function};
 use crate::input::{CdpKeyboard, CdpMouse, CdpTouchscreen, HumanMouse};
 use crate::nodes::AXNode;
+use crate::pdf::PdfOptions;
+use crate::wait::{CdpEventType, LoadState, SelectorState, UrlMatcher, race_trigger};
+use rustenium_bidi_definitions::browsing_context::types::{
+    InnerTextLocatorMatchType, Locator as BidiLocator,
+};
 use rustenium_cdp_definitions::Command;
 use rustenium_cdp_definitions::base::CommandResponse;
 use rustenium_cdp_definitions::browser_protocol::accessibility::commands::GetFullAxTree;
@@ -26,14 +40,18 @@ use rustenium_cdp_definitions::browser_protocol::page::results::NavigateResult;
 use rustenium_cdp_definitions::browser_protocol::page::type_builders::ViewportBuilder;
 use rustenium_cdp_definitions::browser_protocol::page::types::ScriptIdentifier;
 use rustenium_cdp_definitions::browser_protocol::page::types::{
-    ReferrerPolicy, TransitionType, Viewport,
+    Frame, FrameId, FrameTree, ReferrerPolicy, TransitionType, Viewport,
 };
 use rustenium_cdp_definitions::browser_protocol::target::commands::CreateTarget;
 use rustenium_cdp_definitions::js_protocol::runtime::results::EvaluateResult;
 use rustenium_cdp_definitions::js_protocol::runtime::types::RemoteObjectId;
+use rustenium_core::CdpEventManagement;
 use rustenium_core::error::{CdpCommandResultError, CdpSessionSendError};
 use rustenium_core::transport::WebsocketConnectionTransport;
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
 use std::time::Duration;
+use tokio::sync::mpsc::UnboundedReceiver;
 
 #[derive(Debug, Clone, Default)]
 pub struct NavigateOptions {
@@ -360,6 +378,38 @@ pub trait CdpBrowser: Send + Sync {
         }
     }
 
+    // ── PDF ──────────────────────────────────────────────────────────────────
+
+    /// Prints the active page to PDF with `Page.printToPDF`, streaming the document back
+    /// through `IO.read`. `options.shrink_to_fit` has no CDP counterpart and is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::cdp_browser::CdpBrowser;
+    /// use rustenium::browsers::{ChromeConfig, chrome};
+    /// use rustenium::pdf::{PdfOptionsBuilder, PdfOrientation};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ChromeConfig { enable_cdp: true, ..Default::default() };
+    /// let mut browser = chrome(Some(config)).await;
+    /// browser.navigate("https://example.com/report").await?;
+    /// let options = PdfOptionsBuilder::default()
+    ///     .orientation(PdfOrientation::Landscape)
+    ///     .save_path("reports")
+    ///     .build();
+    /// let pdf = browser.pdf(options).await?;
+    /// println!("{} bytes", pdf.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn pdf(
+        &mut self,
+        options: PdfOptions,
+    ) -> impl Future<Output = Result<Vec<u8>, PdfError>> + Send {
+        self.adapter_mut().pdf(options)
+    }
+
     // ── Locating ─────────────────────────────────────────────────────────────
 
     /// Find the first element matching `selector`. Returns `None` if not found.
@@ -368,7 +418,7 @@ pub trait CdpBrowser: Send + Sync {
         selector: Selector,
     ) -> impl Future<Output = Result<Option<Self::BrowserNode>, LocateError>> + Send {
         async move {
-            match self.adapter_mut().locate(selector.as_str()).await? {
+            match self.adapter_mut().locate(&selector).await? {
                 Some(node) => Ok(Some(self.build_node(node))),
                 None => Ok(None),
             }
@@ -381,22 +431,54 @@ pub trait CdpBrowser: Send + Sync {
         selector: Selector,
     ) -> impl Future<Output = Result<Vec<Self::BrowserNode>, LocateError>> + Send {
         async move {
-            let nodes = self.adapter_mut().locate_all(selector.as_str()).await?;
+            let nodes = self.adapter_mut().locate_all(&selector).await?;
+            Ok(nodes.into_iter().map(|n| self.build_node(n)).collect())
+        }
+    }
+
+    /// Find the first element matching `selector`, also looking inside shadow roots.
+    /// The light DOM is searched first, then each shadow root from the outermost inwards.
+    fn locate_piercing(
+        &mut self,
+        selector: Selector,
+        pierce: ShadowPierce,
+    ) -> impl Future<Output = Result<Option<Self::BrowserNode>, LocateError>> + Send {
+        async move {
+            match self
+                .adapter_mut()
+                .locate_piercing(&selector, pierce)
+                .await?
+            {
+                Some(node) => Ok(Some(self.build_node(node))),
+                None => Ok(None),
+            }
+        }
+    }
+
+    /// Find all elements matching `selector`, including those inside shadow roots.
+    fn locate_all_piercing(
+        &mut self,
+        selector: Selector,
+        pierce: ShadowPierce,
+    ) -> impl Future<Output = Result<Vec<Self::BrowserNode>, LocateError>> + Send {
+        async move {
+            let nodes = self
+                .adapter_mut()
+                .locate_all_piercing(&selector, pierce)
+                .await?;
             Ok(nodes.into_iter().map(|n| self.build_node(n)).collect())
         }
     }
 
     /// Poll until the first element matching `selector` appears, or `timeout` elapses.
+    /// [`wait_for_selector`](CdpBrowser::wait_for_selector) waits without polling.
     fn wait_for(
         &mut self,
         selector: Selector,
         timeout: Duration,
     ) -> impl Future<Output = Result<Self::BrowserNode, LocateError>> + Send {
         async move {
-            let node = self
-                .adapter_mut()
-                .wait_for(selector.as_str(), timeout)
-                .await?;
+            let node = self.adapter_mut().wait_for(&selector, timeout).await?;
             Ok(self.build_node(node))
         }
     }
@@ -406,16 +488,299 @@ pub trait CdpBrowser: Send + Sync {
         &mut self,
         selector: Selector,
         timeout: Duration,
+    ) -> impl Future<Output = Result<Vec<Self::BrowserNode>, LocateError>> + Send {
+        async move {
+            let nodes = self.adapter_mut().wait_for_all(&selector, timeout).await?;
+            Ok(nodes.into_iter().map(|n| self.build_node(n)).collect())
+        }
+    }
+
+    // ── Waiting ──────────────────────────────────────────────────────────────
+
+    /// Runs `trigger` and waits for the navigation it causes in the main frame, returning
+    /// the URL navigated to. Navigations to a new document finish at their `load` lifecycle
+    /// event; fragment and History API navigations finish as soon as they happen.
+    fn wait_for_navigation<F>(
+        &self,
+        trigger: F,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<String, WaitError>> + Send
+    where
+        F: Future + Send,
+    {
+        let mut adapter = self.adapter().clone();
+        async move {
+            let main_frame = adapter.frame_tree().await?.frame.id;
+            let before = adapter.lifecycle.frame(main_frame.as_ref());
+            let navigated = adapter.lifecycle.wait_until(main_frame.as_ref(), |frame| {
+                (frame.loader_id != before.loader_id && frame.events.contains("load"))
+                    || frame.same_document_navigations != before.same_document_navigations
+            });
+            let navigated = async {
+                navigated.await;
+                Some(())
+            };
+            match race_trigger(trigger, navigated, timeout).await {
+                Some(()) => Ok(adapter.lifecycle.frame(main_frame.as_ref()).url),
+                None => Err(WaitError::Timeout("a navigation".to_string(), timeout)),
+            }
+        }
+    }
+
+    /// Waits until the main frame's current document reaches `state`, returning at once if
+    /// it already has. [`LoadState::NetworkIdle`] is Chrome's own `networkIdle` lifecycle
+    /// event.
+    fn wait_for_load_state(
+        &self,
+        state: LoadState,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<(), WaitError>> + Send {
+        let mut adapter = self.adapter().clone();
+        async move {
+            let main_frame = adapter.frame_tree().await?.frame.id;
+            tokio::time::timeout(
+                timeout,
+                adapter.lifecycle.wait_until(main_frame.as_ref(), |frame| {
+                    frame.events.contains(state.lifecycle_event())
+                }),
+            )
+            .await
+            .map_err(|_| WaitError::Timeout(state.to_string(), timeout))
+        }
+    }
+
+    /// Waits until the main frame's URL matches `url`, returning at once if it already
+    /// does. A navigation to a new document counts once it has loaded; fragment and History
+    /// API navigations count as soon as they happen.
+    fn wait_for_url(
+        &self,
+        url: impl Into<UrlMatcher>,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<(), WaitError>> + Send {
+        let matcher = url.into();
+        let mut adapter = self.adapter().clone();
+        async move {
+            let main_frame = adapter.frame_tree().await?.frame;
+            if matcher.matches(&main_frame.url) {
+                return Ok(());
+            }
+            tokio::time::timeout(
+                timeout,
+                adapter
+                    .lifecycle
+                    .wait_until(main_frame.id.as_ref(), |frame| {
+                        matcher.matches(&frame.url) && frame.events.contains("load")
+                    }),
+            )
+            .await
+            .map_err(|_| WaitError::Timeout(matcher.to_string(), timeout))
+        }
+    }
+
+    /// Runs `trigger` and returns the first event of type `E` that `predicate` accepts.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::cdp_browser::CdpBrowser;
+    /// use rustenium::browsers::{ChromeConfig, chrome};
+    /// use rustenium_cdp_definitions::browser_protocol::page::events::JavascriptDialogOpening;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ChromeConfig { enable_cdp: true, ..Default::default() };
+    /// let browser = chrome(Some(config)).await;
+    /// let dialog = browser
+    ///     .wait_for_event::<JavascriptDialogOpening, _, _>(
+    ///         async {},
+    ///         |event| event.params.message.contains("Are you sure"),
+    ///         Duration::from_secs(10),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn wait_for_event<E, F, P>(
+        &self,
+        trigger: F,
+        predicate: P,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<E, WaitError>> + Send
+    where
+        E: CdpEventType,
+        F: Future + Send,
+        P: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        let session = self.adapter().session.clone();
+        async move {
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            let handler = session
+                .lock()
+                .await
+                .add_event_handler([E::IDENTIFIER], move |event| {
+                    if let Ok(event) = event.try_into_event::<E>()
+                        && predicate(&event)
+                    {
+                        let _ = tx.send(event);
+                    }
+                    async {}
+                });
+            let event = race_trigger(trigger, rx.recv(), timeout).await;
+            session.lock().await.remove_cdp_event_handler(&handler);
+            event.ok_or_else(|| WaitError::Timeout(E::IDENTIFIER.to_string(), timeout))
+        }
+    }
+
+    /// Waits until the first element matching `selector` in the main frame reaches
+    /// `state`, returning it for [`SelectorState::Attached`] and [`SelectorState::Visible`]
+    /// and `None` for the others.
+    ///
+    /// Rather than polling like [`wait_for`](CdpBrowser::wait_for), this installs a
+    /// `MutationObserver` in an isolated world that reports back through a
+    /// `Runtime.addBinding` binding as soon as the state is reached. Role and frame-owner
+    /// selectors are re-located whenever the DOM changes instead. The observer watches the
+    /// current document, so a navigation during the wait ends in a timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::cdp_browser::CdpBrowser;
+    /// use rustenium::browsers::{ChromeConfig, chrome};
+    /// use rustenium::wait::SelectorState;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ChromeConfig { enable_cdp: true, ..Default::default() };
+    /// let browser = chrome(Some(config)).await;
+    /// let results = browser
+    ///     .wait_for_selector("#results".into(), SelectorState::Visible, Duration::from_secs(10))
+    ///     .await?;
+    /// browser
+    ///     .wait_for_selector(".spinner".into(), SelectorState::Detached, Duration::from_secs(10))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn wait_for_selector(
+        &self,
+        selector: Selector,
+        state: SelectorState,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<Option<Self::BrowserNode>, WaitError>> + Send {
+        let mut adapter = self.adapter().clone();
+        async move {
+            let node = adapter.wait_for_selector(&selector, state, timeout).await?;
+            Ok(node.map(|node| self.build_node(node)))
+        }
+    }
+
+    // ── Frames ───────────────────────────────────────────────────────────────
+
+    /// Every frame nested in the attached target, at any depth, parents before their
+    /// children. The main frame is not included.
+    ///
+    /// Out-of-process frames (cross-site iframes under site isolation) run in their own
+    /// targets and are not listed; the BiDi [`Page::frames`](crate::page::Page::frames)
+    /// covers them.
+    fn frames(&mut self) -> impl Future<Output = Result<Vec<Frame>, FrameError>> + Send {
+        async move {
+            let tree = self.adapter_mut().frame_tree().await?;
+            let mut frames = Vec::new();
+            collect_frames(tree.child_frames, &mut frames);
+            Ok(frames)
+        }
+    }
+
+    /// The first frame whose `name` attribute is `name`.
+    fn frame_by_name(
+        &mut self,
+        name: &str,
+    ) -> impl Future<Output = Result<Option<Frame>, FrameError>> + Send {
+        async move {
+            Ok(self
+                .frames()
+                .await?
+                .into_iter()
+                .find(|frame| frame.name.as_deref() == Some(name)))
+        }
+    }
+
+    /// The first frame whose URL contains `url`.
+    fn frame_by_url(
+        &mut self,
+        url: &str,
+    ) -> impl Future<Output = Result<Option<Frame>, FrameError>> + Send {
+        async move {
+            Ok(self
+                .frames()
+                .await?
+                .into_iter()
+                .find(|frame| frame.url.contains(url)))
+        }
+    }
+
+    /// The id of the frame hosted by the first element matching `selector`, or `None` if
+    /// nothing matches or the element is not a frame.
+    fn content_frame(
+        &mut self,
+        selector: Selector,
+    ) -> impl Future<Output = Result<Option<FrameId>, LocateError>> + Send {
+        async move {
+            let node = self.adapter_mut().locate(&selector).await?;
+            Ok(node.and_then(|node| node.frame_id))
+        }
+    }
+
+    /// Find the first element matching `selector` inside the frame `frame_id`.
+    fn locate_in_frame(
+        &mut self,
+        frame_id: &FrameId,
+        selector: Selector,
+    ) -> impl Future<Output = Result<Option<Self::BrowserNode>, LocateError>> + Send {
+        async move {
+            match self
+                .adapter_mut()
+                .locate_in_frame(frame_id, &selector)
+                .await?
+            {
+                Some(node) => Ok(Some(self.build_node(node))),
+                None => Ok(None),
+            }
+        }
+    }
+
+    /// Find all elements matching `selector` inside the frame `frame_id`.
+    fn locate_all_in_frame(
+        &mut self,
+        frame_id: &FrameId,
+        selector: Selector,
     ) -> impl Future<Output = Result<Vec<Self::BrowserNode>, LocateError>> + Send {
         async move {
             let nodes = self
                 .adapter_mut()
-                .wait_for_all(selector.as_str(), timeout)
+                .locate_all_in_frame(frame_id, &selector)
                 .await?;
             Ok(nodes.into_iter().map(|n| self.build_node(n)).collect())
         }
     }
 
+    /// Evaluates `expression` in an isolated world of the frame `frame_id`. The world sees
+    /// the frame's DOM but not the page's own JavaScript globals.
+    fn evaluate_in_frame(
+        &mut self,
+        frame_id: &FrameId,
+        expression: impl Into<String>,
+        await_promise: bool,
+    ) -> impl Future<Output = Result<EvaluateResult, EvaluateScriptError>> + Send {
+        let expression = expression.into();
+        let adapter = self.adapter_mut();
+        async move {
+            adapter
+                .evaluate_in_frame(frame_id, &expression, await_promise)
+                .await
+        }
+    }
+
     fn fetch_node(
         &mut self,
         options: FetchNodeOptions,
@@ -470,6 +835,298 @@ pub trait CdpBrowser: Send + Sync {
         async move { adapter.evaluate_script(&expression, await_promise).await }
     }
 
+    /// Calls the JavaScript function `function` in the main frame with `args` and
+    /// deserializes what it returns, once any promise has settled, into `R`.
+    ///
+    /// `args` is a tuple of the positional arguments, each serialized with serde: `()` passes
+    /// none and a single argument is written `(value,)`. Elements go in
+    /// as [`NodeRef::cdp`](crate::evaluate::NodeRef::cdp) and arrive as the live DOM
+    /// elements. The result comes back by value, so elements cannot be returned. An
+    /// exception thrown or a promise rejected by the script comes back as
+    /// [`EvaluateError::Exception`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::cdp_browser::CdpBrowser;
+    /// use rustenium::browsers::{ChromeConfig, chrome};
+    /// use rustenium::evaluate::NodeRef;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ChromeConfig { enable_cdp: true, ..Default::default() };
+    /// let mut browser = chrome(Some(config)).await;
+    /// let sum: i64 = browser.evaluate("(a, b) => a + b", (2, 3)).await?;
+    /// let heading = browser.locate("h1".into()).await?.unwrap();
+    /// let text: String = browser
+    ///     .evaluate("(element) => element.textContent", (NodeRef::cdp(heading.node_id()),))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn evaluate<R, A>(
+        &self,
+        function: impl Into<String>,
+        args: A,
+    ) -> impl Future<Output = Result<R, EvaluateError>> + Send
+    where
+        R: DeserializeOwned,
+        A: EvaluateArgs,
+    {
+        let function = function.into();
+        let args = args.into_args();
+        let mut adapter = self.adapter().clone();
+        async move {
+            let args = args.map_err(|e| EvaluateError::SerializeError(e.to_string()))?;
+            let value = adapter.evaluate(&function, args).await?;
+            serde_json::from_value(value)
+                .map_err(|e| EvaluateError::DeserializeError(e.to_string()))
+        }
+    }
+
+    /// Exposes `function` to page scripts as `window[name]`: calling it from the page returns a
+    /// promise for what `function` returns, and an error rejects it with its message.
+    ///
+    /// Arguments reach `function` as JSON, the way `JSON.stringify` writes them. Calls go out
+    /// through a `Runtime.addBinding` binding, which survives navigations and is there in
+    /// every frame of the target, including the documents already loaded. Exposing a name
+    /// again replaces it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::cdp_browser::CdpBrowser;
+    /// use rustenium::browsers::{ChromeConfig, chrome};
+    /// use serde_json::Value;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ChromeConfig { enable_cdp: true, ..Default::default() };
+    /// let mut browser = chrome(Some(config)).await;
+    /// browser
+    ///     .expose_function("double", |args: Vec<Value>| async move {
+    ///         let n = args.first().and_then(Value::as_i64).ok_or("expected a number")?;
+    ///         Ok::<_, &str>(Value::from(n * 2))
+    ///     })
+    ///     .await?;
+    /// let doubled: i64 = browser.evaluate("() => window.double(21)", ()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expose_function<F, Fut, E>(
+        &mut self,
+        name: impl Into<String>,
+        function: F,
+    ) -> impl Future<Output = Result<(), ExposeFunctionError>> + Send
+    where
+        F: Fn(Vec<serde_json::Value>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<serde_json::Value, E>> + Send + 'static,
+        E: std::fmt::Display,
+    {
+        let name = name.into();
+        let function = exposed_function(function);
+        let adapter = self.adapter_mut();
+        async move { adapter.expose_function(&name, function).await }
+    }
+
+    // ── Console ──────────────────────────────────────────────────────────────
+
+    /// Streams the target's console messages from now on, one per `Runtime.consoleAPICalled`.
+    /// The listener goes away once the receiver is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::cdp_browser::CdpBrowser;
+    /// use rustenium::browsers::{ChromeConfig, chrome};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ChromeConfig { enable_cdp: true, ..Default::default() };
+    /// let mut browser = chrome(Some(config)).await;
+    /// let mut messages = browser.console_messages().await?;
+    /// browser.evaluate_script("console.warn('careful')", false).await?;
+    /// let message = messages.recv().await.unwrap();
+    /// println!("[{}] {}", message.level, message.text);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn console_messages(
+        &mut self,
+    ) -> impl Future<Output = Result<UnboundedReceiver<ConsoleMessage>, ConsoleError>> + Send {
+        self.adapter_mut().console_messages()
+    }
+
+    /// Streams the target's uncaught errors and unhandled rejections from now on, one per
+    /// `Runtime.exceptionThrown`. The listener goes away once the receiver is dropped.
+    fn page_errors(
+        &mut self,
+    ) -> impl Future<Output = Result<UnboundedReceiver<PageError>, ConsoleError>> + Send {
+        self.adapter_mut().page_errors()
+    }
+
+    /// Buffers the target's console messages and uncaught errors from now on. The listeners
+    /// go away once every clone of the collector is dropped.
+    fn collect_console(
+        &mut self,
+    ) -> impl Future<Output = Result<ConsoleCollector, ConsoleError>> + Send {
+        self.adapter_mut().collect_console()
+    }
+
+    // ── Dialogs ──────────────────────────────────────────────────────────────
+
+    /// Calls `handler` with every JavaScript dialog the target opens from now on, one per
+    /// `Page.javascriptDialogOpening`, until the returned registration is unregistered. The
+    /// page is blocked until the handler accepts or dismisses the dialog.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::cdp_browser::CdpBrowser;
+    /// use rustenium::browsers::{ChromeConfig, chrome};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ChromeConfig { enable_cdp: true, ..Default::default() };
+    /// let mut browser = chrome(Some(config)).await;
+    /// browser
+    ///     .on_dialog(|dialog| async move {
+    ///         println!("{}: {}", dialog.kind(), dialog.message());
+    ///         let _ = dialog.accept(None).await;
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn on_dialog<F, Fut>(
+        &mut self,
+        handler: F,
+    ) -> impl Future<Output = Result<HandlerRegistration, DialogError>> + Send
+    where
+        F: Fn(Dialog) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.adapter_mut().on_dialog(handler)
+    }
+
+    /// Runs `trigger` and returns the first JavaScript dialog the target opens meanwhile.
+    /// A script that opens a dialog only returns once the dialog is answered, so `trigger`
+    /// runs alongside the wait and is dropped as soon as the dialog opens.
+    fn wait_for_dialog<F>(
+        &mut self,
+        trigger: F,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<Dialog, DialogError>> + Send
+    where
+        F: Future + Send,
+    {
+        self.adapter_mut().wait_for_dialog(trigger, timeout)
+    }
+
+    // ── File choosers ────────────────────────────────────────────────────────
+
+    /// Calls `handler` with every native file chooser the target tries to open from now on,
+    /// one per `Page.fileChooserOpened`, until the returned registration is unregistered.
+    /// Choosers are intercepted and not shown until then.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::cdp_browser::CdpBrowser;
+    /// use rustenium::browsers::{ChromeConfig, chrome};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ChromeConfig { enable_cdp: true, ..Default::default() };
+    /// let mut browser = chrome(Some(config)).await;
+    /// let registration = browser
+    ///     .on_file_chooser(|chooser| async move {
+    ///         if let Err(e) = chooser.set_files(&["avatar.png"]).await {
+    ///             eprintln!("could not pick the file: {e}");
+    ///         }
+    ///     })
+    ///     .await?;
+    /// // Choosers show up natively again once the last handler is gone
+    /// registration.unregister().await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn on_file_chooser<F, Fut>(
+        &mut self,
+        handler: F,
+    ) -> impl Future<Output = Result<HandlerRegistration, FileChooserError>> + Send
+    where
+        F: Fn(FileChooser) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.adapter_mut().on_file_chooser(handler)
+    }
+
+    /// Runs `trigger` and returns the first native file chooser the target tries to open
+    /// meanwhile.
+    fn wait_for_file_chooser<F>(
+        &mut self,
+        trigger: F,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<FileChooser, FileChooserError>> + Send
+    where
+        F: Future + Send,
+    {
+        self.adapter_mut().wait_for_file_chooser(trigger, timeout)
+    }
+
+    // ── Downloads ────────────────────────────────────────────────────────────
+
+    /// Sets what the browser does with downloads via `Browser.setDownloadBehavior`. Chrome
+    /// only reports downloads once this was called.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::cdp_browser::CdpBrowser;
+    /// use rustenium::browsers::{ChromeConfig, chrome};
+    /// use rustenium::download::{DownloadBehavior, DownloadEvent};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ChromeConfig { enable_cdp: true, ..Default::default() };
+    /// let mut browser = chrome(Some(config)).await;
+    /// browser
+    ///     .set_download_behavior(DownloadBehavior::Allow { dir: "downloads".into() })
+    ///     .await?;
+    /// let mut events = browser.download_events().await?;
+    /// tokio::spawn(async move {
+    ///     while let Some(event) = events.recv().await {
+    ///         if let DownloadEvent::Progress(progress) = event {
+    ///             println!("{}/{} bytes", progress.received_bytes, progress.total_bytes);
+    ///         }
+    ///     }
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_download_behavior(
+        &mut self,
+        behavior: DownloadBehavior,
+    ) -> impl Future<Output = Result<(), FileDownloadError>> + Send {
+        self.adapter_mut().set_download_behavior(behavior)
+    }
+
+    /// Streams what happens to downloads from now on, including their progress.
+    fn download_events(
+        &mut self,
+    ) -> impl Future<Output = Result<UnboundedReceiver<DownloadEvent>, FileDownloadError>> + Send
+    {
+        self.adapter_mut().download_events()
+    }
+
+    /// Runs `trigger` and waits for the first download started meanwhile to finish or be
+    /// canceled.
+    fn wait_for_download<F>(
+        &mut self,
+        trigger: F,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<Download, FileDownloadError>> + Send
+    where
+        F: Future + Send,
+    {
+        self.adapter_mut().wait_for_download(trigger, timeout)
+    }
+
     fn add_preload_script(
         &mut self,
         source: impl Into<String>,
@@ -510,6 +1167,14 @@ pub trait CdpBrowser: Send + Sync {
     }
 }
 
+/// Flattens a frame tree's `children` into `frames`, parents before their children.
+fn collect_frames(children: Option<Vec<FrameTree>>, frames: &mut Vec<Frame>) {
+    for child in children.into_iter().flatten() {
+        frames.push(*child.frame);
+        collect_frames(child.child_frames, frames);
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct FetchNodeOptions {
     node_id: Option<NodeId>,
@@ -661,10 +1326,50 @@ impl CreateTabOptionsBuilder {
     }
 }
 
-/// A CSS selector used to locate DOM elements.
-#[derive(Debug, Clone)]
+/// How to find DOM elements with the `locate*` and `wait_for*` methods.
+///
+/// Strings convert into CSS selectors, and every BiDi
+/// [`Locator`](rustenium_bidi_definitions::browsing_context::types::Locator) converts too, so
+/// the selector macros (`css!`, `xpath!`, `role!`, `text!`, `context!`) work with both
+/// protocols:
+///
+/// ```no_run
+/// use rustenium::browsers::cdp_browser::CdpBrowser;
+/// use rustenium::browsers::{ChromeConfig, chrome};
+/// use rustenium_macros::{role, text};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = ChromeConfig {
+///     enable_cdp: true,
+///     ..ChromeConfig::default()
+/// };
+/// let mut browser = chrome(Some(config)).await;
+/// let submit = browser.locate(role!("button", name = "Submit").into()).await?;
+/// let sign_in = browser.locate(text!("Sign in", exact).into()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Selector {
+    /// A CSS selector, matched with `DOM.querySelector`.
     Css(String),
+    /// An XPath expression, evaluated against the document (or frame document).
+    XPath(String),
+    /// Elements exposing an ARIA `role` and/or accessible `name`, matched against the
+    /// accessibility tree with `Accessibility.queryAXTree`.
+    Role {
+        role: Option<String>,
+        name: Option<String>,
+    },
+    /// Elements whose rendered text (whitespace-collapsed) equals `text` when `exact`, or
+    /// contains it otherwise. Only the innermost matching elements are returned.
+    Text {
+        text: String,
+        exact: bool,
+        ignore_case: bool,
+    },
+    /// The `<iframe>` or `<frame>` element hosting the frame with this id.
+    FrameOwner(FrameId),
 }
 
 impl Selector {
@@ -672,16 +1377,124 @@ impl Selector {
         Selector::Css(selector.into())
     }
 
+    pub fn xpath(expression: impl Into<String>) -> Self {
+        Selector::XPath(expression.into())
+    }
+
+    /// Elements with the ARIA role `role`, whatever their accessible name.
+    pub fn role(role: impl Into<String>) -> Self {
+        Selector::Role {
+            role: Some(role.into()),
+            name: None,
+        }
+    }
+
+    /// Elements with the ARIA role `role` and the accessible name `name`.
+    pub fn role_named(role: impl Into<String>, name: impl Into<String>) -> Self {
+        Selector::Role {
+            role: Some(role.into()),
+            name: Some(name.into()),
+        }
+    }
+
+    /// Elements whose text contains `text`, case-sensitively.
+    pub fn text(text: impl Into<String>) -> Self {
+        Selector::Text {
+            text: text.into(),
+            exact: false,
+            ignore_case: false,
+        }
+    }
+
+    /// Elements whose whole text is `text`.
+    pub fn exact_text(text: impl Into<String>) -> Self {
+        Selector::Text {
+            text: text.into(),
+            exact: true,
+            ignore_case: false,
+        }
+    }
+
+    /// The raw selector string: the CSS selector, XPath expression, text, role (or name
+    /// when no role is set) or frame id.
     pub fn as_str(&self) -> &str {
         match self {
-            Selector::Css(s) => s.as_str(),
+            Selector::Css(s) | Selector::XPath(s) => s.as_str(),
+            Selector::Role { role, name } => role.as_deref().or(name.as_deref()).unwrap_or(""),
+            Selector::Text { text, .. } => text.as_str(),
+            Selector::FrameOwner(frame_id) => frame_id.as_ref(),
         }
     }
 }
 
-impl<S: Into<String>> From<S> for Selector {
-    fn from(s: S) -> Self {
-        Selector::Css(s.into())
+impl std::fmt::Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Selector::Css(s) => write!(f, "{}", s),
+            Selector::XPath(s) => write!(f, "xpath={}", s),
+            Selector::Role { role, name } => {
+                write!(f, "role={}", role.as_deref().unwrap_or("*"))?;
+                match name {
+                    Some(name) => write!(f, "[name={:?}]", name),
+                    None => Ok(()),
+                }
+            }
+            Selector::Text {
+                text,
+                exact,
+                ignore_case,
+            } => {
+                write!(f, "text={:?}", text)?;
+                if *exact {
+                    write!(f, " exact")?;
+                }
+                if *ignore_case {
+                    write!(f, " ignore_case")?;
+                }
+                Ok(())
+            }
+            Selector::FrameOwner(frame_id) => write!(f, "frame={}", frame_id.as_ref()),
+        }
+    }
+}
+
+// A blanket `impl<S: Into<String>>` would overlap with `From<BidiLocator>`, so the string
+// types are listed one by one: any of them converts to a CSS selector.
+macro_rules! css_selector_from {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Selector {
+                fn from(s: $ty) -> Self {
+                    Selector::Css(s.into())
+                }
+            }
+        )*
+    };
+}
+
+css_selector_from!(&str, &mut str, String, &String, Box<str>, Cow<'_, str>, char);
+
+/// Maps a BiDi locator onto the equivalent CDP lookup. An inner-text locator's `maxDepth`
+/// has no CDP counterpart and is ignored; a context locator finds the element hosting that
+/// frame, since Chrome uses the same ids for BiDi contexts and CDP frames.
+impl From<BidiLocator> for Selector {
+    fn from(locator: BidiLocator) -> Self {
+        match locator {
+            BidiLocator::CssLocator(css) => Selector::Css(css.value),
+            BidiLocator::XPathLocator(xpath) => Selector::XPath(xpath.value),
+            BidiLocator::AccessibilityLocator(accessibility) => Selector::Role {
+                role: accessibility.value.role,
+                name: accessibility.value.name,
+            },
+            BidiLocator::InnerTextLocator(inner_text) => Selector::Text {
+                text: inner_text.value,
+                exact: inner_text.match_type == Some(InnerTextLocatorMatchType::Full),
+                ignore_case: inner_text.ignore_case.unwrap_or(false),
+            },
+            BidiLocator::ContextLocator(context) => {
+                Selector::FrameOwner(FrameId::from(context.value.context.as_ref().to_string()))
+            }
+        }
     }
 }
 
@@ -1,24 +1,51 @@
 use super::capabilities::ChromeCapabilities;
+use super::handle::BrowserHandle;
 use crate::browsers::BidiBrowser;
 use crate::browsers::cdp_browser::CdpBrowser;
-use crate::cdp::adapter::fetch_ws_debugger_url_with_retry;
-use crate::conduit::bidi::drivers::{BidiDriver, DriverConfiguration, start_bidi_driver};
+use crate::browsers::config::{self, Env, LaunchModeSpec};
+use crate::browsers::supervisor::{BrowserEvent, Supervisor, Watched};
+use crate::cdp::adapter::{fetch_ws_debugger_url, fetch_ws_debugger_url_with_retry};
+use crate::conduit::bidi::drivers::{
+    BidiDriver, DriverConfiguration, SessionRecord, listen_to_context_creation,
+    replay_session_record, start_bidi_driver, start_remote_bidi_driver,
+};
 use crate::conduit::cdp::adapter::{CdpAdapter, start_cdp_session};
+use crate::discovery::{self, SystemBrowser};
+use crate::downloader::Downloader;
+use crate::domain::context::BrowsingContext as Context;
 use crate::error::bidi::BrowserCloseError;
+use crate::error::chrome::{AttachError, DetachError};
+use crate::error::config::ConfigError;
+use crate::error::downloader::DownloadError;
 use crate::input::cdp::{CdpKeyboard, CdpMouse};
 use crate::nodes::ChromeNode;
+use crate::page::ExposedFunctions;
 use rustenium_bidi_definitions::browsing_context::types::{BrowsingContext, Locator};
 use rustenium_bidi_definitions::script::types::NodeRemoteValue;
 use rustenium_bidi_definitions::session::types::ProxyConfiguration;
 use rustenium_cdp_definitions::browser_protocol::dom::types::Node as DomNode;
 use rustenium_core::find_free_port;
-use rustenium_core::process::Process;
+use rustenium_core::process::{Process, ProcessHandle, ProcessOutput, kill_process_on_port};
+use rustenium_core::stats::{ResourceSampler, ResourceThresholds};
+use rustenium_core::webdriver::WebDriverSession;
+use rustenium_core::{BidiSession, CdpSession};
 
 use rustenium_core::transport::{ConnectionTransportConfig, WebsocketConnectionTransport};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Mutex as TokioMutex;
+use tokio::sync::broadcast;
 
 /// How Chrome is launched and managed.
-#[derive(Debug, Clone, Default)]
+///
+/// In config files and `RUSTENIUM_LAUNCH_MODE` it is written as `spawn-and-attach`,
+/// `driver-managed`, `remote:<port>` or `remote-driver:<url>`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(try_from = "String")]
 pub enum ChromeLaunchMode {
     /// Rustenium starts Chrome and attaches chromedriver to it (default).
     #[default]
@@ -27,6 +54,56 @@ pub enum ChromeLaunchMode {
     Remote(u16),
     /// Let chromedriver spawn and manage Chrome.
     DriverManaged,
+    /// Create the session on a remote WebDriver server (chromedriver, Selenium Grid)
+    /// over HTTP `POST /session`, then connect to the returned `webSocketUrl`.
+    /// The value is the server URL, e.g. `http://localhost:4444/wd/hub`.
+    RemoteDriver(String),
+}
+
+impl FromStr for ChromeLaunchMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse()? {
+            LaunchModeSpec::SpawnAndAttach => ChromeLaunchMode::SpawnAndAttach,
+            LaunchModeSpec::DriverManaged => ChromeLaunchMode::DriverManaged,
+            LaunchModeSpec::Remote(port) => ChromeLaunchMode::Remote(port),
+            LaunchModeSpec::RemoteDriver(url) => ChromeLaunchMode::RemoteDriver(url),
+        })
+    }
+}
+
+impl TryFrom<String> for ChromeLaunchMode {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Which Chrome for Testing binary to launch. Written as `chrome` or `headless-shell`
+/// in config files and `RUSTENIUM_CHROME_FLAVOR`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChromeFlavor {
+    /// Full Chrome (default).
+    #[default]
+    Chrome,
+    /// `chrome-headless-shell`: the old headless mode as a standalone binary. Always
+    /// headless, starts faster and uses less memory than full Chrome.
+    HeadlessShell,
+}
+
+impl FromStr for ChromeFlavor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "chrome" => Ok(ChromeFlavor::Chrome),
+            "headless-shell" => Ok(ChromeFlavor::HeadlessShell),
+            _ => Err("expected chrome or headless-shell".to_string()),
+        }
+    }
 }
 
 /// Configuration for Chrome browser and chromedriver.
@@ -55,8 +132,34 @@ pub enum ChromeLaunchMode {
 ///     launch_mode: ChromeLaunchMode::Remote(9222),
 ///     ..Default::default()
 /// };
+///
+/// // Create the session on a remote WebDriver server
+/// let config = ChromeConfig {
+///     launch_mode: ChromeLaunchMode::RemoteDriver("http://localhost:4444".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Configs can also be loaded with [`from_toml`](Self::from_toml) and
+/// [`from_env`](Self::from_env); keys are the field names, and missing keys keep
+/// their defaults:
+///
+/// ```
+/// use rustenium::browsers::{ChromeConfig, ChromeLaunchMode};
+///
+/// let config = ChromeConfig::from_toml(r#"
+///     launch_mode = "driver-managed"
+///     enable_cdp = true
+///     browser_flags = ["--headless=new"]
+///
+///     [capabilities]
+///     acceptInsecureCerts = true
+///     "goog:chromeOptions" = { args = ["--mute-audio"] }
+/// "#).unwrap();
+/// assert!(matches!(config.launch_mode, ChromeLaunchMode::DriverManaged));
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct ChromeConfig {
     /// Path to the chromedriver executable.
     pub driver_executable_path: String,
@@ -67,7 +170,8 @@ pub struct ChromeConfig {
     /// Port for the WebDriver server (auto-assigned if None).
     pub port: Option<u16>,
 
-    /// Additional flags to pass to chromedriver.
+    /// Additional flags to pass to chromedriver. Not read from config files.
+    #[serde(skip)]
     pub driver_flags: Vec<&'static str>,
 
     /// Chrome capabilities for configuring browser behavior.
@@ -90,6 +194,10 @@ pub struct ChromeConfig {
     /// Defaults to auto-downloaded Chrome if not specified.
     pub chrome_executable_path: Option<String>,
 
+    /// Which Chrome binary to launch in SpawnAndAttach and DriverManaged modes
+    /// (default: full Chrome).
+    pub flavor: ChromeFlavor,
+
     /// Chrome user data directory. Used in Managed mode.
     /// If not specified, uses a temporary directory.
     pub user_data_dir: Option<String>,
@@ -103,6 +211,22 @@ pub struct ChromeConfig {
 
     /// Enable CDP (Chrome DevTools Protocol) connection (default: false).
     pub enable_cdp: bool,
+
+    /// Prefer Chrome/Chromium/Edge and chromedriver installed on the system over
+    /// downloading pinned versions (default: false). See [`crate::discovery`].
+    pub prefer_system_browser: bool,
+
+    /// Relaunch Chrome with this config when it crashes or disconnects, restoring preload
+    /// scripts, network intercepts and timezone overrides (default: false).
+    /// Supported in SpawnAndAttach and DriverManaged modes. Event handlers added with
+    /// `subscribe_events` are not restored. See [`ChromeBrowser::browser_events`].
+    pub auto_restart: bool,
+
+    /// Write the output of the Chrome and chromedriver Rustenium spawns to
+    /// `rustenium-chrome-<port>.log` and `rustenium-chromedriver-<port>.log` in the temp
+    /// directory instead of piping it into this process (default: false). Required by
+    /// [`ChromeBrowser::detach`], so they keep running after this process exits.
+    pub detachable: bool,
 }
 
 impl Default for ChromeConfig {
@@ -118,11 +242,169 @@ impl Default for ChromeConfig {
             launch_mode: ChromeLaunchMode::default(),
             remote_debugging_port: None,
             chrome_executable_path: None,
+            flavor: ChromeFlavor::default(),
             user_data_dir: None,
             browser_flags: None,
             enable_bidi: true,
             enable_cdp: false,
+            prefer_system_browser: false,
+            auto_restart: false,
+            detachable: false,
+        }
+    }
+}
+
+impl ChromeConfig {
+    /// Parses a config from a TOML document.
+    pub fn from_toml(toml: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// Reads and parses a TOML config file.
+    pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        config::from_toml_file(path.as_ref())
+    }
+
+    /// Builds a config from the default one and the environment. See [`with_env`](Self::with_env).
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::default().with_env()
+    }
+
+    /// Overrides fields with the environment variables that are set:
+    ///
+    /// | Variable | Field |
+    /// |---|---|
+    /// | `RUSTENIUM_CHROME_LAUNCH_MODE`, else `RUSTENIUM_LAUNCH_MODE` | `launch_mode` |
+    /// | `RUSTENIUM_CHROME_FLAVOR` | `flavor` |
+    /// | `RUSTENIUM_CHROME_HEADLESS` | adds `--headless=new` to (or strips it from) `browser_flags` |
+    /// | `RUSTENIUM_CHROME_ARGS` | appended to `browser_flags`, whitespace-separated |
+    /// | `RUSTENIUM_CHROME_PATH` | `chrome_executable_path` |
+    /// | `RUSTENIUM_CHROME_DRIVER_PATH` | `driver_executable_path` |
+    /// | `RUSTENIUM_CHROME_HOST` | `host` |
+    /// | `RUSTENIUM_CHROME_DRIVER_PORT` | `port` |
+    /// | `RUSTENIUM_CHROME_DEBUGGING_PORT` | `remote_debugging_port` |
+    /// | `RUSTENIUM_CHROME_USER_DATA_DIR` | `user_data_dir` |
+    /// | `RUSTENIUM_CHROME_PROXY` | `proxy`, a manual `host:port` proxy |
+    /// | `RUSTENIUM_CHROME_CAPABILITIES` | `capabilities`, as a JSON object |
+    /// | `RUSTENIUM_CHROME_ENABLE_BIDI`, `_ENABLE_CDP`, `_SANDBOX`, `_PREFER_SYSTEM_BROWSER`, `_AUTO_RESTART`, `_DETACHABLE` | the matching flags |
+    pub fn with_env(mut self) -> Result<Self, ConfigError> {
+        let env = Env::new("RUSTENIUM_CHROME_");
+
+        if let Some(mode) = env.parse("LAUNCH_MODE")? {
+            self.launch_mode = mode;
+        } else if let Some(mode) = config::SHARED.parse("LAUNCH_MODE")? {
+            self.launch_mode = mode;
+        }
+        if let Some(flavor) = env.parse("FLAVOR")? {
+            self.flavor = flavor;
+        }
+        if let Some(headless) = env.bool("HEADLESS")? {
+            // chrome-headless-shell is always headless and takes no flag for it
+            let headless = headless && self.flavor == ChromeFlavor::Chrome;
+            config::set_headless(&mut self.browser_flags, headless, "--headless=new");
+        }
+        if let Some(args) = env.args("ARGS") {
+            self.browser_flags.get_or_insert_with(Vec::new).extend(args);
+        }
+        if let Some(path) = env.string("PATH") {
+            self.chrome_executable_path = Some(path);
+        }
+        if let Some(path) = env.string("DRIVER_PATH") {
+            self.driver_executable_path = path;
+        }
+        if let Some(host) = env.string("HOST") {
+            self.host = Some(host);
+        }
+        if let Some(port) = env.parse("DRIVER_PORT")? {
+            self.port = Some(port);
+        }
+        if let Some(port) = env.parse("DEBUGGING_PORT")? {
+            self.remote_debugging_port = Some(port);
+        }
+        if let Some(dir) = env.string("USER_DATA_DIR") {
+            self.user_data_dir = Some(dir);
+        }
+        if let Some(proxy) = env.proxy("PROXY") {
+            self.proxy = Some(proxy);
+        }
+        if let Some(capabilities) = env.json("CAPABILITIES")? {
+            self.capabilities = capabilities;
+        }
+        if let Some(enable) = env.bool("ENABLE_BIDI")? {
+            self.enable_bidi = enable;
         }
+        if let Some(enable) = env.bool("ENABLE_CDP")? {
+            self.enable_cdp = enable;
+        }
+        if let Some(sandbox) = env.bool("SANDBOX")? {
+            self.sandbox = sandbox;
+        }
+        if let Some(prefer) = env.bool("PREFER_SYSTEM_BROWSER")? {
+            self.prefer_system_browser = prefer;
+        }
+        if let Some(restart) = env.bool("AUTO_RESTART")? {
+            self.auto_restart = restart;
+        }
+        if let Some(detachable) = env.bool("DETACHABLE")? {
+            self.detachable = detachable;
+        }
+        Ok(self)
+    }
+
+    /// Fills in `chrome_executable_path` and `driver_executable_path` where the launch mode
+    /// needs them and they are not set, from the system when `prefer_system_browser` is set
+    /// and with `downloader` otherwise.
+    ///
+    /// [`ChromeBrowser::new`] does this with the default [`Downloader`] and panics if a
+    /// download fails. Call it first to handle the error instead:
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{ChromeBrowser, ChromeConfig};
+    /// use rustenium::downloader::Downloader;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut config = ChromeConfig::default();
+    /// config.resolve_executables(&Downloader::new().cache_dir("/var/cache/rustenium"))?;
+    /// let browser = ChromeBrowser::new(config).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resolve_executables(&mut self, downloader: &Downloader) -> Result<(), DownloadError> {
+        let launches_chrome = matches!(
+            self.launch_mode,
+            ChromeLaunchMode::SpawnAndAttach | ChromeLaunchMode::DriverManaged
+        );
+        if launches_chrome && self.chrome_executable_path.is_none() {
+            self.chrome_executable_path = ChromeBrowser::system_chrome(self);
+        }
+        // chromedriver finds a system Chrome itself, but not chrome-headless-shell
+        let needs_download = launches_chrome
+            && self.chrome_executable_path.is_none()
+            && (matches!(self.launch_mode, ChromeLaunchMode::SpawnAndAttach)
+                || self.flavor == ChromeFlavor::HeadlessShell);
+        if needs_download {
+            let path = match self.flavor {
+                ChromeFlavor::Chrome => downloader.chrome()?,
+                ChromeFlavor::HeadlessShell => downloader.chrome_headless_shell()?,
+            };
+            self.chrome_executable_path = Some(path.to_string_lossy().into_owned());
+        }
+
+        let needs_driver = self.enable_bidi
+            && !matches!(self.launch_mode, ChromeLaunchMode::RemoteDriver(_))
+            && self.driver_executable_path.is_empty();
+        if needs_driver {
+            let path = match self
+                .prefer_system_browser
+                .then(discovery::find_chromedriver)
+                .flatten()
+            {
+                Some(path) => path,
+                None => downloader.chromedriver()?,
+            };
+            self.driver_executable_path = path.to_string_lossy().into_owned();
+        }
+        Ok(())
     }
 }
 
@@ -145,13 +427,30 @@ impl DriverConfiguration for ChromeConfig {
 
         flags
     }
+
+    fn output(&self) -> ProcessOutput {
+        if self.detachable {
+            ProcessOutput::File(std::env::temp_dir().join(format!(
+                "rustenium-chromedriver-{}.log",
+                self.port.unwrap_or_default()
+            )))
+        } else {
+            ProcessOutput::Piped
+        }
+    }
 }
 
 pub struct ChromeBrowser {
     config: ChromeConfig,
+    /// The config as passed in, before launch fills in ports and capabilities.
+    initial_config: ChromeConfig,
     driver: Option<BidiDriver<WebsocketConnectionTransport>>,
     chrome_process: Option<Process>,
     cdp_adapter: Option<CdpAdapter<WebsocketConnectionTransport>>,
+    supervisor: Supervisor,
+    /// Set by [`attach`](Self::attach): Chrome and chromedriver taken over from another
+    /// program. They are not our children, so they are killed through their ports on close.
+    adopted: Option<BrowserHandle>,
 }
 
 impl std::fmt::Debug for ChromeBrowser {
@@ -173,6 +472,11 @@ impl ChromeBrowser {
     ///
     /// * `config` - Chrome configuration including driver path, browser options, and capabilities
     ///
+    /// # Panics
+    ///
+    /// Panics if Chrome or chromedriver has to be downloaded and the download fails. Call
+    /// [`ChromeConfig::resolve_executables`] beforehand to get the [`DownloadError`] instead.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -189,9 +493,30 @@ impl ChromeBrowser {
     /// # }
     /// ```
     pub async fn new(mut config: ChromeConfig) -> ChromeBrowser {
+        let initial_config = config.clone();
         if matches!(config.launch_mode, ChromeLaunchMode::DriverManaged) && !config.enable_bidi {
             panic!("Config Enable Bidi must be set to true to use DriverManaged Mode");
         }
+        if let ChromeLaunchMode::RemoteDriver(server_url) = config.launch_mode.clone() {
+            if !config.enable_bidi || config.enable_cdp {
+                panic!(
+                    "RemoteDriver Mode requires Enable Bidi set to true and Enable CDP set to false"
+                );
+            }
+            Self::init_chrome(&mut config, 0).await;
+            let driver = Self::init_remote_bidi(&mut config, &server_url).await;
+            let mut browser = ChromeBrowser {
+                config,
+                initial_config,
+                driver: Some(driver),
+                chrome_process: None,
+                cdp_adapter: None,
+                supervisor: Supervisor::new(),
+                adopted: None,
+            };
+            browser.supervise().await;
+            return browser;
+        }
         let port = find_free_port().unwrap();
         config.port = Some(config.port.unwrap_or(port));
 
@@ -202,6 +527,7 @@ impl ChromeBrowser {
             ChromeLaunchMode::DriverManaged => config
                 .remote_debugging_port
                 .unwrap_or_else(|| find_free_port().unwrap()),
+            ChromeLaunchMode::RemoteDriver(_) => unreachable!(),
         };
         config.remote_debugging_port = Some(chrome_port);
 
@@ -232,23 +558,90 @@ impl ChromeBrowser {
             (false, false) => (None, None),
         };
 
-        ChromeBrowser {
+        let mut browser = ChromeBrowser {
             config,
+            initial_config,
             driver,
             chrome_process,
             cdp_adapter,
+            supervisor: Supervisor::new(),
+            adopted: None,
+        };
+        browser.supervise().await;
+        browser
+    }
+
+    /// (Re)starts the supervisor watching the Chrome process and protocol connections.
+    async fn supervise(&mut self) {
+        let mut watched = Watched {
+            process: self.chrome_process.as_ref().map(Process::handle),
+            connections: Vec::new(),
+        };
+        if let Some(ref driver) = self.driver {
+            watched
+                .connections
+                .push(driver.session.lock().await.on_close());
+        }
+        if let Some(ref adapter) = self.cdp_adapter {
+            watched
+                .connections
+                .push(adapter.session.lock().await.on_close());
         }
+
+        if !self.config.auto_restart {
+            self.supervisor.watch(watched);
+            return;
+        }
+        if !matches!(
+            self.config.launch_mode,
+            ChromeLaunchMode::SpawnAndAttach | ChromeLaunchMode::DriverManaged
+        ) {
+            tracing::warn!(
+                "auto_restart is not supported in {:?} mode",
+                self.config.launch_mode
+            );
+            self.supervisor.watch(watched);
+            return;
+        }
+
+        let relaunch = Relaunch {
+            config: self.initial_config.clone(),
+            host: self
+                .config
+                .host
+                .clone()
+                .unwrap_or(String::from("localhost")),
+            driver_port: self.config.port,
+            process: watched.process.clone(),
+            bidi: self.driver.as_ref().map(|driver| RelaunchBidi {
+                session: driver.session.clone(),
+                default_context: Arc::new(Mutex::new(
+                    driver
+                        .browsing_contexts
+                        .lock()
+                        .unwrap()
+                        .first()
+                        .map(|context| context.id().clone()),
+                )),
+                browsing_contexts: driver.browsing_contexts.clone(),
+                record: driver.record.clone(),
+                exposed: driver.exposed.clone(),
+            }),
+            cdp: self.cdp_adapter.clone(),
+        };
+        self.supervisor
+            .start(watched, Some(move || relaunch.clone().run()));
     }
 
     async fn init_chrome(config: &mut ChromeConfig, chrome_port: u16) -> Option<Process> {
         let (debugger_address, chrome_process) = match &config.launch_mode {
             ChromeLaunchMode::Remote(port) => (Some(format!("localhost:{}", port)), None),
             ChromeLaunchMode::SpawnAndAttach => {
-                let chrome_exe = config.chrome_executable_path.clone().unwrap_or_else(|| {
-                    crate::downloader::ensure_chrome()
-                        .to_string_lossy()
-                        .into_owned()
-                });
+                Self::ensure_executables(config);
+                let chrome_exe = config
+                    .chrome_executable_path
+                    .clone()
+                    .expect("resolved by ChromeConfig::resolve_executables");
 
                 let user_data_dir = config.user_data_dir.clone().unwrap_or_else(|| {
                     std::env::temp_dir()
@@ -256,15 +649,18 @@ impl ChromeBrowser {
                         .display()
                         .to_string()
                 });
+                config.user_data_dir = Some(user_data_dir.clone());
 
                 let mut chrome_args = vec![
                     format!("--remote-debugging-port={}", chrome_port),
                     format!("--user-data-dir={}", user_data_dir),
                     "--no-first-run".to_string(),
                     "--no-default-browser-check".to_string(),
-                    "--start-maximized".to_string(),
-                    "--disable-infobars".to_string(),
                 ];
+                if config.flavor == ChromeFlavor::Chrome {
+                    chrome_args.push("--start-maximized".to_string());
+                    chrome_args.push("--disable-infobars".to_string());
+                }
                 if !config.sandbox {
                     chrome_args.push("--no-sandbox".to_string());
                 }
@@ -273,7 +669,14 @@ impl ChromeBrowser {
                     chrome_args.extend(flags.iter().cloned());
                 }
 
-                let chrome_proc = Process::create(chrome_exe, chrome_args);
+                let output = if config.detachable {
+                    ProcessOutput::File(
+                        std::env::temp_dir().join(format!("rustenium-chrome-{}.log", chrome_port)),
+                    )
+                } else {
+                    ProcessOutput::Piped
+                };
+                let chrome_proc = Process::spawn(chrome_exe, chrome_args, [], output);
 
                 // Wait briefly for Chrome to start
                 tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -288,15 +691,23 @@ impl ChromeBrowser {
                 config
                     .capabilities
                     .add_arg(format!("remote-debugging-port={}", chrome_port));
+                Self::ensure_executables(config);
+                if let Some(chrome_exe) = config.chrome_executable_path.clone() {
+                    config.capabilities.binary(chrome_exe);
+                }
                 (None, None)
             }
+            // The remote server spawns Chrome wherever it runs
+            ChromeLaunchMode::RemoteDriver(_) => (None, None),
         };
 
         if let Some(addr) = debugger_address {
             config.capabilities.debugger_address(addr);
         }
-        config.capabilities.add_arg("start-maximized".to_string());
-        config.capabilities.add_arg("disable-infobars".to_string());
+        if config.flavor == ChromeFlavor::Chrome {
+            config.capabilities.add_arg("start-maximized".to_string());
+            config.capabilities.add_arg("disable-infobars".to_string());
+        }
 
         if !config.sandbox {
             config.capabilities.add_arg("no-sandbox".to_string());
@@ -319,10 +730,16 @@ impl ChromeBrowser {
         config: &mut ChromeConfig,
         ct_config: &ConnectionTransportConfig,
     ) -> BidiDriver<WebsocketConnectionTransport> {
-        if config.driver_executable_path.is_empty() {
-            config.driver_executable_path = crate::downloader::ensure_chromedriver()
-                .to_string_lossy()
-                .into_owned();
+        Self::ensure_executables(config);
+        if config.prefer_system_browser
+            && let Some(ref chrome_exe) = config.chrome_executable_path
+        {
+            let browser = SystemBrowser::from_path(chrome_exe);
+            if let Err(e) =
+                discovery::check_chromedriver_version(&browser, &config.driver_executable_path)
+            {
+                tracing::warn!("chromedriver may not support {:?}: {}", browser.path, e);
+            }
         }
         let capabilities = config.capabilities.clone().build();
         let (session, process) = start_bidi_driver(config, ct_config, capabilities).await;
@@ -339,6 +756,52 @@ impl ChromeBrowser {
         driver
     }
 
+    /// Chrome-family browser found on the system, if `prefer_system_browser` is set.
+    /// There is no system install of chrome-headless-shell, so it is always downloaded.
+    fn system_chrome(config: &ChromeConfig) -> Option<String> {
+        if !config.prefer_system_browser || config.flavor == ChromeFlavor::HeadlessShell {
+            return None;
+        }
+        let browser = discovery::find_chrome()?;
+        tracing::info!(
+            "Using system {:?} {} at {:?}",
+            browser.kind,
+            browser.version.as_deref().unwrap_or("(unknown version)"),
+            browser.path
+        );
+        Some(browser.path.to_string_lossy().into_owned())
+    }
+
+    /// [`ChromeConfig::resolve_executables`] with the default downloader, panicking if a
+    /// download fails.
+    fn ensure_executables(config: &mut ChromeConfig) {
+        config
+            .resolve_executables(&Downloader::new())
+            .unwrap_or_else(|e| panic!("Failed to download Chrome or chromedriver: {e}"));
+    }
+
+    async fn init_remote_bidi(
+        config: &mut ChromeConfig,
+        server_url: &str,
+    ) -> BidiDriver<WebsocketConnectionTransport> {
+        let capabilities = config.capabilities.clone().build();
+        let (session, remote_session) = start_remote_bidi_driver(server_url, capabilities)
+            .await
+            .unwrap_or_else(|e| panic!("Error creating remote WebDriver session: {}", e));
+
+        let mut driver = BidiDriver::new(
+            server_url.to_string(),
+            vec![],
+            session,
+            0,
+            Arc::new(Mutex::new(Vec::new())),
+            Process::default(),
+        );
+        driver.remote_session = Some(remote_session);
+        driver.listen_to_context_creation().await.unwrap();
+        driver
+    }
+
     async fn init_cdp(host: &str, chrome_port: u16) -> CdpAdapter<WebsocketConnectionTransport> {
         let ws_debugger_url = fetch_ws_debugger_url_with_retry(host, chrome_port)
             .await
@@ -348,6 +811,7 @@ impl ChromeBrowser {
         let mut cdp_adapter = CdpAdapter::new(cdp_session);
         cdp_adapter.listen_to_target_creation().await.unwrap();
         cdp_adapter.enable_page_domain().await.unwrap();
+        cdp_adapter.listen_to_lifecycle_events().await.unwrap();
         cdp_adapter
     }
 
@@ -355,6 +819,11 @@ impl ChromeBrowser {
         if self.driver.is_some() {
             return;
         }
+        if let ChromeLaunchMode::RemoteDriver(server_url) = self.config.launch_mode.clone() {
+            self.driver = Some(Self::init_remote_bidi(&mut self.config, &server_url).await);
+            self.supervise().await;
+            return;
+        }
         let host = self
             .config
             .host
@@ -367,6 +836,7 @@ impl ChromeBrowser {
             ..ConnectionTransportConfig::default()
         };
         self.driver = Some(Self::init_bidi(&mut self.config, &ct_config).await);
+        self.supervise().await;
     }
 
     pub async fn connect_cdp(&mut self) {
@@ -383,6 +853,221 @@ impl ChromeBrowser {
             .remote_debugging_port
             .expect("Remote debugging port not set");
         self.cdp_adapter = Some(Self::init_cdp(&host, chrome_port).await);
+        self.supervise().await;
+    }
+
+    /// Disconnects from Chrome but leaves it and chromedriver running, returning a handle
+    /// that [`attach`](Self::attach) can reconnect with, from this or another process.
+    ///
+    /// Not supported in DriverManaged mode, where chromedriver quits Chrome with the session.
+    /// Processes Rustenium spawned must have been launched with
+    /// [`detachable`](ChromeConfig::detachable) set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{BrowserHandle, ChromeBrowser, ChromeConfig, chrome};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let browser = chrome(Some(ChromeConfig {
+    ///     detachable: true,
+    ///     ..Default::default()
+    /// }))
+    /// .await;
+    /// let handle = browser.detach().await?;
+    /// std::fs::write("browser.json", serde_json::to_string(&handle)?)?;
+    ///
+    /// // Later, possibly after a restart
+    /// let handle: BrowserHandle = serde_json::from_str(&std::fs::read_to_string("browser.json")?)?;
+    /// let browser = ChromeBrowser::attach(handle).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn detach(mut self) -> Result<BrowserHandle, DetachError> {
+        if matches!(self.config.launch_mode, ChromeLaunchMode::DriverManaged) {
+            return Err(DetachError::Unsupported("DriverManaged".to_string()));
+        }
+        let piped = self
+            .chrome_process
+            .as_ref()
+            .is_some_and(|process| !process.is_detachable())
+            || self
+                .driver
+                .as_ref()
+                .is_some_and(|driver| !driver.driver_process.is_detachable());
+        if piped {
+            return Err(DetachError::PipedOutput);
+        }
+        self.supervisor.stop();
+        let adopted = self.adopted.take();
+
+        let host = self
+            .config
+            .host
+            .clone()
+            .unwrap_or(String::from("localhost"));
+        let remote_debugging_port = match self.config.launch_mode {
+            ChromeLaunchMode::RemoteDriver(_) => None,
+            _ => self.config.remote_debugging_port,
+        };
+        let cdp_ws_url = match remote_debugging_port {
+            Some(port) => fetch_ws_debugger_url(&host, port).await.ok(),
+            None => None,
+        };
+        let mut handle = BrowserHandle {
+            chrome_pid: self
+                .chrome_process
+                .take()
+                .and_then(|process| process.detach().ok().flatten())
+                .or(adopted.as_ref().and_then(|a| a.chrome_pid)),
+            remote_debugging_port,
+            cdp_ws_url,
+            user_data_dir: self.config.user_data_dir.clone(),
+            driver_pid: None,
+            driver_port: None,
+            driver_url: None,
+            session_id: None,
+            bidi_ws_url: None,
+            enable_bidi: self.driver.is_some(),
+            enable_cdp: self.cdp_adapter.is_some(),
+            host,
+        };
+
+        if let Some(mut driver) = self.driver.take() {
+            handle.session_id = Some(driver.session.lock().await.id().to_string());
+            if let Some(remote_session) = driver.remote_session.take() {
+                handle.driver_url = Some(remote_session.server_url);
+                handle.bidi_ws_url = Some(remote_session.web_socket_url);
+            } else if let Ok(Some(pid)) = std::mem::take(&mut driver.driver_process).detach() {
+                handle.driver_pid = Some(pid);
+                handle.driver_port = self.config.port;
+            } else if let Some(ref adopted) = adopted {
+                handle.driver_pid = adopted.driver_pid;
+                handle.driver_port = adopted.driver_port;
+            }
+            if let (None, Some(port)) = (&handle.bidi_ws_url, handle.driver_port) {
+                handle.bidi_ws_url = Some(format!("ws://{}:{}/session", handle.host, port));
+            }
+        }
+        tracing::info!("Detached from Chrome: {:?}", handle);
+        Ok(handle)
+    }
+
+    /// Reconnects to a Chrome left running by [`detach`](Self::detach), over the same
+    /// protocols. Chromedriver gets a new session attached to Chrome, and is restarted if
+    /// it is no longer running. RemoteDriver sessions are resumed on their server.
+    ///
+    /// Closing the returned browser shuts down the Chrome and chromedriver that Rustenium
+    /// originally spawned.
+    pub async fn attach(handle: BrowserHandle) -> Result<ChromeBrowser, AttachError> {
+        let mut config = ChromeConfig {
+            host: Some(handle.host.clone()),
+            user_data_dir: handle.user_data_dir.clone(),
+            enable_bidi: handle.enable_bidi,
+            enable_cdp: handle.enable_cdp,
+            ..ChromeConfig::default()
+        };
+        let mut adopted = handle.clone();
+        let mut driver = None;
+        let mut cdp_adapter = None;
+
+        if let Some(ref server_url) = handle.driver_url {
+            config.launch_mode = ChromeLaunchMode::RemoteDriver(server_url.clone());
+            let remote_session = WebDriverSession {
+                server_url: server_url.clone(),
+                session_id: handle
+                    .session_id
+                    .clone()
+                    .ok_or(AttachError::MissingField("session_id"))?,
+                web_socket_url: handle
+                    .bidi_ws_url
+                    .clone()
+                    .ok_or(AttachError::MissingField("bidi_ws_url"))?,
+                capabilities: serde_json::Value::Null,
+            };
+            let ct_config = remote_session
+                .transport_config()
+                .map_err(|e| AttachError::Unreachable(e.to_string()))?;
+            tokio::net::TcpStream::connect(ct_config.host_port())
+                .await
+                .map_err(|e| AttachError::Unreachable(format!("{}: {}", server_url, e)))?;
+            let session = BidiSession::connect(&ct_config, remote_session.session_id.clone()).await;
+            let mut bidi = BidiDriver::new(
+                server_url.clone(),
+                vec![],
+                Arc::new(TokioMutex::new(session)),
+                0,
+                Arc::new(Mutex::new(Vec::new())),
+                Process::default(),
+            );
+            bidi.remote_session = Some(remote_session);
+            bidi.listen_to_context_creation().await?;
+            driver = Some(bidi);
+        } else {
+            let chrome_port = handle
+                .remote_debugging_port
+                .ok_or(AttachError::MissingField("remote_debugging_port"))?;
+            fetch_ws_debugger_url(&handle.host, chrome_port)
+                .await
+                .map_err(AttachError::Unreachable)?;
+            // Chrome is already running; attach to it like a Remote browser
+            config.launch_mode = ChromeLaunchMode::Remote(chrome_port);
+            config.remote_debugging_port = Some(chrome_port);
+            Self::init_chrome(&mut config, chrome_port).await;
+
+            if handle.enable_bidi {
+                let running_driver = match handle.driver_port {
+                    Some(port) => tokio::net::TcpStream::connect((handle.host.as_str(), port))
+                        .await
+                        .is_ok()
+                        .then_some(port),
+                    None => None,
+                };
+                let ct_config = ConnectionTransportConfig {
+                    host: handle.host.clone(),
+                    port: running_driver.unwrap_or_else(|| find_free_port().unwrap()),
+                    ..ConnectionTransportConfig::default()
+                };
+                config.port = Some(ct_config.port);
+                driver = Some(match running_driver {
+                    Some(_) => {
+                        let capabilities = config.capabilities.clone().build();
+                        let session = BidiSession::new(&ct_config, capabilities).await;
+                        let mut bidi = BidiDriver::new(
+                            String::from("chromedriver"),
+                            vec![],
+                            Arc::new(TokioMutex::new(session)),
+                            0,
+                            Arc::new(Mutex::new(Vec::new())),
+                            Process::default(),
+                        );
+                        bidi.listen_to_context_creation().await?;
+                        bidi
+                    }
+                    None => {
+                        // The new chromedriver is our child and is killed with it
+                        adopted.driver_pid = None;
+                        adopted.driver_port = None;
+                        Self::init_bidi(&mut config, &ct_config).await
+                    }
+                });
+            }
+            if handle.enable_cdp {
+                cdp_adapter = Some(Self::init_cdp(&handle.host, chrome_port).await);
+            }
+        }
+
+        let mut browser = ChromeBrowser {
+            initial_config: config.clone(),
+            config,
+            driver,
+            chrome_process: None,
+            cdp_adapter,
+            supervisor: Supervisor::new(),
+            adopted: Some(adopted),
+        };
+        browser.supervise().await;
+        Ok(browser)
     }
 
     /// Returns a reference to the Chrome configuration.
@@ -394,6 +1079,199 @@ impl ChromeBrowser {
     pub fn get_browser_process(&self) -> &Option<Process> {
         &self.chrome_process
     }
+
+    /// Subscribes to crash, disconnect and restart events.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{BrowserEvent, ChromeConfig, chrome};
+    ///
+    /// # async fn example() {
+    /// let config = ChromeConfig {
+    ///     auto_restart: true,
+    ///     ..Default::default()
+    /// };
+    /// let browser = chrome(Some(config)).await;
+    /// let mut events = browser.browser_events();
+    /// tokio::spawn(async move {
+    ///     while let Ok(event) = events.recv().await {
+    ///         if let BrowserEvent::Crashed { status } = event {
+    ///             eprintln!("Chrome crashed: {:?}", status);
+    ///         }
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn browser_events(&self) -> broadcast::Receiver<BrowserEvent> {
+        self.supervisor.subscribe()
+    }
+
+    /// Samples the Chrome process tree every `interval` and reports threshold breaches.
+    /// With [`auto_restart`](ChromeConfig::auto_restart) set, the sampler follows Chrome
+    /// through restarts, publishing
+    /// [`ResourceEvent::Interrupted`](crate::browsers::ResourceEvent::Interrupted) while it
+    /// is down; otherwise it stops when Chrome exits. Returns `None` when Chrome was not
+    /// launched by this browser (driver-managed and remote modes).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{ResourceEvent, ResourceThresholds, chrome};
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() {
+    /// let browser = chrome(None).await;
+    /// let thresholds = ResourceThresholds {
+    ///     max_rss_bytes: Some(2 * 1024 * 1024 * 1024),
+    ///     ..Default::default()
+    /// };
+    /// let sampler = browser
+    ///     .sample_resources(Duration::from_secs(5), thresholds)
+    ///     .unwrap();
+    /// let mut events = sampler.subscribe();
+    /// while let Ok(event) = events.recv().await {
+    ///     if let ResourceEvent::ThresholdExceeded { resource, value, .. } = event {
+    ///         eprintln!("{:?} over limit: {}", resource, value);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn sample_resources(
+        &self,
+        interval: Duration,
+        thresholds: ResourceThresholds,
+    ) -> Option<ResourceSampler> {
+        let process = self.chrome_process.as_ref()?.handle();
+        Some(if self.config.auto_restart {
+            ResourceSampler::start_following(process, interval, thresholds)
+        } else {
+            ResourceSampler::start(process, interval, thresholds)
+        })
+    }
+}
+
+/// Everything the supervisor needs to relaunch Chrome and swap fresh sessions into the
+/// existing driver and adapter, so handles held by callers keep working.
+#[derive(Clone)]
+struct Relaunch {
+    config: ChromeConfig,
+    host: String,
+    driver_port: Option<u16>,
+    process: Option<ProcessHandle>,
+    bidi: Option<RelaunchBidi>,
+    cdp: Option<CdpAdapter<WebsocketConnectionTransport>>,
+}
+
+/// The shared parts of a [`BidiDriver`] that are refilled after a relaunch.
+#[derive(Clone)]
+struct RelaunchBidi {
+    session: Arc<TokioMutex<BidiSession<WebsocketConnectionTransport>>>,
+    /// The top-level context the browser opened with, the only one that carries over.
+    default_context: Arc<Mutex<Option<BrowsingContext>>>,
+    browsing_contexts: Arc<Mutex<Vec<Context>>>,
+    record: Arc<Mutex<SessionRecord>>,
+    exposed: ExposedFunctions,
+}
+
+impl Relaunch {
+    async fn run(self) -> Result<Watched, String> {
+        if let Some(ref process) = self.process {
+            let _ = process.kill();
+        }
+
+        let mut config = self.config;
+        config.port = self.driver_port;
+        let chrome_port = find_free_port().map_err(|e| e.to_string())?;
+        config.remote_debugging_port = Some(chrome_port);
+        let chrome_process = ChromeBrowser::init_chrome(&mut config, chrome_port).await;
+        if let (Some(handle), Some(chrome_process)) = (&self.process, chrome_process) {
+            handle.replace(chrome_process);
+        }
+
+        let mut watched = Watched {
+            process: self.process,
+            connections: Vec::new(),
+        };
+
+        if let Some(RelaunchBidi {
+            session,
+            default_context,
+            browsing_contexts,
+            record,
+            exposed,
+        }) = self.bidi
+        {
+            let ct_config = ConnectionTransportConfig {
+                host: self.host.clone(),
+                port: self.driver_port.ok_or("chromedriver port not set")?,
+                ..ConnectionTransportConfig::default()
+            };
+            let new_session = BidiSession::new(&ct_config, config.capabilities.build()).await;
+            let previous: Vec<_> = browsing_contexts
+                .lock()
+                .unwrap()
+                .drain(..)
+                .map(|context| context.id().clone())
+                .collect();
+            *session.lock().await = new_session;
+            exposed.lock().unwrap().clear();
+
+            listen_to_context_creation(&session, &browsing_contexts)
+                .await
+                .map_err(|e| e.to_string())?;
+            // Other tabs are not reopened, so only the default context has a counterpart
+            let current = browsing_contexts
+                .lock()
+                .unwrap()
+                .first()
+                .map(|context| context.id().clone());
+            let default = std::mem::replace(&mut *default_context.lock().unwrap(), current.clone());
+            let mut contexts = HashMap::new();
+            if let (Some(default), Some(current)) = (default, current)
+                && previous.contains(&default)
+            {
+                contexts.insert(default, current);
+            }
+            for context in previous.iter().filter(|c| !contexts.contains_key(*c)) {
+                tracing::warn!(
+                    "Browsing context {} was not restored after the relaunch",
+                    context.as_ref()
+                );
+            }
+            replay_session_record(&record, &session, &contexts).await;
+            watched.connections.push(session.lock().await.on_close());
+        }
+
+        if let Some(mut adapter) = self.cdp {
+            let ws_debugger_url = fetch_ws_debugger_url_with_retry(&self.host, chrome_port).await?;
+            let cdp_cc = ConnectionTransportConfig::from_ws_url(&ws_debugger_url)?;
+            let new_session = CdpSession::<WebsocketConnectionTransport>::ws_new(&cdp_cc).await;
+            *adapter.session.lock().await = new_session;
+            adapter.page_targets.lock().unwrap().clear();
+            adapter.lifecycle.clear();
+            adapter.isolated_worlds.reset().await;
+            adapter.exposed.lock().unwrap().clear();
+            *adapter.file_choosers.lock().await = 0;
+            adapter
+                .listen_to_target_creation()
+                .await
+                .map_err(|e| e.to_string())?;
+            adapter
+                .enable_page_domain()
+                .await
+                .map_err(|e| e.to_string())?;
+            adapter
+                .listen_to_lifecycle_events()
+                .await
+                .map_err(|e| e.to_string())?;
+            watched
+                .connections
+                .push(adapter.session.lock().await.on_close());
+        }
+
+        Ok(watched)
+    }
 }
 
 impl BidiBrowser for ChromeBrowser {
@@ -431,8 +1309,9 @@ impl BidiBrowser for ChromeBrowser {
 
     async fn close(mut self) -> Result<(), BrowserCloseError> {
         tracing::debug!("Closing ChromeBrowser");
+        self.supervisor.stop();
         if let Some(ref mut driver) = self.driver {
-            driver.end_session().await?;
+            driver.close().await?;
         }
         if let Some(ref mut adapter) = self.cdp_adapter {
             adapter.close().await;
@@ -440,6 +1319,14 @@ impl BidiBrowser for ChromeBrowser {
         if let Some(mut process) = self.chrome_process.take() {
             process.kill()?;
         }
+        if let Some(adopted) = self.adopted.take() {
+            if let (Some(_), Some(port)) = (adopted.chrome_pid, adopted.remote_debugging_port) {
+                kill_process_on_port(port);
+            }
+            if let (Some(_), Some(port)) = (adopted.driver_pid, adopted.driver_port) {
+                kill_process_on_port(port);
+            }
+        }
         tracing::debug!("ChromeBrowser closed");
         Ok(())
     }
@@ -23,12 +23,37 @@ use std::collections::HashMap;
 ///
 /// let capabilities_request = caps.build();
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Deserializes from a W3C capabilities object, with Chrome options under
+/// `goog:chromeOptions`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "CapabilitiesObject")]
 pub struct ChromeCapabilities {
     pub base_capabilities: CapabilityRequest,
     pub chrome_options: ChromeOptions,
 }
 
+#[derive(Deserialize)]
+struct CapabilitiesObject {
+    #[serde(rename = "goog:chromeOptions", default)]
+    chrome_options: ChromeOptions,
+    #[serde(flatten)]
+    base_capabilities: CapabilityRequest,
+}
+
+impl From<CapabilitiesObject> for ChromeCapabilities {
+    fn from(mut object: CapabilitiesObject) -> Self {
+        let defaults = Self::default();
+        if object.base_capabilities.browser_name.is_none() {
+            object.base_capabilities.browser_name = defaults.base_capabilities.browser_name;
+        }
+        ChromeCapabilities {
+            base_capabilities: object.base_capabilities,
+            chrome_options: object.chrome_options,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ChromeOptions {
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+/// A Chrome left running by [`ChromeBrowser::detach`](super::browser::ChromeBrowser::detach).
+///
+/// It serializes with serde, so it can be stored in a file and passed to
+/// [`ChromeBrowser::attach`](super::browser::ChromeBrowser::attach) from another process.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrowserHandle {
+    /// Host Chrome and chromedriver listen on.
+    pub host: String,
+    /// PID of Chrome, if Rustenium spawned it. `None` for Remote and RemoteDriver sessions.
+    pub chrome_pid: Option<u32>,
+    /// Chrome's remote debugging port. `None` for RemoteDriver sessions.
+    pub remote_debugging_port: Option<u16>,
+    /// CDP WebSocket URL of Chrome at the time of detaching.
+    pub cdp_ws_url: Option<String>,
+    /// Chrome user data directory, for spawned Chrome.
+    pub user_data_dir: Option<String>,
+    /// PID of the chromedriver Rustenium spawned.
+    pub driver_pid: Option<u32>,
+    /// Port of the chromedriver Rustenium spawned.
+    pub driver_port: Option<u16>,
+    /// WebDriver server URL of a RemoteDriver session.
+    pub driver_url: Option<String>,
+    /// BiDi session ID at the time of detaching.
+    pub session_id: Option<String>,
+    /// BiDi WebSocket URL of the session.
+    pub bidi_ws_url: Option<String>,
+    /// Whether BiDi was connected when detaching. [`attach`](super::browser::ChromeBrowser::attach)
+    /// reconnects the same protocols.
+    pub enable_bidi: bool,
+    /// Whether CDP was connected when detaching.
+    pub enable_cdp: bool,
+}
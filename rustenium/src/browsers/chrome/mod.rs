@@ -1,3 +1,4 @@
 pub mod browser;
 pub mod capabilities;
+pub mod handle;
 pub mod tab;
@@ -0,0 +1,154 @@
+use crate::error::config::ConfigError;
+use rustenium_bidi_definitions::session::types::{
+    ManualProxyConfiguration, ManualProxyConfigurationProxyType, ProxyConfiguration,
+};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Variables shared by every browser, read when the browser-specific one is unset.
+pub(crate) const SHARED: Env = Env::new("RUSTENIUM_");
+
+pub(crate) fn from_toml_file<T: DeserializeOwned>(path: &Path) -> Result<T, ConfigError> {
+    Ok(toml::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+/// Reads configuration from environment variables sharing a prefix such as `RUSTENIUM_CHROME_`.
+/// Unset and empty variables are treated alike.
+pub(crate) struct Env {
+    prefix: &'static str,
+}
+
+impl Env {
+    pub const fn new(prefix: &'static str) -> Self {
+        Env { prefix }
+    }
+
+    fn lookup(&self, name: &str) -> Option<(String, String)> {
+        let key = format!("{}{}", self.prefix, name);
+        match std::env::var(&key) {
+            Ok(value) if !value.trim().is_empty() => Some((key, value.trim().to_string())),
+            _ => None,
+        }
+    }
+
+    pub fn string(&self, name: &str) -> Option<String> {
+        self.lookup(name).map(|(_, value)| value)
+    }
+
+    pub fn parse<T>(&self, name: &str) -> Result<Option<T>, ConfigError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.lookup(name)
+            .map(|(key, value)| {
+                value
+                    .parse()
+                    .map_err(|e: T::Err| ConfigError::InvalidValue {
+                        key,
+                        value,
+                        reason: e.to_string(),
+                    })
+            })
+            .transpose()
+    }
+
+    /// Accepts `1`/`0`, `true`/`false`, `yes`/`no` and `on`/`off`.
+    pub fn bool(&self, name: &str) -> Result<Option<bool>, ConfigError> {
+        self.lookup(name)
+            .map(|(key, value)| match value.to_ascii_lowercase().as_str() {
+                "1" | "true" | "yes" | "on" => Ok(true),
+                "0" | "false" | "no" | "off" => Ok(false),
+                _ => Err(ConfigError::InvalidValue {
+                    key,
+                    value,
+                    reason: "expected a boolean".to_string(),
+                }),
+            })
+            .transpose()
+    }
+
+    /// Splits the value on whitespace.
+    pub fn args(&self, name: &str) -> Option<Vec<String>> {
+        self.string(name)
+            .map(|value| value.split_whitespace().map(String::from).collect())
+    }
+
+    pub fn json<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>, ConfigError> {
+        self.lookup(name)
+            .map(|(key, value)| {
+                serde_json::from_str(&value).map_err(|e| ConfigError::InvalidValue {
+                    key,
+                    value: value.clone(),
+                    reason: e.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    /// `host:port`, used for both HTTP and HTTPS traffic.
+    pub fn proxy(&self, name: &str) -> Option<ProxyConfiguration> {
+        self.string(name).map(|server| {
+            let mut proxy = ManualProxyConfiguration::new(
+                ManualProxyConfigurationProxyType::Manual,
+                HashMap::new(),
+            );
+            proxy.http_proxy = Some(server.clone());
+            proxy.ssl_proxy = Some(server);
+            ProxyConfiguration::ManualProxyConfiguration(proxy)
+        })
+    }
+}
+
+/// Adds `flag` to `flags` when `headless` is true, or strips every `--headless` variant
+/// when it is false.
+pub(crate) fn set_headless(flags: &mut Option<Vec<String>>, headless: bool, flag: &str) {
+    let list = flags.get_or_insert_with(Vec::new);
+    if headless {
+        if !list
+            .iter()
+            .any(|f| f.trim_start_matches('-').starts_with("headless"))
+        {
+            list.push(flag.to_string());
+        }
+    } else {
+        list.retain(|f| !f.trim_start_matches('-').starts_with("headless"));
+    }
+}
+
+/// Parses `spawn-and-attach`, `driver-managed`, `remote:<port>` and `remote-driver:<url>`.
+pub(crate) enum LaunchModeSpec {
+    SpawnAndAttach,
+    DriverManaged,
+    Remote(u16),
+    RemoteDriver(String),
+}
+
+impl FromStr for LaunchModeSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, arg) = match s.split_once(':') {
+            Some((kind, arg)) => (kind, Some(arg)),
+            None => (s, None),
+        };
+        match (kind.to_ascii_lowercase().as_str(), arg) {
+            ("spawn-and-attach", None) => Ok(LaunchModeSpec::SpawnAndAttach),
+            ("driver-managed", None) => Ok(LaunchModeSpec::DriverManaged),
+            ("remote", Some(port)) => port
+                .parse()
+                .map(LaunchModeSpec::Remote)
+                .map_err(|_| format!("invalid port {port:?}")),
+            ("remote-driver", Some(url)) if !url.is_empty() => {
+                Ok(LaunchModeSpec::RemoteDriver(url.to_string()))
+            }
+            _ => Err(
+                "expected spawn-and-attach, driver-managed, remote:<port> or remote-driver:<url>"
+                    .to_string(),
+            ),
+        }
+    }
+}
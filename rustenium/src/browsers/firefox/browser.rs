@@ -1,28 +1,77 @@
 use super::capabilities::FirefoxCapabilities;
+use super::profile::FirefoxProfile;
 use crate::browsers::BidiBrowser;
-use crate::conduit::bidi::drivers::BidiDriver;
+use crate::browsers::config::{self, Env, LaunchModeSpec};
+use crate::conduit::bidi::drivers::{
+    BidiDriver, DriverConfiguration, start_http_bidi_driver, start_remote_bidi_driver,
+};
+use crate::discovery;
+use crate::downloader::Downloader;
 use crate::error::bidi::BrowserCloseError;
+use crate::error::config::ConfigError;
+use crate::error::downloader::DownloadError;
 use crate::nodes::FirefoxNode;
 use rustenium_bidi_definitions::browsing_context::types::{BrowsingContext, Locator};
 use rustenium_bidi_definitions::script::types::NodeRemoteValue;
 use rustenium_bidi_definitions::session::types::ProxyConfiguration;
 use rustenium_core::find_free_port;
 use rustenium_core::process::Process;
+use rustenium_core::stats::{ResourceSampler, ResourceThresholds};
 use rustenium_core::transport::{ConnectionTransportConfig, WebsocketConnectionTransport};
+use serde::Deserialize;
+use std::path::Path;
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 /// How Firefox is launched and managed.
-#[derive(Debug, Clone, Default)]
+///
+/// In config files and `RUSTENIUM_LAUNCH_MODE` it is written as `spawn-and-attach`,
+/// `driver-managed`, `remote:<port>` or `remote-driver:<url>`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(try_from = "String")]
 pub enum FirefoxLaunchMode {
     /// Rustenium starts Firefox and connects to its BiDi WebSocket directly (default).
     #[default]
     SpawnAndAttach,
     /// Connect to an existing Firefox instance on the specified remote debugging port.
     Remote(u16),
+    /// Let geckodriver spawn and manage Firefox. `moz:firefoxOptions` from
+    /// [`FirefoxCapabilities`] (prefs, log level, env, profile) are honoured.
+    DriverManaged,
+    /// Create the session on a remote WebDriver server (geckodriver, Selenium Grid)
+    /// over HTTP `POST /session`, then connect to the returned `webSocketUrl`.
+    /// The value is the server URL, e.g. `http://localhost:4444/wd/hub`.
+    RemoteDriver(String),
+}
+
+impl FromStr for FirefoxLaunchMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse()? {
+            LaunchModeSpec::SpawnAndAttach => FirefoxLaunchMode::SpawnAndAttach,
+            LaunchModeSpec::DriverManaged => FirefoxLaunchMode::DriverManaged,
+            LaunchModeSpec::Remote(port) => FirefoxLaunchMode::Remote(port),
+            LaunchModeSpec::RemoteDriver(url) => FirefoxLaunchMode::RemoteDriver(url),
+        })
+    }
+}
+
+impl TryFrom<String> for FirefoxLaunchMode {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
 }
 
 /// Configuration for Firefox browser.
-#[derive(Debug, Clone, Default)]
+///
+/// Can be loaded with [`from_toml`](Self::from_toml) and [`from_env`](Self::from_env);
+/// keys are the field names, and missing keys keep their defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct FirefoxConfig {
     /// Host (default: localhost).
     pub host: Option<String>,
@@ -46,8 +95,175 @@ pub struct FirefoxConfig {
     /// Firefox profile directory. If not specified, uses a temporary directory.
     pub profile_dir: Option<String>,
 
+    /// Profile to write before launch (prefs, extensions, certificates).
+    /// Its directory takes precedence over `profile_dir`. Used in SpawnAndAttach
+    /// and DriverManaged modes. Not read from config files.
+    #[serde(skip)]
+    pub profile: Option<FirefoxProfile>,
+
     /// Additional Firefox command-line arguments.
     pub browser_flags: Option<Vec<String>>,
+
+    /// Path to the geckodriver executable. Used in DriverManaged mode.
+    /// Defaults to auto-downloaded geckodriver if not specified.
+    pub driver_executable_path: Option<String>,
+
+    /// Port for geckodriver (auto-assigned if None). Used in DriverManaged mode.
+    pub driver_port: Option<u16>,
+
+    /// Additional flags to pass to geckodriver. Not read from config files.
+    #[serde(skip)]
+    pub driver_flags: Vec<&'static str>,
+
+    /// Prefer a Firefox installed on the system over downloading the pinned
+    /// version (default: false). See [`crate::discovery`].
+    pub prefer_system_browser: bool,
+}
+
+impl FirefoxConfig {
+    /// Parses a config from a TOML document.
+    pub fn from_toml(toml: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// Reads and parses a TOML config file.
+    pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        config::from_toml_file(path.as_ref())
+    }
+
+    /// Builds a config from the default one and the environment. See [`with_env`](Self::with_env).
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::default().with_env()
+    }
+
+    /// Overrides fields with the environment variables that are set:
+    ///
+    /// | Variable | Field |
+    /// |---|---|
+    /// | `RUSTENIUM_FIREFOX_LAUNCH_MODE`, else `RUSTENIUM_LAUNCH_MODE` | `launch_mode` |
+    /// | `RUSTENIUM_FIREFOX_HEADLESS` | adds `--headless` to (or strips it from) `browser_flags` |
+    /// | `RUSTENIUM_FIREFOX_ARGS` | appended to `browser_flags`, whitespace-separated |
+    /// | `RUSTENIUM_FIREFOX_PATH` | `firefox_executable_path` |
+    /// | `RUSTENIUM_FIREFOX_DRIVER_PATH` | `driver_executable_path` |
+    /// | `RUSTENIUM_FIREFOX_HOST` | `host` |
+    /// | `RUSTENIUM_FIREFOX_DRIVER_PORT` | `driver_port` |
+    /// | `RUSTENIUM_FIREFOX_DEBUGGING_PORT` | `remote_debugging_port` |
+    /// | `RUSTENIUM_FIREFOX_PROFILE_DIR` | `profile_dir` |
+    /// | `RUSTENIUM_FIREFOX_PROXY` | `proxy`, a manual `host:port` proxy |
+    /// | `RUSTENIUM_FIREFOX_CAPABILITIES` | `capabilities`, as a JSON object |
+    /// | `RUSTENIUM_FIREFOX_PREFER_SYSTEM_BROWSER` | `prefer_system_browser` |
+    pub fn with_env(mut self) -> Result<Self, ConfigError> {
+        let env = Env::new("RUSTENIUM_FIREFOX_");
+
+        if let Some(mode) = env.parse("LAUNCH_MODE")? {
+            self.launch_mode = mode;
+        } else if let Some(mode) = config::SHARED.parse("LAUNCH_MODE")? {
+            self.launch_mode = mode;
+        }
+        if let Some(headless) = env.bool("HEADLESS")? {
+            config::set_headless(&mut self.browser_flags, headless, "--headless");
+        }
+        if let Some(args) = env.args("ARGS") {
+            self.browser_flags.get_or_insert_with(Vec::new).extend(args);
+        }
+        if let Some(path) = env.string("PATH") {
+            self.firefox_executable_path = Some(path);
+        }
+        if let Some(path) = env.string("DRIVER_PATH") {
+            self.driver_executable_path = Some(path);
+        }
+        if let Some(host) = env.string("HOST") {
+            self.host = Some(host);
+        }
+        if let Some(port) = env.parse("DRIVER_PORT")? {
+            self.driver_port = Some(port);
+        }
+        if let Some(port) = env.parse("DEBUGGING_PORT")? {
+            self.remote_debugging_port = Some(port);
+        }
+        if let Some(dir) = env.string("PROFILE_DIR") {
+            self.profile_dir = Some(dir);
+        }
+        if let Some(proxy) = env.proxy("PROXY") {
+            self.proxy = Some(proxy);
+        }
+        if let Some(capabilities) = env.json("CAPABILITIES")? {
+            self.capabilities = capabilities;
+        }
+        if let Some(prefer) = env.bool("PREFER_SYSTEM_BROWSER")? {
+            self.prefer_system_browser = prefer;
+        }
+        Ok(self)
+    }
+
+    /// Fills in `firefox_executable_path` and, for [`FirefoxLaunchMode::DriverManaged`],
+    /// `driver_executable_path` when they are not set: system Firefox if
+    /// `prefer_system_browser` is set, then a download with `downloader`.
+    ///
+    /// [`FirefoxBrowser::new`] does this with the default [`Downloader`] and panics if a
+    /// download fails; call it first to handle the error instead.
+    pub fn resolve_executables(&mut self, downloader: &Downloader) -> Result<(), DownloadError> {
+        if matches!(
+            self.launch_mode,
+            FirefoxLaunchMode::Remote(_) | FirefoxLaunchMode::RemoteDriver(_)
+        ) {
+            return Ok(());
+        }
+        if self.firefox_executable_path.is_none() {
+            let path = match self
+                .prefer_system_browser
+                .then(discovery::find_firefox)
+                .flatten()
+            {
+                Some(browser) => {
+                    tracing::info!(
+                        "Using system Firefox {} at {:?}",
+                        browser.version.as_deref().unwrap_or("(unknown version)"),
+                        browser.path
+                    );
+                    browser.path
+                }
+                None => downloader.firefox()?,
+            };
+            self.firefox_executable_path = Some(path.to_string_lossy().into_owned());
+        }
+        if matches!(self.launch_mode, FirefoxLaunchMode::DriverManaged)
+            && self.driver_executable_path.is_none()
+        {
+            let path = downloader.geckodriver()?;
+            self.driver_executable_path = Some(path.to_string_lossy().into_owned());
+        }
+        Ok(())
+    }
+}
+
+impl DriverConfiguration for FirefoxConfig {
+    fn exe_path(&self) -> &str {
+        self.driver_executable_path
+            .as_deref()
+            .unwrap_or("geckodriver")
+    }
+
+    fn flags(&self) -> Vec<String> {
+        let mut flags = vec![
+            format!(
+                "--host={}",
+                self.host.clone().unwrap_or(String::from("localhost"))
+            ),
+            format!(
+                "--port={}",
+                self.driver_port
+                    .unwrap_or_else(|| find_free_port().unwrap())
+            ),
+        ];
+        if let Some(port) = self.remote_debugging_port {
+            flags.push(format!("--websocket-port={}", port));
+        }
+
+        flags.extend(self.driver_flags.iter().map(|s| s.to_string()));
+
+        flags
+    }
 }
 
 pub struct FirefoxBrowser {
@@ -66,18 +282,42 @@ impl std::fmt::Debug for FirefoxBrowser {
 }
 
 impl FirefoxBrowser {
+    /// Launches or connects to Firefox according to `config.launch_mode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if Firefox or geckodriver has to be downloaded and the download fails. Call
+    /// [`FirefoxConfig::resolve_executables`] beforehand to get the [`DownloadError`] instead.
     pub async fn new(mut config: FirefoxConfig) -> FirefoxBrowser {
+        if let FirefoxLaunchMode::RemoteDriver(server_url) = config.launch_mode.clone() {
+            let driver = Self::init_remote_bidi(&mut config, &server_url).await;
+            return FirefoxBrowser {
+                config,
+                driver: Some(driver),
+                firefox_process: None,
+            };
+        }
         let host = config.host.clone().unwrap_or(String::from("localhost"));
         let firefox_port = match &config.launch_mode {
             FirefoxLaunchMode::Remote(port) => *port,
-            FirefoxLaunchMode::SpawnAndAttach => config
+            FirefoxLaunchMode::SpawnAndAttach | FirefoxLaunchMode::DriverManaged => config
                 .remote_debugging_port
                 .unwrap_or_else(|| find_free_port().unwrap()),
+            FirefoxLaunchMode::RemoteDriver(_) => unreachable!(),
         };
         config.remote_debugging_port = Some(firefox_port);
 
         let firefox_process = Self::init_firefox(&mut config, firefox_port).await;
 
+        if matches!(config.launch_mode, FirefoxLaunchMode::DriverManaged) {
+            let driver = Self::init_driver_managed_bidi(&mut config).await;
+            return FirefoxBrowser {
+                config,
+                driver: Some(driver),
+                firefox_process,
+            };
+        }
+
         let ct_config = ConnectionTransportConfig {
             host,
             port: firefox_port,
@@ -94,23 +334,89 @@ impl FirefoxBrowser {
     }
 
     async fn init_firefox(config: &mut FirefoxConfig, firefox_port: u16) -> Option<Process> {
+        config
+            .resolve_executables(&Downloader::new())
+            .unwrap_or_else(|e| panic!("Failed to download Firefox or geckodriver: {e}"));
         match &config.launch_mode {
-            FirefoxLaunchMode::Remote(_) => None,
+            FirefoxLaunchMode::Remote(_) | FirefoxLaunchMode::RemoteDriver(_) => None,
+            FirefoxLaunchMode::DriverManaged => {
+                // geckodriver spawns Firefox itself
+                if let Some(firefox_exe) = config.firefox_executable_path.clone() {
+                    config.capabilities.binary(firefox_exe);
+                }
+
+                let profile_dir = match &config.profile {
+                    Some(profile) => Some(
+                        profile
+                            .write()
+                            .unwrap_or_else(|e| panic!("Failed to write Firefox profile: {}", e))
+                            .display()
+                            .to_string(),
+                    ),
+                    None => config.profile_dir.clone(),
+                };
+                if let Some(profile_dir) = profile_dir {
+                    let _ = std::fs::create_dir_all(&profile_dir);
+                    config
+                        .capabilities
+                        .add_args(["-profile".to_string(), profile_dir]);
+                }
+                if let Some(ref flags) = config.browser_flags {
+                    config.capabilities.add_args(flags.iter().cloned());
+                }
+                if let Some(proxy) = config.proxy.clone() {
+                    config.capabilities.base_capabilities.proxy = Some(proxy);
+                }
+                None
+            }
             FirefoxLaunchMode::SpawnAndAttach => {
-                let firefox_exe = config.firefox_executable_path.clone().unwrap_or_else(|| {
-                    crate::downloader::ensure_firefox()
-                        .to_string_lossy()
-                        .into_owned()
-                });
+                let firefox_exe = config
+                    .firefox_executable_path
+                    .clone()
+                    .expect("resolved by FirefoxConfig::resolve_executables");
 
-                let profile_dir = config.profile_dir.clone().unwrap_or_else(|| {
+                let user_profile_dir = config.profile_dir.clone();
+                let profile_dir = user_profile_dir.clone().unwrap_or_else(|| {
                     std::env::temp_dir()
                         .join(format!("rustenium-firefox-{}", firefox_port))
                         .display()
                         .to_string()
                 });
 
-                let _ = std::fs::create_dir_all(&profile_dir);
+                // Apply `add_pref` values through user.js, as nothing else reads moz:firefoxOptions here.
+                // A user's own `profile_dir` is left untouched unless there is something to write.
+                let prefs = config
+                    .capabilities
+                    .firefox_options
+                    .prefs
+                    .clone()
+                    .filter(|prefs| !prefs.is_empty());
+                let profile = match (config.profile.clone(), prefs) {
+                    (Some(mut profile), prefs) => {
+                        profile.add_prefs(prefs.unwrap_or_default());
+                        Some(profile)
+                    }
+                    (None, Some(prefs)) => {
+                        let mut profile = FirefoxProfile::new(&profile_dir);
+                        profile.add_prefs(prefs);
+                        Some(profile)
+                    }
+                    (None, None) if user_profile_dir.is_none() => {
+                        Some(FirefoxProfile::new(&profile_dir))
+                    }
+                    (None, None) => None,
+                };
+                let profile_dir = match profile {
+                    Some(profile) => profile
+                        .write()
+                        .unwrap_or_else(|e| panic!("Failed to write Firefox profile: {}", e))
+                        .display()
+                        .to_string(),
+                    None => {
+                        let _ = std::fs::create_dir_all(&profile_dir);
+                        profile_dir
+                    }
+                };
 
                 let mut firefox_args = vec![
                     format!("--remote-debugging-port={}", firefox_port),
@@ -175,10 +481,74 @@ impl FirefoxBrowser {
         driver
     }
 
+    async fn init_driver_managed_bidi(
+        config: &mut FirefoxConfig,
+    ) -> BidiDriver<WebsocketConnectionTransport> {
+        let host = config.host.clone().unwrap_or(String::from("localhost"));
+        let port = *config
+            .driver_port
+            .get_or_insert_with(|| find_free_port().unwrap());
+        let capabilities = config.capabilities.clone().build();
+        let (session, remote_session, process) =
+            start_http_bidi_driver(config, &host, port, capabilities)
+                .await
+                .unwrap_or_else(|e| panic!("Error creating geckodriver session: {}", e));
+
+        let mut driver = BidiDriver::new(
+            String::from("geckodriver"),
+            vec![],
+            session,
+            0,
+            Arc::new(Mutex::new(Vec::new())),
+            process,
+        );
+        driver.remote_session = Some(remote_session);
+        driver.listen_to_context_creation().await.unwrap();
+        driver
+    }
+
+    async fn init_remote_bidi(
+        config: &mut FirefoxConfig,
+        server_url: &str,
+    ) -> BidiDriver<WebsocketConnectionTransport> {
+        if let Some(proxy) = config.proxy.clone() {
+            config.capabilities.base_capabilities.proxy = Some(proxy);
+        }
+        if let Some(ref flags) = config.browser_flags {
+            for flag in flags {
+                config.capabilities.add_arg(flag.clone());
+            }
+        }
+        let capabilities = config.capabilities.clone().build();
+        let (session, remote_session) = start_remote_bidi_driver(server_url, capabilities)
+            .await
+            .unwrap_or_else(|e| panic!("Error creating remote WebDriver session: {}", e));
+
+        let mut driver = BidiDriver::new(
+            server_url.to_string(),
+            vec![],
+            session,
+            0,
+            Arc::new(Mutex::new(Vec::new())),
+            Process::default(),
+        );
+        driver.remote_session = Some(remote_session);
+        driver.listen_to_context_creation().await.unwrap();
+        driver
+    }
+
     pub async fn connect_bidi(&mut self) {
         if self.driver.is_some() {
             return;
         }
+        if let FirefoxLaunchMode::RemoteDriver(server_url) = self.config.launch_mode.clone() {
+            self.driver = Some(Self::init_remote_bidi(&mut self.config, &server_url).await);
+            return;
+        }
+        if matches!(self.config.launch_mode, FirefoxLaunchMode::DriverManaged) {
+            self.driver = Some(Self::init_driver_managed_bidi(&mut self.config).await);
+            return;
+        }
         let host = self
             .config
             .host
@@ -200,6 +570,17 @@ impl FirefoxBrowser {
     pub fn get_browser_process(&self) -> &Option<Process> {
         &self.firefox_process
     }
+
+    /// Samples the Firefox process tree every `interval` and reports threshold breaches.
+    /// Returns `None` when Firefox was not launched by this browser.
+    pub fn sample_resources(
+        &self,
+        interval: Duration,
+        thresholds: ResourceThresholds,
+    ) -> Option<ResourceSampler> {
+        let process = self.firefox_process.as_ref()?.handle();
+        Some(ResourceSampler::start(process, interval, thresholds))
+    }
 }
 
 impl BidiBrowser for FirefoxBrowser {
@@ -238,7 +619,7 @@ impl BidiBrowser for FirefoxBrowser {
     async fn close(mut self) -> Result<(), BrowserCloseError> {
         tracing::debug!("Closing FirefoxBrowser");
         if let Some(ref mut driver) = self.driver {
-            driver.end_session().await?;
+            driver.close().await?;
         }
         // Drop the stored process (best-effort; may be stale if Firefox respawned)
         drop(self.firefox_process.take());
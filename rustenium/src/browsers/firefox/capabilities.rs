@@ -5,12 +5,36 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 
-#[derive(Debug, Clone)]
+/// Deserializes from a W3C capabilities object, with Firefox options under
+/// `moz:firefoxOptions`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "CapabilitiesObject")]
 pub struct FirefoxCapabilities {
     pub base_capabilities: CapabilityRequest,
     pub firefox_options: FirefoxOptions,
 }
 
+#[derive(Deserialize)]
+struct CapabilitiesObject {
+    #[serde(rename = "moz:firefoxOptions", default)]
+    firefox_options: FirefoxOptions,
+    #[serde(flatten)]
+    base_capabilities: CapabilityRequest,
+}
+
+impl From<CapabilitiesObject> for FirefoxCapabilities {
+    fn from(mut object: CapabilitiesObject) -> Self {
+        let defaults = Self::default();
+        if object.base_capabilities.browser_name.is_none() {
+            object.base_capabilities.browser_name = defaults.base_capabilities.browser_name;
+        }
+        FirefoxCapabilities {
+            base_capabilities: object.base_capabilities,
+            firefox_options: object.firefox_options,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct FirefoxOptions {
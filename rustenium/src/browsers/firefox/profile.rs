@@ -0,0 +1,285 @@
+use crate::error::firefox::FirefoxProfileError;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Prefs Rustenium needs for the Remote Agent (WebDriver BiDi) and a quiet startup.
+/// Prefs added through [`FirefoxProfile::add_pref`] take precedence.
+fn required_prefs() -> Vec<(&'static str, Value)> {
+    vec![
+        // Bitmask of enabled protocols, 1 = WebDriver BiDi
+        ("remote.active-protocols", Value::from(1)),
+        ("browser.shell.checkDefaultBrowser", Value::from(false)),
+        (
+            "browser.startup.homepage_override.mstone",
+            Value::from("ignore"),
+        ),
+        ("browser.aboutwelcome.enabled", Value::from(false)),
+        (
+            "datareporting.policy.dataSubmissionEnabled",
+            Value::from(false),
+        ),
+        (
+            "toolkit.telemetry.reportingpolicy.firstRun",
+            Value::from(false),
+        ),
+        ("app.update.disabledForTesting", Value::from(true)),
+        ("toolkit.startup.max_resumed_crashes", Value::from(-1)),
+    ]
+}
+
+/// Builder for a Firefox profile directory.
+///
+/// Writes `user.js` prefs (including the Remote Agent prefs Rustenium needs),
+/// pre-installs XPI extensions and imports certificates into the profile's NSS
+/// cert store. Profiles can be zipped and unzipped for reuse.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::browsers::FirefoxProfile;
+///
+/// let mut profile = FirefoxProfile::new("/tmp/my-profile");
+/// profile
+///     .add_pref("browser.download.folderList", 2)
+///     .add_extension("/path/to/extension.xpi")
+///     .add_certificate("Test CA", "/path/to/ca.pem");
+/// profile.write().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct FirefoxProfile {
+    dir: PathBuf,
+    prefs: BTreeMap<String, Value>,
+    extensions: Vec<PathBuf>,
+    certificates: Vec<(String, PathBuf)>,
+}
+
+impl FirefoxProfile {
+    /// Creates a profile builder for `dir`. Nothing is written until [`write`](Self::write).
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        FirefoxProfile {
+            dir: dir.into(),
+            prefs: BTreeMap::new(),
+            extensions: Vec::new(),
+            certificates: Vec::new(),
+        }
+    }
+
+    /// Unzips a profile archive created with [`zip`](Self::zip) into `dir`.
+    pub fn from_zip(
+        archive: impl AsRef<Path>,
+        dir: impl Into<PathBuf>,
+    ) -> Result<Self, FirefoxProfileError> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        let archive = archive.as_ref().to_string_lossy().into_owned();
+        let dest = dir.to_string_lossy().into_owned();
+        if cfg!(unix) {
+            run(Command::new("unzip").args(["-oq", &archive, "-d", &dest]))?;
+        } else {
+            run(Command::new("tar").args(["-xf", &archive, "-C", &dest]))?;
+        }
+        Ok(Self::new(dir))
+    }
+
+    /// The profile directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Prefs that will be written to `user.js`, on top of the required ones.
+    pub fn get_prefs(&self) -> &BTreeMap<String, Value> {
+        &self.prefs
+    }
+
+    pub fn add_pref(&mut self, key: impl Into<String>, value: impl Into<Value>) -> &mut Self {
+        self.prefs.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn add_prefs<I, K>(&mut self, prefs: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (K, Value)>,
+        K: Into<String>,
+    {
+        self.prefs
+            .extend(prefs.into_iter().map(|(k, v)| (k.into(), v)));
+        self
+    }
+
+    /// Pre-installs the XPI at `path` into the profile's `extensions` directory.
+    pub fn add_extension(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.extensions.push(path.into());
+        self
+    }
+
+    /// Imports the PEM or DER certificate at `path` as a trusted CA under `name`.
+    /// Requires NSS `certutil` on the PATH.
+    pub fn add_certificate(
+        &mut self,
+        name: impl Into<String>,
+        path: impl Into<PathBuf>,
+    ) -> &mut Self {
+        self.certificates.push((name.into(), path.into()));
+        self
+    }
+
+    /// Renders the `user.js` contents. User prefs override the required ones.
+    pub fn user_js(&self) -> String {
+        let mut prefs: BTreeMap<String, Value> = required_prefs()
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        if !self.extensions.is_empty() {
+            // Allow extensions dropped into the profile to load without a prompt
+            prefs.insert("extensions.autoDisableScopes".to_string(), Value::from(0));
+            prefs.insert("extensions.enabledScopes".to_string(), Value::from(5));
+            prefs.insert(
+                "xpinstall.signatures.required".to_string(),
+                Value::Bool(false),
+            );
+        }
+        prefs.extend(self.prefs.clone());
+
+        prefs
+            .iter()
+            .map(|(k, v)| format!("user_pref({}, {});\n", Value::from(k.as_str()), v))
+            .collect()
+    }
+
+    /// Creates the profile directory and writes prefs, extensions and certificates into it.
+    /// An existing `user.js` is merged: its prefs are kept unless this profile sets them.
+    /// Returns the profile directory.
+    pub fn write(&self) -> Result<PathBuf, FirefoxProfileError> {
+        std::fs::create_dir_all(&self.dir)?;
+        let user_js_path = self.dir.join("user.js");
+        let user_js = self.user_js();
+        let merged = match std::fs::read_to_string(&user_js_path) {
+            Ok(existing) => merge_user_js(&existing, &user_js),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => user_js,
+            Err(e) => return Err(e.into()),
+        };
+        std::fs::write(user_js_path, merged)?;
+
+        if !self.extensions.is_empty() {
+            let extensions_dir = self.dir.join("extensions");
+            std::fs::create_dir_all(&extensions_dir)?;
+            for xpi in &self.extensions {
+                if !xpi.is_file() {
+                    return Err(FirefoxProfileError::ExtensionNotFound(xpi.clone()));
+                }
+                let id = extension_id(xpi);
+                std::fs::copy(xpi, extensions_dir.join(format!("{}.xpi", id)))?;
+            }
+        }
+
+        if !self.certificates.is_empty() {
+            if let Some((_, missing)) = self.certificates.iter().find(|(_, p)| !p.is_file()) {
+                return Err(FirefoxProfileError::CertificateNotFound(missing.clone()));
+            }
+            let db = format!("sql:{}", self.dir.display());
+            if !self.dir.join("cert9.db").exists() {
+                run(Command::new("certutil").args(["-N", "--empty-password", "-d", &db]))?;
+            }
+            for (name, path) in &self.certificates {
+                let mut cmd = Command::new("certutil");
+                cmd.args(["-A", "-n", name, "-t", "C,,", "-d", &db, "-i"])
+                    .arg(path);
+                if std::fs::read(path)?.starts_with(b"-----BEGIN") {
+                    cmd.arg("-a");
+                }
+                run(&mut cmd)?;
+            }
+        }
+
+        tracing::debug!("[FirefoxProfile]: Wrote profile to {:?}", self.dir);
+        Ok(self.dir.clone())
+    }
+
+    /// Zips the profile directory into `archive` for reuse with [`from_zip`](Self::from_zip).
+    pub fn zip(&self, archive: impl AsRef<Path>) -> Result<PathBuf, FirefoxProfileError> {
+        let archive = std::path::absolute(archive.as_ref())?;
+        let archive_str = archive.to_string_lossy().into_owned();
+        if cfg!(unix) {
+            run(Command::new("zip")
+                .args(["-qr", &archive_str, "."])
+                .current_dir(&self.dir))?;
+        } else {
+            run(Command::new("tar")
+                .args(["-a", "-cf", &archive_str, "."])
+                .current_dir(&self.dir))?;
+        }
+        Ok(archive)
+    }
+}
+
+/// Keeps the lines of `existing` whose pref is not set in `ours`, then appends `ours`.
+fn merge_user_js(existing: &str, ours: &str) -> String {
+    let ours_keys: Vec<String> = ours.lines().filter_map(pref_key).collect();
+    let mut merged: String = existing
+        .lines()
+        .filter(|line| pref_key(line).is_none_or(|key| !ours_keys.contains(&key)))
+        .flat_map(|line| [line, "\n"])
+        .collect();
+    merged.push_str(ours);
+    merged
+}
+
+/// Extracts the pref name from a `user_pref("name", value);` line.
+fn pref_key(line: &str) -> Option<String> {
+    let rest = line.trim_start().strip_prefix("user_pref(")?.trim_start();
+    serde_json::Deserializer::from_str(rest)
+        .into_iter::<String>()
+        .next()?
+        .ok()
+}
+
+/// Reads the gecko extension ID from the XPI manifest, falling back to the file stem.
+fn extension_id(xpi: &Path) -> String {
+    let manifest = if cfg!(unix) {
+        Command::new("unzip")
+            .arg("-p")
+            .arg(xpi)
+            .arg("manifest.json")
+            .output()
+    } else {
+        Command::new("tar")
+            .arg("-xOf")
+            .arg(xpi)
+            .arg("manifest.json")
+            .output()
+    };
+    manifest
+        .ok()
+        .filter(|out| out.status.success())
+        .and_then(|out| serde_json::from_slice::<Value>(&out.stdout).ok())
+        .and_then(|manifest| {
+            let settings = manifest
+                .get("browser_specific_settings")
+                .or_else(|| manifest.get("applications"))?;
+            settings["gecko"]["id"].as_str().map(str::to_string)
+        })
+        .unwrap_or_else(|| {
+            xpi.file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "extension".to_string())
+        })
+}
+
+fn run(cmd: &mut Command) -> Result<(), FirefoxProfileError> {
+    let program = cmd.get_program().to_string_lossy().into_owned();
+    tracing::debug!("[FirefoxProfile]: Running {:?}", cmd);
+    let output = cmd
+        .output()
+        .map_err(|e| FirefoxProfileError::Command(format!("{program} failed: {e}")))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(FirefoxProfileError::Command(format!(
+            "{program} exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
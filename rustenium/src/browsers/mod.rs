@@ -1,17 +1,25 @@
-mod bidi_browser;
+pub(crate) mod bidi_browser;
 pub mod cdp_browser;
 pub mod chrome;
+mod config;
 pub mod firefox;
+mod supervisor;
 
 pub use bidi_browser::BidiBrowser;
 pub use bidi_browser::{
     AddPreloadScriptOptionsBuilder, AuthenticateOptionsBuilder, BrowserScreenshotOptionsBuilder,
     CreateContextOptionsBuilder, EmulateTimezoneOptionsBuilder, EvaluateScriptOptionsBuilder,
     FindNodesOptionsBuilder, NavigateOptions, NavigateOptionsBuilder, OnRequestOptionsBuilder,
-    SubscribeEventsOptionsBuilder, WaitForNodesOptionsBuilder,
+    ShadowPierce, SubscribeEventsOptionsBuilder, WaitForNodesOptionsBuilder,
 };
-pub use chrome::browser::{ChromeBrowser, ChromeConfig, ChromeLaunchMode, chrome};
+pub use chrome::browser::{ChromeBrowser, ChromeConfig, ChromeFlavor, ChromeLaunchMode, chrome};
 pub use chrome::capabilities::{ChromeCapabilities, ChromeOptions, PerfLoggingPrefs};
+pub use chrome::handle::BrowserHandle;
 pub use chrome::tab::ChromeTab;
 pub use firefox::browser::{FirefoxBrowser, FirefoxConfig, FirefoxLaunchMode, firefox};
 pub use firefox::capabilities::{FirefoxCapabilities, FirefoxOptions};
+pub use firefox::profile::FirefoxProfile;
+pub use rustenium_core::stats::{
+    ProcessStats, Resource, ResourceEvent, ResourceSampler, ResourceThresholds,
+};
+pub use supervisor::BrowserEvent;
@@ -0,0 +1,137 @@
+use rustenium_core::process::ProcessHandle;
+use std::future::Future;
+use std::process::ExitStatus;
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+use tokio::task::JoinHandle;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Lifecycle events of a supervised browser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrowserEvent {
+    /// The browser process exited without being closed through Rustenium.
+    Crashed { status: Option<ExitStatus> },
+    /// A protocol connection closed while the browser process was still running,
+    /// or while watching a browser whose process Rustenium does not own.
+    Disconnected,
+    /// The browser was relaunched and session state restored.
+    Restarted,
+    /// Relaunching failed. The supervisor stops watching.
+    RestartFailed(String),
+}
+
+/// What the supervisor watches for one browser launch.
+#[derive(Debug, Default)]
+pub(crate) struct Watched {
+    pub process: Option<ProcessHandle>,
+    pub connections: Vec<watch::Receiver<bool>>,
+}
+
+impl Watched {
+    fn has_failed(&self) -> bool {
+        let exited = self
+            .process
+            .as_ref()
+            .is_some_and(|process| !process.is_running());
+        let closed = self
+            .connections
+            .iter()
+            .any(|rx| *rx.borrow() || rx.has_changed().is_err());
+        exited || closed
+    }
+
+    /// Resolves once the process exits or a connection closes.
+    async fn failure(&self) -> BrowserEvent {
+        while !self.has_failed() {
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+        // A crash closes the connections first; give the process a moment to be reaped
+        tokio::time::sleep(POLL_INTERVAL).await;
+        match self.process {
+            Some(ref process) if !process.is_running() => BrowserEvent::Crashed {
+                status: process.try_wait(),
+            },
+            _ => BrowserEvent::Disconnected,
+        }
+    }
+}
+
+/// Watches a browser in the background, publishing [`BrowserEvent`]s and optionally
+/// relaunching it. The watch task is aborted on [`stop`](Self::stop) or drop.
+#[derive(Debug)]
+pub(crate) struct Supervisor {
+    events: broadcast::Sender<BrowserEvent>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl Supervisor {
+    pub fn new() -> Self {
+        Supervisor {
+            events: broadcast::channel(16).0,
+            task: None,
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<BrowserEvent> {
+        self.events.subscribe()
+    }
+
+    /// Starts watching. After a failure, `restart` (if any) relaunches the browser and
+    /// returns what to watch next.
+    pub fn start<F, Fut>(&mut self, mut watched: Watched, mut restart: Option<F>)
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Watched, String>> + Send + 'static,
+    {
+        self.stop();
+        let events = self.events.clone();
+        self.task = Some(tokio::spawn(async move {
+            loop {
+                let event = watched.failure().await;
+                tracing::warn!("[Supervisor]: Browser {:?}", event);
+                let _ = events.send(event);
+
+                let Some(ref mut restart) = restart else {
+                    break;
+                };
+                // Launch failures panic in places; run the restart on its own task to catch them
+                match tokio::spawn(restart()).await {
+                    Ok(Ok(next)) => {
+                        tracing::info!("[Supervisor]: Browser restarted");
+                        watched = next;
+                        let _ = events.send(BrowserEvent::Restarted);
+                    }
+                    Ok(Err(e)) => {
+                        let _ = events.send(BrowserEvent::RestartFailed(e));
+                        break;
+                    }
+                    Err(e) => {
+                        let _ = events.send(BrowserEvent::RestartFailed(e.to_string()));
+                        break;
+                    }
+                }
+            }
+        }));
+    }
+
+    /// Starts watching without relaunching on failure.
+    pub fn watch(&mut self, watched: Watched) {
+        self.start(
+            watched,
+            None::<fn() -> std::future::Ready<Result<Watched, String>>>,
+        );
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+impl Drop for Supervisor {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -1,25 +1,32 @@
+use crate::browsers::ShadowPierce;
 use crate::domain::context::BrowsingContext as Context;
+use rustenium_bidi_definitions::browser::commands::SetDownloadBehavior;
+use rustenium_bidi_definitions::browsing_context::command_builders::LocateNodesBuilder;
+use rustenium_bidi_definitions::browsing_context::types::{CssLocator, CssLocatorType, Locator};
 use rustenium_bidi_definitions::emulation::commands::SetTimezoneOverride;
 use rustenium_bidi_definitions::script::commands::{
     AddPreloadScript, CallFunction, Evaluate, RemovePreloadScript,
 };
+use rustenium_bidi_definitions::script::type_builders::SharedReferenceBuilder;
 use rustenium_core::{
     BidiSession, NetworkRequest,
-    process::Process,
+    process::{Process, ProcessOutput},
     transport::{ConnectionTransport, ConnectionTransportConfig, WebsocketConnectionTransport},
+    webdriver::{self, WebDriverSession},
 };
 
 use crate::error::bidi::{
-    ContextCloseError, ContextCreationError, ContextIndexError, EmulationError,
+    BrowserCloseError, ContextCloseError, ContextCreationError, ContextIndexError, EmulationError,
     EvaluateResultError, FindNodesError, InterceptNetworkError, NavigateError, ScreenshotError,
 };
+use crate::error::download::FileDownloadError;
 use rustenium_bidi_definitions::Command;
 use rustenium_bidi_definitions::Event;
 use rustenium_bidi_definitions::browsing_context::commands::{
-    CaptureScreenshot, Create, LocateNodes, Navigate,
+    CaptureScreenshot, Create, GetTree, LocateNodes, Navigate,
 };
 use rustenium_bidi_definitions::browsing_context::results::{
-    CaptureScreenshotResult, CreateResult, LocateNodesResult, NavigateResult,
+    CaptureScreenshotResult, CreateResult, GetTreeResult, LocateNodesResult, NavigateResult,
 };
 use rustenium_bidi_definitions::network::command_builders::AddInterceptBuilder;
 use rustenium_bidi_definitions::network::events::NetworkEvent;
@@ -27,28 +34,31 @@ use rustenium_bidi_definitions::network::results::AddInterceptResult;
 use rustenium_bidi_definitions::network::types::{InterceptPhase, UrlPattern};
 use rustenium_bidi_definitions::script::results::AddPreloadScriptResult;
 use rustenium_bidi_definitions::script::types::{
-    EvaluateResultException, EvaluateResultSuccess, PreloadScript,
+    EvaluateResultException, EvaluateResultSuccess, PreloadScript, SerializationOptions,
+    SerializationOptionsIncludeShadowTree, SharedReference,
 };
 use rustenium_bidi_definitions::session::results::SubscribeResult;
 use rustenium_bidi_definitions::session::types::CapabilitiesRequest;
 use rustenium_bidi_definitions::{
     base::CommandResponse,
     browsing_context::{
-        events::ContextCreated,
+        events::BrowsingContextEvent,
         types::{BrowsingContext as BidiBrowsingContext, CreateType},
     },
 };
-use rustenium_core::error::{CommandResultError, SessionSendError};
+use rustenium_core::error::{CommandResultError, SessionSendError, WebDriverHttpError};
 use rustenium_core::events::BidiEventManagement;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::future::Future;
+use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::sync::Mutex as TokioMutex;
 use tokio::time::sleep;
 
 use crate::input::{BidiKeyboard, BidiMouse, HumanMouse, HumanTouchscreen, Touchscreen};
+use crate::page::ExposedFunctions;
 
 pub struct OnRequestBuilder<'a, T: ConnectionTransport + Send + Sync, F> {
     driver: &'a mut BidiDriver<T>,
@@ -190,6 +200,10 @@ where
 pub trait DriverConfiguration {
     fn exe_path(&self) -> &str;
     fn flags(&self) -> Vec<String>;
+    /// Where the driver's stdout and stderr go (default: piped into `tracing`).
+    fn output(&self) -> ProcessOutput {
+        ProcessOutput::Piped
+    }
 }
 
 pub struct BidiDriver<T: ConnectionTransport + Send + Sync> {
@@ -204,6 +218,12 @@ pub struct BidiDriver<T: ConnectionTransport + Send + Sync> {
     pub keyboard: Arc<BidiKeyboard<T>>,
     pub touchscreen: Arc<Touchscreen<T>>,
     pub human_touchscreen: Arc<HumanTouchscreen<T>>,
+    /// Set when the session was created over WebDriver HTTP; it is deleted over HTTP on close.
+    pub remote_session: Option<WebDriverSession>,
+    /// Preload scripts, intercepts and emulation overrides applied through this driver.
+    pub record: Arc<Mutex<SessionRecord>>,
+    /// Functions exposed through this driver's pages, so exposing a name again replaces it.
+    pub(crate) exposed: ExposedFunctions,
 }
 
 impl<T: ConnectionTransport + Send + Sync + 'static> BidiDriver<T> {
@@ -233,6 +253,9 @@ impl<T: ConnectionTransport + Send + Sync + 'static> BidiDriver<T> {
             keyboard,
             touchscreen,
             human_touchscreen,
+            remote_session: None,
+            record: Arc::new(Mutex::new(SessionRecord::default())),
+            exposed: ExposedFunctions::default(),
         }
     }
 
@@ -240,146 +263,38 @@ impl<T: ConnectionTransport + Send + Sync + 'static> BidiDriver<T> {
         &mut self,
         command: impl Into<Command>,
     ) -> Result<CommandResponse, SessionSendError> {
-        let rx = {
-            let mut session = self.session.lock().await;
-            session.send_and_get_receiver(command).await
-        };
-
-        match tokio::time::timeout(Duration::from_secs(100), rx).await {
-            Ok(Ok(command_result)) => match command_result {
-                rustenium_core::CommandResponseState::Success(response) => Ok(response),
-                rustenium_core::CommandResponseState::Error(err) => {
-                    Err(SessionSendError::ErrorResponse(err))
-                }
-            },
-            Ok(Err(err)) => panic!("A recv error occurred: {}", err),
-            Err(_) => Err(SessionSendError::ResponseReceiveTimeoutError(
-                rustenium_core::error::ResponseReceiveTimeoutError,
-            )),
-        }
+        send_command(&self.session, command).await
     }
 
     pub async fn listen_to_context_creation(
         &mut self,
     ) -> Result<Option<SubscribeResult>, ContextCreationError> {
-        let browsing_contexts = self.browsing_contexts.clone();
-        let events = HashSet::from(["browsingContext.contextCreated"]);
-        let handler = move |event: Event| {
-            let bc = browsing_contexts.clone();
-            async move {
-                if let Ok(context) = TryInto::<ContextCreated>::try_into(event) {
-                    tracing::debug!(
-                        "[BiDiDriver]: BrowsingContext Created: ID: {}",
-                        context.params.context.as_ref()
-                    );
-                    bc.lock()
-                        .unwrap()
-                        .push(Context::from_id(context.params.context, CreateType::Tab));
-                }
-            }
-        };
-
-        let bidi_event = self
-            .session
-            .lock()
-            .await
-            .create_event::<_, _, BidiSession<T>>(events, handler);
-        let result = self.session.lock().await.subscribe_events(bidi_event).await;
-        // Wait for 2s, to allow current BrowsingContext be updated via the event.
-        sleep(Duration::from_millis(800)).await;
-        match result {
-            Err(error) => Err(ContextCreationError::CommandResultError(error)),
-            Ok(result) => Ok(result),
-        }
+        listen_to_context_creation(&self.session, &self.browsing_contexts).await
     }
 
     pub async fn navigate(&mut self, navigate: Navigate) -> Result<NavigateResult, NavigateError> {
-        // navigate.params.context = navigate.params.context.unwrap_or(self.get_active_context_id()?);
-
-        let result_value = self
-            .send_command(navigate)
-            .await
-            .map_err(|err| {
-                NavigateError::CommandResultError(CommandResultError::SessionSendError(err))
-            })?
-            .result;
-
-        NavigateResult::try_from(result_value.clone()).map_err(|_| {
-            NavigateError::CommandResultError(CommandResultError::InvalidResultTypeError(
-                result_value,
-            ))
-        })
+        self::navigate(&self.session, navigate).await
     }
 
     pub async fn find_nodes(
         &mut self,
         locator: LocateNodes,
     ) -> Result<LocateNodesResult, FindNodesError> {
-        let result_value = self
-            .send_command(locator)
-            .await
-            .map_err(|err| {
-                FindNodesError::CommandResultError(CommandResultError::SessionSendError(err))
-            })?
-            .result;
-
-        LocateNodesResult::try_from(result_value.clone()).map_err(|_| {
-            FindNodesError::CommandResultError(CommandResultError::InvalidResultTypeError(
-                result_value,
-            ))
-        })
+        find_nodes(&self.session, locator).await
     }
 
     pub async fn evaluate_script(
         &mut self,
         evaluate: Evaluate,
     ) -> Result<EvaluateResultSuccess, EvaluateResultError> {
-        let result_value = self
-            .send_command(evaluate)
-            .await
-            .map_err(|err| {
-                EvaluateResultError::CommandResultError(CommandResultError::SessionSendError(err))
-            })?
-            .result;
-
-        if let Ok(success) = serde_json::from_value::<EvaluateResultSuccess>(result_value.clone()) {
-            return Ok(success);
-        }
-        if let Ok(exception) =
-            serde_json::from_value::<EvaluateResultException>(result_value.clone())
-        {
-            return Err(EvaluateResultError::ExceptionError(exception));
-        }
-
-        Err(EvaluateResultError::CommandResultError(
-            CommandResultError::InvalidResultTypeError(result_value),
-        ))
+        evaluate_script(&self.session, evaluate).await
     }
 
     pub async fn call_function(
         &mut self,
         call_function: CallFunction,
     ) -> Result<EvaluateResultSuccess, EvaluateResultError> {
-        let result_value = self
-            .send_command(call_function)
-            .await
-            .map_err(|err| {
-                EvaluateResultError::CommandResultError(CommandResultError::SessionSendError(err))
-            })?
-            .result;
-
-        if let Ok(success) = serde_json::from_value::<EvaluateResultSuccess>(result_value.clone()) {
-            return Ok(success);
-        }
-        if let Ok(exception) =
-            serde_json::from_value::<EvaluateResultException>(result_value.clone())
-        {
-            return Err(EvaluateResultError::ExceptionError(exception));
-        }
-
-        Err(EvaluateResultError::CommandResultError(
-            CommandResultError::InvalidResultTypeError(result_value),
-        ))
+        self::call_function(&self.session, call_function).await
     }
 
     pub async fn add_preload_script(
@@ -387,7 +302,7 @@ impl<T: ConnectionTransport + Send + Sync + 'static> BidiDriver<T> {
         add_preload_script: AddPreloadScript,
     ) -> Result<PreloadScript, EvaluateResultError> {
         let result_value = self
-            .send_command(add_preload_script)
+            .send_command(add_preload_script.clone())
             .await
             .map_err(|err| {
                 EvaluateResultError::CommandResultError(CommandResultError::SessionSendError(err))
@@ -400,13 +315,34 @@ impl<T: ConnectionTransport + Send + Sync + 'static> BidiDriver<T> {
             ))
         })?;
 
+        self.record
+            .lock()
+            .unwrap()
+            .preload_scripts
+            .push(RecordedPreloadScript {
+                id: add_result.script.clone(),
+                current: add_result.script.clone(),
+                command: add_preload_script,
+            });
         Ok(add_result.script)
     }
 
     pub async fn remove_preload_script(
         &mut self,
-        remove_preload_script: RemovePreloadScript,
+        mut remove_preload_script: RemovePreloadScript,
     ) -> Result<(), EvaluateResultError> {
+        let recorded = {
+            let mut record = self.record.lock().unwrap();
+            let index = record
+                .preload_scripts
+                .iter()
+                .position(|ps| ps.id == remove_preload_script.params.script);
+            index.map(|index| record.preload_scripts.remove(index))
+        };
+        // IDs handed out before a restart keep working
+        if let Some(ref recorded) = recorded {
+            remove_preload_script.params.script = recorded.current.clone();
+        }
         self.send_command(remove_preload_script)
             .await
             .map_err(|err| {
@@ -436,32 +372,14 @@ impl<T: ConnectionTransport + Send + Sync + 'static> BidiDriver<T> {
         &mut self,
         context: BidiBrowsingContext,
     ) -> Result<(), ContextCloseError> {
-        use rustenium_bidi_definitions::browsing_context::command_builders::CloseBuilder;
-        let command = CloseBuilder::default().context(context).build().unwrap();
-        self.send_command(command).await.map_err(|err| {
-            ContextCloseError::CommandResultError(CommandResultError::SessionSendError(err))
-        })?;
-        Ok(())
+        close_context(&self.session, context).await
     }
 
     pub async fn create_context(
         &mut self,
         context_create: Create,
     ) -> Result<Context, ContextCreationError> {
-        let context_type = context_create.params.r#type.clone();
-        let response = self.send_command(context_create).await.map_err(|err| {
-            ContextCreationError::CommandResultError(CommandResultError::SessionSendError(err))
-        })?;
-
-        let result: CreateResult = response.result.clone().try_into().map_err(|_| {
-            ContextCreationError::CommandResultError(CommandResultError::InvalidResultTypeError(
-                response.result,
-            ))
-        })?;
-
-        let bc = Context::from_id(result.context, context_type);
-
-        Ok(bc)
+        create_context(&self.session, context_create).await
     }
 
     async fn on_network<F, R>(
@@ -476,40 +394,14 @@ impl<T: ConnectionTransport + Send + Sync + 'static> BidiDriver<T> {
         F: Fn(Event) -> R + Send + Sync + 'static,
         R: Future<Output = ()> + Send + 'static,
     {
-        let mut builder = AddInterceptBuilder::default();
-        builder = builder.phases(phases);
-        if let Some(patterns) = url_patterns {
-            builder = builder.url_patterns(patterns);
-        }
-        let add_intercept_command = builder.build().unwrap();
-
-        let result_value = self
-            .send_command(add_intercept_command)
-            .await
-            .map_err(|e| {
-                InterceptNetworkError::CommandResultError(CommandResultError::SessionSendError(e))
-            })?
-            .result;
-
-        let _ = AddInterceptResult::try_from(result_value.clone()).map_err(|_| {
-            InterceptNetworkError::CommandResultError(CommandResultError::InvalidResultTypeError(
-                result_value,
-            ))
-        })?;
-
-        let events = HashSet::from_iter(event_names);
-        let bidi_event = self
-            .session
-            .lock()
-            .await
-            .create_event::<_, _, BidiSession<T>>(events, handler);
-        self.session
-            .lock()
-            .await
-            .subscribe_events(bidi_event)
-            .await
-            .map_err(InterceptNetworkError::CommandResultError)?;
-
+        let intercept = RecordedIntercept {
+            phases,
+            event_names,
+            url_patterns,
+            handler: Arc::new(move |event| Box::pin(handler(event))),
+        };
+        add_intercept(&self.session, &intercept).await?;
+        self.record.lock().unwrap().intercepts.push(intercept);
         Ok(())
     }
 
@@ -530,72 +422,36 @@ impl<T: ConnectionTransport + Send + Sync + 'static> BidiDriver<T> {
         capture_screenshot: CaptureScreenshot,
         save_path: Option<&str>,
     ) -> Result<String, ScreenshotError> {
-        let result_value = self
-            .send_command(capture_screenshot)
-            .await
-            .map_err(|err| {
-                ScreenshotError::CommandResultError(CommandResultError::SessionSendError(err))
-            })?
-            .result;
-
-        let screenshot_result =
-            CaptureScreenshotResult::try_from(result_value.clone()).map_err(|_| {
-                ScreenshotError::CommandResultError(CommandResultError::InvalidResultTypeError(
-                    result_value,
-                ))
-            })?;
-
-        let base64_data = screenshot_result.data;
-
-        if let Some(path) = save_path {
-            use std::path::Path;
-
-            let path_obj = Path::new(path);
-
-            let final_path = if path_obj.is_dir() {
-                let timestamp = std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .map(|d| d.as_millis())
-                    .unwrap_or(0);
-                let filename = format!("screenshot_{}.png", timestamp);
-                path_obj.join(filename)
-            } else {
-                if let Some(parent) = path_obj.parent()
-                    && !parent.as_os_str().is_empty()
-                    && !parent.exists()
-                {
-                    return Err(ScreenshotError::InvalidPath(format!(
-                        "Parent directory does not exist: {}",
-                        parent.display()
-                    )));
-                }
-                path_obj.to_path_buf()
-            };
-
-            use base64::{Engine as _, engine::general_purpose};
-            let decoded = general_purpose::STANDARD
-                .decode(&base64_data)
-                .map_err(|e| ScreenshotError::Base64DecodeError(e.to_string()))?;
-
-            std::fs::write(&final_path, decoded)
-                .map_err(|e| ScreenshotError::FileWriteError(e.to_string()))?;
-
-            Ok(final_path.to_string_lossy().to_string())
-        } else {
-            Ok(base64_data)
-        }
+        screenshot(&self.session, capture_screenshot, save_path).await
     }
 
     pub async fn set_timezone_override(
         &mut self,
         set_timezone_override: SetTimezoneOverride,
     ) -> Result<(), EmulationError> {
-        self.send_command(set_timezone_override)
+        self.send_command(set_timezone_override.clone())
             .await
             .map_err(|err| {
                 EmulationError::CommandResultError(CommandResultError::SessionSendError(err))
             })?;
 
+        self.record
+            .lock()
+            .unwrap()
+            .timezone_overrides
+            .push(set_timezone_override);
+        Ok(())
+    }
+
+    pub async fn set_download_behavior(
+        &mut self,
+        set_download_behavior: SetDownloadBehavior,
+    ) -> Result<(), FileDownloadError> {
+        self.send_command(set_download_behavior.clone())
+            .await
+            .map_err(CommandResultError::SessionSendError)?;
+
+        self.record.lock().unwrap().download_behavior = Some(set_download_behavior);
         Ok(())
     }
 
@@ -603,6 +459,530 @@ impl<T: ConnectionTransport + Send + Sync + 'static> BidiDriver<T> {
         self.session.lock().await.end_session().await?;
         Ok(())
     }
+
+    /// Ends the session: over HTTP `DELETE /session/{id}` for sessions created
+    /// with [`start_remote_bidi_driver`], otherwise with BiDi `session.end`.
+    pub async fn close(&mut self) -> Result<(), BrowserCloseError> {
+        match self.remote_session.take() {
+            Some(remote_session) => {
+                self.session.lock().await.close().await;
+                webdriver::delete_session(&remote_session).await?;
+            }
+            None => self.end_session().await?,
+        }
+        Ok(())
+    }
+}
+
+type EventHandler = Arc<dyn Fn(Event) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
+struct RecordedPreloadScript {
+    /// ID returned when the script was first added. Stays valid across restarts.
+    id: PreloadScript,
+    /// ID of the script in the current session.
+    current: PreloadScript,
+    command: AddPreloadScript,
+}
+
+struct RecordedIntercept {
+    phases: Vec<InterceptPhase>,
+    event_names: Vec<&'static str>,
+    url_patterns: Option<Vec<UrlPattern>>,
+    handler: EventHandler,
+}
+
+/// Preload scripts, network intercepts, emulation overrides and the download behavior
+/// applied through a [`BidiDriver`], kept so they can be replayed onto a new session after
+/// the browser is relaunched. See [`replay_session_record`].
+#[derive(Default)]
+pub struct SessionRecord {
+    preload_scripts: Vec<RecordedPreloadScript>,
+    intercepts: Vec<RecordedIntercept>,
+    timezone_overrides: Vec<SetTimezoneOverride>,
+    download_behavior: Option<SetDownloadBehavior>,
+}
+
+impl SessionRecord {
+    pub fn preload_scripts(&self) -> usize {
+        self.preload_scripts.len()
+    }
+
+    pub fn intercepts(&self) -> usize {
+        self.intercepts.len()
+    }
+
+    pub fn timezone_overrides(&self) -> usize {
+        self.timezone_overrides.len()
+    }
+}
+
+impl std::fmt::Debug for SessionRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionRecord")
+            .field("preload_scripts", &self.preload_scripts.len())
+            .field("intercepts", &self.intercepts.len())
+            .field("timezone_overrides", &self.timezone_overrides.len())
+            .field("download_behavior", &self.download_behavior.is_some())
+            .finish()
+    }
+}
+
+/// Sends `command` and waits for its response, releasing the session lock while waiting.
+pub async fn send_command<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    command: impl Into<Command>,
+) -> Result<CommandResponse, SessionSendError> {
+    let rx = {
+        let mut session = session.lock().await;
+        session.send_and_get_receiver(command).await
+    };
+
+    match tokio::time::timeout(Duration::from_secs(100), rx).await {
+        Ok(Ok(command_result)) => match command_result {
+            rustenium_core::CommandResponseState::Success(response) => Ok(response),
+            rustenium_core::CommandResponseState::Error(err) => {
+                Err(SessionSendError::ErrorResponse(err))
+            }
+        },
+        Ok(Err(err)) => panic!("A recv error occurred: {}", err),
+        Err(_) => Err(SessionSendError::ResponseReceiveTimeoutError(
+            rustenium_core::error::ResponseReceiveTimeoutError,
+        )),
+    }
+}
+
+pub async fn navigate<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    navigate: Navigate,
+) -> Result<NavigateResult, NavigateError> {
+    let result_value = send_command(session, navigate)
+        .await
+        .map_err(|err| {
+            NavigateError::CommandResultError(CommandResultError::SessionSendError(err))
+        })?
+        .result;
+
+    NavigateResult::try_from(result_value.clone()).map_err(|_| {
+        NavigateError::CommandResultError(CommandResultError::InvalidResultTypeError(result_value))
+    })
+}
+
+pub async fn find_nodes<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    locator: LocateNodes,
+) -> Result<LocateNodesResult, FindNodesError> {
+    let result_value = send_command(session, locator)
+        .await
+        .map_err(|err| {
+            FindNodesError::CommandResultError(CommandResultError::SessionSendError(err))
+        })?
+        .result;
+
+    LocateNodesResult::try_from(result_value.clone()).map_err(|_| {
+        FindNodesError::CommandResultError(CommandResultError::InvalidResultTypeError(result_value))
+    })
+}
+
+/// Runs `locate_nodes` in the document (or its start nodes) and then in every shadow root
+/// below them that `pierce` allows, up to the command's `max_node_count` in total.
+pub async fn find_nodes_piercing<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    locate_nodes: LocateNodes,
+    pierce: ShadowPierce,
+) -> Result<LocateNodesResult, FindNodesError> {
+    let params = locate_nodes.params;
+    let shadow_roots =
+        shadow_roots(session, &params.context, params.start_nodes.clone(), pierce).await?;
+
+    let mut nodes = Vec::new();
+    let mut starts = vec![params.start_nodes.clone()];
+    if !shadow_roots.is_empty() {
+        starts.push(Some(shadow_roots));
+    }
+    for start_nodes in starts {
+        let remaining = match params.max_node_count {
+            Some(max) if nodes.len() as u64 >= max => break,
+            Some(max) => Some(max - nodes.len() as u64),
+            None => None,
+        };
+        let mut builder = LocateNodesBuilder::default()
+            .context(params.context.clone())
+            .locator(params.locator.clone());
+        if let Some(remaining) = remaining {
+            builder = builder.max_node_count(remaining);
+        }
+        if let Some(serialization_options) = params.serialization_options.clone() {
+            builder = builder.serialization_options(serialization_options);
+        }
+        if let Some(start_nodes) = start_nodes {
+            builder = builder.start_nodes(start_nodes);
+        }
+        nodes.extend(find_nodes(session, builder.build().unwrap()).await?.nodes);
+    }
+    Ok(LocateNodesResult { nodes })
+}
+
+/// Collects the shadow roots below `start_nodes` (or the whole document), nested ones
+/// included, one level of the shadow tree per `browsingContext.locateNodes` call.
+async fn shadow_roots<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    context: &BidiBrowsingContext,
+    start_nodes: Option<Vec<SharedReference>>,
+    pierce: ShadowPierce,
+) -> Result<Vec<SharedReference>, FindNodesError> {
+    let include_shadow_tree = match pierce {
+        ShadowPierce::Open => SerializationOptionsIncludeShadowTree::Open,
+        ShadowPierce::All => SerializationOptionsIncludeShadowTree::All,
+    };
+    let mut roots = Vec::new();
+    let mut level = start_nodes;
+    loop {
+        let mut builder = LocateNodesBuilder::default()
+            .context(context.clone())
+            .locator(Locator::CssLocator(CssLocator::new(
+                CssLocatorType::Css,
+                "*",
+            )))
+            .serialization_options(SerializationOptions {
+                max_dom_depth: Some(1),
+                max_object_depth: Some(0),
+                include_shadow_tree: Some(include_shadow_tree.clone()),
+            });
+        if let Some(level) = level {
+            builder = builder.start_nodes(level);
+        }
+        let hosts = find_nodes(session, builder.build().unwrap()).await?;
+        let next: Vec<SharedReference> = hosts
+            .nodes
+            .into_iter()
+            .filter_map(|node| node.value?.shadow_root?.shared_id)
+            .map(|shared_id| {
+                SharedReferenceBuilder::default()
+                    .shared_id(shared_id)
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+        if next.is_empty() {
+            return Ok(roots);
+        }
+        roots.extend(next.iter().cloned());
+        level = Some(next);
+    }
+}
+
+pub async fn evaluate_script<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    evaluate: Evaluate,
+) -> Result<EvaluateResultSuccess, EvaluateResultError> {
+    evaluate_result(session, evaluate).await
+}
+
+pub async fn call_function<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    call_function: CallFunction,
+) -> Result<EvaluateResultSuccess, EvaluateResultError> {
+    evaluate_result(session, call_function).await
+}
+
+async fn evaluate_result<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    command: impl Into<Command>,
+) -> Result<EvaluateResultSuccess, EvaluateResultError> {
+    let result_value = send_command(session, command)
+        .await
+        .map_err(|err| {
+            EvaluateResultError::CommandResultError(CommandResultError::SessionSendError(err))
+        })?
+        .result;
+
+    if let Ok(success) = serde_json::from_value::<EvaluateResultSuccess>(result_value.clone()) {
+        return Ok(success);
+    }
+    if let Ok(exception) = serde_json::from_value::<EvaluateResultException>(result_value.clone()) {
+        return Err(EvaluateResultError::ExceptionError(exception));
+    }
+
+    Err(EvaluateResultError::CommandResultError(
+        CommandResultError::InvalidResultTypeError(result_value),
+    ))
+}
+
+pub async fn get_tree<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    get_tree: GetTree,
+) -> Result<GetTreeResult, CommandResultError> {
+    let result_value = send_command(session, get_tree)
+        .await
+        .map_err(CommandResultError::SessionSendError)?
+        .result;
+
+    GetTreeResult::try_from(result_value.clone())
+        .map_err(|_| CommandResultError::InvalidResultTypeError(result_value))
+}
+
+pub async fn close_context<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    context: BidiBrowsingContext,
+) -> Result<(), ContextCloseError> {
+    use rustenium_bidi_definitions::browsing_context::command_builders::CloseBuilder;
+    let command = CloseBuilder::default().context(context).build().unwrap();
+    send_command(session, command).await.map_err(|err| {
+        ContextCloseError::CommandResultError(CommandResultError::SessionSendError(err))
+    })?;
+    Ok(())
+}
+
+pub async fn create_context<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    context_create: Create,
+) -> Result<Context, ContextCreationError> {
+    let context_type = context_create.params.r#type.clone();
+    let response = send_command(session, context_create).await.map_err(|err| {
+        ContextCreationError::CommandResultError(CommandResultError::SessionSendError(err))
+    })?;
+
+    let result: CreateResult = response.result.clone().try_into().map_err(|_| {
+        ContextCreationError::CommandResultError(CommandResultError::InvalidResultTypeError(
+            response.result,
+        ))
+    })?;
+
+    Ok(Context::from_id(result.context, context_type))
+}
+
+/// Captures a screenshot. With `save_path` the image is written to that file, or to a
+/// timestamped file when it is a directory, and the final path is returned; otherwise the
+/// base64 data is returned.
+pub async fn screenshot<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    capture_screenshot: CaptureScreenshot,
+    save_path: Option<&str>,
+) -> Result<String, ScreenshotError> {
+    let result_value = send_command(session, capture_screenshot)
+        .await
+        .map_err(|err| {
+            ScreenshotError::CommandResultError(CommandResultError::SessionSendError(err))
+        })?
+        .result;
+
+    let screenshot_result =
+        CaptureScreenshotResult::try_from(result_value.clone()).map_err(|_| {
+            ScreenshotError::CommandResultError(CommandResultError::InvalidResultTypeError(
+                result_value,
+            ))
+        })?;
+
+    let base64_data = screenshot_result.data;
+
+    if let Some(path) = save_path {
+        use std::path::Path;
+
+        let path_obj = Path::new(path);
+
+        let final_path = if path_obj.is_dir() {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0);
+            let filename = format!("screenshot_{}.png", timestamp);
+            path_obj.join(filename)
+        } else {
+            if let Some(parent) = path_obj.parent()
+                && !parent.as_os_str().is_empty()
+                && !parent.exists()
+            {
+                return Err(ScreenshotError::InvalidPath(format!(
+                    "Parent directory does not exist: {}",
+                    parent.display()
+                )));
+            }
+            path_obj.to_path_buf()
+        };
+
+        use base64::{Engine as _, engine::general_purpose};
+        let decoded = general_purpose::STANDARD
+            .decode(&base64_data)
+            .map_err(|e| ScreenshotError::Base64DecodeError(e.to_string()))?;
+
+        std::fs::write(&final_path, decoded)
+            .map_err(|e| ScreenshotError::FileWriteError(e.to_string()))?;
+
+        Ok(final_path.to_string_lossy().to_string())
+    } else {
+        Ok(base64_data)
+    }
+}
+
+/// Tracks the top-level browsing contexts (tabs, windows and popups) of `session` in
+/// `browsing_contexts` as they are created and destroyed.
+pub async fn listen_to_context_creation<T: ConnectionTransport + Send + Sync + 'static>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    browsing_contexts: &Arc<Mutex<Vec<Context>>>,
+) -> Result<Option<SubscribeResult>, ContextCreationError> {
+    let browsing_contexts = browsing_contexts.clone();
+    let events = HashSet::from([
+        "browsingContext.contextCreated",
+        "browsingContext.contextDestroyed",
+    ]);
+    let handler = move |event: Event| {
+        let bc = browsing_contexts.clone();
+        async move {
+            match event {
+                Event::BrowsingContext(BrowsingContextEvent::ContextCreated(context))
+                    if context.params.parent.is_none() =>
+                {
+                    tracing::debug!(
+                        "[BiDiDriver]: BrowsingContext Created: ID: {}",
+                        context.params.context.as_ref()
+                    );
+                    bc.lock()
+                        .unwrap()
+                        .push(Context::from_id(context.params.context, CreateType::Tab));
+                }
+                Event::BrowsingContext(BrowsingContextEvent::ContextDestroyed(context)) => {
+                    bc.lock()
+                        .unwrap()
+                        .retain(|bc| bc.id() != &context.params.context);
+                }
+                _ => {}
+            }
+        }
+    };
+
+    let bidi_event = session
+        .lock()
+        .await
+        .create_event::<_, _, BidiSession<T>>(events, handler);
+    let result = session.lock().await.subscribe_events(bidi_event).await;
+    // Wait for 2s, to allow current BrowsingContext be updated via the event.
+    sleep(Duration::from_millis(800)).await;
+    match result {
+        Err(error) => Err(ContextCreationError::CommandResultError(error)),
+        Ok(result) => Ok(result),
+    }
+}
+
+async fn add_intercept<T: ConnectionTransport + Send + Sync + 'static>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    intercept: &RecordedIntercept,
+) -> Result<(), InterceptNetworkError> {
+    let mut builder = AddInterceptBuilder::default();
+    builder = builder.phases(intercept.phases.clone());
+    if let Some(ref patterns) = intercept.url_patterns {
+        builder = builder.url_patterns(patterns.clone());
+    }
+    let add_intercept_command = builder.build().unwrap();
+
+    let result_value = send_command(session, add_intercept_command)
+        .await
+        .map_err(|e| {
+            InterceptNetworkError::CommandResultError(CommandResultError::SessionSendError(e))
+        })?
+        .result;
+
+    let _ = AddInterceptResult::try_from(result_value.clone()).map_err(|_| {
+        InterceptNetworkError::CommandResultError(CommandResultError::InvalidResultTypeError(
+            result_value,
+        ))
+    })?;
+
+    let events = HashSet::from_iter(intercept.event_names.iter().copied());
+    let handler = intercept.handler.clone();
+    let bidi_event = session
+        .lock()
+        .await
+        .create_event::<_, _, BidiSession<T>>(events, move |event| handler(event));
+    session
+        .lock()
+        .await
+        .subscribe_events(bidi_event)
+        .await
+        .map_err(InterceptNetworkError::CommandResultError)?;
+
+    Ok(())
+}
+
+/// Re-applies the preload scripts, intercepts, timezone overrides and download behavior in
+/// `record` on `session`, e.g. after the browser was relaunched. `contexts` maps browsing
+/// contexts of the previous session to their replacements; context-scoped state for
+/// contexts without a replacement is dropped with a warning. Failures are logged and skipped.
+pub async fn replay_session_record<T: ConnectionTransport + Send + Sync + 'static>(
+    record: &Arc<Mutex<SessionRecord>>,
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    contexts: &HashMap<BidiBrowsingContext, BidiBrowsingContext>,
+) {
+    let remap = |scoped: &Option<Vec<BidiBrowsingContext>>| match scoped {
+        Some(scoped) => {
+            let mapped: Vec<_> = scoped
+                .iter()
+                .filter_map(|context| contexts.get(context).cloned())
+                .collect();
+            (!mapped.is_empty()).then_some(Some(mapped))
+        }
+        None => Some(None),
+    };
+
+    let preload_scripts: Vec<_> = record
+        .lock()
+        .unwrap()
+        .preload_scripts
+        .iter()
+        .map(|ps| (ps.id.clone(), ps.command.clone()))
+        .collect();
+    for (id, mut command) in preload_scripts {
+        let Some(scoped) = remap(&command.params.contexts) else {
+            tracing::warn!(
+                "Dropped preload script {:?}: none of its contexts were restored",
+                id
+            );
+            continue;
+        };
+        command.params.contexts = scoped;
+        let result = send_command(session, command).await.map(|response| {
+            AddPreloadScriptResult::try_from(response.result).map(|result| result.script)
+        });
+        match result {
+            Ok(Ok(current)) => {
+                let mut record = record.lock().unwrap();
+                if let Some(ps) = record.preload_scripts.iter_mut().find(|ps| ps.id == id) {
+                    ps.current = current;
+                }
+            }
+            Ok(Err(_)) => tracing::warn!("Unexpected result restoring preload script {:?}", id),
+            Err(e) => tracing::warn!("Failed to restore preload script {:?}: {}", id, e),
+        }
+    }
+
+    let intercepts = std::mem::take(&mut record.lock().unwrap().intercepts);
+    for intercept in &intercepts {
+        if let Err(e) = add_intercept(session, intercept).await {
+            tracing::warn!("Failed to restore network intercept: {}", e);
+        }
+    }
+    record.lock().unwrap().intercepts.extend(intercepts);
+
+    let timezone_overrides = record.lock().unwrap().timezone_overrides.clone();
+    for mut command in timezone_overrides {
+        let Some(scoped) = remap(&command.params.contexts) else {
+            tracing::warn!(
+                "Dropped timezone override {:?}: none of its contexts were restored",
+                command.params.timezone
+            );
+            continue;
+        };
+        command.params.contexts = scoped;
+        if let Err(e) = send_command(session, command).await {
+            tracing::warn!("Failed to restore timezone override: {}", e);
+        }
+    }
+
+    let download_behavior = record.lock().unwrap().download_behavior.clone();
+    if let Some(command) = download_behavior
+        && let Err(e) = send_command(session, command).await
+    {
+        tracing::warn!("Failed to restore download behavior: {}", e);
+    }
 }
 
 pub async fn start_bidi_driver(
@@ -613,9 +993,68 @@ pub async fn start_bidi_driver(
     Arc<TokioMutex<BidiSession<WebsocketConnectionTransport>>>,
     Process,
 ) {
-    let driver_process = Process::create(driver_config.exe_path(), driver_config.flags());
+    let driver_process = Process::spawn(
+        driver_config.exe_path(),
+        driver_config.flags(),
+        [],
+        driver_config.output(),
+    );
     let session =
         BidiSession::<WebsocketConnectionTransport>::new(connection_transport_config, capabilities)
             .await;
     (Arc::new(TokioMutex::new(session)), driver_process)
 }
+
+/// Creates a session on a remote WebDriver server over HTTP `POST /session`,
+/// then connects a [`BidiSession`] to the returned `webSocketUrl`.
+pub async fn start_remote_bidi_driver(
+    server_url: &str,
+    capabilities: CapabilitiesRequest,
+) -> Result<
+    (
+        Arc<TokioMutex<BidiSession<WebsocketConnectionTransport>>>,
+        WebDriverSession,
+    ),
+    WebDriverHttpError,
+> {
+    let remote_session = webdriver::new_session(server_url, &capabilities).await?;
+    let ct_config = remote_session.transport_config()?;
+    let session = BidiSession::<WebsocketConnectionTransport>::connect(
+        &ct_config,
+        remote_session.session_id.clone(),
+    )
+    .await;
+    Ok((Arc::new(TokioMutex::new(session)), remote_session))
+}
+
+/// Spawns a classic WebDriver server (e.g. geckodriver) and creates the session on it
+/// with [`start_remote_bidi_driver`] once it accepts connections on `host:port`.
+pub async fn start_http_bidi_driver(
+    driver_config: &impl DriverConfiguration,
+    host: &str,
+    port: u16,
+    capabilities: CapabilitiesRequest,
+) -> Result<
+    (
+        Arc<TokioMutex<BidiSession<WebsocketConnectionTransport>>>,
+        WebDriverSession,
+        Process,
+    ),
+    WebDriverHttpError,
+> {
+    let driver_process = Process::create(driver_config.exe_path(), driver_config.flags());
+
+    let addr = format!("{}:{}", host, port);
+    let mut attempts = 0;
+    while let Err(e) = tokio::net::TcpStream::connect(&addr).await {
+        attempts += 1;
+        if attempts >= 40 {
+            return Err(WebDriverHttpError::Io(e));
+        }
+        sleep(Duration::from_millis(250)).await;
+    }
+
+    let (session, remote_session) =
+        start_remote_bidi_driver(&format!("http://{}", addr), capabilities).await?;
+    Ok((session, remote_session, driver_process))
+}
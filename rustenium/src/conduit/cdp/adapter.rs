@@ -1,51 +1,243 @@
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex as StdMutex};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex as StdMutex, OnceLock, Weak};
 use std::time::Duration;
 
+use crate::browsers::ShadowPierce;
+use crate::browsers::cdp_browser::Selector;
+use crate::console::{ConsoleCollector, ConsoleMessage, PageError};
+use crate::dialog::{
+    Dialog, DialogKind, DialogResponder, FileChooser, FileChooserResponder, HandlerRegistration,
+};
+use crate::download::{self, Download, DownloadBehavior, DownloadEvent, next_download};
 use crate::error::cdp::ScreenshotError;
+use crate::error::cdp::{LocateError, WaitError};
+use crate::error::dialog::{DialogError, FileChooserError};
+use crate::error::download::FileDownloadError;
+use crate::error::node::{NodeActionError, SetFilesError};
+use crate::error::pdf::PdfError;
+use crate::error::script::JsException;
+use crate::evaluate::{
+    ExposedFunction, REPLY_SCRIPT, call_exposed, expose_source, revive_declaration, take_nodes,
+};
 use crate::input::{CdpKeyboard, CdpMouse, CdpTouchscreen, HumanMouse};
+use crate::pdf::{self, PdfOptions};
+use crate::wait::{
+    LifecycleTracker, SANDBOX, SelectorState, VISIBLE_SCRIPT, race_trigger, unique_wait_id,
+    wait_for_selector_script,
+};
 
 use rustenium_cdp_definitions::Command;
-use rustenium_cdp_definitions::base::CommandResponse;
+use rustenium_cdp_definitions::base::{CommandResponse, EventResponse};
+use rustenium_cdp_definitions::browser_protocol::accessibility::commands::QueryAxTree;
+use rustenium_cdp_definitions::browser_protocol::accessibility::results::QueryAxTreeResult;
+use rustenium_cdp_definitions::browser_protocol::browser::command_builders::SetDownloadBehaviorBuilder;
+use rustenium_cdp_definitions::browser_protocol::browser::commands::SetDownloadBehaviorBehavior;
+use rustenium_cdp_definitions::browser_protocol::browser::events::{
+    DownloadProgress, DownloadWillBegin,
+};
 use rustenium_cdp_definitions::browser_protocol::dom::commands::{
-    DescribeNode, GetDocument, QuerySelector, QuerySelectorAll,
+    DescribeNode, GetDocument, GetFrameOwner, PushNodesByBackendIdsToFrontend, QuerySelector,
+    QuerySelectorAll, RequestNode, ResolveNode, SetFileInputFiles,
 };
 use rustenium_cdp_definitions::browser_protocol::dom::results::{
-    DescribeNodeResult, GetDocumentResult, QuerySelectorAllResult, QuerySelectorResult,
+    DescribeNodeResult, GetDocumentResult, GetFrameOwnerResult,
+    PushNodesByBackendIdsToFrontendResult, QuerySelectorAllResult, QuerySelectorResult,
+    RequestNodeResult, ResolveNodeResult,
+};
+use rustenium_cdp_definitions::browser_protocol::dom::types::{
+    BackendNodeId, Node as DomNode, NodeId, ShadowRootType,
 };
-use rustenium_cdp_definitions::browser_protocol::dom::types::Node as DomNode;
 use rustenium_cdp_definitions::browser_protocol::emulation::commands::SetDeviceMetricsOverride;
+use rustenium_cdp_definitions::browser_protocol::io::commands::{Close, Read};
+use rustenium_cdp_definitions::browser_protocol::io::results::ReadResult;
+use rustenium_cdp_definitions::browser_protocol::io::types::StreamHandle;
 use rustenium_cdp_definitions::browser_protocol::page::command_builders::{
-    EnableBuilder as PageEnableBuilder, GetLayoutMetricsBuilder,
+    AddScriptToEvaluateOnNewDocumentBuilder, CreateIsolatedWorldBuilder,
+    EnableBuilder as PageEnableBuilder, GetFrameTreeBuilder, GetLayoutMetricsBuilder,
+    HandleJavaScriptDialogBuilder, SetInterceptFileChooserDialogBuilder,
 };
 use rustenium_cdp_definitions::browser_protocol::page::commands::Navigate;
+use rustenium_cdp_definitions::browser_protocol::page::commands::SetLifecycleEventsEnabled;
 use rustenium_cdp_definitions::browser_protocol::page::commands::{
     AddScriptToEvaluateOnNewDocument, CaptureScreenshot, RemoveScriptToEvaluateOnNewDocument,
 };
+use rustenium_cdp_definitions::browser_protocol::page::events::{
+    FileChooserOpened, FileChooserOpenedMode, FrameDetached, FrameNavigated,
+    JavascriptDialogOpening, LifecycleEvent, NavigatedWithinDocument,
+};
 use rustenium_cdp_definitions::browser_protocol::page::results::NavigateResult;
 use rustenium_cdp_definitions::browser_protocol::page::results::{
-    AddScriptToEvaluateOnNewDocumentResult, CaptureScreenshotResult, GetLayoutMetricsResult,
+    AddScriptToEvaluateOnNewDocumentResult, CaptureScreenshotResult, CreateIsolatedWorldResult,
+    GetFrameTreeResult, GetLayoutMetricsResult, PrintToPdfResult,
+};
+use rustenium_cdp_definitions::browser_protocol::page::types::{
+    FrameId, FrameTree, ScriptIdentifier,
 };
-use rustenium_cdp_definitions::browser_protocol::page::types::ScriptIdentifier;
 use rustenium_cdp_definitions::browser_protocol::target::command_builders::SetDiscoverTargetsBuilder;
 use rustenium_cdp_definitions::browser_protocol::target::commands::CreateTarget;
 use rustenium_cdp_definitions::browser_protocol::target::events::{TargetCreated, TargetDestroyed};
 use rustenium_cdp_definitions::browser_protocol::target::results::CreateTargetResult;
 use rustenium_cdp_definitions::browser_protocol::target::types::{TargetId, TargetInfo};
-use rustenium_cdp_definitions::js_protocol::runtime::command_builders::EvaluateBuilder as RuntimeEvaluateBuilder;
-use rustenium_cdp_definitions::js_protocol::runtime::results::EvaluateResult;
+use rustenium_cdp_definitions::js_protocol::runtime::command_builders::{
+    EnableBuilder as RuntimeEnableBuilder, EvaluateBuilder as RuntimeEvaluateBuilder,
+};
+use rustenium_cdp_definitions::js_protocol::runtime::commands::{
+    AddBinding, CallFunctionOn, GetProperties, ReleaseObjectGroup,
+};
+use rustenium_cdp_definitions::js_protocol::runtime::events::{
+    BindingCalled, ConsoleApiCalled, ExceptionThrown, ExecutionContextCreated,
+    ExecutionContextDestroyed, ExecutionContextsCleared,
+};
+use rustenium_cdp_definitions::js_protocol::runtime::results::{
+    CallFunctionOnResult, EvaluateResult, GetPropertiesResult,
+};
+use rustenium_cdp_definitions::js_protocol::runtime::types::{
+    CallArgument, ExecutionContextDescription, ExecutionContextId, RemoteObjectId,
+};
 use rustenium_core::CdpEventManagement;
 use rustenium_core::WebsocketConnectionTransport;
 use rustenium_core::error::CdpCommandResultError;
 use rustenium_core::error::CdpSessionSendError;
 use rustenium_core::session::CdpSession;
 use rustenium_core::transport::{ConnectionTransport, ConnectionTransportConfig};
+use serde::Deserialize;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::net::TcpStream;
 use tokio::sync::Mutex as TokioMutex;
+use tokio::sync::mpsc::UnboundedReceiver;
 use tokio::time::sleep;
 
-#[derive(Clone)]
+/// Finds elements under `this` (a document, element or shadow root) for XPath and text
+/// selectors. Text matches are the innermost elements whose collapsed `innerText` equals
+/// (`exact`) or contains the query, skipping elements that never render text.
+const QUERY_SCRIPT: &str = r#"function(kind, query, exact, ignoreCase) {
+    const root = this;
+    const found = [];
+    if (kind === 'xpath') {
+        const doc = root.ownerDocument || root;
+        const result = doc.evaluate(query, root, null, XPathResult.ORDERED_NODE_SNAPSHOT_TYPE, null);
+        for (let i = 0; i < result.snapshotLength; i++) {
+            found.push(result.snapshotItem(i));
+        }
+        return found;
+    }
+    const skip = new Set(['HEAD', 'SCRIPT', 'STYLE', 'NOSCRIPT', 'TEMPLATE']);
+    const normalize = (text) => {
+        const collapsed = (text || '').replace(/\s+/g, ' ').trim();
+        return ignoreCase ? collapsed.toLowerCase() : collapsed;
+    };
+    const needle = normalize(query);
+    const visit = (element) => {
+        if (skip.has(element.tagName)) return false;
+        const text = normalize(element.innerText ?? element.textContent);
+        if (!text.includes(needle)) return false;
+        let inner = false;
+        for (const child of element.children) {
+            inner = visit(child) || inner;
+        }
+        if (inner) return true;
+        if (exact && text !== needle) return false;
+        found.push(element);
+        return true;
+    };
+    for (const child of root.children || []) {
+        visit(child);
+    }
+    return found;
+}"#;
+
+/// The binding selector waits report through, exposed only to the `rustenium` isolated
+/// world.
+const WAIT_BINDING: &str = "__rusteniumWaitForSelector";
+
+/// What a selector wait sent through [`WAIT_BINDING`]: `found` is `node`, `none` or
+/// `changed`.
+#[derive(Deserialize)]
+struct SelectorReport {
+    id: String,
+    found: String,
+}
+
+/// Execution contexts of the [`SANDBOX`] isolated world per frame, so evaluations reuse
+/// one world instead of creating a new one on every call. Entries are dropped when the
+/// context is destroyed or its frame navigates or detaches.
+#[derive(Debug, Default)]
+pub(crate) struct IsolatedWorlds {
+    contexts: StdMutex<HashMap<String, ExecutionContextId>>,
+    /// Unique context id → frame id, for `Runtime.executionContextDestroyed`.
+    unique_ids: StdMutex<HashMap<String, String>>,
+    /// Set once the handlers keeping `contexts` up to date are installed.
+    listening: TokioMutex<bool>,
+    /// Set once [`WAIT_BINDING`] is added to the world.
+    wait_binding: TokioMutex<bool>,
+}
+
+impl IsolatedWorlds {
+    fn get(&self, frame_id: &str) -> Option<ExecutionContextId> {
+        self.contexts.lock().unwrap().get(frame_id).copied()
+    }
+
+    fn insert(&self, frame_id: &str, context_id: ExecutionContextId) {
+        self.contexts
+            .lock()
+            .unwrap()
+            .insert(frame_id.to_string(), context_id);
+    }
+
+    fn forget_frame(&self, frame_id: &str) {
+        self.contexts.lock().unwrap().remove(frame_id);
+    }
+
+    fn created(&self, description: &ExecutionContextDescription) {
+        if description.name != SANDBOX {
+            return;
+        }
+        let Some(frame_id) = description
+            .aux_data
+            .as_ref()
+            .and_then(|data| data["frameId"].as_str())
+        else {
+            return;
+        };
+        self.unique_ids
+            .lock()
+            .unwrap()
+            .insert(description.unique_id.clone(), frame_id.to_string());
+        self.insert(frame_id, description.id);
+    }
+
+    fn destroyed(&self, unique_id: &str) {
+        if let Some(frame_id) = self.unique_ids.lock().unwrap().remove(unique_id) {
+            self.forget_frame(&frame_id);
+        }
+    }
+
+    /// Forgets every context, e.g. after `Runtime.executionContextsCleared` or a relaunch.
+    pub(crate) fn clear(&self) {
+        self.contexts.lock().unwrap().clear();
+        self.unique_ids.lock().unwrap().clear();
+    }
+
+    /// Forgets every context and that the handlers and binding were installed, for a new
+    /// session.
+    pub(crate) async fn reset(&self) {
+        self.clear();
+        *self.listening.lock().await = false;
+        *self.wait_binding.lock().await = false;
+    }
+}
+
+/// What [`CdpAdapter::expose_function`] registered for a name, so exposing it again can
+/// take the previous function down first.
+#[derive(Debug)]
+pub(crate) struct ExposedBinding {
+    /// The `Runtime.bindingCalled` handler answering calls.
+    handler: String,
+    /// The script installing the function in new documents.
+    script: ScriptIdentifier,
+}
+
 pub struct CdpAdapter<T: ConnectionTransport + Send + Sync> {
     pub session: Arc<TokioMutex<CdpSession<T>>>,
     pub page_targets: Arc<StdMutex<HashMap<TargetId, TargetInfo>>>,
@@ -53,6 +245,33 @@ pub struct CdpAdapter<T: ConnectionTransport + Send + Sync> {
     pub human_mouse: Arc<HumanMouse<CdpMouse>>,
     pub keyboard: Arc<CdpKeyboard<T>>,
     pub touchscreen: Arc<CdpTouchscreen>,
+    pub(crate) lifecycle: Arc<LifecycleTracker>,
+    /// Where downloads are saved, once allowed with [`CdpAdapter::set_download_behavior`].
+    pub(crate) download_dir: Arc<StdMutex<Option<PathBuf>>>,
+    /// How many handlers are watching for file choosers. Interception is on while any are.
+    pub(crate) file_choosers: Arc<TokioMutex<usize>>,
+    pub(crate) isolated_worlds: Arc<IsolatedWorlds>,
+    /// Functions exposed with [`CdpAdapter::expose_function`], by name.
+    pub(crate) exposed: Arc<StdMutex<HashMap<String, ExposedBinding>>>,
+}
+
+// Manual impl: every field is shared, so cloning must not require `T: Clone`
+impl<T: ConnectionTransport + Send + Sync> Clone for CdpAdapter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            session: self.session.clone(),
+            page_targets: self.page_targets.clone(),
+            mouse: self.mouse.clone(),
+            human_mouse: self.human_mouse.clone(),
+            keyboard: self.keyboard.clone(),
+            touchscreen: self.touchscreen.clone(),
+            lifecycle: self.lifecycle.clone(),
+            download_dir: self.download_dir.clone(),
+            file_choosers: self.file_choosers.clone(),
+            isolated_worlds: self.isolated_worlds.clone(),
+            exposed: self.exposed.clone(),
+        }
+    }
 }
 
 impl CdpAdapter<WebsocketConnectionTransport> {
@@ -70,6 +289,11 @@ impl CdpAdapter<WebsocketConnectionTransport> {
             human_mouse,
             keyboard,
             touchscreen,
+            lifecycle: Arc::new(LifecycleTracker::default()),
+            download_dir: Arc::new(StdMutex::new(None)),
+            file_choosers: Arc::new(TokioMutex::new(0)),
+            isolated_worlds: Arc::new(IsolatedWorlds::default()),
+            exposed: Arc::new(StdMutex::new(HashMap::new())),
         }
     }
 }
@@ -134,6 +358,66 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
         Ok(())
     }
 
+    /// Tracks the lifecycle of every frame in the attached target for the wait helpers and
+    /// turns on `Page.lifecycleEvent`. The Page domain must be enabled.
+    pub async fn listen_to_lifecycle_events(&mut self) -> Result<(), CdpSessionSendError> {
+        let lifecycle = self.lifecycle.clone();
+        self.session.lock().await.add_event_handler(
+            [
+                LifecycleEvent::IDENTIFIER,
+                FrameNavigated::IDENTIFIER,
+                NavigatedWithinDocument::IDENTIFIER,
+                FrameDetached::IDENTIFIER,
+            ],
+            move |event| {
+                let lifecycle = lifecycle.clone();
+                async move {
+                    match event.identifier() {
+                        LifecycleEvent::IDENTIFIER => {
+                            if let Ok(event) = event.try_into_event::<LifecycleEvent>() {
+                                lifecycle.lifecycle_event(
+                                    event.params.frame_id.as_ref().as_ref(),
+                                    event.params.loader_id.as_ref(),
+                                    &event.params.name,
+                                );
+                            }
+                        }
+                        FrameNavigated::IDENTIFIER => {
+                            if let Ok(event) = event.try_into_event::<FrameNavigated>() {
+                                let frame = event.params.frame;
+                                lifecycle.navigated(
+                                    frame.id.as_ref(),
+                                    frame.loader_id.as_ref(),
+                                    &frame.url,
+                                );
+                            }
+                        }
+                        NavigatedWithinDocument::IDENTIFIER => {
+                            if let Ok(event) = event.try_into_event::<NavigatedWithinDocument>() {
+                                lifecycle.navigated_within_document(
+                                    event.params.frame_id.as_ref().as_ref(),
+                                    &event.params.url,
+                                );
+                            }
+                        }
+                        _ => {
+                            if let Ok(event) = event.try_into_event::<FrameDetached>() {
+                                lifecycle.detached(event.params.frame_id.as_ref().as_ref());
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let command = SetLifecycleEventsEnabled::builder()
+            .enabled(true)
+            .build()
+            .unwrap();
+        self.send_command(command).await?;
+        Ok(())
+    }
+
     pub async fn send_command(
         &mut self,
         command: impl Into<Command>,
@@ -218,12 +502,7 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
     }
 
     /// Returns the root document `NodeId` via `DOM.getDocument`.
-    async fn get_root_node_id(
-        &mut self,
-    ) -> Result<
-        rustenium_cdp_definitions::browser_protocol::dom::types::NodeId,
-        crate::error::cdp::LocateError,
-    > {
+    async fn get_root_node_id(&mut self) -> Result<NodeId, crate::error::cdp::LocateError> {
         let result_value = self
             .send_command(GetDocument::builder().depth(0).build())
             .await
@@ -241,7 +520,7 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
     /// Describe a node by `NodeId`, returning the full `DomNode` subtree.
     async fn describe_by_id(
         &mut self,
-        node_id: rustenium_cdp_definitions::browser_protocol::dom::types::NodeId,
+        node_id: NodeId,
     ) -> Result<DomNode, crate::error::cdp::LocateError> {
         let result_value = self
             .send_command(DescribeNode::builder().node_id(node_id).depth(-1).build())
@@ -257,14 +536,37 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
             .map_err(|e| crate::error::cdp::LocateError::ParseError(e.to_string()))
     }
 
-    /// Find the first element matching `selector` (CSS). Returns `None` if not found.
-    pub async fn locate(
+    /// Returns the `NodeId` of the document loaded in `frame_id` via
+    /// `DOM.getDocument` with `pierce`, which includes the content documents of frames
+    /// rendered in this target's process.
+    async fn get_frame_document_id(
         &mut self,
+        frame_id: &FrameId,
+    ) -> Result<NodeId, crate::error::cdp::LocateError> {
+        let result_value = self
+            .send_command(GetDocument::builder().depth(-1).pierce(true).build())
+            .await
+            .map_err(|e| {
+                crate::error::cdp::LocateError::CommandResultError(
+                    CdpCommandResultError::SessionSendError(e),
+                )
+            })?
+            .result;
+        let doc = GetDocumentResult::try_from(result_value)
+            .map_err(|e| crate::error::cdp::LocateError::ParseError(e.to_string()))?;
+        find_frame_document(&doc.root, frame_id).ok_or_else(|| {
+            crate::error::cdp::LocateError::FrameNotFound(frame_id.as_ref().to_string())
+        })
+    }
+
+    /// `DOM.querySelector` under `root`. Returns `None` if nothing matches.
+    async fn query_css(
+        &mut self,
+        root: NodeId,
         selector: &str,
     ) -> Result<Option<DomNode>, crate::error::cdp::LocateError> {
-        let root_id = self.get_root_node_id().await?;
         let cmd = QuerySelector::builder()
-            .node_id(root_id)
+            .node_id(root)
             .selector(selector)
             .build()
             .map_err(crate::error::cdp::LocateError::ParseError)?;
@@ -287,14 +589,14 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
         Ok(Some(self.describe_by_id(node_id).await?))
     }
 
-    /// Find all elements matching `selector` (CSS).
-    pub async fn locate_all(
+    /// `DOM.querySelectorAll` under `root`.
+    async fn query_css_all(
         &mut self,
+        root: NodeId,
         selector: &str,
     ) -> Result<Vec<DomNode>, crate::error::cdp::LocateError> {
-        let root_id = self.get_root_node_id().await?;
         let cmd = QuerySelectorAll::builder()
-            .node_id(root_id)
+            .node_id(root)
             .selector(selector)
             .build()
             .map_err(crate::error::cdp::LocateError::ParseError)?;
@@ -317,10 +619,376 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
         Ok(nodes)
     }
 
+    /// The first element under `root` matching `selector`. Returns `None` if nothing matches.
+    async fn query_selector(
+        &mut self,
+        root: NodeId,
+        selector: &Selector,
+    ) -> Result<Option<DomNode>, crate::error::cdp::LocateError> {
+        match selector {
+            Selector::Css(css) => self.query_css(root, css).await,
+            _ => Ok(self
+                .query_selector_all(root, selector)
+                .await?
+                .into_iter()
+                .next()),
+        }
+    }
+
+    /// Every element under `root` matching `selector`, in document order for CSS, XPath
+    /// and text selectors and in accessibility tree order for role selectors.
+    async fn query_selector_all(
+        &mut self,
+        root: NodeId,
+        selector: &Selector,
+    ) -> Result<Vec<DomNode>, crate::error::cdp::LocateError> {
+        match selector {
+            Selector::Css(css) => self.query_css_all(root, css).await,
+            Selector::XPath(expression) => {
+                let args = serde_json::json!(["xpath", expression, false, false]);
+                self.query_script(root, args).await
+            }
+            Selector::Text {
+                text,
+                exact,
+                ignore_case,
+            } => {
+                let args = serde_json::json!(["text", text, exact, ignore_case]);
+                self.query_script(root, args).await
+            }
+            Selector::Role { role, name } => {
+                self.query_ax_tree(root, role.as_deref(), name.as_deref())
+                    .await
+            }
+            Selector::FrameOwner(frame_id) => {
+                Ok(self.frame_owner(frame_id).await?.into_iter().collect())
+            }
+        }
+    }
+
+    /// Runs [`QUERY_SCRIPT`] on the node `root` with `args` and resolves the elements it
+    /// returns to DOM nodes.
+    async fn query_script(
+        &mut self,
+        root: NodeId,
+        args: serde_json::Value,
+    ) -> Result<Vec<DomNode>, crate::error::cdp::LocateError> {
+        let object_group = "rustenium-query";
+        let result_value = self
+            .send_command(
+                ResolveNode::builder()
+                    .node_id(root)
+                    .object_group(object_group)
+                    .build(),
+            )
+            .await
+            .map_err(|e| {
+                crate::error::cdp::LocateError::CommandResultError(
+                    CdpCommandResultError::SessionSendError(e),
+                )
+            })?
+            .result;
+        let root_object = ResolveNodeResult::try_from(result_value)
+            .map_err(|e| crate::error::cdp::LocateError::ParseError(e.to_string()))?
+            .object
+            .object_id
+            .ok_or_else(|| {
+                crate::error::cdp::LocateError::ParseError("root node has no object id".into())
+            })?;
+
+        let cmd = CallFunctionOn::builder()
+            .function_declaration(format!(
+                "function() {{ return ({}).apply(this, {}); }}",
+                QUERY_SCRIPT, args
+            ))
+            .object_id(root_object)
+            .object_group(object_group)
+            .build()
+            .map_err(crate::error::cdp::LocateError::ParseError)?;
+        let result = self.send_locate_command(cmd).await;
+        let result = match result {
+            Ok(value) => CallFunctionOnResult::try_from(value)
+                .map_err(|e| crate::error::cdp::LocateError::ParseError(e.to_string())),
+            Err(e) => Err(e),
+        };
+        let nodes = match result {
+            Ok(CallFunctionOnResult {
+                exception_details: Some(details),
+                ..
+            }) => Err(crate::error::cdp::LocateError::InvalidSelector(
+                details.text,
+            )),
+            Ok(CallFunctionOnResult { result, .. }) => match result.object_id {
+                Some(array) => self.request_array_nodes(array).await,
+                None => Ok(Vec::new()),
+            },
+            Err(e) => Err(e),
+        };
+
+        // Release the wrappers whether or not the query succeeded.
+        let _ = self
+            .send_command(
+                ReleaseObjectGroup::builder()
+                    .object_group(object_group)
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+        nodes
+    }
+
+    /// Resolves every indexed element of the JavaScript array `array` to a DOM node.
+    async fn request_array_nodes(
+        &mut self,
+        array: RemoteObjectId,
+    ) -> Result<Vec<DomNode>, crate::error::cdp::LocateError> {
+        let cmd = GetProperties::builder()
+            .object_id(array)
+            .own_properties(true)
+            .build()
+            .map_err(crate::error::cdp::LocateError::ParseError)?;
+        let value = self.send_locate_command(cmd).await?;
+        let properties = GetPropertiesResult::try_from(value)
+            .map_err(|e| crate::error::cdp::LocateError::ParseError(e.to_string()))?
+            .result;
+        let mut elements: Vec<(usize, RemoteObjectId)> = properties
+            .into_iter()
+            .filter_map(|property| {
+                let index = property.name.parse::<usize>().ok()?;
+                Some((index, property.value?.object_id?))
+            })
+            .collect();
+        elements.sort_by_key(|(index, _)| *index);
+
+        let mut nodes = Vec::with_capacity(elements.len());
+        for (_, object_id) in elements {
+            let cmd = RequestNode::builder()
+                .object_id(object_id)
+                .build()
+                .map_err(crate::error::cdp::LocateError::ParseError)?;
+            let value = self.send_locate_command(cmd).await?;
+            let node_id = *RequestNodeResult::try_from(value)
+                .map_err(|e| crate::error::cdp::LocateError::ParseError(e.to_string()))?
+                .node_id;
+            nodes.push(self.describe_by_id(node_id).await?);
+        }
+        Ok(nodes)
+    }
+
+    /// `Accessibility.queryAXTree` under `root`. When only a name is given, text leaves
+    /// (which carry the same name as their parent) are left out.
+    async fn query_ax_tree(
+        &mut self,
+        root: NodeId,
+        role: Option<&str>,
+        name: Option<&str>,
+    ) -> Result<Vec<DomNode>, crate::error::cdp::LocateError> {
+        let mut cmd = QueryAxTree::builder().node_id(root);
+        if let Some(role) = role {
+            cmd = cmd.role(role);
+        }
+        if let Some(name) = name {
+            cmd = cmd.accessible_name(name);
+        }
+        let value = self.send_locate_command(cmd.build()).await?;
+        let ax_nodes = QueryAxTreeResult::try_from(value)
+            .map_err(|e| crate::error::cdp::LocateError::ParseError(e.to_string()))?
+            .nodes;
+        let backend_ids: Vec<BackendNodeId> = ax_nodes
+            .into_iter()
+            .filter(|node| !node.ignored)
+            .filter(|node| {
+                role.is_some()
+                    || !matches!(
+                        node.role
+                            .as_ref()
+                            .and_then(|r| r.value.as_ref())
+                            .and_then(|v| v.as_str()),
+                        Some("StaticText" | "InlineTextBox")
+                    )
+            })
+            .filter_map(|node| node.backend_dom_node_id)
+            .collect();
+        if backend_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let cmd = PushNodesByBackendIdsToFrontend::builder()
+            .backend_node_ids(backend_ids)
+            .build()
+            .map_err(crate::error::cdp::LocateError::ParseError)?;
+        let value = self.send_locate_command(cmd).await?;
+        let node_ids = PushNodesByBackendIdsToFrontendResult::try_from(value)
+            .map_err(|e| crate::error::cdp::LocateError::ParseError(e.to_string()))?
+            .node_ids;
+        let mut nodes = Vec::with_capacity(node_ids.len());
+        for node_id in node_ids {
+            // A node id of 0 means the node could not be pushed (it is already gone).
+            if *node_id.inner() != 0 {
+                nodes.push(self.describe_by_id(node_id).await?);
+            }
+        }
+        Ok(nodes)
+    }
+
+    /// The element hosting the frame `frame_id` via `DOM.getFrameOwner`, or `None` if the
+    /// frame is not in this target.
+    async fn frame_owner(
+        &mut self,
+        frame_id: &FrameId,
+    ) -> Result<Option<DomNode>, crate::error::cdp::LocateError> {
+        let cmd = GetFrameOwner::builder()
+            .frame_id(frame_id.clone())
+            .build()
+            .map_err(crate::error::cdp::LocateError::ParseError)?;
+        let Ok(value) = self.send_locate_command(cmd).await else {
+            return Ok(None);
+        };
+        let owner = GetFrameOwnerResult::try_from(value)
+            .map_err(|e| crate::error::cdp::LocateError::ParseError(e.to_string()))?;
+        let cmd = PushNodesByBackendIdsToFrontend::builder()
+            .backend_node_id(*owner.backend_node_id)
+            .build()
+            .map_err(crate::error::cdp::LocateError::ParseError)?;
+        let value = self.send_locate_command(cmd).await?;
+        let node_ids = PushNodesByBackendIdsToFrontendResult::try_from(value)
+            .map_err(|e| crate::error::cdp::LocateError::ParseError(e.to_string()))?
+            .node_ids;
+        match node_ids.into_iter().find(|id| *id.inner() != 0) {
+            Some(node_id) => Ok(Some(self.describe_by_id(node_id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Sends a command issued while locating elements and returns its raw result.
+    async fn send_locate_command(
+        &mut self,
+        command: impl Into<Command>,
+    ) -> Result<serde_json::Value, crate::error::cdp::LocateError> {
+        Ok(self
+            .send_command(command)
+            .await
+            .map_err(|e| {
+                crate::error::cdp::LocateError::CommandResultError(
+                    CdpCommandResultError::SessionSendError(e),
+                )
+            })?
+            .result)
+    }
+
+    /// Find the first element matching `selector`. Returns `None` if not found.
+    pub async fn locate(
+        &mut self,
+        selector: &Selector,
+    ) -> Result<Option<DomNode>, crate::error::cdp::LocateError> {
+        let root_id = self.get_root_node_id().await?;
+        self.query_selector(root_id, selector).await
+    }
+
+    /// Find all elements matching `selector`.
+    pub async fn locate_all(
+        &mut self,
+        selector: &Selector,
+    ) -> Result<Vec<DomNode>, crate::error::cdp::LocateError> {
+        let root_id = self.get_root_node_id().await?;
+        self.query_selector_all(root_id, selector).await
+    }
+
+    /// Returns the document root followed by every shadow root `pierce` allows, outermost
+    /// first, from a single `DOM.getDocument` call with `pierce`.
+    async fn get_pierced_root_ids(
+        &mut self,
+        pierce: ShadowPierce,
+    ) -> Result<Vec<NodeId>, crate::error::cdp::LocateError> {
+        let result_value = self
+            .send_command(GetDocument::builder().depth(-1).pierce(true).build())
+            .await
+            .map_err(|e| {
+                crate::error::cdp::LocateError::CommandResultError(
+                    CdpCommandResultError::SessionSendError(e),
+                )
+            })?
+            .result;
+        let doc = GetDocumentResult::try_from(result_value)
+            .map_err(|e| crate::error::cdp::LocateError::ParseError(e.to_string()))?;
+        let mut roots = vec![*doc.root.node_id.clone()];
+        let mut level = vec![&doc.root];
+        while !level.is_empty() {
+            let mut next = Vec::new();
+            for node in level {
+                collect_shadow_roots(node, pierce, &mut next);
+            }
+            roots.extend(next.iter().map(|root| *root.node_id.clone()));
+            level = next;
+        }
+        Ok(roots)
+    }
+
+    /// Find the first element matching `selector`, searching the document first and
+    /// then each shadow root `pierce` allows. Returns `None` if not found.
+    pub async fn locate_piercing(
+        &mut self,
+        selector: &Selector,
+        pierce: ShadowPierce,
+    ) -> Result<Option<DomNode>, crate::error::cdp::LocateError> {
+        let mut roots = self.get_pierced_root_ids(pierce).await?;
+        // The accessibility tree already spans shadow roots and frame owners are found by
+        // id, so those selectors only need the document.
+        if matches!(selector, Selector::Role { .. } | Selector::FrameOwner(_)) {
+            roots.truncate(1);
+        }
+        for root in roots {
+            if let Some(node) = self.query_selector(root, selector).await? {
+                return Ok(Some(node));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Find all elements matching `selector` in the document and in every shadow root
+    /// `pierce` allows.
+    pub async fn locate_all_piercing(
+        &mut self,
+        selector: &Selector,
+        pierce: ShadowPierce,
+    ) -> Result<Vec<DomNode>, crate::error::cdp::LocateError> {
+        let mut nodes = Vec::new();
+        let mut roots = self.get_pierced_root_ids(pierce).await?;
+        // The accessibility tree already spans shadow roots and frame owners are found by
+        // id, so those selectors only need the document.
+        if matches!(selector, Selector::Role { .. } | Selector::FrameOwner(_)) {
+            roots.truncate(1);
+        }
+        for root in roots {
+            nodes.extend(self.query_selector_all(root, selector).await?);
+        }
+        Ok(nodes)
+    }
+
+    /// Find the first element matching `selector` inside the frame `frame_id`.
+    pub async fn locate_in_frame(
+        &mut self,
+        frame_id: &FrameId,
+        selector: &Selector,
+    ) -> Result<Option<DomNode>, crate::error::cdp::LocateError> {
+        let root_id = self.get_frame_document_id(frame_id).await?;
+        self.query_selector(root_id, selector).await
+    }
+
+    /// Find all elements matching `selector` inside the frame `frame_id`.
+    pub async fn locate_all_in_frame(
+        &mut self,
+        frame_id: &FrameId,
+        selector: &Selector,
+    ) -> Result<Vec<DomNode>, crate::error::cdp::LocateError> {
+        let root_id = self.get_frame_document_id(frame_id).await?;
+        self.query_selector_all(root_id, selector).await
+    }
+
     /// Poll until the first element matching `selector` appears, or `timeout` elapses.
     pub async fn wait_for(
         &mut self,
-        selector: &str,
+        selector: &Selector,
         timeout: Duration,
     ) -> Result<DomNode, crate::error::cdp::LocateError> {
         let interval = Duration::from_millis(100);
@@ -341,7 +1009,7 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
     /// Poll until at least one element matching `selector` appears, or `timeout` elapses.
     pub async fn wait_for_all(
         &mut self,
-        selector: &str,
+        selector: &Selector,
         timeout: Duration,
     ) -> Result<Vec<DomNode>, crate::error::cdp::LocateError> {
         let interval = Duration::from_millis(100);
@@ -360,6 +1028,213 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
         }
     }
 
+    /// Waits in the main frame until the first element matching `selector` reaches `state`,
+    /// returning it for the attached and visible states. A `MutationObserver` in the
+    /// `rustenium` isolated world reports over a `Runtime.addBinding` binding as soon as
+    /// the state is reached; role and frame-owner selectors are re-located whenever the
+    /// DOM changes instead.
+    pub async fn wait_for_selector(
+        &mut self,
+        selector: &Selector,
+        state: SelectorState,
+        timeout: Duration,
+    ) -> Result<Option<DomNode>, WaitError> {
+        let wait_id = unique_wait_id("rustenium-wait-for-selector");
+        self.add_wait_binding()
+            .await
+            .map_err(WaitError::CommandResultError)?;
+        let main_frame = self.frame_tree().await?.frame.id;
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let listening = wait_id.clone();
+        let handler = self.session.lock().await.add_event_handler(
+            [BindingCalled::IDENTIFIER],
+            move |event| {
+                if let Ok(called) = event.try_into_event::<BindingCalled>()
+                    && called.params.name == WAIT_BINDING
+                    && let Ok(report) =
+                        serde_json::from_str::<SelectorReport>(&called.params.payload)
+                    && report.id == listening
+                {
+                    let _ = tx.send((report.found, called.params.execution_context_id));
+                }
+                async {}
+            },
+        );
+
+        let send = format!(
+            "(found) => {{ \
+                const id = {id}; \
+                if (found instanceof Node) (globalThis.__rusteniumFound ??= new Map()).set(id, found); \
+                globalThis.{WAIT_BINDING}(JSON.stringify({{ id, found: found instanceof Node ? 'node' : found === 'changed' ? 'changed' : 'none' }})); \
+            }}",
+            id = serde_json::Value::from(wait_id.as_str()),
+        );
+        let declaration = format!(
+            "function() {{ return ({})({}); }}",
+            wait_for_selector_script(selector, state, timeout),
+            send
+        );
+        let mut retried = false;
+        let started = loop {
+            let context_id = match self.isolated_world(&main_frame).await {
+                Ok(context_id) => context_id,
+                Err(e) => break Err(WaitError::CommandResultError(e)),
+            };
+            let command = CallFunctionOn::builder()
+                .function_declaration(declaration.clone())
+                .execution_context_id(context_id)
+                .build()
+                .map_err(|e| WaitError::LocateError(LocateError::ParseError(e)))?;
+            match self.send_wait_command(command).await {
+                // The world went away before its destruction event arrived; make a new one
+                Err(WaitError::CommandResultError(CdpCommandResultError::SessionSendError(
+                    CdpSessionSendError::ErrorResponse(_),
+                ))) if !retried => {
+                    retried = true;
+                    self.isolated_worlds.forget_frame(main_frame.as_ref());
+                }
+                started => break started,
+            }
+        };
+        let reached = match started.map(CallFunctionOnResult::try_from) {
+            Ok(Ok(CallFunctionOnResult {
+                exception_details: Some(details),
+                ..
+            })) => Ok(Err(WaitError::LocateError(LocateError::InvalidSelector(
+                details.text,
+            )))),
+            Ok(Ok(_)) => {
+                tokio::time::timeout(timeout, async {
+                    while let Some((found, context)) = rx.recv().await {
+                        match found.as_str() {
+                            "node" => {
+                                let node = self.take_found_node(&wait_id, context).await?;
+                                return Ok(node);
+                            }
+                            "changed" => {
+                                if let Some(node) = self.selector_state(selector, state).await? {
+                                    return Ok(node);
+                                }
+                            }
+                            _ => return Ok(None),
+                        }
+                    }
+                    // The handler outlives this loop, so the channel only closes with the
+                    // session; let the timeout decide
+                    std::future::pending().await
+                })
+                .await
+            }
+            Ok(Err(e)) => Ok(Err(WaitError::LocateError(LocateError::ParseError(
+                e.to_string(),
+            )))),
+            Err(e) => Ok(Err(e)),
+        };
+        self.session.lock().await.remove_cdp_event_handler(&handler);
+        match reached {
+            Ok(result) => result,
+            Err(_) => Err(WaitError::Timeout(
+                format!("{selector} to be {state}"),
+                timeout,
+            )),
+        }
+    }
+
+    /// Hands back the element the selector wait `wait_id` stashed in the isolated world
+    /// `context` as a DOM node.
+    async fn take_found_node(
+        &mut self,
+        wait_id: &str,
+        context: ExecutionContextId,
+    ) -> Result<Option<DomNode>, WaitError> {
+        let expression = format!(
+            "(() => {{ const found = globalThis.__rusteniumFound; const id = {}; \
+                const element = found.get(id); found.delete(id); return [element]; }})()",
+            serde_json::Value::from(wait_id)
+        );
+        let result = self
+            .send_wait_command(
+                RuntimeEvaluateBuilder::default()
+                    .expression(expression)
+                    .context_id(context)
+                    .object_group("rustenium-query")
+                    .build()
+                    .unwrap(),
+            )
+            .await?;
+        let array = EvaluateResult::try_from(result)
+            .map_err(|e| LocateError::ParseError(e.to_string()))?
+            .result
+            .object_id;
+        match array {
+            Some(array) => Ok(self.request_array_nodes(array).await?.into_iter().next()),
+            None => Ok(None),
+        }
+    }
+
+    /// Re-locates `selector` for [`CdpAdapter::wait_for_selector`], returning what the wait
+    /// resolves to if the first match is in `state`, or `None` while it is not.
+    async fn selector_state(
+        &mut self,
+        selector: &Selector,
+        state: SelectorState,
+    ) -> Result<Option<Option<DomNode>>, WaitError> {
+        let node = self.locate(selector).await?;
+        let visible = match &node {
+            Some(node) if state.needs_visibility() => {
+                self.is_visible(*node.node_id.clone()).await?
+            }
+            _ => false,
+        };
+        if !state.reached(node.is_some(), visible) {
+            return Ok(None);
+        }
+        Ok(Some(node.filter(|_| state.yields_node())))
+    }
+
+    async fn is_visible(&mut self, node_id: NodeId) -> Result<bool, LocateError> {
+        let object_group = "rustenium-query";
+        let value = self
+            .send_locate_command(
+                ResolveNode::builder()
+                    .node_id(node_id)
+                    .object_group(object_group)
+                    .build(),
+            )
+            .await?;
+        let Some(object_id) = ResolveNodeResult::try_from(value)
+            .map_err(|e| LocateError::ParseError(e.to_string()))?
+            .object
+            .object_id
+        else {
+            return Ok(false);
+        };
+        let cmd = CallFunctionOn::builder()
+            .function_declaration(VISIBLE_SCRIPT)
+            .object_id(object_id)
+            .object_group(object_group)
+            .return_by_value(true)
+            .build()
+            .map_err(LocateError::ParseError)?;
+        let value = self.send_locate_command(cmd).await?;
+        let result = CallFunctionOnResult::try_from(value)
+            .map_err(|e| LocateError::ParseError(e.to_string()))?;
+        Ok(result.result.value == Some(serde_json::Value::Bool(true)))
+    }
+
+    /// Sends a command issued while waiting and returns its raw result.
+    async fn send_wait_command(
+        &mut self,
+        command: impl Into<Command>,
+    ) -> Result<serde_json::Value, WaitError> {
+        Ok(self
+            .send_command(command)
+            .await
+            .map_err(|e| WaitError::CommandResultError(CdpCommandResultError::SessionSendError(e)))?
+            .result)
+    }
+
     pub async fn layout_metrics(
         &mut self,
     ) -> Result<GetLayoutMetricsResult, CdpCommandResultError> {
@@ -430,6 +1305,59 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
         }
     }
 
+    /// Prints the page with `Page.printToPDF`, reading the document back in chunks with
+    /// `IO.read`, and writes it to `options.save_path` when set.
+    pub async fn pdf(&mut self, options: PdfOptions) -> Result<Vec<u8>, PdfError> {
+        let result_value = self
+            .send_command(options.to_cdp())
+            .await
+            .map_err(CdpCommandResultError::SessionSendError)?
+            .result;
+        let result = PrintToPdfResult::try_from(result_value.clone())
+            .map_err(|_| CdpCommandResultError::InvalidResultTypeError(result_value))?;
+        let pdf = match result.stream {
+            Some(stream) => self.read_stream(stream).await?,
+            None => pdf::decode_base64(result.data.as_ref())?,
+        };
+        pdf::save(pdf, options.save_path.as_deref()).await
+    }
+
+    /// Reads `stream` to its end with `IO.read`, then closes it, whether or not the read
+    /// succeeded.
+    async fn read_stream(&mut self, stream: StreamHandle) -> Result<Vec<u8>, PdfError> {
+        let data = self.read_stream_chunks(&stream).await;
+        let close = Close::builder().handle(stream).build().unwrap();
+        let closed = self
+            .send_command(close)
+            .await
+            .map_err(CdpCommandResultError::SessionSendError);
+        let data = data?;
+        closed?;
+        Ok(data)
+    }
+
+    async fn read_stream_chunks(&mut self, stream: &StreamHandle) -> Result<Vec<u8>, PdfError> {
+        let mut data = Vec::new();
+        loop {
+            let read = Read::builder().handle(stream.clone()).build().unwrap();
+            let result_value = self
+                .send_command(read)
+                .await
+                .map_err(CdpCommandResultError::SessionSendError)?
+                .result;
+            let chunk = ReadResult::try_from(result_value.clone())
+                .map_err(|_| CdpCommandResultError::InvalidResultTypeError(result_value))?;
+            if chunk.base64_encoded.unwrap_or(false) {
+                data.extend(pdf::decode_base64(&chunk.data)?);
+            } else {
+                data.extend(chunk.data.into_bytes());
+            }
+            if chunk.eof {
+                return Ok(data);
+            }
+        }
+    }
+
     pub async fn evaluate_script(
         &mut self,
         expression: &str,
@@ -453,6 +1381,262 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
             .map_err(|e| crate::error::cdp::EvaluateScriptError::ParseError(e.to_string()))
     }
 
+    /// Execution context of the [`SANDBOX`] isolated world in `frame_id`, creating the world
+    /// on first use and reusing it until it is destroyed.
+    pub(crate) async fn isolated_world(
+        &mut self,
+        frame_id: &FrameId,
+    ) -> Result<ExecutionContextId, CdpCommandResultError> {
+        self.track_isolated_worlds().await?;
+        if let Some(context_id) = self.isolated_worlds.get(frame_id.as_ref()) {
+            return Ok(context_id);
+        }
+        let command = CreateIsolatedWorldBuilder::default()
+            .frame_id(frame_id.clone())
+            .world_name(SANDBOX)
+            .build()
+            .unwrap();
+        let result = self
+            .send_command(command)
+            .await
+            .map_err(CdpCommandResultError::SessionSendError)?
+            .result;
+        let context_id = CreateIsolatedWorldResult::try_from(result.clone())
+            .map_err(|_| CdpCommandResultError::InvalidResultTypeError(result))?
+            .execution_context_id;
+        self.isolated_worlds.insert(frame_id.as_ref(), context_id);
+        Ok(context_id)
+    }
+
+    /// Adds [`WAIT_BINDING`] to the [`SANDBOX`] world of every frame, once per session.
+    async fn add_wait_binding(&mut self) -> Result<(), CdpCommandResultError> {
+        let worlds = self.isolated_worlds.clone();
+        let mut added = worlds.wait_binding.lock().await;
+        if *added {
+            return Ok(());
+        }
+        self.send_command(
+            AddBinding::builder()
+                .name(WAIT_BINDING)
+                .execution_context_name(SANDBOX)
+                .build()
+                .unwrap(),
+        )
+        .await
+        .map_err(CdpCommandResultError::SessionSendError)?;
+        *added = true;
+        Ok(())
+    }
+
+    /// Installs the handlers that keep [`IsolatedWorlds`] current, once per session.
+    /// Enables the Runtime domain so context lifecycle events are reported.
+    async fn track_isolated_worlds(&mut self) -> Result<(), CdpCommandResultError> {
+        let worlds = self.isolated_worlds.clone();
+        let mut listening = worlds.listening.lock().await;
+        if *listening {
+            return Ok(());
+        }
+        let tracked = worlds.clone();
+        self.session.lock().await.add_event_handler(
+            [
+                ExecutionContextCreated::IDENTIFIER,
+                ExecutionContextDestroyed::IDENTIFIER,
+                ExecutionContextsCleared::IDENTIFIER,
+                FrameNavigated::IDENTIFIER,
+                FrameDetached::IDENTIFIER,
+            ],
+            move |event| {
+                match event.identifier() {
+                    ExecutionContextCreated::IDENTIFIER => {
+                        if let Ok(event) = event.try_into_event::<ExecutionContextCreated>() {
+                            tracked.created(&event.params.context);
+                        }
+                    }
+                    ExecutionContextDestroyed::IDENTIFIER => {
+                        if let Ok(event) = event.try_into_event::<ExecutionContextDestroyed>() {
+                            tracked.destroyed(&event.params.execution_context_unique_id);
+                        }
+                    }
+                    ExecutionContextsCleared::IDENTIFIER => tracked.clear(),
+                    FrameNavigated::IDENTIFIER => {
+                        if let Ok(event) = event.try_into_event::<FrameNavigated>() {
+                            tracked.forget_frame(event.params.frame.id.as_ref());
+                        }
+                    }
+                    _ => {
+                        if let Ok(event) = event.try_into_event::<FrameDetached>() {
+                            tracked.forget_frame(event.params.frame_id.as_ref().as_ref());
+                        }
+                    }
+                }
+                async {}
+            },
+        );
+        self.send_command(RuntimeEnableBuilder::new().build())
+            .await
+            .map_err(CdpCommandResultError::SessionSendError)?;
+        *listening = true;
+        Ok(())
+    }
+
+    /// Evaluates `expression` in the `rustenium` isolated world of `frame_id`. The world
+    /// shares the frame's DOM but not its JavaScript globals, and is reused across calls.
+    pub async fn evaluate_in_frame(
+        &mut self,
+        frame_id: &FrameId,
+        expression: &str,
+        await_promise: bool,
+    ) -> Result<EvaluateResult, crate::error::cdp::EvaluateScriptError> {
+        let mut retried = false;
+        loop {
+            let context_id = self
+                .isolated_world(frame_id)
+                .await
+                .map_err(crate::error::cdp::EvaluateScriptError::CommandResultError)?;
+            let command = RuntimeEvaluateBuilder::default()
+                .expression(expression)
+                .await_promise(await_promise)
+                .context_id(context_id)
+                .build()
+                .unwrap();
+            let result_value = match self.send_command(command).await {
+                Ok(response) => response.result,
+                // The world went away before its destruction event arrived; make a new one
+                Err(CdpSessionSendError::ErrorResponse(_)) if !retried => {
+                    retried = true;
+                    self.isolated_worlds.forget_frame(frame_id.as_ref());
+                    continue;
+                }
+                Err(e) => {
+                    return Err(crate::error::cdp::EvaluateScriptError::CommandResultError(
+                        CdpCommandResultError::SessionSendError(e),
+                    ));
+                }
+            };
+            return EvaluateResult::try_from(result_value)
+                .map_err(|e| crate::error::cdp::EvaluateScriptError::ParseError(e.to_string()));
+        }
+    }
+
+    /// Calls `function` in the page's main world with the positional arguments `args`,
+    /// handing back its by-value result. [`NodeRef`](crate::evaluate::NodeRef)s anywhere in `args` arrive as the
+    /// elements they refer to.
+    pub async fn evaluate(
+        &mut self,
+        function: &str,
+        args: Vec<serde_json::Value>,
+    ) -> Result<serde_json::Value, crate::error::cdp::EvaluateError> {
+        use crate::error::cdp::EvaluateError;
+
+        let object_group = "rustenium-evaluate";
+        let mut node_ids = Vec::new();
+        let args = take_nodes(serde_json::Value::Array(args), &mut node_ids);
+        let result = async {
+            let global = self
+                .send_evaluate_command(
+                    RuntimeEvaluateBuilder::default()
+                        .expression("globalThis")
+                        .object_group(object_group)
+                        .build()
+                        .unwrap(),
+                )
+                .await?;
+            let global = EvaluateResult::try_from(global)
+                .map_err(|e| EvaluateError::ParseError(e.to_string()))?
+                .result
+                .object_id
+                .ok_or_else(|| EvaluateError::ParseError("globalThis has no object id".into()))?;
+
+            let mut builder = CallFunctionOn::builder()
+                .function_declaration(revive_declaration(function))
+                .object_id(global)
+                .object_group(object_group)
+                .await_promise(true)
+                .return_by_value(true)
+                .argument(CallArgument {
+                    value: Some(args),
+                    unserializable_value: None,
+                    object_id: None,
+                });
+            for node_id in node_ids {
+                let node = self
+                    .send_evaluate_command(
+                        ResolveNode::builder()
+                            .node_id(node_id)
+                            .object_group(object_group)
+                            .build(),
+                    )
+                    .await?;
+                let node = ResolveNodeResult::try_from(node)
+                    .map_err(|e| EvaluateError::ParseError(e.to_string()))?
+                    .object
+                    .object_id
+                    .ok_or_else(|| EvaluateError::ParseError("node has no object id".into()))?;
+                builder = builder.argument(CallArgument {
+                    value: None,
+                    unserializable_value: None,
+                    object_id: Some(node),
+                });
+            }
+            let value = self
+                .send_evaluate_command(builder.build().map_err(EvaluateError::ParseError)?)
+                .await?;
+            match CallFunctionOnResult::try_from(value)
+                .map_err(|e| EvaluateError::ParseError(e.to_string()))?
+            {
+                CallFunctionOnResult {
+                    exception_details: Some(details),
+                    ..
+                } => Err(EvaluateError::Exception(JsException::from(&details))),
+                CallFunctionOnResult { result, .. } => {
+                    Ok(result.value.unwrap_or(serde_json::Value::Null))
+                }
+            }
+        }
+        .await;
+        let _ = self
+            .send_command(
+                ReleaseObjectGroup::builder()
+                    .object_group(object_group)
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+        result
+    }
+
+    /// Sends a command issued by [`CdpAdapter::evaluate`] and returns its raw result.
+    async fn send_evaluate_command(
+        &mut self,
+        command: impl Into<Command>,
+    ) -> Result<serde_json::Value, crate::error::cdp::EvaluateError> {
+        Ok(self
+            .send_command(command)
+            .await
+            .map_err(|e| {
+                crate::error::cdp::EvaluateError::CommandResultError(
+                    CdpCommandResultError::SessionSendError(e),
+                )
+            })?
+            .result)
+    }
+
+    /// The frame tree of the attached target via `Page.getFrameTree`.
+    pub async fn frame_tree(&mut self) -> Result<FrameTree, crate::error::cdp::FrameError> {
+        let result_value = self
+            .send_command(GetFrameTreeBuilder::new().build())
+            .await
+            .map_err(|e| {
+                crate::error::cdp::FrameError::CommandResultError(
+                    CdpCommandResultError::SessionSendError(e),
+                )
+            })?
+            .result;
+        GetFrameTreeResult::try_from(result_value)
+            .map(|r| r.frame_tree)
+            .map_err(|e| crate::error::cdp::FrameError::ParseError(e.to_string()))
+    }
+
     pub async fn add_preload_script(
         &mut self,
         command: AddScriptToEvaluateOnNewDocument,
@@ -471,6 +1655,509 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
         Ok(result.identifier)
     }
 
+    /// Exposes `function` to every frame of the attached target as `window[name]`. Calls go
+    /// out through a `Runtime.addBinding` binding and are answered in the execution context
+    /// they came from. The install script runs in the documents already loaded and in every
+    /// new one. Exposing a name again removes the previous function's handler and install
+    /// script first.
+    pub async fn expose_function(
+        &mut self,
+        name: &str,
+        function: ExposedFunction,
+    ) -> Result<(), crate::error::cdp::ExposeFunctionError>
+    where
+        T: 'static,
+    {
+        use crate::error::cdp::ExposeFunctionError;
+
+        let previous = self.exposed.lock().unwrap().remove(name);
+        if let Some(previous) = previous {
+            self.session
+                .lock()
+                .await
+                .remove_cdp_event_handler(&previous.handler);
+            self.remove_preload_script(
+                RemoveScriptToEvaluateOnNewDocument::builder()
+                    .identifier(previous.script)
+                    .build()
+                    .unwrap(),
+            )
+            .await?;
+        }
+
+        let binding = format!("__rusteniumExpose_{name}");
+        for command in [
+            Command::from(RuntimeEnableBuilder::new().build()),
+            Command::from(AddBinding::builder().name(&binding).build().unwrap()),
+        ] {
+            self.send_command(command).await.map_err(|e| {
+                ExposeFunctionError::CommandResultError(CdpCommandResultError::SessionSendError(e))
+            })?;
+        }
+
+        let session = Arc::downgrade(&self.session);
+        let listening = binding.clone();
+        let exposed = name.to_string();
+        let handler = self.session.lock().await.add_event_handler(
+            [BindingCalled::IDENTIFIER],
+            move |event| {
+                if let Ok(called) = event.try_into_event::<BindingCalled>()
+                    && called.params.name == listening
+                {
+                    let (session, function, name) =
+                        (session.clone(), function.clone(), exposed.clone());
+                    tokio::spawn(async move {
+                        let Some(reply) =
+                            call_exposed(&name, &function, &called.params.payload).await
+                        else {
+                            return;
+                        };
+                        let Some(session) = session.upgrade() else {
+                            return;
+                        };
+                        let args: Vec<_> = reply.iter().map(|arg| arg.to_string()).collect();
+                        let command = RuntimeEvaluateBuilder::default()
+                            .expression(format!("({REPLY_SCRIPT})({})", args.join(", ")))
+                            .context_id(called.params.execution_context_id)
+                            .build()
+                            .unwrap();
+                        if let Err(e) = session.lock().await.send(command).await {
+                            tracing::warn!("Failed to reply to exposed function {}: {}", name, e);
+                        }
+                    });
+                }
+                async {}
+            },
+        );
+
+        let command = AddScriptToEvaluateOnNewDocumentBuilder::default()
+            .source(expose_source(name, &binding))
+            .run_immediately(true)
+            .build()
+            .unwrap();
+        let script = match self.add_preload_script(command).await {
+            Ok(script) => script,
+            Err(e) => {
+                self.session.lock().await.remove_cdp_event_handler(&handler);
+                return Err(e.into());
+            }
+        };
+        self.exposed
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), ExposedBinding { handler, script });
+        Ok(())
+    }
+
+    /// Streams the console messages logged in the attached target from now on, until the
+    /// receiver is dropped.
+    pub async fn console_messages(
+        &mut self,
+    ) -> Result<UnboundedReceiver<ConsoleMessage>, crate::error::cdp::ConsoleError>
+    where
+        T: 'static,
+    {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        self.on_console_message(move |message| tx.send(message).is_ok())
+            .await?;
+        Ok(rx)
+    }
+
+    /// Streams the uncaught errors in the attached target from now on, until the receiver
+    /// is dropped.
+    pub async fn page_errors(
+        &mut self,
+    ) -> Result<UnboundedReceiver<PageError>, crate::error::cdp::ConsoleError>
+    where
+        T: 'static,
+    {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        self.on_page_error(move |error| tx.send(error).is_ok())
+            .await?;
+        Ok(rx)
+    }
+
+    /// Buffers the console messages and uncaught errors of the attached target from now on,
+    /// until the collector is dropped.
+    pub async fn collect_console(
+        &mut self,
+    ) -> Result<ConsoleCollector, crate::error::cdp::ConsoleError>
+    where
+        T: 'static,
+    {
+        let collector = ConsoleCollector::new();
+        let messages = collector.sink();
+        self.on_console_message(move |message| messages.push_message(message))
+            .await?;
+        let errors = collector.sink();
+        self.on_page_error(move |error| errors.push_error(error))
+            .await?;
+        Ok(collector)
+    }
+
+    /// Passes every `Runtime.consoleAPICalled` from now on to `handler`, until it returns
+    /// `false`.
+    async fn on_console_message<F>(
+        &mut self,
+        handler: F,
+    ) -> Result<(), crate::error::cdp::ConsoleError>
+    where
+        T: 'static,
+        F: Fn(ConsoleMessage) -> bool + Send + Sync + 'static,
+    {
+        self.add_handler_while([ConsoleApiCalled::IDENTIFIER], move |event| match event
+            .try_into_event::<ConsoleApiCalled>(
+        ) {
+            Ok(called) => handler(ConsoleMessage::from(&called.params)),
+            Err(_) => true,
+        })
+        .await;
+        self.enable_runtime().await
+    }
+
+    /// Passes every `Runtime.exceptionThrown` from now on to `handler`, until it returns
+    /// `false`.
+    async fn on_page_error<F>(&mut self, handler: F) -> Result<(), crate::error::cdp::ConsoleError>
+    where
+        T: 'static,
+        F: Fn(PageError) -> bool + Send + Sync + 'static,
+    {
+        self.add_handler_while([ExceptionThrown::IDENTIFIER], move |event| match event
+            .try_into_event::<ExceptionThrown>(
+        ) {
+            Ok(thrown) => handler(PageError::from(&thrown.params)),
+            Err(_) => true,
+        })
+        .await;
+        self.enable_runtime().await
+    }
+
+    /// Adds a handler for `methods` that removes itself the first time `handler` returns
+    /// `false`, e.g. once whatever it forwards to is gone.
+    async fn add_handler_while<F>(&mut self, methods: [&str; 1], handler: F) -> String
+    where
+        T: 'static,
+        F: Fn(EventResponse) -> bool + Send + Sync + 'static,
+    {
+        let session = Arc::downgrade(&self.session);
+        let own_id = Arc::new(OnceLock::<String>::new());
+        let id = own_id.clone();
+        let handler_id = self
+            .session
+            .lock()
+            .await
+            .add_event_handler(methods, move |event| {
+                let done = (!handler(event)).then(|| (session.clone(), id.clone()));
+                async move {
+                    if let Some((session, id)) = done
+                        && let Some(id) = id.get()
+                    {
+                        remove_handler_weak(session, id).await;
+                    }
+                }
+            });
+        let _ = own_id.set(handler_id.clone());
+        handler_id
+    }
+
+    /// Enables the Runtime domain, which reports console calls and uncaught errors.
+    async fn enable_runtime(&mut self) -> Result<(), crate::error::cdp::ConsoleError> {
+        self.send_command(RuntimeEnableBuilder::new().build())
+            .await
+            .map_err(|e| {
+                crate::error::cdp::ConsoleError::CommandResultError(
+                    CdpCommandResultError::SessionSendError(e),
+                )
+            })?;
+        Ok(())
+    }
+
+    /// Calls `handler` with every JavaScript dialog the attached target opens from now on,
+    /// until the returned registration is unregistered.
+    pub async fn on_dialog<F, Fut>(
+        &mut self,
+        handler: F,
+    ) -> Result<HandlerRegistration, DialogError>
+    where
+        T: 'static,
+        F: Fn(Dialog) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler = self
+            .watch_dialogs(move |dialog| {
+                tokio::spawn(handler(dialog));
+            })
+            .await?;
+        let session = Arc::downgrade(&self.session);
+        Ok(HandlerRegistration::new(move || async move {
+            remove_handler_weak(session, &handler).await;
+        }))
+    }
+
+    /// Runs `trigger` and returns the first JavaScript dialog the attached target opens
+    /// meanwhile. `trigger` is dropped as soon as the dialog opens, since a script that
+    /// opens one only returns once it is answered.
+    pub async fn wait_for_dialog<F>(
+        &mut self,
+        trigger: F,
+        timeout: Duration,
+    ) -> Result<Dialog, DialogError>
+    where
+        T: 'static,
+        F: Future,
+    {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let handler = self
+            .watch_dialogs(move |dialog| {
+                let _ = tx.send(dialog);
+            })
+            .await?;
+        let dialog = race_trigger(trigger, rx.recv(), timeout).await;
+        self.session.lock().await.remove_cdp_event_handler(&handler);
+        dialog.ok_or(DialogError::Timeout(timeout))
+    }
+
+    /// Passes every `Page.javascriptDialogOpening` from now on to `on_dialog`, returning the
+    /// handler's id.
+    async fn watch_dialogs<D>(&mut self, on_dialog: D) -> Result<String, DialogError>
+    where
+        T: 'static,
+        D: Fn(Dialog) + Send + Sync + 'static,
+    {
+        let session = Arc::downgrade(&self.session);
+        let handler = self.session.lock().await.add_event_handler(
+            [JavascriptDialogOpening::IDENTIFIER],
+            move |event| {
+                if let Ok(opening) = event.try_into_event::<JavascriptDialogOpening>()
+                    && let Some(session) = session.upgrade()
+                {
+                    let params = opening.params;
+                    let respond: DialogResponder = Arc::new(move |accept, text| {
+                        let session = session.clone();
+                        Box::pin(async move {
+                            let mut command =
+                                HandleJavaScriptDialogBuilder::default().accept(accept);
+                            if let Some(text) = text {
+                                command = command.prompt_text(text);
+                            }
+                            session
+                                .lock()
+                                .await
+                                .send(command.build().unwrap())
+                                .await
+                                .map_err(CdpCommandResultError::SessionSendError)?;
+                            Ok(())
+                        })
+                    });
+                    on_dialog(Dialog::new(
+                        DialogKind::from(&params.r#type),
+                        params.message,
+                        params.default_prompt,
+                        false,
+                        respond,
+                    ));
+                }
+                async {}
+            },
+        );
+        if let Err(e) = self.enable_page_domain().await {
+            self.session.lock().await.remove_cdp_event_handler(&handler);
+            return Err(CdpCommandResultError::SessionSendError(e).into());
+        }
+        Ok(handler)
+    }
+
+    /// Calls `handler` with every native file chooser the attached target tries to open
+    /// from now on, until the returned registration is unregistered. Turns on
+    /// `Page.setInterceptFileChooserDialog` meanwhile, so choosers are not shown.
+    pub async fn on_file_chooser<F, Fut>(
+        &mut self,
+        handler: F,
+    ) -> Result<HandlerRegistration, FileChooserError>
+    where
+        T: 'static,
+        F: Fn(FileChooser) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler = self
+            .watch_file_choosers(move |chooser| {
+                tokio::spawn(handler(chooser));
+            })
+            .await?;
+        let session = Arc::downgrade(&self.session);
+        let watching = self.file_choosers.clone();
+        Ok(HandlerRegistration::new(move || async move {
+            if let Some(session) = session.upgrade() {
+                unwatch_file_choosers(&session, &watching, &handler).await;
+            }
+        }))
+    }
+
+    /// Runs `trigger` and returns the first native file chooser the attached target tries to
+    /// open meanwhile.
+    pub async fn wait_for_file_chooser<F>(
+        &mut self,
+        trigger: F,
+        timeout: Duration,
+    ) -> Result<FileChooser, FileChooserError>
+    where
+        T: 'static,
+        F: Future,
+    {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let handler = self
+            .watch_file_choosers(move |chooser| {
+                let _ = tx.send(chooser);
+            })
+            .await?;
+        let chooser = race_trigger(trigger, rx.recv(), timeout).await;
+        unwatch_file_choosers(&self.session, &self.file_choosers, &handler).await;
+        chooser.ok_or(FileChooserError::Timeout(timeout))
+    }
+
+    /// Intercepts file choosers and passes every `Page.fileChooserOpened` from now on to
+    /// `on_chooser`, returning the handler's id. Interception stays on until the handler is
+    /// taken down with [`unwatch_file_choosers`] and no other is left.
+    async fn watch_file_choosers<C>(&mut self, on_chooser: C) -> Result<String, FileChooserError>
+    where
+        T: 'static,
+        C: Fn(FileChooser) + Send + Sync + 'static,
+    {
+        let file_choosers = self.file_choosers.clone();
+        let mut watching = file_choosers.lock().await;
+        let session = Arc::downgrade(&self.session);
+        let handler = self.session.lock().await.add_event_handler(
+            [FileChooserOpened::IDENTIFIER],
+            move |event| {
+                if let Ok(opened) = event.try_into_event::<FileChooserOpened>()
+                    && let Some(session) = session.upgrade()
+                {
+                    let params = opened.params;
+                    let backend_node_id = params.backend_node_id;
+                    let respond: FileChooserResponder = Arc::new(move |files| {
+                        let (session, backend_node_id) = (session.clone(), backend_node_id);
+                        Box::pin(async move {
+                            let backend_node_id =
+                                backend_node_id.ok_or(SetFilesError::NoElement)?;
+                            let command = SetFileInputFiles::builder()
+                                .files(files)
+                                .backend_node_id(backend_node_id)
+                                .build()
+                                .unwrap();
+                            session.lock().await.send(command).await.map_err(|e| {
+                                NodeActionError::from(CdpCommandResultError::SessionSendError(e))
+                            })?;
+                            Ok(())
+                        })
+                    });
+                    on_chooser(FileChooser::new(
+                        params.mode == FileChooserOpenedMode::SelectMultiple,
+                        respond,
+                    ));
+                }
+                async {}
+            },
+        );
+        let intercept = SetInterceptFileChooserDialogBuilder::default()
+            .enabled(true)
+            .build()
+            .unwrap();
+        let enabled = match self.enable_page_domain().await {
+            Ok(()) if *watching == 0 => self.send_command(intercept).await.map(|_| ()),
+            Ok(()) => Ok(()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = enabled {
+            self.session.lock().await.remove_cdp_event_handler(&handler);
+            return Err(CdpCommandResultError::SessionSendError(e).into());
+        }
+        *watching += 1;
+        Ok(handler)
+    }
+
+    /// Sets what the browser does with downloads via `Browser.setDownloadBehavior`, with
+    /// download events turned on.
+    pub async fn set_download_behavior(
+        &mut self,
+        behavior: DownloadBehavior,
+    ) -> Result<(), FileDownloadError> {
+        let dir = behavior.prepare_dir()?;
+        let command = match &dir {
+            Some(dir) => SetDownloadBehaviorBuilder::default()
+                .behavior(SetDownloadBehaviorBehavior::Allow)
+                .download_path(dir.to_string_lossy()),
+            None => {
+                SetDownloadBehaviorBuilder::default().behavior(SetDownloadBehaviorBehavior::Deny)
+            }
+        };
+        self.send_command(command.events_enabled(true).build().unwrap())
+            .await
+            .map_err(CdpCommandResultError::SessionSendError)?;
+        *self.download_dir.lock().unwrap() = dir;
+        Ok(())
+    }
+
+    /// Streams what happens to downloads from now on. Chrome only reports downloads once
+    /// [`CdpAdapter::set_download_behavior`] was called.
+    pub async fn download_events(
+        &mut self,
+    ) -> Result<UnboundedReceiver<DownloadEvent>, FileDownloadError> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        self.watch_downloads(move |event| {
+            let _ = tx.send(event);
+        })
+        .await;
+        Ok(rx)
+    }
+
+    /// Runs `trigger` and waits for the first download started meanwhile to finish or be
+    /// canceled.
+    pub async fn wait_for_download<F>(
+        &mut self,
+        trigger: F,
+        timeout: Duration,
+    ) -> Result<Download, FileDownloadError>
+    where
+        F: Future,
+    {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let handler = self
+            .watch_downloads(move |event| {
+                let _ = tx.send(event);
+            })
+            .await;
+        let dir = self.download_dir.lock().unwrap().clone();
+        let download = race_trigger(trigger, next_download(&mut rx, dir), timeout).await;
+        self.session.lock().await.remove_cdp_event_handler(&handler);
+        download.ok_or(FileDownloadError::Timeout(timeout))
+    }
+
+    /// Passes every `Browser.downloadWillBegin` and `Browser.downloadProgress` from now on to
+    /// `on_event`, returning the handler's id. A progress event that finishes a download is
+    /// followed by a [`DownloadEvent::End`].
+    async fn watch_downloads<D>(&mut self, on_event: D) -> String
+    where
+        D: Fn(DownloadEvent) + Send + Sync + 'static,
+    {
+        self.session.lock().await.add_event_handler(
+            [DownloadWillBegin::IDENTIFIER, DownloadProgress::IDENTIFIER],
+            move |event| {
+                if let Ok(begin) = event.clone().try_into_event::<DownloadWillBegin>() {
+                    on_event(DownloadEvent::WillBegin((&begin.params).into()));
+                } else if let Ok(progress) = event.try_into_event::<DownloadProgress>() {
+                    let params = progress.params;
+                    let progress = download::DownloadProgress::from(&params);
+                    let end = progress.end(params.file_path.as_deref());
+                    on_event(DownloadEvent::Progress(progress));
+                    if let Some(end) = end {
+                        on_event(DownloadEvent::End(end));
+                    }
+                }
+                async {}
+            },
+        )
+    }
+
     pub async fn remove_preload_script(
         &mut self,
         command: RemoveScriptToEvaluateOnNewDocument,
@@ -488,6 +2175,75 @@ impl<T: ConnectionTransport + Send + Sync> CdpAdapter<T> {
     }
 }
 
+/// Removes the event handler `handler` unless the session is already gone. The CDP
+/// counterpart of [`unsubscribe_weak`](crate::page::unsubscribe_weak).
+pub(crate) async fn remove_handler_weak<T: ConnectionTransport + Send + Sync>(
+    session: Weak<TokioMutex<CdpSession<T>>>,
+    handler: &str,
+) {
+    if let Some(session) = session.upgrade() {
+        session.lock().await.remove_cdp_event_handler(handler);
+    }
+}
+
+/// Takes down a handler added by [`CdpAdapter::watch_file_choosers`], turning file chooser
+/// interception back off once no handler is left.
+async fn unwatch_file_choosers<T: ConnectionTransport + Send + Sync>(
+    session: &TokioMutex<CdpSession<T>>,
+    watching: &TokioMutex<usize>,
+    handler: &str,
+) {
+    let mut watching = watching.lock().await;
+    let mut session = session.lock().await;
+    session.remove_cdp_event_handler(handler);
+    *watching = watching.saturating_sub(1);
+    if *watching == 0 {
+        let intercept = SetInterceptFileChooserDialogBuilder::default()
+            .enabled(false)
+            .build()
+            .unwrap();
+        if let Err(e) = session.send(intercept).await {
+            tracing::warn!("Failed to stop intercepting file choosers: {}", e);
+        }
+    }
+}
+
+/// Searches a pierced document tree for the frame owner hosting `frame_id` and returns
+/// the `NodeId` of its content document.
+fn find_frame_document(node: &DomNode, frame_id: &FrameId) -> Option<NodeId> {
+    if let Some(document) = &node.content_document {
+        if node.frame_id.as_ref() == Some(frame_id) {
+            return Some(*document.node_id.clone());
+        }
+        if let Some(id) = find_frame_document(document, frame_id) {
+            return Some(id);
+        }
+    }
+    node.children
+        .iter()
+        .flatten()
+        .chain(node.shadow_roots.iter().flatten())
+        .find_map(|child| find_frame_document(child, frame_id))
+}
+
+/// Pushes the shadow roots hosted in the tree under `node` (but not inside those roots or
+/// inside frames) that `pierce` allows. User-agent shadow roots are always skipped.
+fn collect_shadow_roots<'a>(node: &'a DomNode, pierce: ShadowPierce, roots: &mut Vec<&'a DomNode>) {
+    for root in node.shadow_roots.iter().flatten() {
+        let allowed = match root.shadow_root_type.as_deref() {
+            Some(ShadowRootType::Open) => true,
+            Some(ShadowRootType::Closed) => pierce == ShadowPierce::All,
+            _ => false,
+        };
+        if allowed {
+            roots.push(root);
+        }
+    }
+    for child in node.children.iter().flatten() {
+        collect_shadow_roots(child, pierce, roots);
+    }
+}
+
 pub async fn fetch_ws_debugger_url_with_retry(
     host: &str,
     chrome_port: u16,
@@ -0,0 +1,364 @@
+//! Console messages and uncaught errors reported by pages.
+//!
+//! BiDi reports both through `log.entryAdded`; CDP through `Runtime.consoleAPICalled` and
+//! `Runtime.exceptionThrown`. Either way they arrive as a [`ConsoleMessage`] or a
+//! [`PageError`], streamed one by one or buffered in a [`ConsoleCollector`].
+
+use crate::error::script::{JsException, bidi_stack, cdp_stack};
+use crate::evaluate::from_remote_value;
+use rustenium_bidi_definitions::log::types::{
+    BaseLogEntry, ConsoleLogEntry, JavascriptLogEntry, Level,
+};
+use rustenium_bidi_definitions::script::types::StackTrace as BidiStackTrace;
+use rustenium_cdp_definitions::js_protocol::runtime::events::{
+    ConsoleApiCalledParams, ConsoleApiCalledType, ExceptionThrownParams,
+};
+use rustenium_cdp_definitions::js_protocol::runtime::types::{
+    RemoteObject, RemoteObjectType, StackTrace as CdpStackTrace,
+};
+use serde_json::Value;
+use std::fmt::{self, Display};
+use std::sync::{Arc, Mutex, Weak};
+
+/// How severe a [`ConsoleMessage`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConsoleLevel {
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
+
+impl Display for ConsoleLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ConsoleLevel::Debug => "debug",
+            ConsoleLevel::Info => "info",
+            ConsoleLevel::Warning => "warning",
+            ConsoleLevel::Error => "error",
+        })
+    }
+}
+
+/// Where in the page's scripts a message was logged or an error thrown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub url: String,
+    /// Zero-based, like the browser reports it.
+    pub line: u64,
+    /// Zero-based, like the browser reports it.
+    pub column: u64,
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.url, self.line, self.column)
+    }
+}
+
+/// A call to one of the page's `console` methods.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsoleMessage {
+    pub level: ConsoleLevel,
+    /// The message as the browser's console shows it.
+    pub text: String,
+    /// The arguments `console` was called with, as JSON. Values JSON cannot hold, such as
+    /// `undefined` or `NaN`, are `null`. Over CDP, objects are only described, e.g. `Object`
+    /// or `Array(2)`.
+    pub args: Vec<Value>,
+    /// Where `console` was called from, when the browser reports it.
+    pub source: Option<SourceLocation>,
+    /// The call stack, one `at function (url:line:column)` frame per line. Empty when the
+    /// browser reports no frames.
+    pub stack: String,
+}
+
+/// An uncaught exception or unhandled promise rejection in the page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageError {
+    /// What was thrown, e.g. `Error: boom`.
+    pub message: String,
+    /// Where it was thrown, when the browser reports it.
+    pub source: Option<SourceLocation>,
+    /// The call stack, one `at function (url:line:column)` frame per line.
+    pub stack: String,
+}
+
+impl Display for PageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        if !self.stack.is_empty() {
+            write!(f, "\n{}", self.stack)?;
+        }
+        Ok(())
+    }
+}
+
+fn bidi_source(stack_trace: Option<&BidiStackTrace>) -> Option<SourceLocation> {
+    let frame = stack_trace?.call_frames.first()?;
+    Some(SourceLocation {
+        url: frame.url.clone(),
+        line: frame.line_number,
+        column: frame.column_number,
+    })
+}
+
+fn cdp_source(stack_trace: Option<&CdpStackTrace>) -> Option<SourceLocation> {
+    let frame = stack_trace?.call_frames.first()?;
+    Some(SourceLocation {
+        url: frame.url.clone(),
+        line: frame.line_number.max(0) as u64,
+        column: frame.column_number.max(0) as u64,
+    })
+}
+
+impl From<&ConsoleLogEntry> for ConsoleMessage {
+    fn from(entry: &ConsoleLogEntry) -> Self {
+        let BaseLogEntry {
+            level,
+            text,
+            stack_trace,
+            ..
+        } = &entry.base_log_entry;
+        Self {
+            level: match level {
+                Level::Debug => ConsoleLevel::Debug,
+                Level::Info => ConsoleLevel::Info,
+                Level::Warn => ConsoleLevel::Warning,
+                Level::Error => ConsoleLevel::Error,
+            },
+            text: text.clone().unwrap_or_default(),
+            args: entry.args.iter().map(from_remote_value).collect(),
+            source: bidi_source(stack_trace.as_ref()),
+            stack: stack_trace.as_ref().map(bidi_stack).unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&JavascriptLogEntry> for PageError {
+    fn from(entry: &JavascriptLogEntry) -> Self {
+        let BaseLogEntry {
+            text, stack_trace, ..
+        } = &entry.base_log_entry;
+        Self {
+            message: text.clone().unwrap_or_default(),
+            source: bidi_source(stack_trace.as_ref()),
+            stack: stack_trace.as_ref().map(bidi_stack).unwrap_or_default(),
+        }
+    }
+}
+
+/// A CDP console argument as JSON, read the way [`from_remote_value`] reads BiDi ones.
+fn cdp_arg(arg: &RemoteObject) -> Value {
+    match (&arg.value, &arg.unserializable_value) {
+        (Some(value), _) => value.clone(),
+        (None, Some(special)) => match special.as_ref() {
+            "-0" => Value::from(0),
+            big_int if big_int.ends_with('n') => Value::from(big_int.trim_end_matches('n')),
+            _ => Value::Null,
+        },
+        (None, None) if arg.r#type == RemoteObjectType::Undefined => Value::Null,
+        (None, None) => arg.description.clone().map(Value::from).unwrap_or_default(),
+    }
+}
+
+/// A CDP console argument the way the console prints it.
+fn cdp_text(arg: &RemoteObject) -> String {
+    match (&arg.value, &arg.unserializable_value) {
+        (Some(Value::String(text)), _) => text.clone(),
+        (Some(value), _) => value.to_string(),
+        (None, Some(special)) => special.as_ref().to_string(),
+        (None, None) if arg.r#type == RemoteObjectType::Undefined => "undefined".to_string(),
+        (None, None) => arg.description.clone().unwrap_or_default(),
+    }
+}
+
+impl From<&ConsoleApiCalledParams> for ConsoleMessage {
+    fn from(params: &ConsoleApiCalledParams) -> Self {
+        Self {
+            level: match params.r#type {
+                ConsoleApiCalledType::Debug => ConsoleLevel::Debug,
+                ConsoleApiCalledType::Warning => ConsoleLevel::Warning,
+                ConsoleApiCalledType::Error | ConsoleApiCalledType::Assert => ConsoleLevel::Error,
+                _ => ConsoleLevel::Info,
+            },
+            text: params
+                .args
+                .iter()
+                .map(cdp_text)
+                .collect::<Vec<_>>()
+                .join(" "),
+            args: params.args.iter().map(cdp_arg).collect(),
+            source: cdp_source(params.stack_trace.as_ref()),
+            stack: params
+                .stack_trace
+                .as_ref()
+                .map(cdp_stack)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&ExceptionThrownParams> for PageError {
+    fn from(params: &ExceptionThrownParams) -> Self {
+        let details = &params.exception_details;
+        let exception = JsException::from(details);
+        Self {
+            message: exception.message,
+            source: details
+                .url
+                .clone()
+                .map(|url| SourceLocation {
+                    url,
+                    line: exception.line,
+                    column: exception.column,
+                })
+                .or_else(|| cdp_source(details.stack_trace.as_ref())),
+            stack: exception.stack,
+        }
+    }
+}
+
+// ── Collector ────────────────────────────────────────────────────────────────
+
+/// Buffers a page's console messages and uncaught errors from the moment it is created,
+/// e.g. to assert a test left no console errors or to attach the page log to a failure
+/// report. Clones share the same buffer.
+///
+/// Its [`Display`] output is the page log, one `[level] text` line per message and a
+/// `[pageerror]` entry per uncaught error, in the order they arrived.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::browsers::{BidiBrowser, chrome};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut browser = chrome(None).await;
+/// let page = browser.active_page()?;
+/// let console = page.collect_console().await?;
+/// page.navigate("https://example.com").await?;
+/// assert!(!console.has_errors(), "page logged errors:\n{console}");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConsoleCollector {
+    entries: Arc<Mutex<Vec<Collected>>>,
+}
+
+#[derive(Debug, Clone)]
+enum Collected {
+    Message(ConsoleMessage),
+    Error(PageError),
+}
+
+impl ConsoleCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every console message so far.
+    pub fn messages(&self) -> Vec<ConsoleMessage> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|entry| match entry {
+                Collected::Message(message) => Some(message.clone()),
+                Collected::Error(_) => None,
+            })
+            .collect()
+    }
+
+    /// The console messages logged at [`ConsoleLevel::Error`].
+    pub fn console_errors(&self) -> Vec<ConsoleMessage> {
+        self.messages()
+            .into_iter()
+            .filter(|message| message.level == ConsoleLevel::Error)
+            .collect()
+    }
+
+    /// Every uncaught error so far.
+    pub fn page_errors(&self) -> Vec<PageError> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|entry| match entry {
+                Collected::Error(error) => Some(error.clone()),
+                Collected::Message(_) => None,
+            })
+            .collect()
+    }
+
+    /// Whether the page logged a console error or threw an uncaught error.
+    pub fn has_errors(&self) -> bool {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|entry| match entry {
+                Collected::Message(message) => message.level == ConsoleLevel::Error,
+                Collected::Error(_) => true,
+            })
+    }
+
+    /// Forgets everything collected so far.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Adds `message`, as the page's listener does when one is logged.
+    pub fn push_message(&self, message: ConsoleMessage) {
+        self.entries
+            .lock()
+            .unwrap()
+            .push(Collected::Message(message));
+    }
+
+    /// Adds `error`, as the page's listener does when one is thrown.
+    pub fn push_error(&self, error: PageError) {
+        self.entries.lock().unwrap().push(Collected::Error(error));
+    }
+
+    /// What the page's listener holds, which does not keep the collector alive.
+    pub(crate) fn sink(&self) -> CollectorSink {
+        CollectorSink(Arc::downgrade(&self.entries))
+    }
+}
+
+/// A [`ConsoleCollector`] as its listener holds it. Pushing returns `false` once every
+/// clone of the collector is dropped, so the listener knows to stop.
+#[derive(Debug, Clone)]
+pub(crate) struct CollectorSink(Weak<Mutex<Vec<Collected>>>);
+
+impl CollectorSink {
+    pub(crate) fn push_message(&self, message: ConsoleMessage) -> bool {
+        let Some(entries) = self.0.upgrade() else {
+            return false;
+        };
+        entries.lock().unwrap().push(Collected::Message(message));
+        true
+    }
+
+    pub(crate) fn push_error(&self, error: PageError) -> bool {
+        let Some(entries) = self.0.upgrade() else {
+            return false;
+        };
+        entries.lock().unwrap().push(Collected::Error(error));
+        true
+    }
+}
+
+impl Display for ConsoleCollector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in self.entries.lock().unwrap().iter() {
+            match entry {
+                Collected::Message(message) => writeln!(f, "[{}] {}", message.level, message.text)?,
+                Collected::Error(error) => writeln!(f, "[pageerror] {error}")?,
+            }
+        }
+        Ok(())
+    }
+}
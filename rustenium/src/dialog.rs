@@ -0,0 +1,294 @@
+//! JavaScript dialogs (`alert`, `confirm`, `prompt` and `beforeunload`) and native file
+//! choosers.
+//!
+//! BiDi reports dialogs through `browsingContext.userPromptOpened` and answers with
+//! `browsingContext.handleUserPrompt`; CDP through `Page.javascriptDialogOpening` and
+//! `Page.handleJavaScriptDialog`. Either way they arrive as a [`Dialog`] that is answered
+//! once, with [`Dialog::accept`] or [`Dialog::dismiss`].
+//!
+//! File choosers arrive through `input.fileDialogOpened` or `Page.fileChooserOpened` as a
+//! [`FileChooser`], answered with [`FileChooser::set_files`].
+
+use crate::error::dialog::DialogError;
+use crate::error::node::SetFilesError;
+use crate::nodes::files::resolve_files;
+use rustenium_bidi_definitions::browsing_context::types::UserPromptType;
+use rustenium_cdp_definitions::browser_protocol::page::types::DialogType;
+use std::fmt::{self, Display};
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Which JavaScript function opened a [`Dialog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DialogKind {
+    Alert,
+    Confirm,
+    Prompt,
+    BeforeUnload,
+}
+
+impl Display for DialogKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DialogKind::Alert => "alert",
+            DialogKind::Confirm => "confirm",
+            DialogKind::Prompt => "prompt",
+            DialogKind::BeforeUnload => "beforeunload",
+        })
+    }
+}
+
+impl From<&UserPromptType> for DialogKind {
+    fn from(kind: &UserPromptType) -> Self {
+        match kind {
+            UserPromptType::Alert => DialogKind::Alert,
+            UserPromptType::Confirm => DialogKind::Confirm,
+            UserPromptType::Prompt => DialogKind::Prompt,
+            UserPromptType::Beforeunload => DialogKind::BeforeUnload,
+        }
+    }
+}
+
+impl From<&DialogType> for DialogKind {
+    fn from(kind: &DialogType) -> Self {
+        match kind {
+            DialogType::Alert => DialogKind::Alert,
+            DialogType::Confirm => DialogKind::Confirm,
+            DialogType::Prompt => DialogKind::Prompt,
+            DialogType::Beforeunload => DialogKind::BeforeUnload,
+        }
+    }
+}
+
+/// Sends the answer to a dialog: whether to accept it, and the text to enter into a prompt.
+pub(crate) type DialogResponder = Arc<
+    dyn Fn(bool, Option<String>) -> Pin<Box<dyn Future<Output = Result<(), DialogError>> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// A JavaScript dialog open in a page. The page is blocked until it is answered.
+///
+/// Clones refer to the same dialog, which can only be answered once.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::browsers::{BidiBrowser, chrome};
+/// use rustenium::dialog::DialogKind;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut browser = chrome(None).await;
+/// let page = browser.active_page()?;
+/// page.on_dialog(|dialog| async move {
+///     let answer = match dialog.kind() {
+///         DialogKind::Prompt => dialog.accept(Some("rustenium")).await,
+///         _ => dialog.dismiss().await,
+///     };
+///     if let Err(e) = answer {
+///         eprintln!("could not answer {}: {e}", dialog.message());
+///     }
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Dialog {
+    kind: DialogKind,
+    message: String,
+    default_value: Option<String>,
+    handled: Arc<AtomicBool>,
+    respond: DialogResponder,
+}
+
+impl std::fmt::Debug for Dialog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Dialog")
+            .field("kind", &self.kind)
+            .field("message", &self.message)
+            .field("default_value", &self.default_value)
+            .field("handled", &self.is_handled())
+            .finish()
+    }
+}
+
+impl Dialog {
+    /// A dialog answered through `respond`. `handled` is for dialogs the browser has
+    /// already answered itself.
+    pub(crate) fn new(
+        kind: DialogKind,
+        message: String,
+        default_value: Option<String>,
+        handled: bool,
+        respond: DialogResponder,
+    ) -> Self {
+        Self {
+            kind,
+            message,
+            default_value,
+            handled: Arc::new(AtomicBool::new(handled)),
+            respond,
+        }
+    }
+
+    pub fn kind(&self) -> DialogKind {
+        self.kind
+    }
+
+    /// The text the dialog shows.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The text a prompt is prefilled with.
+    pub fn default_value(&self) -> Option<&str> {
+        self.default_value.as_deref()
+    }
+
+    /// Whether the dialog was already answered, by this crate or by the browser.
+    pub fn is_handled(&self) -> bool {
+        self.handled.load(Ordering::SeqCst)
+    }
+
+    /// Clicks OK, entering `text` into a prompt first. A prompt accepted without `text`
+    /// returns its default value.
+    pub async fn accept(&self, text: Option<&str>) -> Result<(), DialogError> {
+        self.answer(true, text.map(str::to_string)).await
+    }
+
+    /// Clicks Cancel, or closes an alert.
+    pub async fn dismiss(&self) -> Result<(), DialogError> {
+        self.answer(false, None).await
+    }
+
+    async fn answer(&self, accept: bool, text: Option<String>) -> Result<(), DialogError> {
+        if self.handled.swap(true, Ordering::SeqCst) {
+            return Err(DialogError::AlreadyHandled);
+        }
+        let answered = (self.respond)(accept, text).await;
+        if answered.is_err() {
+            self.handled.store(false, Ordering::SeqCst);
+        }
+        answered
+    }
+}
+
+/// A handler passed to `on_dialog`. It keeps being called until
+/// [`HandlerRegistration::unregister`] is awaited or the session ends; dropping the
+/// registration leaves the handler in place.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::browsers::{BidiBrowser, chrome};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut browser = chrome(None).await;
+/// let page = browser.active_page()?;
+/// let dismissing = page
+///     .on_dialog(|dialog| async move {
+///         let _ = dialog.dismiss().await;
+///     })
+///     .await?;
+/// // ...
+/// dismissing.unregister().await;
+/// # Ok(())
+/// # }
+/// ```
+pub struct HandlerRegistration {
+    unregister: Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send>,
+}
+
+impl std::fmt::Debug for HandlerRegistration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HandlerRegistration")
+            .finish_non_exhaustive()
+    }
+}
+
+impl HandlerRegistration {
+    pub(crate) fn new<F, Fut>(unregister: F) -> Self
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            unregister: Box::new(move || Box::pin(unregister())),
+        }
+    }
+
+    /// Stops calling the handler.
+    pub async fn unregister(self) {
+        (self.unregister)().await
+    }
+}
+
+/// Sets the files of the `<input type=file>` behind a file chooser to absolute paths.
+pub(crate) type FileChooserResponder = Arc<
+    dyn Fn(Vec<String>) -> Pin<Box<dyn Future<Output = Result<(), SetFilesError>> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// A native file chooser a page tried to open, intercepted before it was shown.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::browsers::{BidiBrowser, chrome};
+/// use rustenium::nodes::Node;
+/// use rustenium_macros::css;
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut browser = chrome(None).await;
+/// let page = browser.active_page()?;
+/// let mut button = page.find_node(css!("#upload")).await?.unwrap();
+/// let chooser = page
+///     .wait_for_file_chooser(
+///         async { button.mouse_click().await.unwrap() },
+///         Duration::from_secs(5),
+///     )
+///     .await?;
+/// chooser.set_files(&["report.pdf"]).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FileChooser {
+    multiple: bool,
+    respond: FileChooserResponder,
+}
+
+impl std::fmt::Debug for FileChooser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileChooser")
+            .field("multiple", &self.multiple)
+            .finish()
+    }
+}
+
+impl FileChooser {
+    pub(crate) fn new(multiple: bool, respond: FileChooserResponder) -> Self {
+        Self { multiple, respond }
+    }
+
+    /// Whether the chooser accepts more than one file.
+    pub fn is_multiple(&self) -> bool {
+        self.multiple
+    }
+
+    /// Picks `paths` in the chooser, as if the user had selected them. Relative paths are
+    /// resolved against the current directory; an empty slice clears the selection.
+    pub async fn set_files<P: AsRef<Path>>(&self, paths: &[P]) -> Result<(), SetFilesError> {
+        let files = resolve_files(paths)?;
+        if files.len() > 1 && !self.multiple {
+            return Err(SetFilesError::NotMultiple(files.len()));
+        }
+        (self.respond)(files).await
+    }
+}
@@ -0,0 +1,235 @@
+//! Discovery of browsers and drivers already installed on the system, as an
+//! alternative to the pinned downloads in [`crate::downloader`].
+//!
+//! Lookup order: env override (`RUSTENIUM_CHROME`, `RUSTENIUM_FIREFOX`,
+//! `RUSTENIUM_CHROMEDRIVER`), then executables on `PATH`, then standard install locations.
+
+use crate::error::discovery::DiscoveryError;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Env var pointing at a Chrome/Chromium/Edge executable.
+pub const CHROME_ENV: &str = "RUSTENIUM_CHROME";
+/// Env var pointing at a Firefox executable.
+pub const FIREFOX_ENV: &str = "RUSTENIUM_FIREFOX";
+/// Env var pointing at a chromedriver executable.
+pub const CHROMEDRIVER_ENV: &str = "RUSTENIUM_CHROMEDRIVER";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserKind {
+    Chrome,
+    Chromium,
+    Edge,
+    Firefox,
+}
+
+/// A browser executable found on the system.
+#[derive(Debug, Clone)]
+pub struct SystemBrowser {
+    pub kind: BrowserKind,
+    pub path: PathBuf,
+    /// Version reported by `--version`, e.g. `146.0.7680.153`.
+    pub version: Option<String>,
+}
+
+impl SystemBrowser {
+    /// Inspects the executable at `path`, reading its version and kind.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let output = version_output(&path);
+        let kind = output
+            .as_deref()
+            .map(browser_kind)
+            .unwrap_or_else(|| browser_kind(&path.to_string_lossy()));
+        SystemBrowser {
+            kind,
+            version: output.as_deref().and_then(parse_version),
+            path,
+        }
+    }
+
+    pub fn major_version(&self) -> Option<u32> {
+        self.version.as_deref().and_then(major_version)
+    }
+}
+
+/// Finds a Chrome-family browser (Chrome, Chromium or Edge).
+pub fn find_chrome() -> Option<SystemBrowser> {
+    find_executable(CHROME_ENV, chrome_names(), chrome_locations()).map(SystemBrowser::from_path)
+}
+
+/// Finds Firefox.
+pub fn find_firefox() -> Option<SystemBrowser> {
+    find_firefox_executable().map(SystemBrowser::from_path)
+}
+
+/// Path of the Firefox executable [`find_firefox`] would pick, without running `--version`.
+pub(crate) fn find_firefox_executable() -> Option<PathBuf> {
+    find_executable(FIREFOX_ENV, firefox_names(), firefox_locations())
+}
+
+/// Finds chromedriver.
+pub fn find_chromedriver() -> Option<PathBuf> {
+    find_executable(CHROMEDRIVER_ENV, &["chromedriver"], &[])
+}
+
+/// Returns the version printed by `chromedriver --version`.
+pub fn chromedriver_version(path: impl AsRef<Path>) -> Option<String> {
+    version_output(path.as_ref())
+        .as_deref()
+        .and_then(parse_version)
+}
+
+/// Checks that `browser` and the chromedriver at `chromedriver` share a major version.
+pub fn check_chromedriver_version(
+    browser: &SystemBrowser,
+    chromedriver: impl AsRef<Path>,
+) -> Result<(), DiscoveryError> {
+    let chromedriver = chromedriver.as_ref();
+    let browser_major = browser
+        .major_version()
+        .ok_or_else(|| DiscoveryError::UnknownVersion(browser.path.clone()))?;
+    let driver_major = chromedriver_version(chromedriver)
+        .as_deref()
+        .and_then(major_version)
+        .ok_or_else(|| DiscoveryError::UnknownVersion(chromedriver.to_path_buf()))?;
+    if browser_major != driver_major {
+        return Err(DiscoveryError::VersionMismatch {
+            browser: browser_major,
+            driver: driver_major,
+        });
+    }
+    Ok(())
+}
+
+/// Extracts the first dotted version number from `--version` output,
+/// e.g. `Google Chrome 146.0.7680.153` → `146.0.7680.153`.
+pub fn parse_version(output: &str) -> Option<String> {
+    output
+        .split_whitespace()
+        .find(|token| {
+            token.contains('.')
+                && token
+                    .split('.')
+                    .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(str::to_string)
+}
+
+fn major_version(version: &str) -> Option<u32> {
+    version.split('.').next()?.parse().ok()
+}
+
+fn browser_kind(text: &str) -> BrowserKind {
+    let text = text.to_lowercase();
+    if text.contains("firefox") {
+        BrowserKind::Firefox
+    } else if text.contains("edge") {
+        BrowserKind::Edge
+    } else if text.contains("chromium") {
+        BrowserKind::Chromium
+    } else {
+        BrowserKind::Chrome
+    }
+}
+
+fn version_output(path: &Path) -> Option<String> {
+    let output = Command::new(path).arg("--version").output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn find_executable(env: &str, names: &[&str], locations: &[&str]) -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(env).map(PathBuf::from) {
+        if path.is_file() {
+            return Some(path);
+        }
+        tracing::warn!("{env} is set to {path:?}, which does not exist");
+    }
+
+    if let Some(paths) = std::env::var_os("PATH") {
+        for dir in std::env::split_paths(&paths) {
+            for name in names {
+                let candidate = dir.join(exe_name(name));
+                if candidate.is_file() {
+                    return Some(candidate);
+                }
+            }
+        }
+    }
+
+    locations
+        .iter()
+        .map(PathBuf::from)
+        .find(|candidate| candidate.is_file())
+}
+
+fn exe_name(tool: &str) -> String {
+    if cfg!(windows) {
+        format!("{tool}.exe")
+    } else {
+        tool.to_string()
+    }
+}
+
+fn chrome_names() -> &'static [&'static str] {
+    &[
+        "google-chrome",
+        "google-chrome-stable",
+        "chromium",
+        "chromium-browser",
+        "microsoft-edge",
+        "microsoft-edge-stable",
+        "chrome",
+        "msedge",
+    ]
+}
+
+fn chrome_locations() -> &'static [&'static str] {
+    if cfg!(windows) {
+        &[
+            r"C:\Program Files\Google\Chrome\Application\chrome.exe",
+            r"C:\Program Files (x86)\Google\Chrome\Application\chrome.exe",
+            r"C:\Program Files (x86)\Microsoft\Edge\Application\msedge.exe",
+            r"C:\Program Files\Microsoft\Edge\Application\msedge.exe",
+        ]
+    } else if cfg!(target_os = "macos") {
+        &[
+            "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
+            "/Applications/Chromium.app/Contents/MacOS/Chromium",
+            "/Applications/Microsoft Edge.app/Contents/MacOS/Microsoft Edge",
+        ]
+    } else {
+        &[
+            "/opt/google/chrome/chrome",
+            "/usr/lib/chromium/chromium",
+            "/usr/lib/chromium-browser/chromium-browser",
+            "/snap/bin/chromium",
+            "/opt/microsoft/msedge/msedge",
+        ]
+    }
+}
+
+fn firefox_names() -> &'static [&'static str] {
+    &["firefox", "firefox-esr"]
+}
+
+fn firefox_locations() -> &'static [&'static str] {
+    if cfg!(windows) {
+        &[
+            r"C:\Program Files\Mozilla Firefox\firefox.exe",
+            r"C:\Program Files (x86)\Mozilla Firefox\firefox.exe",
+        ]
+    } else if cfg!(target_os = "macos") {
+        &["/Applications/Firefox.app/Contents/MacOS/firefox"]
+    } else {
+        &[
+            "/usr/lib/firefox/firefox",
+            "/usr/lib/firefox-esr/firefox-esr",
+            "/opt/firefox/firefox",
+            "/snap/bin/firefox",
+        ]
+    }
+}
@@ -0,0 +1,283 @@
+//! Files the browser downloads.
+//!
+//! Whether downloads are saved at all is set browser-wide with a [`DownloadBehavior`]:
+//! BiDi `browser.setDownloadBehavior` or CDP `Browser.setDownloadBehavior`. BiDi then reports
+//! downloads through `browsingContext.downloadWillBegin` and `browsingContext.downloadEnd`;
+//! CDP through `Browser.downloadWillBegin` and `Browser.downloadProgress`. Either way they
+//! arrive as [`DownloadEvent`]s, or as a finished [`Download`] from `wait_for_download`.
+
+use crate::error::download::FileDownloadError;
+use rustenium_bidi_definitions::browser::types::{
+    DownloadBehavior as BidiDownloadBehavior, DownloadBehaviorAllowed, DownloadBehaviorAllowedType,
+    DownloadBehaviorDenied, DownloadBehaviorDeniedType,
+};
+use rustenium_bidi_definitions::browsing_context::events::{
+    DownloadEndParams, DownloadWillBeginParams,
+};
+use rustenium_bidi_definitions::browsing_context::types::{
+    BaseNavigationInfo, DownloadCanceledParamsDownloadCompleteParamsUnion,
+};
+use rustenium_cdp_definitions::browser_protocol::browser::events::{
+    DownloadProgressParams, DownloadProgressState,
+    DownloadWillBeginParams as CdpDownloadWillBeginParams,
+};
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+/// What the browser does with the files pages download.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadBehavior {
+    /// Saves downloads into `dir`, which is created if it does not exist.
+    Allow { dir: PathBuf },
+    /// Cancels every download.
+    Deny,
+}
+
+impl DownloadBehavior {
+    /// `dir` made absolute and created, for [`DownloadBehavior::Allow`].
+    pub(crate) fn prepare_dir(&self) -> Result<Option<PathBuf>, FileDownloadError> {
+        match self {
+            DownloadBehavior::Allow { dir } => {
+                let dir = std::path::absolute(dir)?;
+                std::fs::create_dir_all(&dir)?;
+                Ok(Some(dir))
+            }
+            DownloadBehavior::Deny => Ok(None),
+        }
+    }
+
+    /// The BiDi behavior, saving into `dir` when allowed.
+    pub(crate) fn to_bidi(&self, dir: Option<&Path>) -> BidiDownloadBehavior {
+        match dir {
+            Some(dir) => DownloadBehaviorAllowed::new(
+                DownloadBehaviorAllowedType::Allowed,
+                dir.to_string_lossy(),
+            )
+            .into(),
+            None => DownloadBehaviorDenied {
+                r#type: DownloadBehaviorDeniedType::Denied,
+            }
+            .into(),
+        }
+    }
+}
+
+/// How far a download got.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DownloadState {
+    InProgress,
+    Completed,
+    Canceled,
+}
+
+impl Display for DownloadState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DownloadState::InProgress => "in progress",
+            DownloadState::Completed => "completed",
+            DownloadState::Canceled => "canceled",
+        })
+    }
+}
+
+impl From<&DownloadProgressState> for DownloadState {
+    fn from(state: &DownloadProgressState) -> Self {
+        match state {
+            DownloadProgressState::InProgress => DownloadState::InProgress,
+            DownloadProgressState::Completed => DownloadState::Completed,
+            DownloadProgressState::Canceled => DownloadState::Canceled,
+        }
+    }
+}
+
+/// A download starting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadWillBegin {
+    /// Identifies the download in later events: the BiDi navigation id or the CDP guid.
+    pub id: String,
+    pub url: String,
+    /// The file name the server or the page suggested. The file saved may be named otherwise.
+    pub suggested_filename: String,
+}
+
+/// How much of a download has arrived. Only CDP reports progress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadProgress {
+    pub id: String,
+    pub received_bytes: u64,
+    /// Zero when the size is unknown.
+    pub total_bytes: u64,
+    pub state: DownloadState,
+}
+
+/// A download finishing or being canceled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadEnd {
+    pub id: String,
+    /// [`DownloadState::Completed`] or [`DownloadState::Canceled`].
+    pub state: DownloadState,
+    /// Where the file was saved, when the browser reports it.
+    pub path: Option<PathBuf>,
+}
+
+/// Something that happened to a download.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadEvent {
+    WillBegin(DownloadWillBegin),
+    Progress(DownloadProgress),
+    End(DownloadEnd),
+}
+
+/// The navigation id a BiDi download event is reported under.
+fn navigation_id(info: &BaseNavigationInfo) -> String {
+    info.navigation
+        .as_ref()
+        .map(|navigation| navigation.inner().clone())
+        .unwrap_or_default()
+}
+
+impl From<&DownloadWillBeginParams> for DownloadWillBegin {
+    fn from(params: &DownloadWillBeginParams) -> Self {
+        Self {
+            id: navigation_id(&params.base_navigation_info),
+            url: params.base_navigation_info.url.clone(),
+            suggested_filename: params.suggested_filename.clone(),
+        }
+    }
+}
+
+impl From<&DownloadEndParams> for DownloadEnd {
+    fn from(params: &DownloadEndParams) -> Self {
+        match &params.download_canceled_params_download_complete_params_union {
+            DownloadCanceledParamsDownloadCompleteParamsUnion::DownloadCanceledParams(canceled) => {
+                Self {
+                    id: navigation_id(&canceled.base_navigation_info),
+                    state: DownloadState::Canceled,
+                    path: None,
+                }
+            }
+            DownloadCanceledParamsDownloadCompleteParamsUnion::DownloadCompleteParams(complete) => {
+                Self {
+                    id: navigation_id(&complete.base_navigation_info),
+                    state: DownloadState::Completed,
+                    path: complete.filepath.as_ref().map(PathBuf::from),
+                }
+            }
+        }
+    }
+}
+
+impl From<&CdpDownloadWillBeginParams> for DownloadWillBegin {
+    fn from(params: &CdpDownloadWillBeginParams) -> Self {
+        Self {
+            id: params.guid.clone(),
+            url: params.url.clone(),
+            suggested_filename: params.suggested_filename.clone(),
+        }
+    }
+}
+
+impl From<&DownloadProgressParams> for DownloadProgress {
+    fn from(params: &DownloadProgressParams) -> Self {
+        Self {
+            id: params.guid.clone(),
+            received_bytes: params.received_bytes as u64,
+            total_bytes: params.total_bytes as u64,
+            state: DownloadState::from(&params.state),
+        }
+    }
+}
+
+impl DownloadProgress {
+    /// The end of the download this progress finishes, if it does. CDP reports the end as a
+    /// last progress event.
+    pub(crate) fn end(&self, path: Option<&str>) -> Option<DownloadEnd> {
+        (self.state != DownloadState::InProgress).then(|| DownloadEnd {
+            id: self.id.clone(),
+            state: self.state,
+            path: path.map(PathBuf::from),
+        })
+    }
+}
+
+/// A download that has finished, returned by `wait_for_download`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::browsers::{BidiBrowser, chrome};
+/// use rustenium::download::DownloadBehavior;
+/// use rustenium::nodes::Node;
+/// use rustenium_macros::css;
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut browser = chrome(None).await;
+/// browser
+///     .set_download_behavior(DownloadBehavior::Allow { dir: "downloads".into() })
+///     .await?;
+/// let page = browser.active_page()?;
+/// let mut link = page.find_node(css!("a[download]")).await?.unwrap();
+/// let download = page
+///     .wait_for_download(
+///         async { link.mouse_click().await.unwrap() },
+///         Duration::from_secs(30),
+///     )
+///     .await?;
+/// download.save_as("report.csv").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Download {
+    pub url: String,
+    pub suggested_filename: String,
+    /// Where the browser saved the file. `None` when the download was canceled.
+    pub path: Option<PathBuf>,
+    pub state: DownloadState,
+}
+
+impl Download {
+    /// Copies the downloaded file to `path`.
+    pub async fn save_as(&self, path: impl AsRef<Path>) -> Result<(), FileDownloadError> {
+        if self.state != DownloadState::Completed {
+            return Err(FileDownloadError::NotCompleted(self.state));
+        }
+        let source = self.path.as_ref().ok_or(FileDownloadError::NoPath)?;
+        tokio::fs::copy(source, path).await?;
+        Ok(())
+    }
+}
+
+/// Waits on `events` for the first download to begin and then end. Downloads saved without
+/// a reported path are looked for in `dir` under their suggested name.
+pub(crate) async fn next_download(
+    events: &mut UnboundedReceiver<DownloadEvent>,
+    dir: Option<PathBuf>,
+) -> Option<Download> {
+    let begin = loop {
+        if let DownloadEvent::WillBegin(begin) = events.recv().await? {
+            break begin;
+        }
+    };
+    let end = loop {
+        if let DownloadEvent::End(end) = events.recv().await?
+            && end.id == begin.id
+        {
+            break end;
+        }
+    };
+    let path = match end.state {
+        DownloadState::Completed => end
+            .path
+            .or_else(|| dir.map(|dir| dir.join(&begin.suggested_filename))),
+        _ => None,
+    };
+    Some(Download {
+        url: begin.url,
+        suggested_filename: begin.suggested_filename,
+        path,
+        state: end.state,
+    })
+}
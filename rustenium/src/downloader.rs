@@ -1,73 +1,59 @@
+use crate::error::downloader::DownloadError;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Arc;
+use std::time::Duration;
 
 // ── Chrome ───────────────────────────────────────────────────────────────────
 
 const CHROME_VERSION: &str = "146.0.7680.153";
 const CHROME_BASE_URL: &str = "https://storage.googleapis.com/chrome-for-testing-public";
+const CHROME_VERSIONS_URL: &str = "https://googlechromelabs.github.io/chrome-for-testing";
 
-fn chrome_platform() -> &'static str {
-    match (std::env::consts::OS, std::env::consts::ARCH) {
+fn chrome_platform() -> Result<&'static str, DownloadError> {
+    Ok(match (std::env::consts::OS, std::env::consts::ARCH) {
         ("linux", _) => "linux64",
         ("macos", "aarch64") => "mac-arm64",
         ("macos", _) => "mac-x64",
         ("windows", "x86") => "win32",
         ("windows", _) => "win64",
-        (os, arch) => panic!("Unsupported platform: {os}/{arch}"),
-    }
+        (os, arch) => return Err(DownloadError::UnsupportedPlatform(format!("{os}/{arch}"))),
+    })
 }
 
 /// Downloads chromedriver if not already cached. Returns the path to the executable.
+///
+/// # Panics
+///
+/// Panics if the download fails. Use [`Downloader::chromedriver`] to handle the error.
 pub fn ensure_chromedriver() -> PathBuf {
-    let dir = cache_dir("chromedriver", CHROME_VERSION, chrome_platform());
-    let name = exe_name("chromedriver");
-    if let Some(path) = find_exe(&dir, &name) {
-        make_executable(&path);
-        return path;
-    }
-
-    let url = format!(
-        "{CHROME_BASE_URL}/{CHROME_VERSION}/{0}/chromedriver-{0}.zip",
-        chrome_platform()
-    );
-    tracing::info!(
-        "Downloading chromedriver {CHROME_VERSION} for {} ...",
-        chrome_platform()
-    );
-    download_and_extract(&url, &dir)
-        .unwrap_or_else(|e| panic!("Failed to download chromedriver: {e}"));
-
-    let path = find_exe(&dir, &name)
-        .unwrap_or_else(|| panic!("{name} not found after extraction in {dir:?}"));
-    make_executable(&path);
-    tracing::info!("chromedriver ready at {path:?}");
-    path
+    Downloader::new()
+        .chromedriver()
+        .unwrap_or_else(|e| panic!("Failed to download chromedriver: {e}"))
 }
 
 /// Downloads Chrome browser if not already cached. Returns the path to the executable.
+///
+/// # Panics
+///
+/// Panics if the download fails. Use [`Downloader::chrome`] to handle the error.
 pub fn ensure_chrome() -> PathBuf {
-    let dir = cache_dir("chrome", CHROME_VERSION, chrome_platform());
-    let name = exe_name("chrome");
-    if let Some(path) = find_exe(&dir, &name) {
-        make_executable(&path);
-        return path;
-    }
+    Downloader::new()
+        .chrome()
+        .unwrap_or_else(|e| panic!("Failed to download Chrome: {e}"))
+}
 
-    let url = format!(
-        "{CHROME_BASE_URL}/{CHROME_VERSION}/{0}/chrome-{0}.zip",
-        chrome_platform()
-    );
-    tracing::info!(
-        "Downloading Chrome {CHROME_VERSION} for {} ...",
-        chrome_platform()
-    );
-    download_and_extract(&url, &dir).unwrap_or_else(|e| panic!("Failed to download Chrome: {e}"));
-
-    let path = find_exe(&dir, &name)
-        .unwrap_or_else(|| panic!("{name} not found after extraction in {dir:?}"));
-    make_executable(&path);
-    tracing::info!("Chrome ready at {path:?}");
-    path
+/// Downloads chrome-headless-shell if not already cached. Returns the path to the executable.
+///
+/// # Panics
+///
+/// Panics if the download fails. Use [`Downloader::chrome_headless_shell`] to handle the error.
+pub fn ensure_chrome_headless_shell() -> PathBuf {
+    Downloader::new()
+        .chrome_headless_shell()
+        .unwrap_or_else(|e| panic!("Failed to download chrome-headless-shell: {e}"))
 }
 
 // ── Firefox ──────────────────────────────────────────────────────────────────
@@ -76,144 +62,567 @@ const FIREFOX_VERSION: &str = "149.0";
 const FIREFOX_BASE_URL: &str = "https://ftp.mozilla.org/pub/firefox/releases";
 
 /// Downloads Firefox if not already cached. Returns the path to the firefox executable.
+///
+/// # Panics
+///
+/// Panics if the download fails. Use [`Downloader::firefox`] to handle the error.
 pub fn ensure_firefox() -> PathBuf {
-    let plat = firefox_platform();
-    let dir = cache_dir("firefox", FIREFOX_VERSION, plat);
-    let name = exe_name("firefox");
-    if let Some(path) = find_exe(&dir, &name) {
-        make_executable(&path);
-        return path;
-    }
-
-    tracing::info!("Downloading Firefox {FIREFOX_VERSION} for {plat} ...");
-    download_firefox(&dir).unwrap_or_else(|e| panic!("Failed to download Firefox: {e}"));
-
-    let path = find_exe(&dir, &name)
-        .unwrap_or_else(|| panic!("{name} not found after extraction in {dir:?}"));
-    make_executable(&path);
-    tracing::info!("Firefox ready at {path:?}");
-    path
+    Downloader::new()
+        .firefox()
+        .unwrap_or_else(|e| panic!("Failed to download Firefox: {e}"))
 }
 
-fn firefox_platform() -> &'static str {
-    match (std::env::consts::OS, std::env::consts::ARCH) {
+fn firefox_platform() -> Result<&'static str, DownloadError> {
+    Ok(match (std::env::consts::OS, std::env::consts::ARCH) {
         ("linux", "aarch64") => "linux-aarch64",
         ("linux", _) => "linux-x86_64",
         ("macos", _) => "mac",
         ("windows", "x86") => "win32",
         ("windows", _) => "win64",
-        (os, arch) => panic!("Unsupported platform for Firefox: {os}/{arch}"),
+        (os, arch) => return Err(DownloadError::UnsupportedPlatform(format!("{os}/{arch}"))),
+    })
+}
+
+/// Attempts to find the system-installed Firefox executable.
+///
+/// Uses the same lookup as [`crate::discovery::find_firefox`].
+pub fn find_system_firefox() -> Option<PathBuf> {
+    crate::discovery::find_firefox_executable()
+}
+
+// ── geckodriver ──────────────────────────────────────────────────────────────
+
+const GECKODRIVER_VERSION: &str = "0.36.0";
+const GECKODRIVER_BASE_URL: &str = "https://github.com/mozilla/geckodriver/releases/download";
+
+fn geckodriver_platform() -> Result<&'static str, DownloadError> {
+    Ok(match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("linux", "aarch64") => "linux-aarch64",
+        ("linux", _) => "linux64",
+        ("macos", "aarch64") => "macos-aarch64",
+        ("macos", _) => "macos",
+        ("windows", "aarch64") => "win-aarch64",
+        ("windows", "x86") => "win32",
+        ("windows", _) => "win64",
+        (os, arch) => return Err(DownloadError::UnsupportedPlatform(format!("{os}/{arch}"))),
+    })
+}
+
+/// Downloads geckodriver if not already cached. Returns the path to the executable.
+///
+/// # Panics
+///
+/// Panics if the download fails. Use [`Downloader::geckodriver`] to handle the error.
+pub fn ensure_geckodriver() -> PathBuf {
+    Downloader::new()
+        .geckodriver()
+        .unwrap_or_else(|e| panic!("Failed to download geckodriver: {e}"))
+}
+
+// ── Downloader ───────────────────────────────────────────────────────────────
+
+/// Chrome for Testing release channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChromeChannel {
+    Stable,
+    Beta,
+    Dev,
+    Canary,
+}
+
+impl ChromeChannel {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ChromeChannel::Stable => "Stable",
+            ChromeChannel::Beta => "Beta",
+            ChromeChannel::Dev => "Dev",
+            ChromeChannel::Canary => "Canary",
+        }
     }
 }
 
-fn download_firefox(dest: &Path) -> Result<(), String> {
-    std::fs::create_dir_all(dest).map_err(|e| e.to_string())?;
+/// An artifact the [`Downloader`] can fetch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Artifact {
+    Chrome,
+    Chromedriver,
+    HeadlessShell,
+    Firefox,
+    Geckodriver,
+}
+
+/// Progress of an archive download, passed to [`Downloader::progress`] callbacks.
+#[derive(Debug, Clone)]
+pub struct DownloadProgress {
+    pub artifact: Artifact,
+    pub url: String,
+    pub downloaded: u64,
+    /// Total size, when the server reports a `Content-Length`.
+    pub total: Option<u64>,
+}
+
+type ProgressCallback = Arc<dyn Fn(DownloadProgress) + Send + Sync>;
+
+/// Configurable downloader for browsers and drivers.
+///
+/// Defaults to the pinned versions used by [`ensure_chrome`] and friends. Archives are
+/// cached per tool, version and platform, so repeated calls are cheap.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::downloader::{Artifact, ChromeChannel, Downloader};
+///
+/// # fn example() -> Result<(), rustenium::error::downloader::DownloadError> {
+/// let chrome = Downloader::new()
+///     .channel(ChromeChannel::Beta)
+///     .cache_dir("/var/cache/rustenium")
+///     .progress(|p| println!("{}/{:?} bytes", p.downloaded, p.total))
+///     .chrome()?;
+///
+/// let chromedriver = Downloader::new()
+///     .version("146.0.7680.153")
+///     .base_url("https://mirror.example.com/chrome-for-testing-public")
+///     .sha256(Artifact::Chromedriver, "3f1c…")
+///     .chromedriver()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct Downloader {
+    version: Option<String>,
+    channel: Option<ChromeChannel>,
+    base_url: Option<String>,
+    versions_url: Option<String>,
+    firefox_version: Option<String>,
+    firefox_base_url: Option<String>,
+    geckodriver_version: Option<String>,
+    geckodriver_base_url: Option<String>,
+    cache_dir: Option<PathBuf>,
+    checksums: HashMap<Artifact, String>,
+    progress: Option<ProgressCallback>,
+}
+
+impl std::fmt::Debug for Downloader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Downloader")
+            .field("version", &self.version)
+            .field("channel", &self.channel)
+            .field("base_url", &self.base_url)
+            .field("versions_url", &self.versions_url)
+            .field("firefox_version", &self.firefox_version)
+            .field("firefox_base_url", &self.firefox_base_url)
+            .field("geckodriver_version", &self.geckodriver_version)
+            .field("geckodriver_base_url", &self.geckodriver_base_url)
+            .field("cache_dir", &self.cache_dir)
+            .field("checksums", &self.checksums)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Downloader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exact Chrome for Testing version, e.g. `146.0.7680.153`. Takes precedence over `channel`.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Resolve the Chrome version from the latest known-good release of `channel`.
+    pub fn channel(mut self, channel: ChromeChannel) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Mirror of `https://storage.googleapis.com/chrome-for-testing-public`.
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    /// Mirror of `https://googlechromelabs.github.io/chrome-for-testing`, used to resolve channels.
+    pub fn versions_url(mut self, url: impl Into<String>) -> Self {
+        self.versions_url = Some(url.into());
+        self
+    }
+
+    pub fn firefox_version(mut self, version: impl Into<String>) -> Self {
+        self.firefox_version = Some(version.into());
+        self
+    }
+
+    /// Mirror of `https://ftp.mozilla.org/pub/firefox/releases`.
+    pub fn firefox_base_url(mut self, url: impl Into<String>) -> Self {
+        self.firefox_base_url = Some(url.into());
+        self
+    }
+
+    pub fn geckodriver_version(mut self, version: impl Into<String>) -> Self {
+        self.geckodriver_version = Some(version.into());
+        self
+    }
+
+    /// Mirror of `https://github.com/mozilla/geckodriver/releases/download`.
+    pub fn geckodriver_base_url(mut self, url: impl Into<String>) -> Self {
+        self.geckodriver_base_url = Some(url.into());
+        self
+    }
+
+    /// Root directory for cached downloads (default: the system temp dir).
+    pub fn cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Expected SHA-256 (hex) of the archive downloaded for `artifact`.
+    pub fn sha256(mut self, artifact: Artifact, hex: impl Into<String>) -> Self {
+        self.checksums.insert(artifact, hex.into().to_lowercase());
+        self
+    }
+
+    /// Called periodically while an archive downloads.
+    pub fn progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(DownloadProgress) + Send + Sync + 'static,
+    {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
 
-    match std::env::consts::OS {
-        "linux" => {
-            let plat = firefox_platform();
-            let url = format!(
-                "{FIREFOX_BASE_URL}/{FIREFOX_VERSION}/{plat}/en-US/firefox-{FIREFOX_VERSION}.tar.xz"
-            );
-            download_and_extract(&url, dest)
+    /// Resolves the Chrome for Testing version: explicit version, then channel, then pinned.
+    pub fn resolve_chrome_version(&self) -> Result<String, DownloadError> {
+        if let Some(ref version) = self.version {
+            return Ok(version.clone());
+        }
+        let Some(channel) = self.channel else {
+            return Ok(CHROME_VERSION.to_string());
+        };
+
+        let url = format!(
+            "{}/last-known-good-versions.json",
+            self.versions_url
+                .as_deref()
+                .unwrap_or(CHROME_VERSIONS_URL)
+                .trim_end_matches('/')
+        );
+        let output = Command::new("curl")
+            .args(["-fsSL", &url])
+            .output()
+            .map_err(|e| DownloadError::Command(format!("curl failed: {e}")))?;
+        if !output.status.success() {
+            return Err(DownloadError::VersionResolution(format!(
+                "fetching {url}: curl exited with {}",
+                output.status
+            )));
         }
-        "windows" => {
-            let plat = firefox_platform();
-            let url = format!(
-                "{FIREFOX_BASE_URL}/{FIREFOX_VERSION}/{plat}/en-US/Firefox%20Setup%20{FIREFOX_VERSION}.exe"
-            );
-            let exe = dest.join("firefox-setup.exe");
-            let exe_str = exe.to_str().unwrap().replace('/', "\\");
-            run("curl", &["-sL", "-o", &exe_str, &url])?;
-
-            // Firefox installer supports /S (silent) and /D= (install directory)
-            // /D= must be the last arg with no quotes and use backslashes
-            let install_dir = dest.to_str().unwrap().replace('/', "\\");
-            run(&exe_str, &["/S", &format!("/D={install_dir}")])?;
-
-            let _ = std::fs::remove_file(&exe);
-            Ok(())
+        let versions: serde_json::Value = serde_json::from_slice(&output.stdout)
+            .map_err(|e| DownloadError::VersionResolution(format!("parse {url}: {e}")))?;
+        versions["channels"][channel.as_str()]["version"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| {
+                DownloadError::VersionResolution(format!(
+                    "channel {} not found in {url}",
+                    channel.as_str()
+                ))
+            })
+    }
+
+    /// Downloads chromedriver if not already cached. Returns the path to the executable.
+    pub fn chromedriver(&self) -> Result<PathBuf, DownloadError> {
+        self.chrome_for_testing(Artifact::Chromedriver, "chromedriver", "chromedriver")
+    }
+
+    /// Downloads Chrome if not already cached. Returns the path to the executable.
+    pub fn chrome(&self) -> Result<PathBuf, DownloadError> {
+        self.chrome_for_testing(Artifact::Chrome, "chrome", "chrome")
+    }
+
+    /// Downloads chrome-headless-shell if not already cached. Returns the path to the executable.
+    pub fn chrome_headless_shell(&self) -> Result<PathBuf, DownloadError> {
+        self.chrome_for_testing(
+            Artifact::HeadlessShell,
+            "chrome-headless-shell",
+            "chrome-headless-shell",
+        )
+    }
+
+    /// Downloads geckodriver if not already cached. Returns the path to the executable.
+    pub fn geckodriver(&self) -> Result<PathBuf, DownloadError> {
+        let plat = geckodriver_platform()?;
+        let version = self
+            .geckodriver_version
+            .as_deref()
+            .unwrap_or(GECKODRIVER_VERSION);
+        let dir = self.tool_dir("geckodriver", version, plat);
+        let name = exe_name("geckodriver");
+        if let Some(path) = find_exe(&dir, &name) {
+            make_executable(&path);
+            return Ok(path);
         }
-        "macos" => {
-            let url = format!(
-                "{FIREFOX_BASE_URL}/{FIREFOX_VERSION}/mac/en-US/Firefox%20{FIREFOX_VERSION}.dmg"
-            );
-            let dmg = dest.join("firefox.dmg");
-            let dmg_str = dmg.to_str().unwrap();
-            run("curl", &["-sL", "-o", dmg_str, &url])?;
-
-            let mount_point = dest.join("_dmg_mount");
-            let mount_str = mount_point.to_str().unwrap();
-            std::fs::create_dir_all(&mount_point).map_err(|e| e.to_string())?;
-
-            run(
-                "hdiutil",
-                &[
-                    "attach",
-                    "-nobrowse",
-                    "-readonly",
-                    "-mountpoint",
-                    mount_str,
-                    dmg_str,
-                ],
-            )?;
-
-            let copy_result = run(
-                "cp",
-                &[
-                    "-R",
-                    &format!("{mount_str}/Firefox.app"),
-                    dest.to_str().unwrap(),
-                ],
-            );
-
-            // Always attempt to detach, even if copy failed
-            let _ = run("hdiutil", &["detach", mount_str]);
-            let _ = std::fs::remove_file(&dmg);
-            let _ = std::fs::remove_dir_all(&mount_point);
-
-            copy_result?;
-            Ok(())
+
+        let base = self
+            .geckodriver_base_url
+            .as_deref()
+            .unwrap_or(GECKODRIVER_BASE_URL)
+            .trim_end_matches('/');
+        let ext = if cfg!(windows) { "zip" } else { "tar.gz" };
+        let url = format!("{base}/v{version}/geckodriver-v{version}-{plat}.{ext}");
+        tracing::info!("Downloading geckodriver {version} for {plat} ...");
+        self.download_and_extract(Artifact::Geckodriver, &url, &dir)?;
+
+        let path = find_exe(&dir, &name).ok_or(DownloadError::NotFound { name, dir })?;
+        make_executable(&path);
+        tracing::info!("geckodriver ready at {path:?}");
+        Ok(path)
+    }
+
+    /// Downloads Firefox if not already cached. Returns the path to the firefox executable.
+    pub fn firefox(&self) -> Result<PathBuf, DownloadError> {
+        let plat = firefox_platform()?;
+        let version = self.firefox_version.as_deref().unwrap_or(FIREFOX_VERSION);
+        let dir = self.tool_dir("firefox", version, plat);
+        let name = exe_name("firefox");
+        if let Some(path) = find_exe(&dir, &name) {
+            make_executable(&path);
+            return Ok(path);
         }
-        other => Err(format!("Unsupported OS for Firefox download: {other}")),
+
+        tracing::info!("Downloading Firefox {version} for {plat} ...");
+        self.download_firefox(version, plat, &dir)?;
+
+        let path = find_exe(&dir, &name).ok_or(DownloadError::NotFound { name, dir })?;
+        make_executable(&path);
+        tracing::info!("Firefox ready at {path:?}");
+        Ok(path)
     }
-}
 
-/// Attempts to find the system-installed Firefox executable.
-pub fn find_system_firefox() -> Option<PathBuf> {
-    if cfg!(windows) {
-        let candidates = [
-            r"C:\Program Files\Mozilla Firefox\firefox.exe",
-            r"C:\Program Files (x86)\Mozilla Firefox\firefox.exe",
-        ];
-        for path in &candidates {
-            let p = PathBuf::from(path);
-            if p.exists() {
-                return Some(p);
+    fn chrome_for_testing(
+        &self,
+        artifact: Artifact,
+        tool: &str,
+        exe: &str,
+    ) -> Result<PathBuf, DownloadError> {
+        let plat = chrome_platform()?;
+        let version = self.resolve_chrome_version()?;
+        let dir = self.tool_dir(tool, &version, plat);
+        let name = exe_name(exe);
+        if let Some(path) = find_exe(&dir, &name) {
+            make_executable(&path);
+            return Ok(path);
+        }
+
+        let base = self
+            .base_url
+            .as_deref()
+            .unwrap_or(CHROME_BASE_URL)
+            .trim_end_matches('/');
+        let url = format!("{base}/{version}/{plat}/{tool}-{plat}.zip");
+        tracing::info!("Downloading {tool} {version} for {plat} ...");
+        self.download_and_extract(artifact, &url, &dir)?;
+
+        let path = find_exe(&dir, &name).ok_or(DownloadError::NotFound { name, dir })?;
+        make_executable(&path);
+        tracing::info!("{tool} ready at {path:?}");
+        Ok(path)
+    }
+
+    fn tool_dir(&self, tool: &str, version: &str, platform: &str) -> PathBuf {
+        let root = self.cache_dir.clone().unwrap_or_else(std::env::temp_dir);
+        root.join(format!("rustenium-{tool}-{version}-{platform}"))
+    }
+
+    fn download_firefox(
+        &self,
+        version: &str,
+        plat: &str,
+        dest: &Path,
+    ) -> Result<(), DownloadError> {
+        std::fs::create_dir_all(dest)?;
+        let base = self
+            .firefox_base_url
+            .as_deref()
+            .unwrap_or(FIREFOX_BASE_URL)
+            .trim_end_matches('/');
+
+        match std::env::consts::OS {
+            "linux" => {
+                let url = format!("{base}/{version}/{plat}/en-US/firefox-{version}.tar.xz");
+                self.download_and_extract(Artifact::Firefox, &url, dest)
+            }
+            "windows" => {
+                let url = format!("{base}/{version}/{plat}/en-US/Firefox%20Setup%20{version}.exe");
+                let exe = dest.join("firefox-setup.exe");
+                self.download(Artifact::Firefox, &url, &exe)?;
+                let exe_str = exe.to_str().unwrap().replace('/', "\\");
+
+                // Firefox installer supports /S (silent) and /D= (install directory)
+                // /D= must be the last arg with no quotes and use backslashes
+                let install_dir = dest.to_str().unwrap().replace('/', "\\");
+                run(&exe_str, &["/S", &format!("/D={install_dir}")])?;
+
+                let _ = std::fs::remove_file(&exe);
+                Ok(())
             }
+            "macos" => {
+                let url = format!("{base}/{version}/mac/en-US/Firefox%20{version}.dmg");
+                let dmg = dest.join("firefox.dmg");
+                self.download(Artifact::Firefox, &url, &dmg)?;
+                let dmg_str = dmg.to_str().unwrap();
+
+                let mount_point = dest.join("_dmg_mount");
+                let mount_str = mount_point.to_str().unwrap();
+                std::fs::create_dir_all(&mount_point)?;
+
+                run(
+                    "hdiutil",
+                    &[
+                        "attach",
+                        "-nobrowse",
+                        "-readonly",
+                        "-mountpoint",
+                        mount_str,
+                        dmg_str,
+                    ],
+                )?;
+
+                let copy_result = run(
+                    "cp",
+                    &[
+                        "-R",
+                        &format!("{mount_str}/Firefox.app"),
+                        dest.to_str().unwrap(),
+                    ],
+                );
+
+                // Always attempt to detach, even if copy failed
+                let _ = run("hdiutil", &["detach", mount_str]);
+                let _ = std::fs::remove_file(&dmg);
+                let _ = std::fs::remove_dir_all(&mount_point);
+
+                copy_result
+            }
+            other => Err(DownloadError::UnsupportedPlatform(other.to_string())),
         }
-    } else if cfg!(target_os = "macos") {
-        let p = PathBuf::from("/Applications/Firefox.app/Contents/MacOS/firefox");
-        if p.exists() {
-            return Some(p);
+    }
+
+    // TODO: If running on an OS with the missing dependency, you might get unzip failed: No such file or directory (os error 2)
+    // Where in actuality, the actual error is: bash: unzip: command not found
+    fn download_and_extract(
+        &self,
+        artifact: Artifact,
+        url: &str,
+        dest: &Path,
+    ) -> Result<(), DownloadError> {
+        std::fs::create_dir_all(dest)?;
+        let archive = dest.join("download_archive");
+        let archive_str = archive.to_str().unwrap();
+        let dest_str = dest.to_str().unwrap();
+
+        self.download(artifact, url, &archive)?;
+
+        let extracted = if url.ends_with(".tar.xz") {
+            run("tar", &["-xJf", archive_str, "-C", dest_str])
+        } else if url.ends_with(".tar.gz") {
+            run("tar", &["-xzf", archive_str, "-C", dest_str])
+        } else if url.ends_with(".zip") {
+            if cfg!(unix) {
+                run("unzip", &["-oq", archive_str, "-d", dest_str])
+            } else {
+                run("tar", &["-xf", archive_str, "-C", dest_str])
+            }
+        } else {
+            Err(DownloadError::Command(format!(
+                "Unknown archive format: {url}"
+            )))
+        };
+
+        let _ = std::fs::remove_file(&archive);
+        extracted
+    }
+
+    /// Downloads `url` to `dest` with curl, reporting progress and verifying the checksum.
+    fn download(&self, artifact: Artifact, url: &str, dest: &Path) -> Result<(), DownloadError> {
+        let dest_str = dest.to_str().unwrap();
+        tracing::debug!("Running: curl -fsSL -o {dest_str} {url}");
+        let mut child = Command::new("curl")
+            .args(["-fsSL", "-o", dest_str, url])
+            .spawn()
+            .map_err(|e| DownloadError::Command(format!("curl failed: {e}")))?;
+
+        let total = self.progress.as_ref().and_then(|_| content_length(url));
+        let report = |downloaded: u64| {
+            if let Some(ref progress) = self.progress {
+                progress(DownloadProgress {
+                    artifact,
+                    url: url.to_string(),
+                    downloaded,
+                    total,
+                });
+            }
+        };
+        let size = || std::fs::metadata(dest).map(|m| m.len()).unwrap_or(0);
+
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            report(size());
+            std::thread::sleep(Duration::from_millis(100));
+        };
+        if !status.success() {
+            let _ = std::fs::remove_file(dest);
+            return Err(DownloadError::Command(format!(
+                "curl exited with {status} for {url}"
+            )));
         }
-    } else if let Ok(output) = Command::new("which").arg("firefox").output()
-        && output.status.success()
-    {
-        let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        if !path.is_empty() {
-            return Some(PathBuf::from(path));
+        report(size());
+
+        if let Some(expected) = self.checksums.get(&artifact) {
+            let actual = sha256_file(dest)?;
+            if &actual != expected {
+                let _ = std::fs::remove_file(dest);
+                return Err(DownloadError::ChecksumMismatch {
+                    url: url.to_string(),
+                    expected: expected.clone(),
+                    actual,
+                });
+            }
         }
+        Ok(())
     }
-    None
 }
 
-// ── Shared helpers ───────────────────────────────────────────────────────────
+/// `Content-Length` of the final response for `url`, from a HEAD request.
+fn content_length(url: &str) -> Option<u64> {
+    let output = Command::new("curl").args(["-fsSLI", url]).output().ok()?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .rev()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("content-length")
+                .then(|| value.trim().parse().ok())
+                .flatten()
+        })
+}
 
-fn cache_dir(tool: &str, version: &str, platform: &str) -> PathBuf {
-    std::env::temp_dir().join(format!("rustenium-{tool}-{version}-{platform}"))
+fn sha256_file(path: &Path) -> Result<String, DownloadError> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect())
 }
 
+// ── Shared helpers ───────────────────────────────────────────────────────────
+
 fn exe_name(tool: &str) -> String {
     if cfg!(windows) {
         format!("{tool}.exe")
@@ -235,44 +644,16 @@ fn find_exe(dir: &Path, name: &str) -> Option<PathBuf> {
     })
 }
 
-// TODO: If running on an OS with the missing dependency, you might get unzip failed: No such file or directory (os error 2)
-// Where in actuality, the actual error is: bash: unzip: command not found
-fn download_and_extract(url: &str, dest: &Path) -> Result<(), String> {
-    std::fs::create_dir_all(dest).map_err(|e| e.to_string())?;
-    let archive = dest.join("download_archive");
-    let archive_str = archive.to_str().unwrap();
-    let dest_str = dest.to_str().unwrap();
-
-    run("curl", &["-sL", "-o", archive_str, url])?;
-
-    if url.ends_with(".tar.xz") {
-        run("tar", &["-xJf", archive_str, "-C", dest_str])?;
-    } else if url.ends_with(".tar.gz") {
-        run("tar", &["-xzf", archive_str, "-C", dest_str])?;
-    } else if url.ends_with(".zip") {
-        if cfg!(unix) {
-            run("unzip", &["-oq", archive_str, "-d", dest_str])?;
-        } else {
-            run("tar", &["-xf", archive_str, "-C", dest_str])?;
-        }
-    } else {
-        return Err(format!("Unknown archive format: {url}"));
-    }
-
-    let _ = std::fs::remove_file(&archive);
-    Ok(())
-}
-
-fn run(cmd: &str, args: &[&str]) -> Result<(), String> {
+fn run(cmd: &str, args: &[&str]) -> Result<(), DownloadError> {
     tracing::debug!("Running: {cmd} {}", args.join(" "));
     let status = Command::new(cmd)
         .args(args)
         .status()
-        .map_err(|e| format!("{cmd} failed: {e}"))?;
+        .map_err(|e| DownloadError::Command(format!("{cmd} failed: {e}")))?;
     status
         .success()
         .then_some(())
-        .ok_or_else(|| format!("{cmd} exited with {status}"))
+        .ok_or_else(|| DownloadError::Command(format!("{cmd} exited with {status}")))
 }
 
 fn make_executable(_path: &Path) {
@@ -1,5 +1,8 @@
+use crate::error::script::JsException;
 use rustenium_bidi_definitions::script::types::EvaluateResultException;
-use rustenium_core::error::{CommandResultError, ProcessKillError, SessionSendError};
+use rustenium_core::error::{
+    CommandResultError, ProcessKillError, SessionSendError, WebDriverHttpError,
+};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -52,6 +55,55 @@ pub enum EvaluateResultError {
     NoSharedId,
 }
 
+#[derive(Debug, Error)]
+pub enum EvaluateError {
+    #[error(transparent)]
+    ContextIndexError(#[from] ContextIndexError),
+    #[error("Script threw {0}")]
+    Exception(#[from] JsException),
+    #[error("Failed to serialize arguments: {0}")]
+    SerializeError(String),
+    #[error("Failed to deserialize result: {0}")]
+    DeserializeError(String),
+    #[error("An error occured executing command")]
+    CommandResultError(CommandResultError),
+}
+
+impl From<EvaluateResultError> for EvaluateError {
+    fn from(err: EvaluateResultError) -> Self {
+        match err {
+            EvaluateResultError::ContextIndexError(err) => Self::ContextIndexError(err),
+            EvaluateResultError::ExceptionError(exception) => {
+                Self::Exception(JsException::from(&exception.exception_details))
+            }
+            EvaluateResultError::CommandResultError(err) => Self::CommandResultError(err),
+            EvaluateResultError::NoSharedId => {
+                Self::SerializeError("Node does not have a shared ID".to_string())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExposeFunctionError {
+    #[error(transparent)]
+    ContextIndexError(#[from] ContextIndexError),
+    #[error(transparent)]
+    EvaluateResultError(#[from] EvaluateResultError),
+    #[error(transparent)]
+    FrameError(#[from] FrameError),
+    #[error("An error occured executing command")]
+    CommandResultError(CommandResultError),
+}
+
+#[derive(Debug, Error)]
+pub enum ConsoleError {
+    #[error(transparent)]
+    ContextIndexError(#[from] ContextIndexError),
+    #[error("An error occured executing command")]
+    CommandResultError(CommandResultError),
+}
+
 #[derive(Debug, Error)]
 pub enum InterceptNetworkError {
     #[error(transparent)]
@@ -110,6 +162,8 @@ pub enum BrowserCloseError {
     SessionSendError(#[from] SessionSendError),
     #[error(transparent)]
     ProcessKillError(#[from] ProcessKillError),
+    #[error(transparent)]
+    WebDriverHttpError(#[from] WebDriverHttpError),
 }
 
 #[derive(Debug, Error)]
@@ -119,3 +173,41 @@ pub enum EmulationError {
     #[error("An error occured executing command")]
     CommandResultError(CommandResultError),
 }
+
+#[derive(Debug, Error)]
+pub enum PopupError {
+    #[error("No popup was opened within {0:?}")]
+    Timeout(std::time::Duration),
+    #[error("An error occured executing command")]
+    CommandResultError(CommandResultError),
+}
+
+#[derive(Debug, Error)]
+pub enum WaitError {
+    #[error("Timed out after {1:?} waiting for {0}")]
+    Timeout(String, std::time::Duration),
+    #[error("Navigation to {0} failed")]
+    NavigationFailed(String),
+    #[error(transparent)]
+    EvaluateResultError(#[from] EvaluateResultError),
+    #[error(transparent)]
+    FindNodesError(#[from] FindNodesError),
+    #[error("An error occured executing command")]
+    CommandResultError(CommandResultError),
+}
+
+#[derive(Debug, Error)]
+pub enum FrameError {
+    #[error("No element matches the frame locator {0}")]
+    NotFound(String),
+    #[error("Element matching {0} is not a frame")]
+    NotAFrame(String),
+    #[error("Browsing context {0} no longer exists")]
+    Detached(String),
+    #[error(transparent)]
+    FindNodesError(#[from] FindNodesError),
+    #[error(transparent)]
+    EvaluateResultError(#[from] EvaluateResultError),
+    #[error("An error occured executing command")]
+    CommandResultError(CommandResultError),
+}
@@ -1,3 +1,4 @@
+use crate::error::script::JsException;
 use rustenium_core::error::CdpCommandResultError;
 use thiserror::Error;
 
@@ -59,6 +60,18 @@ pub enum LocateError {
     ParseError(String),
     #[error("Timed out waiting for selector: {0}")]
     Timeout(String),
+    #[error("No document found for frame {0}")]
+    FrameNotFound(String),
+    #[error("Invalid selector: {0}")]
+    InvalidSelector(String),
+}
+
+#[derive(Debug, Error)]
+pub enum FrameError {
+    #[error("An error occured executing command")]
+    CommandResultError(CdpCommandResultError),
+    #[error("Failed to parse frame tree: {0}")]
+    ParseError(String),
 }
 
 #[derive(Debug, Error)]
@@ -69,6 +82,20 @@ pub enum EvaluateScriptError {
     ParseError(String),
 }
 
+#[derive(Debug, Error)]
+pub enum EvaluateError {
+    #[error("Script threw {0}")]
+    Exception(#[from] JsException),
+    #[error("Failed to serialize arguments: {0}")]
+    SerializeError(String),
+    #[error("Failed to deserialize result: {0}")]
+    DeserializeError(String),
+    #[error("An error occured executing command")]
+    CommandResultError(CdpCommandResultError),
+    #[error("Failed to parse evaluate result: {0}")]
+    ParseError(String),
+}
+
 #[derive(Debug, Error)]
 pub enum PreloadScriptError {
     #[error("An error occured executing command")]
@@ -77,6 +104,20 @@ pub enum PreloadScriptError {
     ParseError(String),
 }
 
+#[derive(Debug, Error)]
+pub enum ExposeFunctionError {
+    #[error(transparent)]
+    PreloadScriptError(#[from] PreloadScriptError),
+    #[error("An error occured executing command")]
+    CommandResultError(CdpCommandResultError),
+}
+
+#[derive(Debug, Error)]
+pub enum ConsoleError {
+    #[error("An error occured executing command")]
+    CommandResultError(CdpCommandResultError),
+}
+
 #[derive(Debug, Error)]
 pub enum ScreenshotError {
     #[error("An error occured executing command")]
@@ -90,3 +131,15 @@ pub enum ScreenshotError {
     #[error("Failed to write file: {0}")]
     FileWriteError(String),
 }
+
+#[derive(Debug, Error)]
+pub enum WaitError {
+    #[error("Timed out after {1:?} waiting for {0}")]
+    Timeout(String, std::time::Duration),
+    #[error(transparent)]
+    FrameError(#[from] FrameError),
+    #[error(transparent)]
+    LocateError(#[from] LocateError),
+    #[error("An error occured executing command")]
+    CommandResultError(CdpCommandResultError),
+}
@@ -0,0 +1,22 @@
+use super::bidi::ContextCreationError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DetachError {
+    #[error("Detaching is not supported in {0} mode")]
+    Unsupported(String),
+    #[error(
+        "Chrome or chromedriver output is piped into this process; launch with `detachable` set"
+    )]
+    PipedOutput,
+}
+
+#[derive(Debug, Error)]
+pub enum AttachError {
+    #[error("Browser handle has no {0}")]
+    MissingField(&'static str),
+    #[error("Browser is not reachable: {0}")]
+    Unreachable(String),
+    #[error(transparent)]
+    ContextCreation(#[from] ContextCreationError),
+}
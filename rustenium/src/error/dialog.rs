@@ -0,0 +1,33 @@
+use crate::error::bidi::ContextIndexError;
+use rustenium_core::error::{CdpCommandResultError, CommandResultError};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Error from listening for, waiting for or answering a JavaScript dialog, over either
+/// protocol.
+#[derive(Debug, Error)]
+pub enum DialogError {
+    #[error(transparent)]
+    Bidi(#[from] CommandResultError),
+    #[error(transparent)]
+    Cdp(#[from] CdpCommandResultError),
+    #[error(transparent)]
+    ContextIndexError(#[from] ContextIndexError),
+    #[error("The dialog was already accepted or dismissed")]
+    AlreadyHandled,
+    #[error("No dialog opened within {0:?}")]
+    Timeout(Duration),
+}
+
+/// Error from listening for or waiting for a file chooser, over either protocol.
+#[derive(Debug, Error)]
+pub enum FileChooserError {
+    #[error(transparent)]
+    Bidi(#[from] CommandResultError),
+    #[error(transparent)]
+    Cdp(#[from] CdpCommandResultError),
+    #[error(transparent)]
+    ContextIndexError(#[from] ContextIndexError),
+    #[error("No file chooser opened within {0:?}")]
+    Timeout(Duration),
+}
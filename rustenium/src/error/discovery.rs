@@ -0,0 +1,10 @@
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DiscoveryError {
+    #[error("Could not determine the version of {0}")]
+    UnknownVersion(PathBuf),
+    #[error("Browser major version {browser} does not match chromedriver major version {driver}")]
+    VersionMismatch { browser: u32, driver: u32 },
+}
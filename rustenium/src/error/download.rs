@@ -0,0 +1,25 @@
+use crate::download::DownloadState;
+use crate::error::bidi::ContextIndexError;
+use rustenium_core::error::{CdpCommandResultError, CommandResultError};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Error from setting the download behavior, waiting for a download or saving it, over
+/// either protocol.
+#[derive(Debug, Error)]
+pub enum FileDownloadError {
+    #[error(transparent)]
+    Bidi(#[from] CommandResultError),
+    #[error(transparent)]
+    Cdp(#[from] CdpCommandResultError),
+    #[error(transparent)]
+    ContextIndexError(#[from] ContextIndexError),
+    #[error("No download finished within {0:?}")]
+    Timeout(Duration),
+    #[error("The download was {0}, not completed")]
+    NotCompleted(DownloadState),
+    #[error("The browser did not report where the download was saved")]
+    NoPath,
+    #[error("Download file error: {0}")]
+    Io(#[from] std::io::Error),
+}
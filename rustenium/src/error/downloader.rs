@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DownloadError {
+    #[error("Unsupported platform: {0}")]
+    UnsupportedPlatform(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Command(String),
+    #[error("Failed to resolve version: {0}")]
+    VersionResolution(String),
+    #[error("Checksum mismatch for {url}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        url: String,
+        expected: String,
+        actual: String,
+    },
+    #[error("{name} not found after extraction in {dir:?}")]
+    NotFound { name: String, dir: PathBuf },
+}
@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum FirefoxProfileError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Extension not found: {0}")]
+    ExtensionNotFound(PathBuf),
+    #[error("Certificate not found: {0}")]
+    CertificateNotFound(PathBuf),
+    #[error("Profile command failed: {0}")]
+    Command(String),
+}
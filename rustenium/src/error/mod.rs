@@ -1,3 +1,12 @@
 pub mod bidi;
 pub mod cdp;
+pub mod chrome;
+pub mod config;
+pub mod dialog;
+pub mod discovery;
+pub mod download;
+pub mod downloader;
+pub mod firefox;
 pub mod node;
+pub mod pdf;
+pub mod script;
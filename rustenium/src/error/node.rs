@@ -1,10 +1,12 @@
 use rustenium_bidi_definitions::script::types::EvaluateResultException;
 use rustenium_core::error::{CdpCommandResultError, CommandResultError};
+use std::path::PathBuf;
 use thiserror::Error;
 
 use crate::error::bidi::{
-    ContextIndexError, EvaluateResultError, InputError as BidiInputError,
-    MouseInputError as BidiMouseInputError, ScreenshotError as BidiScreenshotError,
+    ContextIndexError, EvaluateResultError, FindNodesError, FrameError,
+    InputError as BidiInputError, MouseInputError as BidiMouseInputError,
+    ScreenshotError as BidiScreenshotError,
 };
 
 #[derive(Debug, Error)]
@@ -30,12 +32,26 @@ pub enum CdpNodeActionError {
 }
 
 /// Error from DOM-level node actions: scroll, visibility check, delete, etc.
+///
+/// The actionability checks run before pointer and keyboard input fail with `Detached`,
+/// `NotVisible`, `NotStable`, `Disabled` or `Obscured`. All but `Detached` are retried until
+/// the timeout, and the last one is returned.
 #[derive(Debug, Error)]
 pub enum NodeActionError {
     #[error(transparent)]
     Bidi(#[from] BidiNodeActionError),
     #[error(transparent)]
     Cdp(#[from] CdpNodeActionError),
+    #[error("Node is no longer attached to the document")]
+    Detached,
+    #[error("Node is not visible")]
+    NotVisible,
+    #[error("Node is still moving")]
+    NotStable,
+    #[error("Node is disabled")]
+    Disabled,
+    #[error("Node is obscured by {by}")]
+    Obscured { by: String },
 }
 
 #[derive(Debug, Error)]
@@ -137,6 +153,46 @@ pub enum NodeScreenshotError {
     FileWriteError(String),
 }
 
+/// Error from setting the files of an `<input type=file>` or a file chooser.
+#[derive(Debug, Error)]
+pub enum SetFilesError {
+    #[error(transparent)]
+    Action(Box<NodeActionError>),
+    #[error("File not found: {}", .0.display())]
+    FileNotFound(PathBuf),
+    #[error("Node is not an <input type=file>")]
+    NotFileInput,
+    #[error("The input takes a single file, got {0}")]
+    NotMultiple(usize),
+    #[error("The file chooser has no input element to set files on")]
+    NoElement,
+    #[error("Setting input files is not supported by this node")]
+    Unsupported,
+}
+
+/// Error from resolving a [`Locator`](crate::locator::Locator) or acting on what it matches.
+#[derive(Debug, Error)]
+pub enum LocatorError {
+    #[error("No element matches {0}")]
+    NotFound(String),
+    #[error(transparent)]
+    FindNodes(#[from] FindNodesError),
+    #[error(transparent)]
+    Evaluate(#[from] EvaluateResultError),
+    #[error(transparent)]
+    Frame(#[from] FrameError),
+    #[error(transparent)]
+    Action(#[from] NodeActionError),
+    #[error(transparent)]
+    Mouse(#[from] NodeMouseError),
+    #[error(transparent)]
+    Input(#[from] NodeInputError),
+    #[error(transparent)]
+    Screenshot(#[from] NodeScreenshotError),
+    #[error(transparent)]
+    SetFiles(#[from] SetFilesError),
+}
+
 impl From<CommandResultError> for NodeActionError {
     fn from(error: CommandResultError) -> Self {
         Self::Bidi(error.into())
@@ -149,6 +205,12 @@ impl From<CdpCommandResultError> for NodeActionError {
     }
 }
 
+impl From<NodeActionError> for SetFilesError {
+    fn from(error: NodeActionError) -> Self {
+        Self::Action(Box::new(error))
+    }
+}
+
 impl From<CommandResultError> for NodeMouseError {
     fn from(error: CommandResultError) -> Self {
         Self::Bidi(error.into())
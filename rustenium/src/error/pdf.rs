@@ -0,0 +1,18 @@
+use crate::error::bidi::ContextIndexError;
+use rustenium_core::error::{CdpCommandResultError, CommandResultError};
+use thiserror::Error;
+
+/// Error from printing a page to PDF or saving it, over either protocol.
+#[derive(Debug, Error)]
+pub enum PdfError {
+    #[error(transparent)]
+    Bidi(#[from] CommandResultError),
+    #[error(transparent)]
+    Cdp(#[from] CdpCommandResultError),
+    #[error(transparent)]
+    ContextIndexError(#[from] ContextIndexError),
+    #[error("Failed to decode base64 data: {0}")]
+    Base64DecodeError(String),
+    #[error("PDF file error: {0}")]
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,100 @@
+use rustenium_bidi_definitions::script::types::{
+    ExceptionDetails as BidiExceptionDetails, StackTrace as BidiStackTrace,
+};
+use rustenium_cdp_definitions::js_protocol::runtime::types::{
+    ExceptionDetails as CdpExceptionDetails, StackTrace as CdpStackTrace,
+};
+use thiserror::Error;
+
+/// An exception thrown, or a promise rejected, by a script run with `evaluate`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{message} (line {line}, column {column})")]
+pub struct JsException {
+    /// What was thrown, e.g. `Error: boom` or `TypeError: x is not a function`.
+    pub message: String,
+    /// The call stack when it was thrown, one `at function (url:line:column)` frame per
+    /// line. Empty when the browser reports no frames.
+    pub stack: String,
+    /// The zero-based line it was thrown at, within the evaluated script.
+    pub line: u64,
+    /// The zero-based column it was thrown at.
+    pub column: u64,
+}
+
+fn stack_frame(function_name: &str, url: &str, line: u64, column: u64) -> String {
+    let function_name = if function_name.is_empty() {
+        "<anonymous>"
+    } else {
+        function_name
+    };
+    format!("    at {function_name} ({url}:{line}:{column})")
+}
+
+/// Formats a BiDi stack trace one `at function (url:line:column)` frame per line.
+pub(crate) fn bidi_stack(stack_trace: &BidiStackTrace) -> String {
+    stack_trace
+        .call_frames
+        .iter()
+        .map(|frame| {
+            stack_frame(
+                &frame.function_name,
+                &frame.url,
+                frame.line_number,
+                frame.column_number,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Formats a CDP stack trace one `at function (url:line:column)` frame per line.
+pub(crate) fn cdp_stack(stack_trace: &CdpStackTrace) -> String {
+    stack_trace
+        .call_frames
+        .iter()
+        .map(|frame| {
+            stack_frame(
+                &frame.function_name,
+                &frame.url,
+                frame.line_number.max(0) as u64,
+                frame.column_number.max(0) as u64,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl From<&BidiExceptionDetails> for JsException {
+    fn from(details: &BidiExceptionDetails) -> Self {
+        Self {
+            message: details.text.clone(),
+            stack: bidi_stack(&details.stack_trace),
+            line: details.line_number,
+            column: details.column_number,
+        }
+    }
+}
+
+/// CDP puts `Uncaught` in `text` and the thrown value's own description, stack included,
+/// on `exception`, so the message is the first line of that description when there is one.
+impl From<&CdpExceptionDetails> for JsException {
+    fn from(details: &CdpExceptionDetails) -> Self {
+        let message = details
+            .exception
+            .as_ref()
+            .and_then(|exception| exception.description.as_deref())
+            .and_then(|description| description.lines().next())
+            .map(String::from)
+            .unwrap_or_else(|| details.text.clone());
+        Self {
+            message,
+            stack: details
+                .stack_trace
+                .as_ref()
+                .map(cdp_stack)
+                .unwrap_or_default(),
+            line: details.line_number.max(0) as u64,
+            column: details.column_number.max(0) as u64,
+        }
+    }
+}
@@ -0,0 +1,387 @@
+//! Conversions behind the typed `evaluate` and `expose_function` helpers.
+//!
+//! Arguments are serialized to JSON with serde and then turned into BiDi `LocalValue`s or
+//! CDP call arguments. Results come back the other way: BiDi `RemoteValue`s and CDP
+//! by-value results are read into JSON and deserialized into the type asked for.
+//! Exposed functions go the other way round: page scripts send their arguments as JSON and
+//! get the Rust function's result back.
+
+use rustenium_bidi_definitions::script::type_builders::SharedReferenceBuilder;
+use rustenium_bidi_definitions::script::types::{
+    ArrayLocalValue, ArrayLocalValueType, BooleanValue, BooleanValueType, ListLocalValue,
+    LocalValue, MappingLocalValue, NullValue, NullValueType, NumberValue, NumberValueType,
+    ObjectLocalValue, ObjectLocalValueType, PrimitiveProtocolValue, RemoteReference, RemoteValue,
+    StringValue, StringValueType,
+};
+use rustenium_cdp_definitions::browser_protocol::dom::types::NodeId;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// The key a [`NodeRef`] serializes under. Objects carrying it are swapped for the element
+/// they refer to on their way into the page.
+const NODE_KEY: &str = "__rusteniumNode";
+
+/// An element passed to, or returned from, `evaluate`.
+///
+/// It serializes to a marker object, so it can sit anywhere in the arguments, nested in
+/// structs, tuples or vectors. The element reaches the script as the live DOM element. Over
+/// BiDi it is referred to by its shared id, and elements a script returns deserialize back
+/// into a `NodeRef`. Over CDP it is referred to by its node id.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::browsers::{BidiBrowser, chrome};
+/// use rustenium::evaluate::NodeRef;
+/// use rustenium_macros::css;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut browser = chrome(None).await;
+/// let page = browser.active_page()?;
+/// let heading = page.find_node(css!("h1")).await?.unwrap();
+/// let tag: String = page
+///     .evaluate("(element) => element.tagName", (NodeRef::from_node(&heading).unwrap(),))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeRef {
+    #[serde(rename = "__rusteniumNode")]
+    node: NodeKey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum NodeKey {
+    SharedId(String),
+    NodeId(i64),
+}
+
+impl NodeRef {
+    /// A BiDi element, by its shared id.
+    pub fn shared(shared_id: impl Into<String>) -> Self {
+        Self {
+            node: NodeKey::SharedId(shared_id.into()),
+        }
+    }
+
+    /// A CDP element, by its node id.
+    pub fn cdp(node_id: NodeId) -> Self {
+        Self {
+            node: NodeKey::NodeId(*node_id.inner()),
+        }
+    }
+
+    /// A node found over BiDi. `None` when the browser gave it no shared id.
+    pub fn from_node(node: &impl crate::nodes::Node) -> Option<Self> {
+        node.get_shared_id()
+            .map(|shared_id| Self::shared(shared_id.as_ref()))
+    }
+
+    pub fn shared_id(&self) -> Option<&str> {
+        match &self.node {
+            NodeKey::SharedId(shared_id) => Some(shared_id),
+            _ => None,
+        }
+    }
+
+    pub fn node_id(&self) -> Option<NodeId> {
+        match &self.node {
+            NodeKey::NodeId(node_id) => Some(NodeId::new(*node_id)),
+            _ => None,
+        }
+    }
+}
+
+/// The positional arguments of an `evaluate` call, serialized to JSON one by one.
+///
+/// Implemented for tuples of up to twelve [`Serialize`] values, each of which is one
+/// argument, and for `()`, which passes none. A single argument is a one-element tuple,
+/// `(value,)`, so a `Vec` or a JSON array is never mistaken for several arguments.
+pub trait EvaluateArgs {
+    fn into_args(self) -> Result<Vec<Value>, serde_json::Error>;
+}
+
+impl EvaluateArgs for () {
+    fn into_args(self) -> Result<Vec<Value>, serde_json::Error> {
+        Ok(Vec::new())
+    }
+}
+
+macro_rules! tuple_args {
+    ($($arg:ident),+) => {
+        impl<$($arg: Serialize),+> EvaluateArgs for ($($arg,)+) {
+            #[allow(non_snake_case)]
+            fn into_args(self) -> Result<Vec<Value>, serde_json::Error> {
+                let ($($arg,)+) = self;
+                Ok(vec![$(serde_json::to_value($arg)?),+])
+            }
+        }
+    };
+}
+
+tuple_args!(A);
+tuple_args!(A, B);
+tuple_args!(A, B, C);
+tuple_args!(A, B, C, D);
+tuple_args!(A, B, C, D, E);
+tuple_args!(A, B, C, D, E, F);
+tuple_args!(A, B, C, D, E, F, G);
+tuple_args!(A, B, C, D, E, F, G, H);
+tuple_args!(A, B, C, D, E, F, G, H, I);
+tuple_args!(A, B, C, D, E, F, G, H, I, J);
+tuple_args!(A, B, C, D, E, F, G, H, I, J, K);
+tuple_args!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Turns a JSON value into the BiDi `LocalValue` that builds it in the page. A
+/// [`NodeRef`] with a shared id becomes a reference to that element.
+pub fn to_local_value(value: &Value) -> LocalValue {
+    if let Ok(NodeRef {
+        node: NodeKey::SharedId(shared_id),
+    }) = NodeRef::deserialize(value)
+        && let Ok(reference) = SharedReferenceBuilder::default()
+            .shared_id(shared_id)
+            .build()
+    {
+        return LocalValue::RemoteReference(RemoteReference::from(reference));
+    }
+    match value {
+        Value::Null => LocalValue::PrimitiveProtocolValue(PrimitiveProtocolValue::NullValue(
+            NullValue::new(NullValueType::Null),
+        )),
+        Value::Bool(value) => {
+            LocalValue::PrimitiveProtocolValue(PrimitiveProtocolValue::BooleanValue(
+                BooleanValue::new(BooleanValueType::Boolean, *value),
+            ))
+        }
+        Value::Number(number) => {
+            LocalValue::PrimitiveProtocolValue(PrimitiveProtocolValue::NumberValue(
+                NumberValue::new(NumberValueType::Number, number.clone()),
+            ))
+        }
+        Value::String(value) => {
+            LocalValue::PrimitiveProtocolValue(PrimitiveProtocolValue::StringValue(
+                StringValue::new(StringValueType::String, value.clone()),
+            ))
+        }
+        Value::Array(items) => LocalValue::ArrayLocalValue(ArrayLocalValue::new(
+            ArrayLocalValueType::Array,
+            ListLocalValue::new(items.iter().map(to_local_value).collect::<Vec<_>>()),
+        )),
+        Value::Object(entries) => LocalValue::ObjectLocalValue(ObjectLocalValue::new(
+            ObjectLocalValueType::Object,
+            MappingLocalValue::new(
+                entries
+                    .iter()
+                    .map(|(key, item)| {
+                        vec![
+                            Value::from(key.as_str()),
+                            serde_json::to_value(to_local_value(item)).unwrap_or_default(),
+                        ]
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+        )),
+    }
+}
+
+/// Reads a BiDi `RemoteValue` as JSON, the way `JSON.stringify` would where it can:
+/// `undefined`, `NaN` and infinities become `null`, dates become their ISO string and
+/// big integers their decimal string. Maps become objects, sets become arrays, and elements
+/// become [`NodeRef`]s. Values the browser did not serialize, such as functions, become
+/// `null`.
+pub fn from_remote_value(value: &RemoteValue) -> Value {
+    match value {
+        RemoteValue::PrimitiveProtocolValue(primitive) => match primitive {
+            PrimitiveProtocolValue::UndefinedValue(_) | PrimitiveProtocolValue::NullValue(_) => {
+                Value::Null
+            }
+            PrimitiveProtocolValue::StringValue(string) => Value::from(string.value.as_str()),
+            PrimitiveProtocolValue::NumberValue(number) => match &number.value {
+                Value::Number(number) => Value::Number(number.clone()),
+                Value::String(special) if special == "-0" => Value::from(0),
+                _ => Value::Null,
+            },
+            PrimitiveProtocolValue::BooleanValue(boolean) => Value::Bool(boolean.value),
+            PrimitiveProtocolValue::BigIntValue(big_int) => Value::from(big_int.value.as_str()),
+        },
+        RemoteValue::ArrayRemoteValue(array) => list(array.value.as_ref().map(|v| v.inner())),
+        RemoteValue::SetRemoteValue(set) => list(set.value.as_ref().map(|v| v.inner())),
+        RemoteValue::ObjectRemoteValue(object) => mapping(object.value.as_ref().map(|v| v.inner())),
+        RemoteValue::MapRemoteValue(map) => mapping(map.value.as_ref().map(|v| v.inner())),
+        RemoteValue::DateRemoteValue(date) => Value::from(date.date_local_value.value.as_str()),
+        RemoteValue::NodeRemoteValue(node) => match &node.shared_id {
+            Some(shared_id) => {
+                serde_json::to_value(NodeRef::shared(shared_id.as_ref())).unwrap_or_default()
+            }
+            None => Value::Null,
+        },
+        _ => Value::Null,
+    }
+}
+
+fn list(items: Option<&Vec<RemoteValue>>) -> Value {
+    Value::Array(items.into_iter().flatten().map(from_remote_value).collect())
+}
+
+/// A BiDi mapping is a list of `[key, value]` pairs whose keys are plain strings or, in
+/// maps, remote values of their own.
+fn mapping(entries: Option<&Vec<Vec<Value>>>) -> Value {
+    let mut object = serde_json::Map::new();
+    for entry in entries.into_iter().flatten() {
+        let [key, item] = entry.as_slice() else {
+            continue;
+        };
+        let key = match key {
+            Value::String(key) => key.clone(),
+            key => match serde_json::from_value::<RemoteValue>(key.clone()) {
+                Ok(key) => match from_remote_value(&key) {
+                    Value::String(key) => key,
+                    key => key.to_string(),
+                },
+                Err(_) => key.to_string(),
+            },
+        };
+        let item = serde_json::from_value::<RemoteValue>(item.clone())
+            .map(|item| from_remote_value(&item))
+            .unwrap_or(Value::Null);
+        object.insert(key, item);
+    }
+    Value::Object(object)
+}
+
+/// Swaps every [`NodeRef`] with a CDP node id in `value` for a marker holding its index
+/// in `nodes`, where the node id is pushed. [`revive_declaration`] swaps them back for
+/// the elements in the page.
+pub(crate) fn take_nodes(value: Value, nodes: &mut Vec<NodeId>) -> Value {
+    if let Ok(NodeRef {
+        node: NodeKey::NodeId(node_id),
+    }) = NodeRef::deserialize(&value)
+    {
+        nodes.push(NodeId::new(node_id));
+        return serde_json::json!({ NODE_KEY: { "index": nodes.len() - 1 } });
+    }
+    match value {
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| take_nodes(item, nodes))
+                .collect(),
+        ),
+        Value::Object(entries) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, item)| (key, take_nodes(item, nodes)))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+/// A CDP function declaration that takes the positional arguments as one array followed by
+/// the elements [`take_nodes`] pulled out of them, puts the elements back in place of their
+/// markers and calls `function` with the result.
+pub(crate) fn revive_declaration(function: &str) -> String {
+    format!(
+        r#"function(args, ...nodes) {{
+    const revive = (value) => {{
+        if (Array.isArray(value)) return value.map(revive);
+        if (value === null || typeof value !== 'object') return value;
+        if ('{NODE_KEY}' in value) return nodes[value.{NODE_KEY}.index];
+        return Object.fromEntries(Object.entries(value).map(([key, item]) => [key, revive(item)]));
+    }};
+    return ({function}).apply(this, revive(args));
+}}"#
+    )
+}
+
+// ── Exposed functions ────────────────────────────────────────────────────────
+
+/// A Rust function page scripts can call, boxed so both protocols can share it.
+pub(crate) type ExposedFunction = Arc<
+    dyn Fn(Vec<Value>) -> Pin<Box<dyn Future<Output = Result<Value, String>> + Send>> + Send + Sync,
+>;
+
+pub(crate) fn exposed_function<F, Fut, E>(function: F) -> ExposedFunction
+where
+    F: Fn(Vec<Value>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Value, E>> + Send + 'static,
+    E: Display,
+{
+    Arc::new(move |args| {
+        let call = function(args);
+        Box::pin(async move { call.await.map_err(|err| err.to_string()) })
+    })
+}
+
+/// Installs `globalThis[name]` as an async function that sends its arguments through `send`
+/// as JSON and settles once [`REPLY_SCRIPT`] is called with the same id. Installing a name
+/// again replaces it.
+const EXPOSE_SCRIPT: &str = r#"function(name, send) {
+    const replies = globalThis.__rusteniumExposed ||
+        Object.defineProperty(globalThis, '__rusteniumExposed', { value: {} }).__rusteniumExposed;
+    const pending = new Map();
+    let next = 0;
+    replies[name] = (id, error, value) => {
+        const call = pending.get(id);
+        if (!call) return;
+        pending.delete(id);
+        if (error === null) call.resolve(value);
+        else call.reject(new Error(error));
+    };
+    globalThis[name] = (...args) => new Promise((resolve, reject) => {
+        const id = ++next;
+        pending.set(id, { resolve, reject });
+        send(JSON.stringify({ id, args }));
+    });
+}"#;
+
+/// Settles a call to an exposed function, given the arguments [`call_exposed`] returns.
+pub(crate) const REPLY_SCRIPT: &str = r#"function(name, id, error, value) {
+    const reply = globalThis.__rusteniumExposed && globalThis.__rusteniumExposed[name];
+    if (reply) reply(id, error, value);
+}"#;
+
+/// A BiDi function declaration installing `name`, taking the channel its calls go out on.
+pub(crate) fn expose_declaration(name: &str) -> String {
+    format!(
+        "function(send) {{ ({EXPOSE_SCRIPT})({}, send); }}",
+        Value::from(name)
+    )
+}
+
+/// A CDP script installing `name`, sending its calls through the binding `binding`.
+pub(crate) fn expose_source(name: &str, binding: &str) -> String {
+    format!(
+        "({EXPOSE_SCRIPT})({}, globalThis[{}]);",
+        Value::from(name),
+        Value::from(binding)
+    )
+}
+
+/// What a page script sends when it calls an exposed function.
+#[derive(Deserialize)]
+struct ExposedCall {
+    id: u64,
+    args: Vec<Value>,
+}
+
+/// Runs `function` on the call a page script sent as `payload` and returns the arguments
+/// [`REPLY_SCRIPT`] settles it with: a thrown error rejects the call with its message.
+pub(crate) async fn call_exposed(
+    name: &str,
+    function: &ExposedFunction,
+    payload: &str,
+) -> Option<Vec<Value>> {
+    let call: ExposedCall = serde_json::from_str(payload).ok()?;
+    let (error, value) = match function(call.args).await {
+        Ok(value) => (Value::Null, value),
+        Err(error) => (Value::from(error), Value::Null),
+    };
+    Some(vec![Value::from(name), Value::from(call.id), error, value])
+}
@@ -0,0 +1,323 @@
+use crate::conduit::bidi::drivers;
+use crate::error::bidi::{EvaluateResultError, FrameError};
+use crate::nodes::FromBidiNode;
+use crate::page::Page;
+use rustenium_bidi_definitions::browsing_context::command_builders::{
+    GetTreeBuilder, LocateNodesBuilder,
+};
+use rustenium_bidi_definitions::browsing_context::types::{BrowsingContext, Info, Locator};
+use rustenium_bidi_definitions::script::command_builders::CallFunctionBuilder;
+use rustenium_bidi_definitions::script::type_builders::SharedReferenceBuilder;
+use rustenium_bidi_definitions::script::types::{
+    ContextTarget, EvaluateResultSuccess, PrimitiveProtocolValue, RemoteReference, RemoteValue,
+    SerializationOptions, Target,
+};
+use rustenium_core::BidiSession;
+use rustenium_core::transport::ConnectionTransport;
+use std::ops::Deref;
+use std::sync::Arc;
+use tokio::sync::Mutex as TokioMutex;
+
+/// A child browsing context (an `<iframe>` or `<frame>`) of a [`Page`].
+///
+/// A frame is a browsing context in its own right, so it derefs to a [`Page`] bound to the
+/// frame: `find_nodes`, `evaluate_script`, `navigate` and the rest all act inside it.
+pub struct Frame<T: ConnectionTransport, N> {
+    page: Page<T, N>,
+    parent: BrowsingContext,
+    url: String,
+}
+
+impl<T: ConnectionTransport, N> Clone for Frame<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            page: self.page.clone(),
+            parent: self.parent.clone(),
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<T: ConnectionTransport, N> std::fmt::Debug for Frame<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Frame")
+            .field("page", &self.page)
+            .field("parent", &self.parent)
+            .field("url", &self.url)
+            .finish()
+    }
+}
+
+impl<T: ConnectionTransport, N> Deref for Frame<T, N> {
+    type Target = Page<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.page
+    }
+}
+
+impl<T, N> Frame<T, N>
+where
+    T: ConnectionTransport + Send + Sync + 'static,
+    N: FromBidiNode<T>,
+{
+    /// The browsing context that embeds this frame.
+    pub fn parent(&self) -> &BrowsingContext {
+        &self.parent
+    }
+
+    /// The frame's URL at the time it was looked up.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The page bound to this frame's browsing context.
+    pub fn page(&self) -> &Page<T, N> {
+        &self.page
+    }
+
+    /// The frame's `window.name`, which defaults to the `name` attribute of its element.
+    pub async fn name(&self) -> Result<String, EvaluateResultError> {
+        let result = self.evaluate_script("window.name", false).await?;
+        match result.result {
+            RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::StringValue(name)) => {
+                Ok(name.value)
+            }
+            _ => Ok(String::new()),
+        }
+    }
+}
+
+/// Finds a frame element by locator and works inside the browsing context it hosts.
+///
+/// The element is looked up again every time the locator is used, so a frame that is
+/// reloaded or replaced between calls is still found. Locators can be chained with
+/// [`FrameLocator::frame_locator`] to reach nested frames.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::browsers::{BidiBrowser, chrome};
+/// use rustenium_macros::css;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut browser = chrome(None).await;
+/// let page = browser.active_page()?;
+/// page.navigate("https://example.com/checkout").await?;
+///
+/// let payment = page.frame_locator(css!("iframe#payment"));
+/// let card_number = payment.find_node(css!("input[name=card]")).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct FrameLocator<T: ConnectionTransport, N> {
+    page: Page<T, N>,
+    chain: Vec<Locator>,
+}
+
+impl<T: ConnectionTransport, N> Clone for FrameLocator<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            page: self.page.clone(),
+            chain: self.chain.clone(),
+        }
+    }
+}
+
+impl<T: ConnectionTransport, N> std::fmt::Debug for FrameLocator<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FrameLocator")
+            .field("page", &self.page)
+            .field("chain", &self.chain)
+            .finish()
+    }
+}
+
+impl<T, N> FrameLocator<T, N>
+where
+    T: ConnectionTransport + Send + Sync + 'static,
+    N: FromBidiNode<T>,
+{
+    pub(crate) fn new(page: Page<T, N>, locator: Locator) -> Self {
+        Self {
+            page,
+            chain: vec![locator],
+        }
+    }
+
+    /// A locator for a frame element inside this locator's frame.
+    pub fn frame_locator(&self, locator: Locator) -> Self {
+        let mut chain = self.chain.clone();
+        chain.push(locator);
+        Self {
+            page: self.page.clone(),
+            chain,
+        }
+    }
+
+    /// A lazy [`Locator`](crate::locator::Locator) for elements inside the frame. The frame
+    /// is resolved again along with the elements each time the locator is used.
+    pub fn locator(&self, locator: Locator) -> crate::locator::Locator<T, N> {
+        crate::locator::Locator::in_frame(self.clone(), locator)
+    }
+
+    /// Resolves the frame element to the browsing context it hosts.
+    pub async fn frame(&self) -> Result<Frame<T, N>, FrameError> {
+        let session = self.page.session();
+        let mut parent = self.page.id().clone();
+        let mut context = parent.clone();
+        for locator in &self.chain {
+            parent = context;
+            context = content_frame(session, &parent, locator).await?;
+        }
+
+        let tree = drivers::get_tree(
+            session,
+            GetTreeBuilder::default()
+                .root(context.clone())
+                .max_depth(0u64)
+                .build(),
+        )
+        .await
+        .map_err(FrameError::CommandResultError)?;
+        let info = tree
+            .contexts
+            .inner()
+            .first()
+            .ok_or_else(|| FrameError::Detached(context.as_ref().to_string()))?;
+
+        Ok(Frame {
+            page: Page::with_exposed(session.clone(), self.page.exposed().clone(), context),
+            parent,
+            url: info.url.clone(),
+        })
+    }
+
+    pub async fn find_nodes(&self, locator: Locator) -> Result<Vec<N>, FrameError> {
+        Ok(self.frame().await?.find_nodes(locator).await?)
+    }
+
+    pub async fn find_node(&self, locator: Locator) -> Result<Option<N>, FrameError> {
+        Ok(self.frame().await?.find_node(locator).await?)
+    }
+
+    /// Waits for matching nodes inside the frame (default 4s timeout). Returns an empty list
+    /// on timeout.
+    pub async fn wait_for_nodes(&self, locator: Locator) -> Result<Vec<N>, FrameError> {
+        Ok(self.frame().await?.wait_for_nodes(locator).await?)
+    }
+
+    pub async fn wait_for_node(&self, locator: Locator) -> Result<Option<N>, FrameError> {
+        Ok(self.frame().await?.wait_for_node(locator).await?)
+    }
+
+    pub async fn evaluate_script(
+        &self,
+        expression: impl Into<String>,
+        await_promise: bool,
+    ) -> Result<EvaluateResultSuccess, FrameError> {
+        Ok(self
+            .frame()
+            .await?
+            .evaluate_script(expression, await_promise)
+            .await?)
+    }
+}
+
+/// Flattens the descendants of a `browsingContext.getTree` result into frames, parents
+/// before their children.
+pub(crate) fn collect_frames<T, N>(page: &Page<T, N>, parent: &Info, frames: &mut Vec<Frame<T, N>>)
+where
+    T: ConnectionTransport + Send + Sync + 'static,
+    N: FromBidiNode<T>,
+{
+    let Some(children) = &parent.children else {
+        return;
+    };
+    for child in children.inner() {
+        frames.push(Frame {
+            page: Page::with_exposed(
+                page.session().clone(),
+                page.exposed().clone(),
+                child.context.clone(),
+            ),
+            parent: child
+                .parent
+                .clone()
+                .unwrap_or_else(|| parent.context.clone()),
+            url: child.url.clone(),
+        });
+        collect_frames(page, child, frames);
+    }
+}
+
+/// The top-level browsing context that `context` belongs to, or `context` itself when it
+/// is top-level or no longer in the tree.
+pub(crate) async fn top_level_context<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    context: &BrowsingContext,
+) -> Result<BrowsingContext, FrameError> {
+    let tree = drivers::get_tree(session, GetTreeBuilder::default().build())
+        .await
+        .map_err(FrameError::CommandResultError)?;
+    Ok(tree
+        .contexts
+        .inner()
+        .iter()
+        .find(|info| contains_context(info, context))
+        .map_or_else(|| context.clone(), |info| info.context.clone()))
+}
+
+fn contains_context(info: &Info, context: &BrowsingContext) -> bool {
+    &info.context == context
+        || info.children.as_ref().is_some_and(|children| {
+            children
+                .inner()
+                .iter()
+                .any(|child| contains_context(child, context))
+        })
+}
+
+/// Finds the first element matching `locator` in `context` and returns the browsing
+/// context of its `contentWindow`.
+async fn content_frame<T: ConnectionTransport>(
+    session: &Arc<TokioMutex<BidiSession<T>>>,
+    context: &BrowsingContext,
+    locator: &Locator,
+) -> Result<BrowsingContext, FrameError> {
+    let command = LocateNodesBuilder::default()
+        .context(context.clone())
+        .locator(locator.clone())
+        .max_node_count(1u64)
+        .serialization_options(SerializationOptions {
+            max_dom_depth: Some(0),
+            max_object_depth: Some(0),
+            include_shadow_tree: None,
+        })
+        .build()
+        .unwrap();
+    let located = drivers::find_nodes(session, command).await?;
+    let node = located
+        .nodes
+        .into_iter()
+        .next()
+        .ok_or_else(|| FrameError::NotFound(format!("{:?}", locator)))?;
+    let shared_id = node.shared_id.ok_or(EvaluateResultError::NoSharedId)?;
+
+    let command = CallFunctionBuilder::default()
+        .function_declaration("function() { return this.contentWindow; }")
+        .await_promise(false)
+        .target(Target::ContextTarget(ContextTarget::new(context.clone())))
+        .this(RemoteReference::from(
+            SharedReferenceBuilder::default()
+                .shared_id(shared_id)
+                .build()
+                .unwrap(),
+        ))
+        .build()
+        .unwrap();
+    match drivers::call_function(session, command).await?.result {
+        RemoteValue::WindowProxyRemoteValue(window) => Ok(window.value.context),
+        _ => Err(FrameError::NotAFrame(format!("{:?}", locator))),
+    }
+}
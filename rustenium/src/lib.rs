@@ -73,15 +73,26 @@
 //! rustenium = { version = "1.0.0", features = ["macros"] }
 //! ```
 //!
-//! Then use `css!()` and `xpath!()` macros for element selection.
+//! Then use the `css!()`, `xpath!()`, `role!()`, `text!()` and `context!()` macros for
+//! element selection.
 
 pub mod browsers;
 mod conduit;
+pub mod console;
+pub mod dialog;
+pub mod discovery;
 pub mod domain;
+pub mod download;
 pub mod downloader;
 pub mod error;
+pub mod evaluate;
+pub mod frame;
 pub mod input;
+pub mod locator;
 pub mod nodes;
+pub mod page;
+pub mod pdf;
+pub mod wait;
 
 #[cfg(feature = "macros")]
 pub use rustenium_macros::*;
@@ -0,0 +1,467 @@
+use crate::browsers::bidi_browser::default_node_serialization;
+use crate::conduit::bidi::drivers;
+use crate::error::node::LocatorError;
+use crate::frame::FrameLocator;
+use crate::nodes::FromBidiNode;
+use crate::page::Page;
+use rustenium_bidi_definitions::browsing_context::command_builders::LocateNodesBuilder;
+use rustenium_bidi_definitions::browsing_context::types::Locator as BidiLocator;
+use rustenium_bidi_definitions::script::command_builders::CallFunctionBuilder;
+use rustenium_bidi_definitions::script::type_builders::SharedReferenceBuilder;
+use rustenium_bidi_definitions::script::types::{
+    ContextTarget, NodeRemoteValue, PrimitiveProtocolValue, RemoteReference, RemoteValue,
+    SerializationOptions, SharedReference, Target,
+};
+use rustenium_core::transport::ConnectionTransport;
+use std::path::Path;
+
+/// Narrows the elements a [`Locator`] matches.
+#[derive(Debug, Clone)]
+pub enum LocatorFilter {
+    /// Keeps elements whose text contains the given string.
+    HasText(String),
+    /// Keeps elements with a descendant matching the locator.
+    Has(BidiLocator),
+    /// Keeps elements without a descendant matching the locator.
+    HasNot(BidiLocator),
+}
+
+impl LocatorFilter {
+    pub fn has_text(text: impl Into<String>) -> Self {
+        Self::HasText(text.into())
+    }
+
+    pub fn has(locator: BidiLocator) -> Self {
+        Self::Has(locator)
+    }
+
+    pub fn has_not(locator: BidiLocator) -> Self {
+        Self::HasNot(locator)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Locate(BidiLocator),
+    Nth(usize),
+    Last,
+    Filter(LocatorFilter),
+}
+
+enum Scope<T: ConnectionTransport, N> {
+    Page(Page<T, N>),
+    Frame(FrameLocator<T, N>),
+}
+
+impl<T: ConnectionTransport, N> Clone for Scope<T, N> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Page(page) => Self::Page(page.clone()),
+            Self::Frame(frame) => Self::Frame(frame.clone()),
+        }
+    }
+}
+
+/// A lazy description of how to find elements in a page.
+///
+/// Building a locator sends nothing to the browser. It is resolved with
+/// `browsingContext.locateNodes` every time it is acted on, each step searching below the
+/// matches of the previous one through `startNodes`, so it never holds on to stale nodes.
+/// Actions such as [`click`](Locator::click) use the first match.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::browsers::{BidiBrowser, chrome};
+/// use rustenium::locator::LocatorFilter;
+/// use rustenium_macros::css;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut browser = chrome(None).await;
+/// let page = browser.active_page()?;
+/// page.navigate("https://example.com/shop").await?;
+///
+/// let products = page.locator(css!(".product"));
+/// let in_stock = products.filter(LocatorFilter::has_not(css!(".sold-out")));
+/// println!("{} products in stock", in_stock.count().await?);
+///
+/// in_stock
+///     .filter(LocatorFilter::has_text("Keyboard"))
+///     .locator(css!("button.add-to-cart"))
+///     .first()
+///     .click()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Locator<T: ConnectionTransport, N> {
+    scope: Scope<T, N>,
+    steps: Vec<Step>,
+}
+
+impl<T: ConnectionTransport, N> Clone for Locator<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            scope: self.scope.clone(),
+            steps: self.steps.clone(),
+        }
+    }
+}
+
+impl<T: ConnectionTransport, N> std::fmt::Debug for Locator<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Locator")
+            .field("steps", &self.steps)
+            .finish()
+    }
+}
+
+impl<T, N> Locator<T, N>
+where
+    T: ConnectionTransport + Send + Sync + 'static,
+    N: FromBidiNode<T>,
+{
+    pub(crate) fn in_page(page: Page<T, N>, locator: BidiLocator) -> Self {
+        Self {
+            scope: Scope::Page(page),
+            steps: vec![Step::Locate(locator)],
+        }
+    }
+
+    pub(crate) fn in_frame(frame: FrameLocator<T, N>, locator: BidiLocator) -> Self {
+        Self {
+            scope: Scope::Frame(frame),
+            steps: vec![Step::Locate(locator)],
+        }
+    }
+
+    fn then(&self, step: Step) -> Self {
+        let mut locator = self.clone();
+        locator.steps.push(step);
+        locator
+    }
+
+    // ── Refining ─────────────────────────────────────────────────────────────
+
+    /// Elements matching `locator` below any element this locator matches.
+    pub fn locator(&self, locator: BidiLocator) -> Self {
+        self.then(Step::Locate(locator))
+    }
+
+    /// The match at `index`, counting from zero.
+    pub fn nth(&self, index: usize) -> Self {
+        self.then(Step::Nth(index))
+    }
+
+    pub fn first(&self) -> Self {
+        self.nth(0)
+    }
+
+    pub fn last(&self) -> Self {
+        self.then(Step::Last)
+    }
+
+    /// The matches that pass `filter`. Call it again to combine filters.
+    pub fn filter(&self, filter: LocatorFilter) -> Self {
+        self.then(Step::Filter(filter))
+    }
+
+    // ── Resolving ────────────────────────────────────────────────────────────
+
+    /// The number of elements matched right now.
+    pub async fn count(&self) -> Result<usize, LocatorError> {
+        let page = self.page().await?;
+        Ok(self.resolve(&page).await?.len())
+    }
+
+    /// Snapshots of every element matched right now.
+    pub async fn all(&self) -> Result<Vec<N>, LocatorError> {
+        let page = self.page().await?;
+        let locator = self.last_locator().clone();
+        Ok(self
+            .resolve(&page)
+            .await?
+            .into_iter()
+            .map(|raw| page.build_node(raw, locator.clone()))
+            .collect())
+    }
+
+    /// A snapshot of the first element matched right now.
+    pub async fn node(&self) -> Result<N, LocatorError> {
+        self.all()
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| LocatorError::NotFound(self.to_string()))
+    }
+
+    // ── Actions ──────────────────────────────────────────────────────────────
+
+    pub async fn click(&self) -> Result<(), LocatorError> {
+        Ok(self.node().await?.mouse_click().await?)
+    }
+
+    pub async fn hover(&self) -> Result<(), LocatorError> {
+        Ok(self.node().await?.mouse_move().await?)
+    }
+
+    /// Focuses the element and types `text` into it.
+    pub async fn type_text(&self, text: impl Into<String>) -> Result<(), LocatorError> {
+        Ok(self.node().await?.type_text(text.into()).await?)
+    }
+
+    /// Sets the files of the `<input type=file>` matched. See
+    /// [`Node::set_input_files`](crate::nodes::Node::set_input_files).
+    pub async fn set_input_files<P: AsRef<Path>>(&self, paths: &[P]) -> Result<(), LocatorError> {
+        Ok(self.node().await?.set_input_files(paths).await?)
+    }
+
+    pub async fn inner_text(&self) -> Result<String, LocatorError> {
+        Ok(self.node().await?.get_inner_text().await)
+    }
+
+    pub async fn text_content(&self) -> Result<String, LocatorError> {
+        Ok(self.node().await?.get_text_content().await)
+    }
+
+    pub async fn html(&self) -> Result<String, LocatorError> {
+        Ok(self.node().await?.get_html().await)
+    }
+
+    pub async fn attribute(&self, name: &str) -> Result<Option<serde_json::Value>, LocatorError> {
+        Ok(self.node().await?.get_attribute(name))
+    }
+
+    pub async fn is_visible(&self) -> Result<bool, LocatorError> {
+        match self.all().await?.into_iter().next() {
+            Some(node) => Ok(node.is_visible().await?),
+            None => Ok(false),
+        }
+    }
+
+    pub async fn scroll_into_view(&self) -> Result<(), LocatorError> {
+        Ok(self.node().await?.scroll_into_view().await?)
+    }
+
+    /// Captures the element. Returns base64 PNG data.
+    pub async fn screenshot(&self) -> Result<String, LocatorError> {
+        Ok(self.node().await?.screenshot().await?)
+    }
+
+    // ── Internals ────────────────────────────────────────────────────────────
+
+    async fn page(&self) -> Result<Page<T, N>, LocatorError> {
+        match &self.scope {
+            Scope::Page(page) => Ok(page.clone()),
+            Scope::Frame(frame) => Ok(frame.frame().await?.page().clone()),
+        }
+    }
+
+    fn last_locator(&self) -> &BidiLocator {
+        self.steps
+            .iter()
+            .rev()
+            .find_map(|step| match step {
+                Step::Locate(locator) => Some(locator),
+                _ => None,
+            })
+            .expect("a locator always starts with a locate step")
+    }
+
+    async fn resolve(&self, page: &Page<T, N>) -> Result<Vec<NodeRemoteValue>, LocatorError> {
+        let last_locate = self
+            .steps
+            .iter()
+            .rposition(|step| matches!(step, Step::Locate(_)));
+        let mut nodes: Option<Vec<NodeRemoteValue>> = None;
+        for (index, step) in self.steps.iter().enumerate() {
+            nodes = Some(match step {
+                Step::Locate(locator) => {
+                    // `nth(i)` straight after a locate only needs the first i + 1 matches
+                    let max_node_count = match self.steps.get(index + 1) {
+                        Some(Step::Nth(nth)) => Some(*nth as u64 + 1),
+                        _ => None,
+                    };
+                    let serialization = if Some(index) == last_locate {
+                        default_node_serialization()
+                    } else {
+                        shallow_serialization()
+                    };
+                    locate(
+                        page,
+                        locator,
+                        nodes.as_deref(),
+                        max_node_count,
+                        serialization,
+                    )
+                    .await?
+                }
+                Step::Nth(nth) => nodes
+                    .unwrap_or_default()
+                    .into_iter()
+                    .skip(*nth)
+                    .take(1)
+                    .collect(),
+                Step::Last => nodes
+                    .unwrap_or_default()
+                    .into_iter()
+                    .last()
+                    .into_iter()
+                    .collect(),
+                Step::Filter(filter) => {
+                    let mut kept = Vec::new();
+                    for node in nodes.unwrap_or_default() {
+                        if passes(page, &node, filter).await? {
+                            kept.push(node);
+                        }
+                    }
+                    kept
+                }
+            });
+        }
+        Ok(nodes.unwrap_or_default())
+    }
+}
+
+impl<T: ConnectionTransport, N> std::fmt::Display for Locator<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, step) in self.steps.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" >> ")?;
+            }
+            match step {
+                Step::Locate(locator) => f.write_str(&describe(locator))?,
+                Step::Nth(nth) => write!(f, "nth={}", nth)?,
+                Step::Last => f.write_str("last")?,
+                Step::Filter(LocatorFilter::HasText(text)) => write!(f, "has_text={:?}", text)?,
+                Step::Filter(LocatorFilter::Has(locator)) => {
+                    write!(f, "has({})", describe(locator))?
+                }
+                Step::Filter(LocatorFilter::HasNot(locator)) => {
+                    write!(f, "has_not({})", describe(locator))?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn describe(locator: &BidiLocator) -> String {
+    match locator {
+        BidiLocator::CssLocator(css) => format!("css={:?}", css.value),
+        BidiLocator::XPathLocator(xpath) => format!("xpath={:?}", xpath.value),
+        BidiLocator::InnerTextLocator(text) => format!("text={:?}", text.value),
+        other => format!("{:?}", other),
+    }
+}
+
+/// Intermediate matches only need a shared id, not their subtree.
+fn shallow_serialization() -> SerializationOptions {
+    SerializationOptions {
+        max_dom_depth: Some(0),
+        max_object_depth: Some(0),
+        include_shadow_tree: None,
+    }
+}
+
+pub(crate) fn shared_reference(node: &NodeRemoteValue) -> Option<SharedReference> {
+    let mut builder = SharedReferenceBuilder::default().shared_id(node.shared_id.clone()?);
+    if let Some(handle) = node.handle.clone() {
+        builder = builder.handle(handle);
+    }
+    builder.build().ok()
+}
+
+/// Runs `locator` in the page, or below `start` when given. An empty `start` matches
+/// nothing.
+async fn locate<T, N>(
+    page: &Page<T, N>,
+    locator: &BidiLocator,
+    start: Option<&[NodeRemoteValue]>,
+    max_node_count: Option<u64>,
+    serialization: SerializationOptions,
+) -> Result<Vec<NodeRemoteValue>, LocatorError>
+where
+    T: ConnectionTransport + Send + Sync + 'static,
+    N: FromBidiNode<T>,
+{
+    let mut builder = LocateNodesBuilder::default()
+        .context(page.id().clone())
+        .locator(locator.clone())
+        .serialization_options(serialization);
+    if let Some(start) = start {
+        let start_nodes: Vec<SharedReference> = start.iter().filter_map(shared_reference).collect();
+        if start_nodes.is_empty() {
+            return Ok(Vec::new());
+        }
+        builder = builder.start_nodes(start_nodes);
+    }
+    if let Some(max_node_count) = max_node_count {
+        builder = builder.max_node_count(max_node_count);
+    }
+    let result = drivers::find_nodes(page.session(), builder.build().unwrap()).await?;
+    Ok(result.nodes)
+}
+
+async fn passes<T, N>(
+    page: &Page<T, N>,
+    node: &NodeRemoteValue,
+    filter: &LocatorFilter,
+) -> Result<bool, LocatorError>
+where
+    T: ConnectionTransport + Send + Sync + 'static,
+    N: FromBidiNode<T>,
+{
+    match filter {
+        LocatorFilter::HasText(text) => has_text(page, node, text).await,
+        LocatorFilter::Has(locator) => Ok(!locate(
+            page,
+            locator,
+            Some(std::slice::from_ref(node)),
+            Some(1),
+            shallow_serialization(),
+        )
+        .await?
+        .is_empty()),
+        LocatorFilter::HasNot(locator) => Ok(locate(
+            page,
+            locator,
+            Some(std::slice::from_ref(node)),
+            Some(1),
+            shallow_serialization(),
+        )
+        .await?
+        .is_empty()),
+    }
+}
+
+async fn has_text<T, N>(
+    page: &Page<T, N>,
+    node: &NodeRemoteValue,
+    text: &str,
+) -> Result<bool, LocatorError>
+where
+    T: ConnectionTransport + Send + Sync + 'static,
+    N: FromBidiNode<T>,
+{
+    let Some(reference) = shared_reference(node) else {
+        return Ok(false);
+    };
+    let script = format!(
+        "function() {{ return (this.innerText ?? this.textContent ?? '').includes({}); }}",
+        serde_json::Value::from(text)
+    );
+    let command = CallFunctionBuilder::default()
+        .function_declaration(script)
+        .await_promise(false)
+        .target(Target::ContextTarget(ContextTarget::new(page.id().clone())))
+        .this(RemoteReference::from(reference))
+        .build()
+        .unwrap();
+    let result = drivers::call_function(page.session(), command).await?;
+    Ok(matches!(
+        result.result,
+        RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::BooleanValue(ref value))
+            if value.value
+    ))
+}
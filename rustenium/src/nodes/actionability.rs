@@ -0,0 +1,158 @@
+use crate::error::node::NodeActionError;
+use crate::nodes::NodePosition;
+use crate::nodes::node::ActionabilityOptions;
+use serde::Deserialize;
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::{Instant, sleep};
+
+/// How long an action waits for its node to become actionable unless
+/// [`ActionabilityOptions::timeout`] says otherwise.
+pub(crate) const DEFAULT_ACTIONABILITY_TIMEOUT: Duration = Duration::from_secs(4);
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Scrolls `this` into view and reports whether it can be acted on, as JSON. The element is
+/// stable when its bounding box is unchanged across an animation frame, and it is the hit
+/// target when `elementFromPoint` at its centre returns it or one of its descendants.
+const ACTIONABILITY_SCRIPT: &str = r#"async function(checkEnabled, checkHitTarget) {
+    const report = (state, extra) => JSON.stringify(Object.assign({ state }, extra));
+    if (!this.isConnected) return report('detached');
+    this.scrollIntoView({ block: 'center', inline: 'center', behavior: 'instant' });
+    const before = this.getBoundingClientRect();
+    await new Promise((resolve) => {
+        requestAnimationFrame(() => resolve());
+        setTimeout(resolve, 100);
+    });
+    if (!this.isConnected) return report('detached');
+    const rect = this.getBoundingClientRect();
+    if (rect.x !== before.x || rect.y !== before.y ||
+        rect.width !== before.width || rect.height !== before.height) {
+        return report('unstable');
+    }
+    const style = window.getComputedStyle(this);
+    if (rect.width <= 0 || rect.height <= 0 || style.visibility === 'hidden' ||
+        style.display === 'none' || style.opacity === '0') {
+        return report('notVisible');
+    }
+    if (checkEnabled && ((this.matches && this.matches(':disabled')) ||
+        this.closest('[aria-disabled="true"]'))) {
+        return report('disabled');
+    }
+    const x = rect.x + rect.width / 2;
+    const y = rect.y + rect.height / 2;
+    if (checkHitTarget) {
+        const root = this.getRootNode();
+        const hit = (root.elementFromPoint ? root : document).elementFromPoint(x, y);
+        if (!hit) return report('notVisible');
+        let node = hit;
+        while (node && node !== this) {
+            node = node.parentNode || node.host;
+        }
+        if (!node) {
+            const id = hit.id ? '#' + hit.id : '';
+            const classes = Array.from(hit.classList).map((name) => '.' + name).join('');
+            return report('obscured', { by: hit.localName + id + classes });
+        }
+    }
+    const scrollX = window.pageXOffset || document.documentElement.scrollLeft;
+    const scrollY = window.pageYOffset || document.documentElement.scrollTop;
+    return report('ready', {
+        x: rect.x,
+        y: rect.y,
+        width: rect.width,
+        height: rect.height,
+        scroll_x: rect.x + scrollX,
+        scroll_y: rect.y + scrollY,
+    });
+}"#;
+
+/// Which checks an action needs on top of attached, visible and stable.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Checks {
+    enabled: bool,
+    hit_target: bool,
+}
+
+impl Checks {
+    /// Moving the pointer onto the node.
+    pub(crate) const HOVER: Checks = Checks {
+        enabled: false,
+        hit_target: true,
+    };
+    /// Clicking the node.
+    pub(crate) const CLICK: Checks = Checks {
+        enabled: true,
+        hit_target: true,
+    };
+    /// Focusing the node and typing into it.
+    pub(crate) const INPUT: Checks = Checks {
+        enabled: true,
+        hit_target: false,
+    };
+
+    /// A function declaration that runs the actionability script on `this` with these checks.
+    pub(crate) fn function_declaration(&self) -> String {
+        format!(
+            "function() {{ return ({}).call(this, {}, {}); }}",
+            ACTIONABILITY_SCRIPT, self.enabled, self.hit_target
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+enum Report {
+    Ready {
+        #[serde(flatten)]
+        position: NodePosition,
+    },
+    Detached,
+    Unstable,
+    NotVisible,
+    Disabled,
+    Obscured {
+        by: String,
+    },
+}
+
+/// Turns the script's JSON report into the node's fresh position or the failed check. The
+/// script always reports while `this` is an element, so anything else means it no longer is.
+// Only builds the small variants; the large ones come from the callers' commands
+#[allow(clippy::result_large_err)]
+pub(crate) fn parse_report(json: &str) -> Result<NodePosition, NodeActionError> {
+    let report: Report = serde_json::from_str(json).map_err(|_| NodeActionError::Detached)?;
+    match report {
+        Report::Ready { position } => Ok(position),
+        Report::Detached => Err(NodeActionError::Detached),
+        Report::Unstable => Err(NodeActionError::NotStable),
+        Report::NotVisible => Err(NodeActionError::NotVisible),
+        Report::Disabled => Err(NodeActionError::Disabled),
+        Report::Obscured { by } => Err(NodeActionError::Obscured { by }),
+    }
+}
+
+/// Runs `check` until it succeeds, fails for a reason waiting cannot fix, or the timeout in
+/// `options` elapses. A timeout returns the last failed check.
+pub(crate) async fn wait_until_actionable<F, Fut>(
+    options: &ActionabilityOptions,
+    mut check: F,
+) -> Result<NodePosition, NodeActionError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<NodePosition, NodeActionError>>,
+{
+    let timeout = options.timeout.unwrap_or(DEFAULT_ACTIONABILITY_TIMEOUT);
+    let start = Instant::now();
+    loop {
+        match check().await {
+            Err(
+                NodeActionError::NotVisible
+                | NodeActionError::NotStable
+                | NodeActionError::Disabled
+                | NodeActionError::Obscured { .. },
+            ) if start.elapsed() < timeout => sleep(POLL_INTERVAL).await,
+            result => return result,
+        }
+    }
+}
@@ -1,15 +1,19 @@
 use crate::error::bidi::{
     EvaluateResultError, InvalidPositionError, MouseInputError, ScreenshotError,
 };
+use crate::error::node::{BidiNodeActionError, NodeActionError, SetFilesError};
 use crate::input::Point;
 use crate::input::{Mouse, MouseClickOptions, MouseMoveOptions};
 use crate::nodes::NodePosition;
-use crate::nodes::node::{NodeScreenShotOptions, NodeType};
+use crate::nodes::actionability::{Checks, parse_report, wait_until_actionable};
+use crate::nodes::files::{file_input_check, parse_file_input_report};
+use crate::nodes::node::{ActionabilityOptions, NodeScreenShotOptions, NodeType};
 use rustenium_bidi_definitions::Command;
-use rustenium_bidi_definitions::base::CommandResponse;
+use rustenium_bidi_definitions::base::{CommandResponse, ErrorCode};
 use rustenium_bidi_definitions::browsing_context::command_builders::CaptureScreenshotBuilder;
 use rustenium_bidi_definitions::browsing_context::results::CaptureScreenshotResult;
 use rustenium_bidi_definitions::browsing_context::types::{BrowsingContext, Locator};
+use rustenium_bidi_definitions::input::command_builders::SetFilesBuilder;
 use rustenium_bidi_definitions::script::command_builders::CallFunctionBuilder;
 use rustenium_bidi_definitions::script::results::{CallFunctionResult, EvaluateResult};
 use rustenium_bidi_definitions::script::type_builders::{
@@ -35,6 +39,7 @@ pub(crate) struct BidiNode<
     pub position: Option<NodePosition>,
     pub session: Option<Arc<Mutex<BidiSession<T>>>>,
     pub context_id: BrowsingContext,
+    pub actionability: ActionabilityOptions,
 }
 
 impl<T: ConnectionTransport> std::fmt::Debug for BidiNode<T> {
@@ -82,6 +87,7 @@ impl<T: ConnectionTransport> BidiNode<T> {
             position: None,
             session: Some(session),
             context_id,
+            actionability: ActionabilityOptions::default(),
         }
     }
 
@@ -223,6 +229,56 @@ impl<T: ConnectionTransport> BidiNode<T> {
         }
     }
 
+    /// Waits until the node passes `checks` and stores the position it was found at. Does
+    /// nothing when the checks are forced off.
+    pub async fn wait_for_actionable(&mut self, checks: Checks) -> Result<(), NodeActionError> {
+        if self.actionability.force {
+            return Ok(());
+        }
+        let position =
+            wait_until_actionable(&self.actionability, || self.actionability_report(checks))
+                .await?;
+        self.position = Some(position);
+        Ok(())
+    }
+
+    async fn actionability_report(&self, checks: Checks) -> Result<NodePosition, NodeActionError> {
+        let remote_reference = self
+            .shared_reference()
+            .ok_or(EvaluateResultError::NoSharedId)?;
+
+        let command = CallFunctionBuilder::default()
+            .function_declaration(checks.function_declaration())
+            .await_promise(true)
+            .target(self.context_target())
+            .this(remote_reference)
+            .build()
+            .unwrap();
+
+        let response = match self.send_command(command).await {
+            Ok(response) => response,
+            Err(SessionSendError::ErrorResponse(error))
+                if matches!(error.error, ErrorCode::NoSuchNode) =>
+            {
+                return Err(NodeActionError::Detached);
+            }
+            Err(error) => return Err(CommandResultError::SessionSendError(error).into()),
+        };
+
+        let result: CallFunctionResult = response.result.try_into().unwrap();
+        match result {
+            EvaluateResult::EvaluateResultSuccess(success) => match success.result {
+                RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::StringValue(sv)) => {
+                    parse_report(&sv.value)
+                }
+                _ => Err(NodeActionError::Detached),
+            },
+            EvaluateResult::EvaluateResultException(exception) => {
+                Err(BidiNodeActionError::Exception(exception).into())
+            }
+        }
+    }
+
     pub async fn get_inner_text(&self) -> Result<String, EvaluateResultError> {
         let remote_reference = self
             .shared_reference()
@@ -457,6 +513,57 @@ impl<T: ConnectionTransport> BidiNode<T> {
         keyboard.type_text(&text, &self.context_id, None).await
     }
 
+    /// Sets the files of this `<input type=file>` to the absolute paths in `files`.
+    pub async fn set_input_files(&self, files: Vec<String>) -> Result<(), SetFilesError> {
+        let (Some(remote_reference), Some(shared_id)) =
+            (self.shared_reference(), self._raw_node.shared_id.clone())
+        else {
+            return Err(NodeActionError::Bidi(BidiNodeActionError::NoSharedId).into());
+        };
+
+        let command = CallFunctionBuilder::default()
+            .function_declaration(file_input_check(files.len()))
+            .await_promise(false)
+            .target(self.context_target())
+            .this(remote_reference)
+            .build()
+            .unwrap();
+        let response = self
+            .send_command(command)
+            .await
+            .map_err(|e| NodeActionError::from(CommandResultError::SessionSendError(e)))?;
+        let report = match CallFunctionResult::try_from(response.result) {
+            Ok(EvaluateResult::EvaluateResultSuccess(success)) => match success.result {
+                RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::StringValue(sv)) => {
+                    sv.value
+                }
+                _ => String::new(),
+            },
+            Ok(EvaluateResult::EvaluateResultException(exception)) => {
+                return Err(
+                    NodeActionError::Bidi(BidiNodeActionError::Exception(exception)).into(),
+                );
+            }
+            Err(_) => String::new(),
+        };
+        parse_file_input_report(&report, files.len())?;
+
+        let mut element = SharedReferenceBuilder::default().shared_id(shared_id);
+        if let Some(handle) = self._raw_node.handle.clone() {
+            element = element.handle(handle);
+        }
+        let command = SetFilesBuilder::default()
+            .context(self.context_id.clone())
+            .element(element.build().unwrap())
+            .files(files)
+            .build()
+            .unwrap();
+        self.send_command(command)
+            .await
+            .map_err(|e| NodeActionError::from(CommandResultError::SessionSendError(e)))?;
+        Ok(())
+    }
+
     pub async fn mouse_move<M: Mouse>(
         &mut self,
         mouse: &M,
@@ -1,13 +1,16 @@
 use crate::error::node::{
     CdpNodeActionError, CdpNodeInputError, CdpNodeScreenshotError, NodeActionError, NodeInputError,
-    NodeMouseError, NodeScreenshotError,
+    NodeMouseError, NodeScreenshotError, SetFilesError,
 };
 use crate::input::{Mouse, MouseClickOptions, MouseMoveOptions};
 use crate::nodes::NodePosition;
-use crate::nodes::node::{NodeScreenShotOptions, NodeType};
+use crate::nodes::actionability::{Checks, parse_report, wait_until_actionable};
+use crate::nodes::files::{file_input_check, parse_file_input_report};
+use crate::nodes::node::{ActionabilityOptions, NodeScreenShotOptions, NodeType};
 use rustenium_bidi_definitions::browsing_context::types::BrowsingContext;
 use rustenium_cdp_definitions::browser_protocol::dom::commands::{
     Focus, GetBoxModel, GetOuterHtml, RemoveNode, ResolveNode, ScrollIntoViewIfNeeded,
+    SetFileInputFiles,
 };
 use rustenium_cdp_definitions::browser_protocol::dom::results::{
     GetBoxModelResult, GetOuterHtmlResult, ResolveNodeResult,
@@ -21,7 +24,7 @@ use rustenium_cdp_definitions::browser_protocol::page::results::CaptureScreensho
 use rustenium_cdp_definitions::browser_protocol::page::types::Viewport;
 use rustenium_cdp_definitions::js_protocol::runtime::commands::CallFunctionOn;
 use rustenium_cdp_definitions::js_protocol::runtime::results::CallFunctionOnResult;
-use rustenium_core::error::CdpCommandResultError;
+use rustenium_core::error::{CdpCommandResultError, CdpSessionSendError};
 use rustenium_core::session::CdpSession;
 use rustenium_core::transport::ConnectionTransport;
 use std::collections::HashMap;
@@ -37,6 +40,7 @@ pub struct CdpNode<T: ConnectionTransport = rustenium_core::transport::Websocket
     pub attributes: HashMap<String, String>,
     pub children: Vec<CdpNode<T>>,
     pub position: Option<NodePosition>,
+    pub actionability: ActionabilityOptions,
     pub(crate) session: Arc<TokioMutex<CdpSession<T>>>,
 }
 
@@ -84,6 +88,7 @@ impl<T: ConnectionTransport> CdpNode<T> {
             attributes,
             children,
             position: None,
+            actionability: ActionabilityOptions::default(),
             session,
         }
     }
@@ -202,6 +207,33 @@ impl<T: ConnectionTransport> CdpNode<T> {
         }
     }
 
+    /// Waits until the node passes `checks` and stores the position it was found at. Does
+    /// nothing when the checks are forced off.
+    pub(crate) async fn wait_for_actionable(
+        &mut self,
+        checks: Checks,
+    ) -> Result<(), NodeActionError> {
+        if self.actionability.force {
+            return Ok(());
+        }
+        let position =
+            wait_until_actionable(&self.actionability, || self.actionability_report(checks))
+                .await?;
+        self.position = Some(position);
+        Ok(())
+    }
+
+    async fn actionability_report(&self, checks: Checks) -> Result<NodePosition, NodeActionError> {
+        match self.eval_with(&checks.function_declaration(), true).await {
+            Ok(json) => parse_report(&json),
+            // resolveNode and callFunctionOn only reject a node that no longer exists
+            Err(NodeActionError::Cdp(CdpNodeActionError::Command(
+                CdpCommandResultError::SessionSendError(CdpSessionSendError::ErrorResponse(_)),
+            ))) => Err(NodeActionError::Detached),
+            Err(error) => Err(error),
+        }
+    }
+
     pub async fn delete(&self) -> Result<(), NodeActionError> {
         let cmd = RemoveNode::builder()
             .node_id(self.node_id())
@@ -274,6 +306,15 @@ impl<T: ConnectionTransport> CdpNode<T> {
 
     /// Resolve this node to a remote JS object, then evaluate `fn_body` on it.
     async fn eval(&self, fn_body: &str) -> Result<String, NodeActionError> {
+        self.eval_with(fn_body, false).await
+    }
+
+    /// Like [`eval`](Self::eval), optionally awaiting the promise `fn_body` returns.
+    async fn eval_with(
+        &self,
+        fn_body: &str,
+        await_promise: bool,
+    ) -> Result<String, NodeActionError> {
         let resolve_cmd = ResolveNode::builder()
             .backend_node_id(self.backend_node_id())
             .build();
@@ -291,6 +332,7 @@ impl<T: ConnectionTransport> CdpNode<T> {
             .function_declaration(fn_body)
             .object_id(object_id)
             .return_by_value(true)
+            .await_promise(await_promise)
             .build()
             .unwrap();
 
@@ -427,6 +469,22 @@ impl<T: ConnectionTransport> CdpNode<T> {
             .map_err(|error| NodeMouseError::Driver(error.to_string()))
     }
 
+    // ── File input ────────────────────────────────────────────────────────
+
+    /// Sets the files of this `<input type=file>` to the absolute paths in `files` via
+    /// `DOM.setFileInputFiles`.
+    pub async fn set_input_files(&self, files: Vec<String>) -> Result<(), SetFilesError> {
+        let report = self.eval(&file_input_check(files.len())).await?;
+        parse_file_input_report(&report, files.len())?;
+        let cmd = SetFileInputFiles::builder()
+            .files(files)
+            .backend_node_id(self.backend_node_id())
+            .build()
+            .unwrap();
+        self.send(cmd).await.map_err(NodeActionError::from)?;
+        Ok(())
+    }
+
     // ── Keyboard input ────────────────────────────────────────────────────
 
     /// Focus this node and insert `text` via `Input.insertText`.
@@ -3,6 +3,7 @@ use rustenium_cdp_definitions::browser_protocol::accessibility::types::{
 };
 use rustenium_cdp_definitions::browser_protocol::dom::types::{Node as DomNode, NodeId};
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -11,12 +12,18 @@ use rustenium_bidi_definitions::script::types::{Handle, NodeRemoteValue, SharedI
 use rustenium_core::transport::ConnectionTransport;
 use rustenium_core::{BidiSession, CdpSession};
 
-use crate::error::node::{NodeActionError, NodeInputError, NodeMouseError, NodeScreenshotError};
+use crate::error::node::{
+    NodeActionError, NodeInputError, NodeMouseError, NodeScreenshotError, SetFilesError,
+};
 use crate::input::{BidiKeyboard, BidiMouse, Keyboard, Mouse, MouseClickOptions, MouseMoveOptions};
 use crate::nodes::NodePosition;
+use crate::nodes::actionability::Checks;
 use crate::nodes::bidi::node::BidiNode;
 use crate::nodes::cdp::CdpNode;
-use crate::nodes::node::{Node, NodeScreenShotOptions, NodeType};
+use crate::nodes::files::resolve_files;
+use crate::nodes::node::{
+    ActionabilityOptions, FromBidiNode, Node, NodeScreenShotOptions, NodeType,
+};
 
 // Bidi and Cdp variants are intentionally near-equal in size — both hold a node
 // plus the same Arc<M>/Arc<K> handles — so boxing one wouldn't measurably
@@ -138,6 +145,19 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
     }
 }
 
+impl<T: ConnectionTransport + Send + Sync + 'static> FromBidiNode<T> for ChromeNode<T> {
+    fn from_bidi_node(
+        raw_node: NodeRemoteValue,
+        locator: Locator,
+        session: Arc<Mutex<BidiSession<T>>>,
+        context: BrowsingContext,
+        mouse: Arc<BidiMouse<T>>,
+        keyboard: Arc<BidiKeyboard<T>>,
+    ) -> Self {
+        ChromeNode::from_bidi(raw_node, locator, session, context, mouse, keyboard)
+    }
+}
+
 impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Send + Sync + 'static>
     Node for ChromeNode<T, M, K>
 {
@@ -257,6 +277,20 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
         }
     }
 
+    fn set_actionability(&mut self, options: ActionabilityOptions) {
+        match &mut self.inner {
+            ChromeNodeInner::Bidi { node, .. } => node.actionability = options,
+            ChromeNodeInner::Cdp { node, .. } => node.actionability = options,
+        }
+    }
+
+    async fn wait_for_actionable(&mut self) -> Result<(), NodeActionError> {
+        match &mut self.inner {
+            ChromeNodeInner::Bidi { node, .. } => node.wait_for_actionable(Checks::CLICK).await,
+            ChromeNodeInner::Cdp { node, .. } => node.wait_for_actionable(Checks::CLICK).await,
+        }
+    }
+
     async fn scroll_into_view(&self) -> Result<(), NodeActionError> {
         match &self.inner {
             ChromeNodeInner::Bidi { node, .. } => {
@@ -292,12 +326,14 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
         match &mut self.inner {
             ChromeNodeInner::Bidi { node, mouse, .. } => {
                 let mouse = mouse.clone();
+                node.wait_for_actionable(Checks::HOVER).await?;
                 node.mouse_move(mouse.as_ref(), MouseMoveOptions::default())
                     .await
                     .map_err(NodeMouseError::from)
             }
             ChromeNodeInner::Cdp { node, mouse, .. } => {
                 let mouse = mouse.clone();
+                node.wait_for_actionable(Checks::HOVER).await?;
                 node.mouse_move(mouse.as_ref(), MouseMoveOptions::default())
                     .await
             }
@@ -311,12 +347,14 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
         match &mut self.inner {
             ChromeNodeInner::Bidi { node, mouse, .. } => {
                 let mouse = mouse.clone();
+                node.wait_for_actionable(Checks::HOVER).await?;
                 node.mouse_move(mouse.as_ref(), options)
                     .await
                     .map_err(NodeMouseError::from)
             }
             ChromeNodeInner::Cdp { node, mouse, .. } => {
                 let mouse = mouse.clone();
+                node.wait_for_actionable(Checks::HOVER).await?;
                 node.mouse_move(mouse.as_ref(), options).await
             }
         }
@@ -326,12 +364,14 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
         match &mut self.inner {
             ChromeNodeInner::Bidi { node, mouse, .. } => {
                 let mouse = mouse.clone();
+                node.wait_for_actionable(Checks::CLICK).await?;
                 node.mouse_click(mouse.as_ref(), MouseClickOptions::default())
                     .await
                     .map_err(NodeMouseError::from)
             }
             ChromeNodeInner::Cdp { node, mouse, .. } => {
                 let mouse = mouse.clone();
+                node.wait_for_actionable(Checks::CLICK).await?;
                 node.mouse_click(mouse.as_ref(), MouseClickOptions::default())
                     .await
             }
@@ -345,12 +385,14 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
         match &mut self.inner {
             ChromeNodeInner::Bidi { node, mouse, .. } => {
                 let mouse = mouse.clone();
+                node.wait_for_actionable(Checks::CLICK).await?;
                 node.mouse_click(mouse.as_ref(), options)
                     .await
                     .map_err(NodeMouseError::from)
             }
             ChromeNodeInner::Cdp { node, mouse, .. } => {
                 let mouse = mouse.clone();
+                node.wait_for_actionable(Checks::CLICK).await?;
                 node.mouse_click(mouse.as_ref(), options).await
             }
         }
@@ -388,6 +430,7 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
         match &mut self.inner {
             ChromeNodeInner::Bidi { node, keyboard, .. } => {
                 let keyboard = keyboard.clone();
+                node.wait_for_actionable(Checks::INPUT).await?;
                 node.type_text(keyboard.as_ref(), text)
                     .await
                     .map_err(NodeInputError::from)
@@ -397,6 +440,14 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
             }
         }
     }
+
+    async fn set_input_files<P: AsRef<Path>>(&self, paths: &[P]) -> Result<(), SetFilesError> {
+        let files = resolve_files(paths)?;
+        match &self.inner {
+            ChromeNodeInner::Bidi { node, .. } => node.set_input_files(files).await,
+            ChromeNodeInner::Cdp { node, .. } => node.set_input_files(files).await,
+        }
+    }
 }
 
 /// A node in the browser's accessibility tree, built from CDP `Accessibility.getFullAXTree`.
@@ -0,0 +1,40 @@
+use crate::error::node::SetFilesError;
+use std::path::Path;
+
+/// Reports whether `this` can take `count` files: `ok`, `notFileInput` or `notMultiple`.
+const FILE_INPUT_SCRIPT: &str = r#"function(count) {
+    if (!this || this.localName !== 'input' || this.type !== 'file') return 'notFileInput';
+    if (count > 1 && !this.multiple) return 'notMultiple';
+    return 'ok';
+}"#;
+
+/// A function declaration that checks `this` can take `count` files.
+pub(crate) fn file_input_check(count: usize) -> String {
+    format!(
+        "function() {{ return ({}).call(this, {}); }}",
+        FILE_INPUT_SCRIPT, count
+    )
+}
+
+/// Turns the check's report into the reason the files cannot be set, if any.
+pub(crate) fn parse_file_input_report(report: &str, count: usize) -> Result<(), SetFilesError> {
+    match report {
+        "ok" => Ok(()),
+        "notMultiple" => Err(SetFilesError::NotMultiple(count)),
+        _ => Err(SetFilesError::NotFileInput),
+    }
+}
+
+/// The absolute paths the browser reads `paths` from. Fails on the first that does not exist.
+pub(crate) fn resolve_files<P: AsRef<Path>>(paths: &[P]) -> Result<Vec<String>, SetFilesError> {
+    paths
+        .iter()
+        .map(|path| {
+            let path = path.as_ref();
+            match std::path::absolute(path) {
+                Ok(absolute) if absolute.exists() => Ok(absolute.to_string_lossy().into_owned()),
+                _ => Err(SetFilesError::FileNotFound(path.to_path_buf())),
+            }
+        })
+        .collect()
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -7,11 +8,17 @@ use rustenium_bidi_definitions::script::types::{Handle, NodeRemoteValue, SharedI
 use rustenium_core::BidiSession;
 use rustenium_core::transport::ConnectionTransport;
 
-use crate::error::node::{NodeActionError, NodeInputError, NodeMouseError, NodeScreenshotError};
+use crate::error::node::{
+    NodeActionError, NodeInputError, NodeMouseError, NodeScreenshotError, SetFilesError,
+};
 use crate::input::{BidiKeyboard, BidiMouse, Keyboard, Mouse, MouseClickOptions, MouseMoveOptions};
 use crate::nodes::NodePosition;
+use crate::nodes::actionability::Checks;
 use crate::nodes::bidi::node::BidiNode;
-use crate::nodes::node::{Node, NodeScreenShotOptions, NodeType};
+use crate::nodes::files::resolve_files;
+use crate::nodes::node::{
+    ActionabilityOptions, FromBidiNode, Node, NodeScreenShotOptions, NodeType,
+};
 
 pub struct FirefoxNode<
     T: ConnectionTransport,
@@ -74,6 +81,7 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
     }
 
     pub async fn mouse_move(&mut self) -> Result<(), NodeMouseError> {
+        self.bidi_node.wait_for_actionable(Checks::HOVER).await?;
         self.bidi_node
             .mouse_move(self.mouse.as_ref(), MouseMoveOptions::default())
             .await
@@ -84,6 +92,7 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
         &mut self,
         options: MouseMoveOptions,
     ) -> Result<(), NodeMouseError> {
+        self.bidi_node.wait_for_actionable(Checks::HOVER).await?;
         self.bidi_node
             .mouse_move(self.mouse.as_ref(), options)
             .await
@@ -91,6 +100,7 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
     }
 
     pub async fn mouse_click(&mut self) -> Result<(), NodeMouseError> {
+        self.bidi_node.wait_for_actionable(Checks::CLICK).await?;
         self.bidi_node
             .mouse_click(self.mouse.as_ref(), MouseClickOptions::default())
             .await
@@ -101,6 +111,7 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
         &mut self,
         options: MouseClickOptions,
     ) -> Result<(), NodeMouseError> {
+        self.bidi_node.wait_for_actionable(Checks::CLICK).await?;
         self.bidi_node
             .mouse_click(self.mouse.as_ref(), options)
             .await
@@ -108,6 +119,19 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
     }
 }
 
+impl<T: ConnectionTransport + Send + Sync + 'static> FromBidiNode<T> for FirefoxNode<T> {
+    fn from_bidi_node(
+        raw_node: NodeRemoteValue,
+        locator: Locator,
+        session: Arc<Mutex<BidiSession<T>>>,
+        context: BrowsingContext,
+        mouse: Arc<BidiMouse<T>>,
+        keyboard: Arc<BidiKeyboard<T>>,
+    ) -> Self {
+        FirefoxNode::from_bidi(raw_node, locator, session, context, mouse, keyboard)
+    }
+}
+
 impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Send + Sync + 'static>
     Node for FirefoxNode<T, M, K>
 {
@@ -168,6 +192,14 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
         self.bidi_node.position = Some(position);
     }
 
+    fn set_actionability(&mut self, options: ActionabilityOptions) {
+        self.bidi_node.actionability = options;
+    }
+
+    async fn wait_for_actionable(&mut self) -> Result<(), NodeActionError> {
+        self.bidi_node.wait_for_actionable(Checks::CLICK).await
+    }
+
     async fn scroll_into_view(&self) -> Result<(), NodeActionError> {
         self.bidi_node
             .scroll_into_view()
@@ -187,6 +219,7 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
     }
 
     async fn mouse_move(&mut self) -> Result<(), NodeMouseError> {
+        self.bidi_node.wait_for_actionable(Checks::HOVER).await?;
         self.bidi_node
             .mouse_move(self.mouse.as_ref(), MouseMoveOptions::default())
             .await
@@ -197,6 +230,7 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
         &mut self,
         options: MouseMoveOptions,
     ) -> Result<(), NodeMouseError> {
+        self.bidi_node.wait_for_actionable(Checks::HOVER).await?;
         self.bidi_node
             .mouse_move(self.mouse.as_ref(), options)
             .await
@@ -204,6 +238,7 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
     }
 
     async fn mouse_click(&mut self) -> Result<(), NodeMouseError> {
+        self.bidi_node.wait_for_actionable(Checks::CLICK).await?;
         self.bidi_node
             .mouse_click(self.mouse.as_ref(), MouseClickOptions::default())
             .await
@@ -214,6 +249,7 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
         &mut self,
         options: MouseClickOptions,
     ) -> Result<(), NodeMouseError> {
+        self.bidi_node.wait_for_actionable(Checks::CLICK).await?;
         self.bidi_node
             .mouse_click(self.mouse.as_ref(), options)
             .await
@@ -238,9 +274,14 @@ impl<T: ConnectionTransport, M: Mouse + Send + Sync + 'static, K: Keyboard + Sen
     }
 
     async fn type_text(&mut self, text: String) -> Result<(), NodeInputError> {
+        self.bidi_node.wait_for_actionable(Checks::INPUT).await?;
         self.bidi_node
             .type_text(self.keyboard.as_ref(), text)
             .await
             .map_err(NodeInputError::from)
     }
+
+    async fn set_input_files<P: AsRef<Path>>(&self, paths: &[P]) -> Result<(), SetFilesError> {
+        self.bidi_node.set_input_files(resolve_files(paths)?).await
+    }
 }
@@ -1,10 +1,14 @@
+mod actionability;
 mod bidi;
 mod cdp;
 mod chrome;
+pub(crate) mod files;
 mod firefox;
 mod node;
 
 pub use cdp::CdpNode;
 pub use chrome::{AXNode, ChromeNode};
 pub use firefox::FirefoxNode;
-pub use node::{Node, NodePosition, NodeScreenShotOptions, NodeType};
+pub use node::{
+    ActionabilityOptions, FromBidiNode, Node, NodePosition, NodeScreenShotOptions, NodeType,
+};
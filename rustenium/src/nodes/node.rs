@@ -1,14 +1,22 @@
-use crate::error::node::{NodeActionError, NodeInputError, NodeMouseError, NodeScreenshotError};
-use crate::input::{MouseClickOptions, MouseMoveOptions};
+use crate::error::node::{
+    NodeActionError, NodeInputError, NodeMouseError, NodeScreenshotError, SetFilesError,
+};
+use crate::input::{BidiKeyboard, BidiMouse, MouseClickOptions, MouseMoveOptions};
 use rustenium_bidi_definitions::browsing_context::commands::CaptureScreenshotOrigin;
 use rustenium_bidi_definitions::browsing_context::types::ImageFormat;
 use rustenium_bidi_definitions::browsing_context::types::{BrowsingContext, Locator};
-use rustenium_bidi_definitions::script::types::{Handle, SharedId};
+use rustenium_bidi_definitions::script::types::{Handle, NodeRemoteValue, SharedId};
 use rustenium_cdp_definitions::browser_protocol::page::commands::CaptureScreenshotFormat;
+use rustenium_core::BidiSession;
+use rustenium_core::transport::ConnectionTransport;
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u16)]
@@ -76,6 +84,29 @@ pub struct NodeScreenShotOptions {
     pub save_path: Option<String>,
 }
 
+/// Controls the checks `mouse_move`, `mouse_click` and `type_text` run before acting. The
+/// node is scrolled into view and must be attached, visible and stable (its bounding box
+/// unchanged across an animation frame). Clicks and typing also need it enabled, and pointer
+/// input needs it to be the hit target at its centre.
+#[derive(Debug, Clone, Default)]
+pub struct ActionabilityOptions {
+    /// How long to wait for the checks to pass. Defaults to 4 seconds.
+    pub timeout: Option<Duration>,
+    /// Skip the checks and act on the last known position.
+    pub force: bool,
+}
+
+impl ActionabilityOptions {
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.timeout = Some(v);
+        self
+    }
+    pub fn force(mut self, v: bool) -> Self {
+        self.force = v;
+        self
+    }
+}
+
 pub trait Node {
     fn get_children_nodes(&self) -> &Vec<impl Node>;
 
@@ -105,6 +136,27 @@ pub trait Node {
 
     fn set_position(&mut self, position: NodePosition) -> ();
 
+    /// Replaces the actionability checks run before input on this node. Ignored by nodes
+    /// that run no checks, which is the default.
+    fn set_actionability(&mut self, options: ActionabilityOptions) {
+        let _ = options;
+    }
+
+    /// Scrolls the node into view and waits until it can be clicked: attached, visible,
+    /// stable, enabled and not covered by another element.
+    ///
+    /// The default only scrolls the node into view and checks that it is visible.
+    fn wait_for_actionable(&mut self) -> impl Future<Output = Result<(), NodeActionError>> {
+        async move {
+            self.scroll_into_view().await?;
+            if self.is_visible().await? {
+                Ok(())
+            } else {
+                Err(NodeActionError::NotVisible)
+            }
+        }
+    }
+
     fn scroll_into_view(&self) -> impl Future<Output = Result<(), NodeActionError>>;
 
     fn is_visible(&self) -> impl Future<Output = Result<bool, NodeActionError>>;
@@ -134,4 +186,30 @@ pub trait Node {
 
     /// Focuses the element and types the given text into it.
     fn type_text(&mut self, text: String) -> impl Future<Output = Result<(), NodeInputError>>;
+
+    /// Sets the files of an `<input type=file>` as if the user had picked them, firing its
+    /// `input` and `change` events. Every path must exist, and an input without `multiple`
+    /// takes one file at most. An empty list clears the selection.
+    ///
+    /// Nodes that can't set files return [`SetFilesError::Unsupported`].
+    fn set_input_files<P: AsRef<Path>>(
+        &self,
+        paths: &[P],
+    ) -> impl Future<Output = Result<(), SetFilesError>> {
+        let _ = paths;
+        async { Err(SetFilesError::Unsupported) }
+    }
+}
+
+/// A node that can be built from a BiDi `NodeRemoteValue` without borrowing the browser,
+/// so a [`Page`](crate::page::Page) can hand out nodes on its own.
+pub trait FromBidiNode<T: ConnectionTransport>: Node {
+    fn from_bidi_node(
+        raw_node: NodeRemoteValue,
+        locator: Locator,
+        session: Arc<Mutex<BidiSession<T>>>,
+        context: BrowsingContext,
+        mouse: Arc<BidiMouse<T>>,
+        keyboard: Arc<BidiKeyboard<T>>,
+    ) -> Self;
 }
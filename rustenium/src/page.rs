@@ -0,0 +1,1701 @@
+use crate::browsers::bidi_browser::default_node_serialization;
+use crate::browsers::bidi_browser::options::{
+    BrowserScreenshotOptions, EvaluateScriptOptions, FindNodesOptions, NavigateOptions,
+    WaitForNodesOptions,
+};
+use crate::browsers::cdp_browser::Selector;
+use crate::conduit::bidi::drivers;
+use crate::console::{ConsoleCollector, ConsoleMessage, PageError};
+use crate::dialog::{
+    Dialog, DialogKind, DialogResponder, FileChooser, FileChooserResponder, HandlerRegistration,
+};
+use crate::download::{Download, DownloadEvent, next_download};
+use crate::error::bidi::{
+    ConsoleError, ContextCloseError, EvaluateError, EvaluateResultError, ExposeFunctionError,
+    FindNodesError, FrameError, InputError, NavigateError, PopupError, ScreenshotError, WaitError,
+};
+use crate::error::dialog::{DialogError, FileChooserError};
+use crate::error::download::FileDownloadError;
+use crate::error::node::{NodeActionError, SetFilesError};
+use crate::error::pdf::PdfError;
+use crate::evaluate::{
+    EvaluateArgs, REPLY_SCRIPT, call_exposed, expose_declaration, exposed_function,
+    from_remote_value, to_local_value,
+};
+use crate::frame::{Frame, FrameLocator, collect_frames, top_level_context};
+use crate::input::{
+    BidiKeyboard, BidiMouse, HumanMouse, HumanTouchscreen, MouseClickOptions, MouseMoveOptions,
+    Point, Touchscreen,
+};
+use crate::locator::shared_reference;
+use crate::nodes::FromBidiNode;
+use crate::pdf::{self, PdfOptions};
+use crate::wait::{
+    BidiEventType, InFlightRequests, LoadState, SANDBOX, SelectorState, UrlMatcher, VISIBLE_SCRIPT,
+    race_trigger, unique_wait_id, wait_for_selector_script,
+};
+use rustenium_bidi_definitions::Event;
+use rustenium_bidi_definitions::browsing_context::command_builders::{
+    CaptureScreenshotBuilder, GetTreeBuilder, HandleUserPromptBuilder, LocateNodesBuilder,
+    NavigateBuilder,
+};
+use rustenium_bidi_definitions::browsing_context::events::{
+    BrowsingContextEvent, DomContentLoaded, DownloadEnd, DownloadWillBegin, FragmentNavigated,
+    HistoryUpdated, Load, NavigationFailed, UserPromptOpened,
+};
+use rustenium_bidi_definitions::browsing_context::results::{NavigateResult, PrintResult};
+use rustenium_bidi_definitions::browsing_context::types::{BrowsingContext, Locator};
+use rustenium_bidi_definitions::input::command_builders::SetFilesBuilder;
+use rustenium_bidi_definitions::input::events::{FileDialogOpened, InputEvent};
+use rustenium_bidi_definitions::log::events::{EntryAdded, LogEvent};
+use rustenium_bidi_definitions::log::types::Entry;
+use rustenium_bidi_definitions::network::events::{
+    BeforeRequestSent, FetchError, NetworkEvent, ResponseCompleted,
+};
+use rustenium_bidi_definitions::script::command_builders::{
+    AddPreloadScriptBuilder, CallFunctionBuilder, EvaluateBuilder, RemovePreloadScriptBuilder,
+};
+use rustenium_bidi_definitions::script::events::{Message, ScriptEvent};
+use rustenium_bidi_definitions::script::results::AddPreloadScriptResult;
+use rustenium_bidi_definitions::script::types::{
+    Channel, ChannelProperties, ChannelValue, ChannelValueType, ContextTarget,
+    EvaluateResultSuccess, NodeRemoteValue, PreloadScript, PrimitiveProtocolValue, RealmTarget,
+    RemoteReference, RemoteValue, ResultOwnership, Target,
+};
+use rustenium_bidi_definitions::session::results::SubscribeResult;
+use rustenium_bidi_definitions::session::types::{Subscription, UserPromptHandlerType};
+use rustenium_core::BidiSession;
+use rustenium_core::error::CommandResultError;
+use rustenium_core::events::BidiEventManagement;
+use rustenium_core::transport::ConnectionTransport;
+use serde::de::DeserializeOwned;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex as StdMutex, OnceLock, Weak};
+use std::time::Duration;
+use tokio::sync::Mutex as TokioMutex;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+/// What [`Page::expose_function`] registered for a name in a context, so exposing it again
+/// can take the previous function down first.
+#[derive(Debug)]
+pub(crate) struct ExposedChannel {
+    /// The `script.message` subscription answering calls.
+    subscription: Option<Subscription>,
+    /// The script installing the function in new documents.
+    script: PreloadScript,
+}
+
+/// Functions exposed through the pages of one session, by context and name.
+pub(crate) type ExposedFunctions = Arc<StdMutex<HashMap<(String, String), ExposedChannel>>>;
+
+/// A single browsing context (tab, window or popup) and everything needed to drive it.
+///
+/// Unlike the methods on [`BidiBrowser`](crate::browsers::BidiBrowser), which target the
+/// active context unless told otherwise, every method here acts on this page's context.
+/// Pages are cheap to clone and only take `&self`, so several can be driven concurrently
+/// from different tasks over the same session. Each page has its own mouse, keyboard and
+/// touchscreen.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::browsers::{BidiBrowser, chrome};
+/// use rustenium_macros::css;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut browser = chrome(None).await;
+/// let first = browser.active_page()?;
+/// let second = browser.create_context(false).await?;
+///
+/// let (a, b) = tokio::join!(
+///     first.navigate("https://example.com"),
+///     second.navigate("https://www.rust-lang.org"),
+/// );
+/// a?;
+/// b?;
+/// let headings = second.find_nodes(css!("h1")).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Page<T: ConnectionTransport, N> {
+    context: BrowsingContext,
+    session: Arc<TokioMutex<BidiSession<T>>>,
+    mouse: Arc<BidiMouse<T>>,
+    human_mouse: Arc<HumanMouse<BidiMouse<T>>>,
+    keyboard: Arc<BidiKeyboard<T>>,
+    touchscreen: Arc<Touchscreen<T>>,
+    human_touchscreen: Arc<HumanTouchscreen<T>>,
+    exposed: ExposedFunctions,
+    node: PhantomData<fn() -> N>,
+}
+
+impl<T: ConnectionTransport, N> Clone for Page<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context.clone(),
+            session: self.session.clone(),
+            mouse: self.mouse.clone(),
+            human_mouse: self.human_mouse.clone(),
+            keyboard: self.keyboard.clone(),
+            touchscreen: self.touchscreen.clone(),
+            human_touchscreen: self.human_touchscreen.clone(),
+            exposed: self.exposed.clone(),
+            node: PhantomData,
+        }
+    }
+}
+
+impl<T: ConnectionTransport, N> std::fmt::Debug for Page<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Page")
+            .field("context", &self.context)
+            .finish()
+    }
+}
+
+impl<T, N> Page<T, N>
+where
+    T: ConnectionTransport + Send + Sync + 'static,
+    N: FromBidiNode<T>,
+{
+    pub fn new(session: Arc<TokioMutex<BidiSession<T>>>, context: BrowsingContext) -> Self {
+        Self::with_exposed(session, ExposedFunctions::default(), context)
+    }
+
+    /// A page sharing the functions exposed through the other pages of its session.
+    pub(crate) fn with_exposed(
+        session: Arc<TokioMutex<BidiSession<T>>>,
+        exposed: ExposedFunctions,
+        context: BrowsingContext,
+    ) -> Self {
+        let touchscreen = Arc::new(Touchscreen::new(session.clone()));
+        Self {
+            context,
+            mouse: Arc::new(BidiMouse::new(session.clone())),
+            human_mouse: Arc::new(HumanMouse::new(BidiMouse::new(session.clone()))),
+            keyboard: Arc::new(BidiKeyboard::new(session.clone())),
+            human_touchscreen: Arc::new(HumanTouchscreen::new(touchscreen.clone())),
+            touchscreen,
+            session,
+            exposed,
+            node: PhantomData,
+        }
+    }
+
+    /// The browsing context this page is bound to.
+    pub fn id(&self) -> &BrowsingContext {
+        &self.context
+    }
+
+    pub(crate) fn session(&self) -> &Arc<TokioMutex<BidiSession<T>>> {
+        &self.session
+    }
+
+    pub(crate) fn exposed(&self) -> &ExposedFunctions {
+        &self.exposed
+    }
+
+    /// Wraps a node serialized from this page, sharing the page's input devices.
+    pub(crate) fn build_node(&self, raw_node: NodeRemoteValue, locator: Locator) -> N {
+        N::from_bidi_node(
+            raw_node,
+            locator,
+            self.session.clone(),
+            self.context.clone(),
+            self.mouse.clone(),
+            self.keyboard.clone(),
+        )
+    }
+
+    // ── Navigation ───────────────────────────────────────────────────────────
+
+    pub async fn navigate(&self, url: &str) -> Result<NavigateResult, NavigateError> {
+        self.navigate_with_options(url, NavigateOptions::default())
+            .await
+    }
+
+    /// Navigates this page. `options.context_id` is ignored.
+    pub async fn navigate_with_options(
+        &self,
+        url: &str,
+        options: NavigateOptions,
+    ) -> Result<NavigateResult, NavigateError> {
+        let mut builder = NavigateBuilder::default()
+            .url(url)
+            .context(self.context.clone());
+        if let Some(wait) = options.wait {
+            builder = builder.wait(wait);
+        }
+        drivers::navigate(&self.session, builder.build().unwrap()).await
+    }
+
+    // ── Node finding ─────────────────────────────────────────────────────────
+
+    pub async fn find_nodes(&self, locator: Locator) -> Result<Vec<N>, FindNodesError> {
+        self.find_nodes_with_options(locator, FindNodesOptions::default())
+            .await
+    }
+
+    /// Finds all matching nodes in this page. `options.context_id` is ignored.
+    pub async fn find_nodes_with_options(
+        &self,
+        locator: Locator,
+        options: FindNodesOptions,
+    ) -> Result<Vec<N>, FindNodesError> {
+        let serialization_options = options
+            .serialization_options
+            .unwrap_or_else(default_node_serialization);
+        let mut builder = LocateNodesBuilder::default()
+            .context(self.context.clone())
+            .locator(locator.clone())
+            .serialization_options(serialization_options);
+        if let Some(max_count) = options.max_node_count {
+            builder = builder.max_node_count(max_count);
+        }
+        if let Some(start_nodes) = options.start_nodes {
+            builder = builder.start_nodes(start_nodes);
+        }
+        let result = match options.pierce_shadow {
+            Some(pierce) => {
+                drivers::find_nodes_piercing(&self.session, builder.build().unwrap(), pierce)
+                    .await?
+            }
+            None => drivers::find_nodes(&self.session, builder.build().unwrap()).await?,
+        };
+        Ok(result
+            .nodes
+            .into_iter()
+            .map(|node| self.build_node(node, locator.clone()))
+            .collect())
+    }
+
+    /// A lazy [`Locator`](crate::locator::Locator) for elements matching `locator`. Nothing
+    /// is looked up until it is acted on.
+    pub fn locator(&self, locator: Locator) -> crate::locator::Locator<T, N> {
+        crate::locator::Locator::in_page(self.clone(), locator)
+    }
+
+    pub async fn find_node(&self, locator: Locator) -> Result<Option<N>, FindNodesError> {
+        let nodes = self
+            .find_nodes_with_options(
+                locator,
+                FindNodesOptions {
+                    max_node_count: Some(1),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        Ok(nodes.into_iter().next())
+    }
+
+    /// Waits for matching nodes to appear (default 4s timeout). Returns an empty list on timeout.
+    /// [`Page::wait_for_selector`] waits without polling.
+    pub async fn wait_for_nodes(&self, locator: Locator) -> Result<Vec<N>, FindNodesError> {
+        self.wait_for_nodes_with_options(locator, WaitForNodesOptions::default())
+            .await
+    }
+
+    /// Waits for matching nodes with a custom timeout and poll interval.
+    /// `options.context_id` is ignored.
+    pub async fn wait_for_nodes_with_options(
+        &self,
+        locator: Locator,
+        options: WaitForNodesOptions,
+    ) -> Result<Vec<N>, FindNodesError> {
+        let timeout = options.timeout_ms.unwrap_or(4000);
+        let poll_interval = options.poll_interval_ms.unwrap_or(timeout / 6);
+        let start = std::time::Instant::now();
+
+        loop {
+            let nodes = self.find_nodes(locator.clone()).await?;
+            if !nodes.is_empty() || start.elapsed().as_millis() as u64 >= timeout {
+                return Ok(nodes);
+            }
+            tokio::time::sleep(Duration::from_millis(poll_interval)).await;
+        }
+    }
+
+    pub async fn wait_for_node(&self, locator: Locator) -> Result<Option<N>, FindNodesError> {
+        let nodes = self.wait_for_nodes(locator).await?;
+        Ok(nodes.into_iter().next())
+    }
+
+    // ── Frames ───────────────────────────────────────────────────────────────
+
+    /// Every frame nested in this page, at any depth, parents before their children.
+    pub async fn frames(&self) -> Result<Vec<Frame<T, N>>, FrameError> {
+        let tree = drivers::get_tree(
+            &self.session,
+            GetTreeBuilder::default().root(self.context.clone()).build(),
+        )
+        .await
+        .map_err(FrameError::CommandResultError)?;
+        let mut frames = Vec::new();
+        for info in tree.contexts.inner() {
+            collect_frames(self, info, &mut frames);
+        }
+        Ok(frames)
+    }
+
+    /// The first frame whose `window.name` is `name`.
+    pub async fn frame_by_name(&self, name: &str) -> Result<Option<Frame<T, N>>, FrameError> {
+        for frame in self.frames().await? {
+            if frame.name().await? == name {
+                return Ok(Some(frame));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The first frame whose URL contains `url`.
+    pub async fn frame_by_url(&self, url: &str) -> Result<Option<Frame<T, N>>, FrameError> {
+        Ok(self
+            .frames()
+            .await?
+            .into_iter()
+            .find(|frame| frame.url().contains(url)))
+    }
+
+    /// A locator for the frame hosted by the element matching `locator`, e.g.
+    /// `css!("iframe#payment")`.
+    pub fn frame_locator(&self, locator: Locator) -> FrameLocator<T, N> {
+        FrameLocator::new(self.clone(), locator)
+    }
+
+    // ── Script evaluation ────────────────────────────────────────────────────
+
+    pub async fn evaluate_script(
+        &self,
+        expression: impl Into<String>,
+        await_promise: bool,
+    ) -> Result<EvaluateResultSuccess, EvaluateResultError> {
+        self.evaluate_script_with_options(expression, await_promise, Default::default())
+            .await
+    }
+
+    /// Evaluates `expression` in this page, or in `options.target` when set (e.g. a sandbox).
+    pub async fn evaluate_script_with_options(
+        &self,
+        expression: impl Into<String>,
+        await_promise: bool,
+        options: EvaluateScriptOptions,
+    ) -> Result<EvaluateResultSuccess, EvaluateResultError> {
+        let target = options
+            .target
+            .unwrap_or_else(|| Target::ContextTarget(ContextTarget::new(self.context.clone())));
+        let mut builder = EvaluateBuilder::default()
+            .expression(expression.into())
+            .await_promise(await_promise)
+            .target(target);
+        if let Some(ro) = options.result_ownership {
+            builder = builder.result_ownership(ro);
+        }
+        if let Some(so) = options.serialization_options {
+            builder = builder.serialization_options(so);
+        }
+        if let Some(ua) = options.user_activation {
+            builder = builder.user_activation(ua);
+        }
+        drivers::evaluate_script(&self.session, builder.build().unwrap()).await
+    }
+
+    /// Calls the JavaScript function `function` in this page with `args` and deserializes
+    /// what it returns, once any promise has settled, into `R`.
+    ///
+    /// `args` is a tuple of the positional arguments, each serialized with serde: `()` passes
+    /// none and a single argument is written `(value,)`. See
+    /// [`EvaluateArgs`](crate::evaluate::EvaluateArgs). Elements go in as
+    /// [`NodeRef`](crate::evaluate::NodeRef)s and arrive as the live DOM elements. An
+    /// exception thrown or a promise rejected by the script comes back as
+    /// [`EvaluateError::Exception`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{BidiBrowser, chrome};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut browser = chrome(None).await;
+    /// let page = browser.active_page()?;
+    /// let sum: i64 = page.evaluate("(a, b) => a + b", (2, 3)).await?;
+    /// let title: String = page.evaluate("() => document.title", ()).await?;
+    /// let links: Vec<String> = page
+    ///     .evaluate(
+    ///         "(selector) => [...document.querySelectorAll(selector)].map((a) => a.href)",
+    ///         ("a",),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn evaluate<R, A>(
+        &self,
+        function: impl Into<String>,
+        args: A,
+    ) -> Result<R, EvaluateError>
+    where
+        R: DeserializeOwned,
+        A: EvaluateArgs,
+    {
+        let args = args
+            .into_args()
+            .map_err(|e| EvaluateError::SerializeError(e.to_string()))?;
+        let command = CallFunctionBuilder::default()
+            .function_declaration(function.into())
+            .await_promise(true)
+            .target(Target::ContextTarget(ContextTarget::new(
+                self.context.clone(),
+            )))
+            .arguments(args.iter().map(to_local_value))
+            .build()
+            .unwrap();
+        let result = drivers::call_function(&self.session, command).await?;
+        serde_json::from_value(from_remote_value(&result.result))
+            .map_err(|e| EvaluateError::DeserializeError(e.to_string()))
+    }
+
+    /// Exposes `function` to page scripts as `window[name]`: calling it from the page returns a
+    /// promise for what `function` returns, and an error rejects it with its message.
+    ///
+    /// Arguments reach `function` as JSON, the way `JSON.stringify` writes them. The
+    /// function is installed through a preload script that sends calls over a
+    /// `script.message` channel, so it survives navigations and is there in every frame of
+    /// this page, including the documents already loaded. Called on a [`Frame`], it exposes
+    /// the function on the frame's top-level page. Exposing a name again removes the
+    /// previous function's subscription and preload script first.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{BidiBrowser, chrome};
+    /// use serde_json::Value;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut browser = chrome(None).await;
+    /// let page = browser.active_page()?;
+    /// page.expose_function("double", |args: Vec<Value>| async move {
+    ///     let n = args.first().and_then(Value::as_i64).ok_or("expected a number")?;
+    ///     Ok::<_, &str>(Value::from(n * 2))
+    /// })
+    /// .await?;
+    /// let doubled: i64 = page.evaluate("() => window.double(21)", ()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn expose_function<F, Fut, E>(
+        &self,
+        name: impl Into<String>,
+        function: F,
+    ) -> Result<(), ExposeFunctionError>
+    where
+        F: Fn(Vec<serde_json::Value>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<serde_json::Value, E>> + Send + 'static,
+        E: std::fmt::Display,
+    {
+        let name = name.into();
+        // Preload scripts only take top-level contexts, so a frame exposes on its page.
+        let top_level = top_level_context(&self.session, &self.context).await?;
+        let top_page;
+        let page = if top_level == self.context {
+            self
+        } else {
+            top_page = Page::<T, N>::with_exposed(
+                self.session.clone(),
+                self.exposed.clone(),
+                top_level,
+            );
+            &top_page
+        };
+        let key = (page.context.as_ref().to_string(), name.clone());
+        let previous = self.exposed.lock().unwrap().remove(&key);
+        if let Some(previous) = previous {
+            if let Some(subscription) = previous.subscription {
+                let _ = self
+                    .session
+                    .lock()
+                    .await
+                    .unsubscribe_events_by_ids(vec![subscription])
+                    .await;
+            }
+            let command = RemovePreloadScriptBuilder::default()
+                .script(previous.script)
+                .build()
+                .unwrap();
+            drivers::send_command(&self.session, command)
+                .await
+                .map_err(|e| {
+                    ExposeFunctionError::CommandResultError(CommandResultError::SessionSendError(e))
+                })?;
+        }
+
+        let function = exposed_function(function);
+        let channel = unique_wait_id("rustenium-expose");
+
+        // Calls are answered on their own tasks, so a slow function does not hold up others.
+        let session = Arc::downgrade(&self.session);
+        let listening = channel.clone();
+        let exposed = name.clone();
+        let handler = move |event: Event| {
+            if let Event::Script(ScriptEvent::Message(message)) = event
+                && message.params.channel.as_ref() == listening
+                && let RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::StringValue(
+                    payload,
+                )) = message.params.data
+            {
+                let (session, function, name) =
+                    (session.clone(), function.clone(), exposed.clone());
+                let realm = message.params.source.realm;
+                tokio::spawn(async move {
+                    let Some(reply) = call_exposed(&name, &function, &payload.value).await else {
+                        return;
+                    };
+                    let Some(session) = session.upgrade() else {
+                        return;
+                    };
+                    let command = CallFunctionBuilder::default()
+                        .function_declaration(REPLY_SCRIPT)
+                        .await_promise(false)
+                        .target(Target::RealmTarget(RealmTarget::new(realm)))
+                        .arguments(reply.iter().map(to_local_value))
+                        .build()
+                        .unwrap();
+                    if let Err(e) = drivers::call_function(&session, command).await {
+                        tracing::warn!("Failed to reply to exposed function {}: {:?}", name, e);
+                    }
+                });
+            }
+            async {}
+        };
+        let mut bidi_event = self
+            .session
+            .lock()
+            .await
+            .create_event::<_, _, BidiSession<T>>(HashSet::from([Message::IDENTIFIER]), handler);
+        bidi_event.add_browsing_context(page.context.as_ref().to_string());
+        let subscription = self
+            .session
+            .lock()
+            .await
+            .subscribe_events(bidi_event)
+            .await
+            .map_err(ExposeFunctionError::CommandResultError)?
+            .map(|result| result.subscription);
+
+        let channel = ChannelValue::new(
+            ChannelValueType::Channel,
+            ChannelProperties {
+                channel: Channel::new(channel),
+                serialization_options: None,
+                ownership: None,
+            },
+        );
+        let command = AddPreloadScriptBuilder::default()
+            .function_declaration(expose_declaration(&name))
+            .argument(channel.clone())
+            .context(page.context.clone())
+            .build()
+            .unwrap();
+        let installed = drivers::send_command(&self.session, command)
+            .await
+            .map_err(CommandResultError::SessionSendError)
+            .and_then(|response| {
+                AddPreloadScriptResult::try_from(response.result.clone())
+                    .map_err(|_| CommandResultError::InvalidResultTypeError(response.result))
+            });
+        let script = match installed {
+            Ok(installed) => installed.script,
+            Err(e) => {
+                self.unwatch(subscription).await;
+                return Err(ExposeFunctionError::CommandResultError(e));
+            }
+        };
+        self.exposed.lock().unwrap().insert(
+            key,
+            ExposedChannel {
+                subscription,
+                script,
+            },
+        );
+
+        let mut contexts = vec![page.context.clone()];
+        contexts.extend(page.frames().await?.iter().map(|frame| frame.id().clone()));
+        for context in contexts {
+            let command = CallFunctionBuilder::default()
+                .function_declaration(expose_declaration(&name))
+                .await_promise(false)
+                .target(Target::ContextTarget(ContextTarget::new(context)))
+                .argument(channel.clone())
+                .build()
+                .unwrap();
+            drivers::call_function(&self.session, command).await?;
+        }
+        Ok(())
+    }
+
+    // ── Screenshot ───────────────────────────────────────────────────────────
+
+    pub async fn screenshot(&self) -> Result<String, ScreenshotError> {
+        self.screenshot_with_options(BrowserScreenshotOptions::default())
+            .await
+    }
+
+    /// Captures this page. See
+    /// [`BidiBrowser::screenshot_with_options`](crate::browsers::BidiBrowser::screenshot_with_options)
+    /// for how `save_path` is handled. `options.context_id` is ignored.
+    pub async fn screenshot_with_options(
+        &self,
+        options: BrowserScreenshotOptions,
+    ) -> Result<String, ScreenshotError> {
+        let mut builder = CaptureScreenshotBuilder::default().context(self.context.clone());
+        if let Some(origin) = options.origin {
+            builder = builder.origin(origin);
+        }
+        if let Some(format) = options.format {
+            builder = builder.format(format);
+        }
+        if let Some(clip) = options.clip {
+            builder = builder.clip(clip);
+        }
+        drivers::screenshot(
+            &self.session,
+            builder.build().unwrap(),
+            options.save_path.as_deref(),
+        )
+        .await
+    }
+
+    // ── PDF ──────────────────────────────────────────────────────────────────
+
+    /// Prints this page to PDF with `browsingContext.print` and returns the document,
+    /// also writing it to `options.save_path` when set.
+    pub async fn pdf(&self, options: PdfOptions) -> Result<Vec<u8>, PdfError> {
+        let result_value =
+            drivers::send_command(&self.session, options.to_bidi(self.context.clone()))
+                .await
+                .map_err(CommandResultError::SessionSendError)?
+                .result;
+        let result = PrintResult::try_from(result_value.clone())
+            .map_err(|_| CommandResultError::InvalidResultTypeError(result_value))?;
+        pdf::save(
+            pdf::decode_base64(&result.data)?,
+            options.save_path.as_deref(),
+        )
+        .await
+    }
+
+    // ── Input ────────────────────────────────────────────────────────────────
+
+    pub fn mouse(&self) -> &BidiMouse<T> {
+        self.mouse.as_ref()
+    }
+
+    pub fn human_mouse(&self) -> &HumanMouse<BidiMouse<T>> {
+        self.human_mouse.as_ref()
+    }
+
+    pub fn keyboard(&self) -> &BidiKeyboard<T> {
+        self.keyboard.as_ref()
+    }
+
+    pub fn touchscreen(&self) -> &Touchscreen<T> {
+        self.touchscreen.as_ref()
+    }
+
+    pub fn human_touchscreen(&self) -> &HumanTouchscreen<T> {
+        self.human_touchscreen.as_ref()
+    }
+
+    /// Moves the mouse to `point` in this page.
+    pub async fn mouse_move(&self, point: Point) -> Result<(), InputError> {
+        self.mouse
+            .move_to(point, &self.context, MouseMoveOptions::default())
+            .await
+    }
+
+    /// Clicks at `point` in this page.
+    pub async fn click(&self, point: Point) -> Result<(), InputError> {
+        self.mouse
+            .click(Some(point), &self.context, MouseClickOptions::default())
+            .await
+    }
+
+    /// Types `text` into whatever has focus in this page.
+    pub async fn type_text(&self, text: &str) -> Result<(), InputError> {
+        self.keyboard.type_text(text, &self.context, None).await
+    }
+
+    /// Presses and releases `key`, e.g. `"Enter"`.
+    pub async fn press(&self, key: &str) -> Result<(), InputError> {
+        self.keyboard.press(key, &self.context, None).await
+    }
+
+    // ── Events ───────────────────────────────────────────────────────────────
+
+    /// Subscribes to `events` for this page only. Events that belong to other contexts are
+    /// not passed to `handler`, even when another subscription covers them.
+    pub async fn subscribe_events<F, R>(
+        &self,
+        events: HashSet<&str>,
+        mut handler: F,
+    ) -> Result<Option<SubscribeResult>, CommandResultError>
+    where
+        F: FnMut(Event) -> R + Send + Sync + 'static,
+        R: Future<Output = ()> + Send + 'static,
+    {
+        let context = self.context.clone();
+        let filtered = move |event: Event| {
+            let call = event_context(&event)
+                .is_none_or(|c| c == context.as_ref())
+                .then(|| handler(event));
+            async move {
+                if let Some(call) = call {
+                    call.await;
+                }
+            }
+        };
+        let mut bidi_event = self
+            .session
+            .lock()
+            .await
+            .create_event::<_, _, BidiSession<T>>(events, filtered);
+        bidi_event.add_browsing_context(self.context.as_ref().to_string());
+        self.session.lock().await.subscribe_events(bidi_event).await
+    }
+
+    /// Runs `trigger` and returns the popup this page opens while it runs, e.g. through
+    /// `window.open` or a link with `target="_blank"`.
+    pub async fn wait_for_popup<F>(&self, trigger: F, timeout: Duration) -> Result<Self, PopupError>
+    where
+        F: Future,
+    {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let opener = self.context.clone();
+        let handler = move |event: Event| {
+            if let Event::BrowsingContext(BrowsingContextEvent::ContextCreated(created)) = event
+                && created.params.original_opener.as_ref() == Some(&opener)
+            {
+                let _ = tx.send(created.params.context);
+            }
+            async {}
+        };
+        let bidi_event = self
+            .session
+            .lock()
+            .await
+            .create_event::<_, _, BidiSession<T>>(
+                HashSet::from(["browsingContext.contextCreated"]),
+                handler,
+            );
+        let subscription = self
+            .session
+            .lock()
+            .await
+            .subscribe_events(bidi_event)
+            .await
+            .map_err(PopupError::CommandResultError)?;
+
+        let popup = race_trigger(trigger, rx.recv(), timeout).await;
+
+        if let Some(subscription) = subscription {
+            let _ = self
+                .session
+                .lock()
+                .await
+                .unsubscribe_events_by_ids(vec![subscription.subscription])
+                .await;
+        }
+        match popup {
+            Some(context) => Ok(Page::with_exposed(
+                self.session.clone(),
+                self.exposed.clone(),
+                context,
+            )),
+            None => Err(PopupError::Timeout(timeout)),
+        }
+    }
+
+    // ── Console ──────────────────────────────────────────────────────────────
+
+    /// Streams the console messages this page logs from now on, until the receiver is
+    /// dropped. Frames report their own messages; see [`Page::frames`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{BidiBrowser, chrome};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut browser = chrome(None).await;
+    /// let page = browser.active_page()?;
+    /// let mut messages = page.console_messages().await?;
+    /// tokio::spawn(async move {
+    ///     while let Some(message) = messages.recv().await {
+    ///         println!("[{}] {}", message.level, message.text);
+    ///     }
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn console_messages(
+        &self,
+    ) -> Result<UnboundedReceiver<ConsoleMessage>, ConsoleError> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let open = tx.clone();
+        self.watch_log(
+            move |message| tx.send(message).is_ok(),
+            move |_| !open.is_closed(),
+        )
+        .await?;
+        Ok(rx)
+    }
+
+    /// Streams the uncaught exceptions and unhandled promise rejections in this page from
+    /// now on, until the receiver is dropped.
+    pub async fn page_errors(&self) -> Result<UnboundedReceiver<PageError>, ConsoleError> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let open = tx.clone();
+        self.watch_log(
+            move |_| !open.is_closed(),
+            move |error| tx.send(error).is_ok(),
+        )
+        .await?;
+        Ok(rx)
+    }
+
+    /// Buffers this page's console messages and uncaught errors from now on, until the
+    /// collector is dropped. See [`ConsoleCollector`].
+    pub async fn collect_console(&self) -> Result<ConsoleCollector, ConsoleError> {
+        let collector = ConsoleCollector::new();
+        let (messages, errors) = (collector.sink(), collector.sink());
+        self.watch_log(
+            move |message| messages.push_message(message),
+            move |error| errors.push_error(error),
+        )
+        .await?;
+        Ok(collector)
+    }
+
+    /// Subscribes to `log.entryAdded` in this page, passing console entries to `on_message`
+    /// and JavaScript errors to `on_error`. The subscription ends the first time either
+    /// returns `false`.
+    async fn watch_log<M, E>(&self, on_message: M, on_error: E) -> Result<(), ConsoleError>
+    where
+        M: Fn(ConsoleMessage) -> bool + Send + Sync + 'static,
+        E: Fn(PageError) -> bool + Send + Sync + 'static,
+    {
+        let session = Arc::downgrade(&self.session);
+        let own_subscription = Arc::new(OnceLock::<Subscription>::new());
+        let subscription = own_subscription.clone();
+        let subscribed = self
+            .subscribe_events(HashSet::from([EntryAdded::IDENTIFIER]), move |event| {
+                let keep = match event {
+                    Event::Log(LogEvent::EntryAdded(added)) => match &added.params.entry {
+                        Entry::ConsoleLogEntry(entry) => on_message(ConsoleMessage::from(entry)),
+                        Entry::JavascriptLogEntry(entry) => on_error(PageError::from(entry)),
+                        Entry::GenericLogEntry(_) => true,
+                    },
+                    _ => true,
+                };
+                let done = (!keep).then(|| (session.clone(), subscription.clone()));
+                async move {
+                    if let Some((session, subscription)) = done
+                        && let Some(subscription) = subscription.get()
+                    {
+                        unsubscribe_weak(session, subscription.clone()).await;
+                    }
+                }
+            })
+            .await
+            .map_err(ConsoleError::CommandResultError)?;
+        if let Some(subscribed) = subscribed {
+            let _ = own_subscription.set(subscribed.subscription);
+        }
+        Ok(())
+    }
+
+    // ── Dialogs ──────────────────────────────────────────────────────────────
+
+    /// Calls `handler` with every JavaScript dialog this page opens from now on. The page
+    /// is blocked until the handler accepts or dismisses the dialog. Frames report their
+    /// own dialogs; see [`Page::frames`].
+    ///
+    /// The browser only leaves a dialog to the handler when the session's unhandled prompt
+    /// behavior for it is `ignore`. Otherwise it answers the dialog itself, and the handler
+    /// gets it already handled. See
+    /// [`ChromeCapabilities::unhandled_prompt_behavior`](crate::browsers::ChromeCapabilities::unhandled_prompt_behavior).
+    ///
+    /// The handler is called until the returned registration is unregistered.
+    pub async fn on_dialog<F, Fut>(&self, handler: F) -> Result<HandlerRegistration, DialogError>
+    where
+        F: Fn(Dialog) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let subscription = self
+            .watch_dialogs(move |dialog| {
+                tokio::spawn(handler(dialog));
+            })
+            .await?;
+        Ok(self.registration(subscription))
+    }
+
+    /// Runs `trigger` and returns the first JavaScript dialog this page opens meanwhile.
+    ///
+    /// A script that opens a dialog only returns once the dialog is answered, so `trigger`
+    /// runs alongside the wait and is dropped as soon as the dialog opens.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{BidiBrowser, chrome};
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut browser = chrome(None).await;
+    /// let page = browser.active_page()?;
+    /// let dialog = page
+    ///     .wait_for_dialog(
+    ///         page.evaluate_script("confirm('Delete everything?')", false),
+    ///         Duration::from_secs(5),
+    ///     )
+    ///     .await?;
+    /// assert_eq!(dialog.message(), "Delete everything?");
+    /// dialog.dismiss().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_dialog<F>(
+        &self,
+        trigger: F,
+        timeout: Duration,
+    ) -> Result<Dialog, DialogError>
+    where
+        F: Future,
+    {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let subscription = self
+            .watch_dialogs(move |dialog| {
+                let _ = tx.send(dialog);
+            })
+            .await?;
+        let dialog = race_trigger(trigger, rx.recv(), timeout).await;
+        self.unwatch(subscription).await;
+        dialog.ok_or(DialogError::Timeout(timeout))
+    }
+
+    /// Subscribes to `browsingContext.userPromptOpened` in this page, passing each dialog
+    /// to `on_dialog`.
+    async fn watch_dialogs<D>(&self, on_dialog: D) -> Result<Option<Subscription>, DialogError>
+    where
+        D: Fn(Dialog) + Send + Sync + 'static,
+    {
+        let session = Arc::downgrade(&self.session);
+        let subscription = self
+            .subscribe_events(
+                HashSet::from([UserPromptOpened::IDENTIFIER]),
+                move |event| {
+                    if let Event::BrowsingContext(BrowsingContextEvent::UserPromptOpened(opened)) =
+                        event
+                        && let Some(session) = session.upgrade()
+                    {
+                        let params = opened.params;
+                        let context = params.context.clone();
+                        let respond: DialogResponder = Arc::new(move |accept, text| {
+                            let (session, context) = (session.clone(), context.clone());
+                            Box::pin(async move {
+                                let mut command = HandleUserPromptBuilder::default()
+                                    .context(context)
+                                    .accept(accept);
+                                if let Some(text) = text {
+                                    command = command.user_text(text);
+                                }
+                                drivers::send_command(&session, command.build().unwrap())
+                                    .await
+                                    .map_err(CommandResultError::SessionSendError)?;
+                                Ok(())
+                            })
+                        });
+                        on_dialog(Dialog::new(
+                            DialogKind::from(&params.r#type),
+                            params.message,
+                            params.default_value,
+                            params.handler != UserPromptHandlerType::Ignore,
+                            respond,
+                        ));
+                    }
+                    async {}
+                },
+            )
+            .await?;
+        Ok(subscription.map(|result| result.subscription))
+    }
+
+    // ── File choosers ────────────────────────────────────────────────────────
+
+    /// Calls `handler` with every native file chooser this page tries to open from now on,
+    /// such as the one a click on an `<input type=file>` opens.
+    ///
+    /// The browser only leaves a chooser to the handler when the session's unhandled prompt
+    /// behavior for file dialogs is `ignore`. Otherwise it dismisses the chooser itself.
+    ///
+    /// The handler is called until the returned registration is unregistered.
+    pub async fn on_file_chooser<F, Fut>(
+        &self,
+        handler: F,
+    ) -> Result<HandlerRegistration, FileChooserError>
+    where
+        F: Fn(FileChooser) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let subscription = self
+            .watch_file_choosers(move |chooser| {
+                tokio::spawn(handler(chooser));
+            })
+            .await?;
+        Ok(self.registration(subscription))
+    }
+
+    /// Runs `trigger` and returns the first native file chooser this page tries to open
+    /// meanwhile. See [`FileChooser`] for an example.
+    pub async fn wait_for_file_chooser<F>(
+        &self,
+        trigger: F,
+        timeout: Duration,
+    ) -> Result<FileChooser, FileChooserError>
+    where
+        F: Future,
+    {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let subscription = self
+            .watch_file_choosers(move |chooser| {
+                let _ = tx.send(chooser);
+            })
+            .await?;
+        let chooser = race_trigger(trigger, rx.recv(), timeout).await;
+        self.unwatch(subscription).await;
+        chooser.ok_or(FileChooserError::Timeout(timeout))
+    }
+
+    /// Subscribes to `input.fileDialogOpened` in this page, passing each chooser to
+    /// `on_chooser`.
+    async fn watch_file_choosers<C>(
+        &self,
+        on_chooser: C,
+    ) -> Result<Option<Subscription>, FileChooserError>
+    where
+        C: Fn(FileChooser) + Send + Sync + 'static,
+    {
+        let session = Arc::downgrade(&self.session);
+        let subscription = self
+            .subscribe_events(
+                HashSet::from([FileDialogOpened::IDENTIFIER]),
+                move |event| {
+                    if let Event::Input(InputEvent::FileDialogOpened(opened)) = event
+                        && let Some(session) = session.upgrade()
+                    {
+                        let params = opened.params;
+                        let (context, element) = (params.context, params.element);
+                        let respond: FileChooserResponder = Arc::new(move |files| {
+                            let (session, context, element) =
+                                (session.clone(), context.clone(), element.clone());
+                            Box::pin(async move {
+                                let element = element.ok_or(SetFilesError::NoElement)?;
+                                let command = SetFilesBuilder::default()
+                                    .context(context)
+                                    .element(element)
+                                    .files(files)
+                                    .build()
+                                    .unwrap();
+                                drivers::send_command(&session, command)
+                                    .await
+                                    .map_err(|e| {
+                                        NodeActionError::from(CommandResultError::SessionSendError(
+                                            e,
+                                        ))
+                                    })?;
+                                Ok(())
+                            })
+                        });
+                        on_chooser(FileChooser::new(params.multiple, respond));
+                    }
+                    async {}
+                },
+            )
+            .await?;
+        Ok(subscription.map(|result| result.subscription))
+    }
+
+    // ── Downloads ────────────────────────────────────────────────────────────
+
+    /// Streams what happens to the downloads this page starts from now on. Downloads are
+    /// only saved once allowed with
+    /// [`BidiBrowser::set_download_behavior`](crate::browsers::BidiBrowser::set_download_behavior).
+    pub async fn download_events(
+        &self,
+    ) -> Result<UnboundedReceiver<DownloadEvent>, FileDownloadError> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        self.watch_downloads(move |event| {
+            let _ = tx.send(event);
+        })
+        .await?;
+        Ok(rx)
+    }
+
+    /// Runs `trigger` and waits for the first download this page starts meanwhile to finish
+    /// or be canceled. See [`Download`] for an example.
+    pub async fn wait_for_download<F>(
+        &self,
+        trigger: F,
+        timeout: Duration,
+    ) -> Result<Download, FileDownloadError>
+    where
+        F: Future,
+    {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let subscription = self
+            .watch_downloads(move |event| {
+                let _ = tx.send(event);
+            })
+            .await?;
+        let download = race_trigger(trigger, next_download(&mut rx, None), timeout).await;
+        self.unwatch(subscription).await;
+        download.ok_or(FileDownloadError::Timeout(timeout))
+    }
+
+    /// Subscribes to `browsingContext.downloadWillBegin` and `browsingContext.downloadEnd`
+    /// in this page, passing each to `on_event`.
+    async fn watch_downloads<D>(
+        &self,
+        on_event: D,
+    ) -> Result<Option<Subscription>, FileDownloadError>
+    where
+        D: Fn(DownloadEvent) + Send + Sync + 'static,
+    {
+        let subscription = self
+            .subscribe_events(
+                HashSet::from([DownloadWillBegin::IDENTIFIER, DownloadEnd::IDENTIFIER]),
+                move |event| {
+                    match event {
+                        Event::BrowsingContext(BrowsingContextEvent::DownloadWillBegin(begin)) => {
+                            on_event(DownloadEvent::WillBegin((&begin.params).into()))
+                        }
+                        Event::BrowsingContext(BrowsingContextEvent::DownloadEnd(end)) => {
+                            on_event(DownloadEvent::End((&end.params).into()))
+                        }
+                        _ => {}
+                    }
+                    async {}
+                },
+            )
+            .await?;
+        Ok(subscription.map(|result| result.subscription))
+    }
+
+    // ── Waiting ──────────────────────────────────────────────────────────────
+
+    /// Runs `trigger` and waits for the navigation it causes in this page, returning the
+    /// URL navigated to. Navigations to a new document finish at their `load` event;
+    /// fragment and History API navigations finish as soon as they happen.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{BidiBrowser, chrome};
+    /// use rustenium::nodes::Node;
+    /// use rustenium_macros::css;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut browser = chrome(None).await;
+    /// let page = browser.active_page()?;
+    /// page.navigate("https://example.com").await?;
+    ///
+    /// let mut link = page.find_node(css!("a")).await?.unwrap();
+    /// let url = page
+    ///     .wait_for_navigation(async { link.mouse_click().await.unwrap() }, Duration::from_secs(10))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_navigation<F>(
+        &self,
+        trigger: F,
+        timeout: Duration,
+    ) -> Result<String, WaitError>
+    where
+        F: Future,
+    {
+        let (mut rx, subscription) = self
+            .watch_events(
+                HashSet::from([
+                    Load::IDENTIFIER,
+                    FragmentNavigated::IDENTIFIER,
+                    HistoryUpdated::IDENTIFIER,
+                    NavigationFailed::IDENTIFIER,
+                ]),
+                |event| match event {
+                    Event::BrowsingContext(BrowsingContextEvent::NavigationFailed(failed)) => {
+                        Some(Err(WaitError::NavigationFailed(
+                            failed.params.base_navigation_info.url,
+                        )))
+                    }
+                    event => navigated_url(event).map(Ok),
+                },
+            )
+            .await?;
+        let navigation = race_trigger(trigger, rx.recv(), timeout).await;
+        self.unwatch(subscription).await;
+        match navigation {
+            Some(result) => result,
+            None => Err(WaitError::Timeout("a navigation".to_string(), timeout)),
+        }
+    }
+
+    /// Waits until the current document reaches `state`, returning at once if it already
+    /// has. [`LoadState::NetworkIdle`] counts the requests this page sends from the moment
+    /// it is called.
+    pub async fn wait_for_load_state(
+        &self,
+        state: LoadState,
+        timeout: Duration,
+    ) -> Result<(), WaitError> {
+        let in_flight = Arc::new(InFlightRequests::default());
+        let network = match state {
+            LoadState::NetworkIdle => Some(self.track_requests(in_flight.clone()).await?),
+            _ => None,
+        };
+        let event = match state {
+            LoadState::DomContentLoaded => DomContentLoaded::IDENTIFIER,
+            LoadState::Load | LoadState::NetworkIdle => Load::IDENTIFIER,
+        };
+        let (mut rx, subscription) = self
+            .watch_events(HashSet::from([event]), |_| Some(()))
+            .await?;
+
+        let reached = tokio::time::timeout(timeout, async {
+            if !state.reached_by(&self.ready_state().await?) {
+                rx.recv().await;
+            }
+            if state == LoadState::NetworkIdle {
+                in_flight.idle().await;
+            }
+            Ok(())
+        })
+        .await;
+
+        self.unwatch(subscription).await;
+        if let Some(network) = network {
+            self.unwatch(network).await;
+        }
+        match reached {
+            Ok(result) => result,
+            Err(_) => Err(WaitError::Timeout(state.to_string(), timeout)),
+        }
+    }
+
+    /// Waits until this page's URL matches `url`, returning at once if it already does. A
+    /// navigation to a new document counts once it has loaded; fragment and History API
+    /// navigations, as single-page apps use, count as soon as they happen.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{BidiBrowser, chrome};
+    /// use rustenium::wait::UrlMatcher;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut browser = chrome(None).await;
+    /// let page = browser.active_page()?;
+    /// page.wait_for_url("https://example.com/**/done", Duration::from_secs(10)).await?;
+    /// page.wait_for_url(UrlMatcher::contains("#step-2"), Duration::from_secs(10)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_url(
+        &self,
+        url: impl Into<UrlMatcher>,
+        timeout: Duration,
+    ) -> Result<(), WaitError> {
+        let matcher = url.into();
+        let accepts = matcher.clone();
+        let (mut rx, subscription) = self
+            .watch_events(
+                HashSet::from([
+                    Load::IDENTIFIER,
+                    FragmentNavigated::IDENTIFIER,
+                    HistoryUpdated::IDENTIFIER,
+                ]),
+                move |event| navigated_url(event).filter(|url| accepts.matches(url)),
+            )
+            .await?;
+
+        let result = match self.current_url().await {
+            Ok(current) if matcher.matches(&current) => Ok(()),
+            Ok(_) => match tokio::time::timeout(timeout, rx.recv()).await {
+                Ok(Some(_)) => Ok(()),
+                _ => Err(WaitError::Timeout(matcher.to_string(), timeout)),
+            },
+            Err(err) => Err(err),
+        };
+        self.unwatch(subscription).await;
+        result
+    }
+
+    /// Runs `trigger` and returns the first event of type `E` in this page that `predicate`
+    /// accepts.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{BidiBrowser, chrome};
+    /// use rustenium_bidi_definitions::network::events::ResponseCompleted;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut browser = chrome(None).await;
+    /// let page = browser.active_page()?;
+    /// let response = page
+    ///     .wait_for_event::<ResponseCompleted, _, _>(
+    ///         page.navigate("https://example.com"),
+    ///         |event| event.params.base_parameters.request.url.ends_with("/api/items"),
+    ///         Duration::from_secs(10),
+    ///     )
+    ///     .await?;
+    /// println!("{}", response.params.response.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_event<E, F, P>(
+        &self,
+        trigger: F,
+        predicate: P,
+        timeout: Duration,
+    ) -> Result<E, WaitError>
+    where
+        E: BidiEventType,
+        F: Future,
+        P: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        let (mut rx, subscription) = self
+            .watch_events(HashSet::from([E::IDENTIFIER]), move |event| {
+                E::try_from(event).ok().filter(|event| predicate(event))
+            })
+            .await?;
+        let event = race_trigger(trigger, rx.recv(), timeout).await;
+        self.unwatch(subscription).await;
+        event.ok_or_else(|| WaitError::Timeout(E::IDENTIFIER.to_string(), timeout))
+    }
+
+    /// Waits until the first element matching `locator` reaches `state`, returning it for
+    /// [`SelectorState::Attached`] and [`SelectorState::Visible`] and `None` for the others.
+    ///
+    /// Rather than polling, this installs a `MutationObserver` in an isolated sandbox that
+    /// reports back over a `script.message` channel as soon as the state is reached.
+    /// Accessibility and context locators, which only the browser can resolve, are re-run
+    /// whenever the DOM changes instead. The observer watches the current document, so a
+    /// navigation during the wait ends in a timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rustenium::browsers::{BidiBrowser, chrome};
+    /// use rustenium::wait::SelectorState;
+    /// use rustenium_macros::css;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut browser = chrome(None).await;
+    /// let page = browser.active_page()?;
+    /// let results = page
+    ///     .wait_for_selector(css!("#results"), SelectorState::Visible, Duration::from_secs(10))
+    ///     .await?;
+    /// page.wait_for_selector(css!(".spinner"), SelectorState::Hidden, Duration::from_secs(10))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_selector(
+        &self,
+        locator: Locator,
+        state: SelectorState,
+        timeout: Duration,
+    ) -> Result<Option<N>, WaitError> {
+        let selector = Selector::from(locator.clone());
+        let channel = unique_wait_id("rustenium-wait-for-selector");
+        let listening = channel.clone();
+        let (mut rx, subscription) = self
+            .watch_events(
+                HashSet::from([Message::IDENTIFIER]),
+                move |event| match event {
+                    Event::Script(ScriptEvent::Message(message))
+                        if message.params.channel.as_ref() == listening =>
+                    {
+                        Some(message.params.data)
+                    }
+                    _ => None,
+                },
+            )
+            .await?;
+
+        let command = CallFunctionBuilder::default()
+            .function_declaration(wait_for_selector_script(&selector, state, timeout))
+            .await_promise(false)
+            .target(Target::ContextTarget(ContextTarget {
+                context: self.context.clone(),
+                sandbox: Some(SANDBOX.to_string()),
+            }))
+            .argument(ChannelValue::new(
+                ChannelValueType::Channel,
+                ChannelProperties {
+                    channel: Channel::new(channel),
+                    serialization_options: Some(default_node_serialization()),
+                    ownership: Some(ResultOwnership::Root),
+                },
+            ))
+            .build()
+            .unwrap();
+        let reached = match drivers::call_function(&self.session, command).await {
+            Ok(_) => {
+                tokio::time::timeout(timeout, async {
+                    while let Some(data) = rx.recv().await {
+                        match data {
+                            RemoteValue::NodeRemoteValue(node) => {
+                                return Ok(Some(self.build_node(node, locator.clone())));
+                            }
+                            RemoteValue::PrimitiveProtocolValue(
+                                PrimitiveProtocolValue::StringValue(_),
+                            ) => {
+                                if let Some(node) = self.selector_state(&locator, state).await? {
+                                    return Ok(node);
+                                }
+                            }
+                            _ => return Ok(None),
+                        }
+                    }
+                    // The subscription outlives this loop, so the channel only closes with
+                    // the session; let the timeout decide
+                    std::future::pending().await
+                })
+                .await
+            }
+            Err(err) => Ok(Err(err.into())),
+        };
+        self.unwatch(subscription).await;
+        match reached {
+            Ok(result) => result,
+            Err(_) => Err(WaitError::Timeout(
+                format!("{selector} to be {state}"),
+                timeout,
+            )),
+        }
+    }
+
+    /// Subscribes to `events` in this page and forwards whatever `matcher` makes of them.
+    async fn watch_events<R, M>(
+        &self,
+        events: HashSet<&str>,
+        mut matcher: M,
+    ) -> Result<(UnboundedReceiver<R>, Option<Subscription>), WaitError>
+    where
+        R: Send + 'static,
+        M: FnMut(Event) -> Option<R> + Send + Sync + 'static,
+    {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let subscription = self
+            .subscribe_events(events, move |event| {
+                if let Some(matched) = matcher(event) {
+                    let _ = tx.send(matched);
+                }
+                async {}
+            })
+            .await
+            .map_err(WaitError::CommandResultError)?;
+        Ok((rx, subscription.map(|result| result.subscription)))
+    }
+
+    /// A registration that ends `subscription` when unregistered.
+    fn registration(&self, subscription: Option<Subscription>) -> HandlerRegistration {
+        let session = Arc::downgrade(&self.session);
+        HandlerRegistration::new(move || async move {
+            if let Some(subscription) = subscription {
+                unsubscribe_weak(session, subscription).await;
+            }
+        })
+    }
+
+    async fn unwatch(&self, subscription: Option<Subscription>) {
+        if let Some(subscription) = subscription {
+            let _ = self
+                .session
+                .lock()
+                .await
+                .unsubscribe_events_by_ids(vec![subscription])
+                .await;
+        }
+    }
+
+    /// Records the requests this page starts and finishes in `in_flight`.
+    async fn track_requests(
+        &self,
+        in_flight: Arc<InFlightRequests>,
+    ) -> Result<Option<Subscription>, WaitError> {
+        let (_, subscription) = self
+            .watch_events::<(), _>(
+                HashSet::from([
+                    BeforeRequestSent::IDENTIFIER,
+                    ResponseCompleted::IDENTIFIER,
+                    FetchError::IDENTIFIER,
+                ]),
+                move |event| {
+                    match event {
+                        Event::Network(NetworkEvent::BeforeRequestSent(sent)) => {
+                            in_flight.started(sent.params.base_parameters.request.request.into())
+                        }
+                        Event::Network(NetworkEvent::ResponseCompleted(completed)) => in_flight
+                            .finished(completed.params.base_parameters.request.request.as_ref()),
+                        Event::Network(NetworkEvent::FetchError(failed)) => in_flight
+                            .finished(failed.params.base_parameters.request.request.as_ref()),
+                        _ => {}
+                    }
+                    None
+                },
+            )
+            .await?;
+        Ok(subscription)
+    }
+
+    async fn ready_state(&self) -> Result<String, WaitError> {
+        let result = self.evaluate_script("document.readyState", false).await?;
+        match result.result {
+            RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::StringValue(state)) => {
+                Ok(state.value)
+            }
+            _ => Ok(String::new()),
+        }
+    }
+
+    async fn current_url(&self) -> Result<String, WaitError> {
+        let tree = drivers::get_tree(
+            &self.session,
+            GetTreeBuilder::default()
+                .root(self.context.clone())
+                .max_depth(0u64)
+                .build(),
+        )
+        .await
+        .map_err(WaitError::CommandResultError)?;
+        Ok(tree
+            .contexts
+            .inner()
+            .first()
+            .map(|info| info.url.clone())
+            .unwrap_or_default())
+    }
+
+    /// Re-runs `locator` for [`Page::wait_for_selector`], returning what the wait resolves
+    /// to if the first match is in `state`, or `None` while it is not.
+    async fn selector_state(
+        &self,
+        locator: &Locator,
+        state: SelectorState,
+    ) -> Result<Option<Option<N>>, WaitError> {
+        let command = LocateNodesBuilder::default()
+            .context(self.context.clone())
+            .locator(locator.clone())
+            .max_node_count(1u64)
+            .serialization_options(default_node_serialization())
+            .build()
+            .unwrap();
+        let node = drivers::find_nodes(&self.session, command)
+            .await?
+            .nodes
+            .into_iter()
+            .next();
+        let visible = match &node {
+            Some(node) if state.needs_visibility() => self.is_visible(node).await?,
+            _ => false,
+        };
+        if !state.reached(node.is_some(), visible) {
+            return Ok(None);
+        }
+        Ok(Some(match node {
+            Some(node) if state.yields_node() => Some(self.build_node(node, locator.clone())),
+            _ => None,
+        }))
+    }
+
+    async fn is_visible(&self, node: &NodeRemoteValue) -> Result<bool, WaitError> {
+        let Some(reference) = shared_reference(node) else {
+            return Ok(false);
+        };
+        let command = CallFunctionBuilder::default()
+            .function_declaration(VISIBLE_SCRIPT)
+            .await_promise(false)
+            .target(Target::ContextTarget(ContextTarget::new(
+                self.context.clone(),
+            )))
+            .this(RemoteReference::from(reference))
+            .build()
+            .unwrap();
+        let result = drivers::call_function(&self.session, command).await?;
+        Ok(matches!(
+            result.result,
+            RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::BooleanValue(ref value))
+                if value.value
+        ))
+    }
+
+    // ── Lifecycle ────────────────────────────────────────────────────────────
+
+    /// Closes this page's browsing context.
+    pub async fn close(&self) -> Result<(), ContextCloseError> {
+        drivers::close_context(&self.session, self.context.clone()).await
+    }
+}
+
+/// Ends `subscription` unless the session is already gone.
+///
+/// Event handlers live as long as the session and registrations as long as the caller
+/// keeps them, so both hold the session through a [`Weak`] rather than keeping it alive.
+pub(crate) async fn unsubscribe_weak<T: ConnectionTransport>(
+    session: Weak<TokioMutex<BidiSession<T>>>,
+    subscription: Subscription,
+) {
+    if let Some(session) = session.upgrade() {
+        let _ = session
+            .lock()
+            .await
+            .unsubscribe_events_by_ids(vec![subscription])
+            .await;
+    }
+}
+
+/// The URL a page moved to, for the events that mark a finished navigation.
+fn navigated_url(event: Event) -> Option<String> {
+    match event {
+        Event::BrowsingContext(BrowsingContextEvent::Load(load)) => {
+            Some(load.params.base_navigation_info.url)
+        }
+        Event::BrowsingContext(BrowsingContextEvent::FragmentNavigated(navigated)) => {
+            Some(navigated.params.base_navigation_info.url)
+        }
+        Event::BrowsingContext(BrowsingContextEvent::HistoryUpdated(updated)) => {
+            Some(updated.params.url)
+        }
+        _ => None,
+    }
+}
+
+/// The browsing context an event belongs to, read from `params.context` or, for log and
+/// script events, `params.source.context`.
+pub(crate) fn event_context(event: &Event) -> Option<String> {
+    let value = serde_json::to_value(event).ok()?;
+    let params = value.get("params")?;
+    params
+        .get("context")
+        .or_else(|| params.get("source")?.get("context"))
+        .and_then(|context| context.as_str())
+        .map(String::from)
+}
@@ -0,0 +1,302 @@
+//! Printing pages to PDF.
+//!
+//! BiDi prints with `browsingContext.print`, CDP with `Page.printToPDF`. Both take the same
+//! [`PdfOptions`], measured in centimeters as in BiDi; CDP's inches are converted. CDP streams
+//! the document back through `IO.read`, so large reports are not sent as one message.
+
+use crate::error::pdf::PdfError;
+use base64::{Engine as _, engine::general_purpose};
+use rustenium_bidi_definitions::browsing_context::command_builders::PrintBuilder;
+use rustenium_bidi_definitions::browsing_context::commands::{Print, PrintOrientation};
+use rustenium_bidi_definitions::browsing_context::types::{
+    BrowsingContext, PrintMarginParameters, PrintPageParameters,
+};
+use rustenium_cdp_definitions::browser_protocol::page::command_builders::PrintToPdfBuilder;
+use rustenium_cdp_definitions::browser_protocol::page::commands::{
+    PrintToPdf, PrintToPdfTransferMode,
+};
+use std::path::{Path, PathBuf};
+
+const CM_PER_INCH: f64 = 2.54;
+
+/// How the page is turned on the paper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PdfOrientation {
+    #[default]
+    Portrait,
+    Landscape,
+}
+
+/// A paper size in centimeters, before [`PdfOrientation`] is applied.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageSize {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl PageSize {
+    /// US Letter, 8.5 × 11 in. The default of both protocols.
+    pub const LETTER: PageSize = PageSize {
+        width: 21.59,
+        height: 27.94,
+    };
+    /// US Legal, 8.5 × 14 in.
+    pub const LEGAL: PageSize = PageSize {
+        width: 21.59,
+        height: 35.56,
+    };
+    pub const A3: PageSize = PageSize {
+        width: 29.7,
+        height: 42.0,
+    };
+    pub const A4: PageSize = PageSize {
+        width: 21.0,
+        height: 29.7,
+    };
+}
+
+impl Default for PageSize {
+    fn default() -> Self {
+        PageSize::LETTER
+    }
+}
+
+/// Page margins in centimeters. Both protocols default to about 1 cm on every side.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PdfMargins {
+    pub top: f64,
+    pub bottom: f64,
+    pub left: f64,
+    pub right: f64,
+}
+
+impl PdfMargins {
+    /// The same margin on every side.
+    pub fn uniform(margin: f64) -> Self {
+        Self {
+            top: margin,
+            bottom: margin,
+            left: margin,
+            right: margin,
+        }
+    }
+}
+
+/// How to print a page to PDF. Fields left `None` use the browser's defaults.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustenium::browsers::{BidiBrowser, chrome};
+/// use rustenium::pdf::{PageSize, PdfMargins, PdfOptionsBuilder};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut browser = chrome(None).await;
+/// browser.navigate("https://example.com/invoice/42").await?;
+/// let options = PdfOptionsBuilder::default()
+///     .page_size(PageSize::A4)
+///     .margins(PdfMargins::uniform(1.5))
+///     .background(true)
+///     .page_ranges(["1-2"])
+///     .save_path("invoice-42.pdf")
+///     .build();
+/// let pdf = browser.pdf(options).await?;
+/// assert!(pdf.starts_with(b"%PDF"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PdfOptions {
+    pub page_size: Option<PageSize>,
+    pub margins: Option<PdfMargins>,
+    pub orientation: Option<PdfOrientation>,
+    /// Zoom applied to the content, between 0.1 and 2.
+    pub scale: Option<f64>,
+    /// Pages to print, such as `"1"` or `"3-5"`. Empty prints every page.
+    pub page_ranges: Vec<String>,
+    /// Prints background colors and images.
+    pub background: bool,
+    /// Shrinks content that is wider than the page to fit it. Only BiDi honours this; CDP
+    /// always shrinks.
+    pub shrink_to_fit: Option<bool>,
+    /// Also writes the PDF to this file, or to a timestamped file when it is a directory.
+    pub save_path: Option<String>,
+}
+
+#[derive(Default, Clone)]
+pub struct PdfOptionsBuilder {
+    page_size: Option<PageSize>,
+    margins: Option<PdfMargins>,
+    orientation: Option<PdfOrientation>,
+    scale: Option<f64>,
+    page_ranges: Vec<String>,
+    background: bool,
+    shrink_to_fit: Option<bool>,
+    save_path: Option<String>,
+}
+
+impl PdfOptionsBuilder {
+    pub fn page_size(mut self, page_size: PageSize) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn margins(mut self, margins: PdfMargins) -> Self {
+        self.margins = Some(margins);
+        self
+    }
+
+    pub fn orientation(mut self, orientation: PdfOrientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    pub fn page_range(mut self, page_range: impl Into<String>) -> Self {
+        self.page_ranges.push(page_range.into());
+        self
+    }
+
+    pub fn page_ranges<I, S>(mut self, page_ranges: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.page_ranges
+            .extend(page_ranges.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn background(mut self, background: bool) -> Self {
+        self.background = background;
+        self
+    }
+
+    pub fn shrink_to_fit(mut self, shrink_to_fit: bool) -> Self {
+        self.shrink_to_fit = Some(shrink_to_fit);
+        self
+    }
+
+    pub fn save_path(mut self, save_path: impl Into<String>) -> Self {
+        self.save_path = Some(save_path.into());
+        self
+    }
+
+    pub fn build(self) -> PdfOptions {
+        PdfOptions {
+            page_size: self.page_size,
+            margins: self.margins,
+            orientation: self.orientation,
+            scale: self.scale,
+            page_ranges: self.page_ranges,
+            background: self.background,
+            shrink_to_fit: self.shrink_to_fit,
+            save_path: self.save_path,
+        }
+    }
+}
+
+impl PdfOptions {
+    /// The BiDi `browsingContext.print` command for `context`.
+    pub fn to_bidi(&self, context: impl Into<BrowsingContext>) -> Print {
+        let mut builder = PrintBuilder::default()
+            .context(context)
+            .background(self.background);
+        if let Some(page_size) = self.page_size {
+            builder = builder.page(
+                PrintPageParameters::builder()
+                    .width(page_size.width)
+                    .height(page_size.height)
+                    .build(),
+            );
+        }
+        if let Some(margins) = self.margins {
+            builder = builder.margin(
+                PrintMarginParameters::builder()
+                    .top(margins.top)
+                    .bottom(margins.bottom)
+                    .left(margins.left)
+                    .right(margins.right)
+                    .build(),
+            );
+        }
+        if let Some(orientation) = self.orientation {
+            builder = builder.orientation(match orientation {
+                PdfOrientation::Portrait => PrintOrientation::Portrait,
+                PdfOrientation::Landscape => PrintOrientation::Landscape,
+            });
+        }
+        if let Some(scale) = self.scale {
+            builder = builder.scale(scale);
+        }
+        if !self.page_ranges.is_empty() {
+            builder = builder.page_ranges(self.page_ranges.iter().cloned());
+        }
+        if let Some(shrink_to_fit) = self.shrink_to_fit {
+            builder = builder.shrink_to_fit(shrink_to_fit);
+        }
+        builder.build().unwrap()
+    }
+
+    /// The CDP `Page.printToPDF` command, returning the document as a stream.
+    pub fn to_cdp(&self) -> PrintToPdf {
+        let mut builder = PrintToPdfBuilder::default()
+            .print_background(self.background)
+            .transfer_mode(PrintToPdfTransferMode::ReturnAsStream);
+        if let Some(page_size) = self.page_size {
+            builder = builder
+                .paper_width(page_size.width / CM_PER_INCH)
+                .paper_height(page_size.height / CM_PER_INCH);
+        }
+        if let Some(margins) = self.margins {
+            builder = builder
+                .margin_top(margins.top / CM_PER_INCH)
+                .margin_bottom(margins.bottom / CM_PER_INCH)
+                .margin_left(margins.left / CM_PER_INCH)
+                .margin_right(margins.right / CM_PER_INCH);
+        }
+        if let Some(orientation) = self.orientation {
+            builder = builder.landscape(orientation == PdfOrientation::Landscape);
+        }
+        if let Some(scale) = self.scale {
+            builder = builder.scale(scale);
+        }
+        if !self.page_ranges.is_empty() {
+            builder = builder.page_ranges(self.page_ranges.join(","));
+        }
+        builder.build()
+    }
+}
+
+pub(crate) fn decode_base64(data: &str) -> Result<Vec<u8>, PdfError> {
+    general_purpose::STANDARD
+        .decode(data)
+        .map_err(|e| PdfError::Base64DecodeError(e.to_string()))
+}
+
+/// Where a PDF saved to `save_path` goes: `save_path` itself, or a timestamped file in it
+/// when it is a directory.
+pub(crate) fn output_path(save_path: &str) -> PathBuf {
+    let path = Path::new(save_path);
+    if path.is_dir() {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+        path.join(format!("page_{}.pdf", timestamp))
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Writes `pdf` to `save_path` when one was given, then hands it back.
+pub(crate) async fn save(pdf: Vec<u8>, save_path: Option<&str>) -> Result<Vec<u8>, PdfError> {
+    if let Some(save_path) = save_path {
+        tokio::fs::write(output_path(save_path), &pdf).await?;
+    }
+    Ok(pdf)
+}
@@ -0,0 +1,556 @@
+use crate::browsers::cdp_browser::Selector;
+use rustenium_bidi_definitions::Event;
+use serde::de::DeserializeOwned;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::sleep;
+
+/// How long no request may be in flight before the network counts as idle.
+pub const NETWORK_IDLE_TIME: Duration = Duration::from_millis(500);
+
+/// A point in the life of a document that the `wait_for_load_state` helpers wait for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadState {
+    /// The HTML is parsed and `DOMContentLoaded` has fired. Images and stylesheets may still
+    /// be loading.
+    DomContentLoaded,
+    /// The `load` event has fired.
+    Load,
+    /// The `load` event has fired and no request has been in flight for
+    /// [`NETWORK_IDLE_TIME`].
+    NetworkIdle,
+}
+
+impl LoadState {
+    /// The `Page.lifecycleEvent` name that marks this state over CDP.
+    pub(crate) fn lifecycle_event(&self) -> &'static str {
+        match self {
+            LoadState::DomContentLoaded => "DOMContentLoaded",
+            LoadState::Load => "load",
+            LoadState::NetworkIdle => "networkIdle",
+        }
+    }
+
+    /// Whether a document whose `document.readyState` is `ready_state` has fired the event
+    /// this state starts at. Network idleness cannot be read off the document.
+    pub(crate) fn reached_by(&self, ready_state: &str) -> bool {
+        match self {
+            LoadState::DomContentLoaded => matches!(ready_state, "interactive" | "complete"),
+            LoadState::Load | LoadState::NetworkIdle => ready_state == "complete",
+        }
+    }
+}
+
+impl fmt::Display for LoadState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadState::DomContentLoaded => write!(f, "DOMContentLoaded"),
+            LoadState::Load => write!(f, "load"),
+            LoadState::NetworkIdle => write!(f, "network idle"),
+        }
+    }
+}
+
+/// Decides which URLs the `wait_for_url` helpers accept.
+///
+/// Strings convert to [`UrlMatcher::Glob`].
+///
+/// # Examples
+///
+/// ```
+/// use rustenium::wait::UrlMatcher;
+///
+/// let matcher = UrlMatcher::from("https://example.com/**/checkout");
+/// assert!(matcher.matches("https://example.com/shop/cart/checkout"));
+/// assert!(UrlMatcher::contains("/checkout").matches("https://example.com/checkout?step=2"));
+/// assert!(UrlMatcher::predicate(|url| url.ends_with("#done")).matches("https://example.com/#done"));
+/// ```
+#[derive(Clone)]
+pub enum UrlMatcher {
+    /// The whole URL matches a glob. `**` matches any run of characters, `*` any run without
+    /// a `/`, and everything else matches itself, so `?` and `#` need no escaping.
+    Glob(String),
+    /// The URL contains the string.
+    Contains(String),
+    /// The closure returns `true` for the URL.
+    Predicate(Arc<dyn Fn(&str) -> bool + Send + Sync>),
+}
+
+impl UrlMatcher {
+    pub fn glob(pattern: impl Into<String>) -> Self {
+        Self::Glob(pattern.into())
+    }
+
+    pub fn contains(part: impl Into<String>) -> Self {
+        Self::Contains(part.into())
+    }
+
+    pub fn predicate(predicate: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        Self::Predicate(Arc::new(predicate))
+    }
+
+    pub fn matches(&self, url: &str) -> bool {
+        match self {
+            UrlMatcher::Glob(pattern) => glob_matches(pattern, url),
+            UrlMatcher::Contains(part) => url.contains(part.as_str()),
+            UrlMatcher::Predicate(predicate) => predicate(url),
+        }
+    }
+}
+
+impl fmt::Debug for UrlMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UrlMatcher::Glob(pattern) => f.debug_tuple("Glob").field(pattern).finish(),
+            UrlMatcher::Contains(part) => f.debug_tuple("Contains").field(part).finish(),
+            UrlMatcher::Predicate(_) => f.debug_tuple("Predicate").field(&"<fn>").finish(),
+        }
+    }
+}
+
+impl fmt::Display for UrlMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UrlMatcher::Glob(pattern) => write!(f, "URL {pattern}"),
+            UrlMatcher::Contains(part) => write!(f, "URL containing {part}"),
+            UrlMatcher::Predicate(_) => write!(f, "URL accepted by predicate"),
+        }
+    }
+}
+
+impl From<&str> for UrlMatcher {
+    fn from(pattern: &str) -> Self {
+        Self::Glob(pattern.to_string())
+    }
+}
+
+impl From<String> for UrlMatcher {
+    fn from(pattern: String) -> Self {
+        Self::Glob(pattern)
+    }
+}
+
+impl From<&String> for UrlMatcher {
+    fn from(pattern: &String) -> Self {
+        Self::Glob(pattern.clone())
+    }
+}
+
+enum GlobToken {
+    Char(char),
+    Segment,
+    Any,
+}
+
+fn glob_matches(pattern: &str, url: &str) -> bool {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '*' if chars.next_if_eq(&'*').is_some() => GlobToken::Any,
+            '*' => GlobToken::Segment,
+            c => GlobToken::Char(c),
+        });
+    }
+    let url: Vec<char> = url.chars().collect();
+
+    // matched[j]: the tokens seen so far match the first j characters of the URL
+    let mut matched = vec![false; url.len() + 1];
+    matched[0] = true;
+    for token in &tokens {
+        let mut next = vec![false; url.len() + 1];
+        for j in 0..=url.len() {
+            next[j] = match token {
+                GlobToken::Char(c) => j > 0 && matched[j - 1] && url[j - 1] == *c,
+                GlobToken::Segment => matched[j] || (j > 0 && next[j - 1] && url[j - 1] != '/'),
+                GlobToken::Any => matched[j] || (j > 0 && next[j - 1]),
+            };
+        }
+        matched = next;
+    }
+    matched[url.len()]
+}
+
+// ── Selector states ──────────────────────────────────────────────────────────
+
+/// The state the `wait_for_selector` helpers wait for the first matching element to reach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectorState {
+    /// An element matches, whether or not it is rendered.
+    Attached,
+    /// No element matches.
+    Detached,
+    /// An element matches, has a non-empty bounding box and is not `visibility: hidden`.
+    Visible,
+    /// No element matches, or the first one is not visible.
+    Hidden,
+}
+
+impl SelectorState {
+    /// Whether reaching this state hands back the element.
+    pub(crate) fn yields_node(&self) -> bool {
+        matches!(self, SelectorState::Attached | SelectorState::Visible)
+    }
+
+    /// Whether the state depends on the element being rendered, not just present.
+    pub(crate) fn needs_visibility(&self) -> bool {
+        matches!(self, SelectorState::Visible | SelectorState::Hidden)
+    }
+
+    /// Whether the first matching element, if any, and its visibility put the page in
+    /// this state.
+    pub(crate) fn reached(&self, attached: bool, visible: bool) -> bool {
+        match self {
+            SelectorState::Attached => attached,
+            SelectorState::Detached => !attached,
+            SelectorState::Visible => attached && visible,
+            SelectorState::Hidden => !attached || !visible,
+        }
+    }
+}
+
+impl fmt::Display for SelectorState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelectorState::Attached => write!(f, "attached"),
+            SelectorState::Detached => write!(f, "detached"),
+            SelectorState::Visible => write!(f, "visible"),
+            SelectorState::Hidden => write!(f, "hidden"),
+        }
+    }
+}
+
+/// The isolated world (CDP) or sandbox (BiDi) selector waits run in, out of reach of the
+/// page's own scripts.
+pub(crate) const SANDBOX: &str = "rustenium";
+
+/// Whether `this` is rendered: it has a non-empty bounding box and is not
+/// `visibility: hidden`.
+pub(crate) const VISIBLE_SCRIPT: &str = r#"function() {
+    const rect = this.getBoundingClientRect();
+    return rect.width > 0 && rect.height > 0 &&
+        window.getComputedStyle(this).visibility !== 'hidden';
+}"#;
+
+/// Watches the page until the first element matching a CSS, XPath or text query reaches
+/// `state`, then calls `send` once with the element, or with `null` for the states that have
+/// none. A `MutationObserver` re-checks after every DOM change and, for the visibility
+/// states, an animation-frame loop catches layout changes that mutate nothing. Queries of
+/// kind `changes` stand in for locators only the browser can run: they call `send` with
+/// `'changed'` at once and after DOM changes, at most once a frame. The watch stops after
+/// `timeout` milliseconds.
+const WAIT_FOR_SELECTOR_SCRIPT: &str = r#"function(send, options) {
+    const { kind, query, exact, ignoreCase, state, timeout } = options;
+    const skip = new Set(['HEAD', 'SCRIPT', 'STYLE', 'NOSCRIPT', 'TEMPLATE']);
+    const normalize = (text) => {
+        const collapsed = (text || '').replace(/\s+/g, ' ').trim();
+        return ignoreCase ? collapsed.toLowerCase() : collapsed;
+    };
+    const byText = (root, needle) => {
+        for (const element of root.children || []) {
+            if (skip.has(element.tagName)) continue;
+            const text = normalize(element.innerText ?? element.textContent);
+            if (!text.includes(needle)) continue;
+            const inner = byText(element, needle);
+            if (inner) return inner;
+            if (!exact || text === needle) return element;
+        }
+        return null;
+    };
+    const find = () => {
+        if (kind === 'css') return document.querySelector(query);
+        if (kind === 'xpath') {
+            const node = document.evaluate(query, document, null,
+                XPathResult.FIRST_ORDERED_NODE_TYPE, null).singleNodeValue;
+            return node instanceof Element ? node : null;
+        }
+        return byText(document, normalize(query));
+    };
+    const visible = (element) => {
+        const rect = element.getBoundingClientRect();
+        return rect.width > 0 && rect.height > 0 &&
+            window.getComputedStyle(element).visibility !== 'hidden';
+    };
+    let observer = null;
+    let frame = 0;
+    let timer = 0;
+    let done = false;
+    const stop = () => {
+        done = true;
+        if (observer) observer.disconnect();
+        cancelAnimationFrame(frame);
+        clearTimeout(timer);
+    };
+    const check = () => {
+        if (done) return true;
+        const element = find();
+        const attached = element !== null;
+        const shown = attached && visible(element);
+        const reached = state === 'attached' ? attached
+            : state === 'detached' ? !attached
+            : state === 'visible' ? shown
+            : !shown;
+        if (!reached) return false;
+        stop();
+        send(state === 'attached' || state === 'visible' ? element : null);
+        return true;
+    };
+    let pending = false;
+    const changed = () => {
+        if (pending) return;
+        pending = true;
+        frame = requestAnimationFrame(() => {
+            pending = false;
+            if (!done) send('changed');
+        });
+    };
+    if (kind === 'changes') {
+        send('changed');
+        observer = new MutationObserver(changed);
+    } else {
+        if (check()) return;
+        observer = new MutationObserver(check);
+        if (state === 'visible' || state === 'hidden') {
+            const poll = () => {
+                if (!check()) frame = requestAnimationFrame(poll);
+            };
+            frame = requestAnimationFrame(poll);
+        }
+    }
+    observer.observe(document, { childList: true, subtree: true, attributes: true, characterData: true });
+    timer = setTimeout(stop, timeout);
+}"#;
+
+/// A one-argument function declaration that runs [`WAIT_FOR_SELECTOR_SCRIPT`] for
+/// `selector` and `state`, handing what it finds to its argument. Role and frame-owner
+/// selectors run as `changes` queries.
+pub(crate) fn wait_for_selector_script(
+    selector: &Selector,
+    state: SelectorState,
+    timeout: Duration,
+) -> String {
+    let (kind, query, exact, ignore_case) = match selector {
+        Selector::Css(css) => ("css", css.as_str(), false, false),
+        Selector::XPath(expression) => ("xpath", expression.as_str(), false, false),
+        Selector::Text {
+            text,
+            exact,
+            ignore_case,
+        } => ("text", text.as_str(), *exact, *ignore_case),
+        Selector::Role { .. } | Selector::FrameOwner(_) => ("changes", "", false, false),
+    };
+    let options = serde_json::json!({
+        "kind": kind,
+        "query": query,
+        "exact": exact,
+        "ignoreCase": ignore_case,
+        "state": state.to_string(),
+        "timeout": timeout.as_millis() as u64,
+    });
+    format!(
+        "function(send) {{ return ({}).call(this, send, {}); }}",
+        WAIT_FOR_SELECTOR_SCRIPT, options
+    )
+}
+
+/// A name no other wait in this process has used, for script channels and bindings.
+pub(crate) fn unique_wait_id(prefix: &str) -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    format!(
+        "{prefix}-{}-{}",
+        std::process::id(),
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Runs `trigger` alongside `next` and returns what `next` resolves to, or `None` after
+/// `timeout`, which covers `trigger` too. `trigger` is dropped as soon as `next` resolves,
+/// since a script that opens a dialog or file chooser only returns once it is answered.
+pub(crate) async fn race_trigger<R>(
+    trigger: impl Future,
+    next: impl Future<Output = Option<R>>,
+    timeout: Duration,
+) -> Option<R> {
+    tokio::time::timeout(timeout, async {
+        tokio::pin!(next);
+        tokio::select! {
+            found = &mut next => found,
+            _ = async { trigger.await; } => next.await,
+        }
+    })
+    .await
+    .ok()
+    .flatten()
+}
+
+// ── Typed events ─────────────────────────────────────────────────────────────
+
+/// A WebDriver BiDi event type that [`Page::wait_for_event`](crate::page::Page::wait_for_event)
+/// can wait for, e.g. [`browsing_context::events::Load`]. Implemented for every event the
+/// generated definitions mark with [`rustenium_bidi_definitions::EventType`].
+///
+/// [`browsing_context::events::Load`]: rustenium_bidi_definitions::browsing_context::events::Load
+pub trait BidiEventType: TryFrom<Event> + Send + 'static {
+    /// The event name to subscribe to, e.g. `"browsingContext.load"`.
+    const IDENTIFIER: &'static str;
+}
+
+impl<T> BidiEventType for T
+where
+    T: rustenium_bidi_definitions::EventType + TryFrom<Event> + Send + 'static,
+{
+    const IDENTIFIER: &'static str = <T as rustenium_bidi_definitions::EventType>::IDENTIFIER;
+}
+
+/// A CDP event type that
+/// [`CdpBrowser::wait_for_event`](crate::browsers::cdp_browser::CdpBrowser::wait_for_event)
+/// can wait for, e.g. [`browser_protocol::page::events::LifecycleEvent`]. The event's domain
+/// has to be enabled for Chrome to send it. Implemented for every event the generated
+/// definitions mark with [`rustenium_cdp_definitions::EventType`].
+///
+/// [`browser_protocol::page::events::LifecycleEvent`]: rustenium_cdp_definitions::browser_protocol::page::events::LifecycleEvent
+pub trait CdpEventType: DeserializeOwned + Send + 'static {
+    /// The event method, e.g. `"Page.lifecycleEvent"`.
+    const IDENTIFIER: &'static str;
+}
+
+impl<T> CdpEventType for T
+where
+    T: rustenium_cdp_definitions::EventType + DeserializeOwned + Send + 'static,
+{
+    const IDENTIFIER: &'static str = <T as rustenium_cdp_definitions::EventType>::IDENTIFIER;
+}
+
+// ── Trackers ─────────────────────────────────────────────────────────────────
+
+/// The requests a page has sent that have neither completed nor failed yet.
+#[derive(Debug, Default)]
+pub(crate) struct InFlightRequests {
+    requests: StdMutex<HashSet<String>>,
+    changed: Notify,
+}
+
+impl InFlightRequests {
+    pub(crate) fn started(&self, request: String) {
+        self.requests.lock().unwrap().insert(request);
+        self.changed.notify_waiters();
+    }
+
+    pub(crate) fn finished(&self, request: &str) {
+        self.requests.lock().unwrap().remove(request);
+        self.changed.notify_waiters();
+    }
+
+    /// Resolves once no request has started or been in flight for [`NETWORK_IDLE_TIME`].
+    pub(crate) async fn idle(&self) {
+        loop {
+            let changed = self.changed.notified();
+            if self.requests.lock().unwrap().is_empty() {
+                tokio::select! {
+                    _ = sleep(NETWORK_IDLE_TIME) => return,
+                    _ = changed => {}
+                }
+            } else {
+                changed.await;
+            }
+        }
+    }
+}
+
+/// What `Page.lifecycleEvent` and the navigation events have said about one frame's
+/// current document.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FrameLifecycle {
+    pub(crate) loader_id: String,
+    pub(crate) url: String,
+    /// Lifecycle events fired for the current loader, e.g. `"load"` or `"networkIdle"`.
+    pub(crate) events: HashSet<String>,
+    /// Fragment and History API navigations within the current document.
+    pub(crate) same_document_navigations: u64,
+}
+
+/// Keeps the [`FrameLifecycle`] of every frame in a CDP target up to date. Chrome replays
+/// the events a document already fired once lifecycle events are enabled, so the state is
+/// complete from then on.
+#[derive(Debug, Default)]
+pub(crate) struct LifecycleTracker {
+    frames: StdMutex<HashMap<String, FrameLifecycle>>,
+    changed: Notify,
+}
+
+impl LifecycleTracker {
+    pub(crate) fn lifecycle_event(&self, frame_id: &str, loader_id: &str, name: &str) {
+        {
+            let mut frames = self.frames.lock().unwrap();
+            let frame = frames.entry(frame_id.to_string()).or_default();
+            if name == "init" {
+                frame.loader_id = loader_id.to_string();
+                frame.events.clear();
+            }
+            frame.events.insert(name.to_string());
+        }
+        self.changed.notify_waiters();
+    }
+
+    pub(crate) fn navigated(&self, frame_id: &str, loader_id: &str, url: &str) {
+        {
+            let mut frames = self.frames.lock().unwrap();
+            let frame = frames.entry(frame_id.to_string()).or_default();
+            if frame.loader_id != loader_id {
+                frame.loader_id = loader_id.to_string();
+                frame.events.clear();
+            }
+            frame.url = url.to_string();
+        }
+        self.changed.notify_waiters();
+    }
+
+    pub(crate) fn navigated_within_document(&self, frame_id: &str, url: &str) {
+        {
+            let mut frames = self.frames.lock().unwrap();
+            let frame = frames.entry(frame_id.to_string()).or_default();
+            frame.url = url.to_string();
+            frame.same_document_navigations += 1;
+        }
+        self.changed.notify_waiters();
+    }
+
+    pub(crate) fn detached(&self, frame_id: &str) {
+        self.frames.lock().unwrap().remove(frame_id);
+        self.changed.notify_waiters();
+    }
+
+    pub(crate) fn clear(&self) {
+        self.frames.lock().unwrap().clear();
+        self.changed.notify_waiters();
+    }
+
+    pub(crate) fn frame(&self, frame_id: &str) -> FrameLifecycle {
+        self.frames
+            .lock()
+            .unwrap()
+            .get(frame_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Resolves once `condition` holds for the frame `frame_id`.
+    pub(crate) async fn wait_until(
+        &self,
+        frame_id: &str,
+        mut condition: impl FnMut(&FrameLifecycle) -> bool,
+    ) {
+        loop {
+            let changed = self.changed.notified();
+            if condition(&self.frame(frame_id)) {
+                return;
+            }
+            changed.await;
+        }
+    }
+}
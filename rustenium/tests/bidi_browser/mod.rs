@@ -1,9 +1,29 @@
-use rustenium::browsers::{BidiBrowser, NavigateOptionsBuilder};
+use rustenium::browsers::{
+    BidiBrowser, EvaluateScriptOptionsBuilder, FindNodesOptionsBuilder, NavigateOptionsBuilder,
+    ShadowPierce,
+};
+use rustenium::console::ConsoleLevel;
+use rustenium::dialog::DialogKind;
+use rustenium::download::{DownloadBehavior, DownloadState};
+use rustenium::error::bidi::{EvaluateError, FrameError, PopupError, WaitError};
+use rustenium::error::dialog::{DialogError, FileChooserError};
+use rustenium::error::download::FileDownloadError;
+use rustenium::error::node::{LocatorError, NodeActionError, NodeMouseError, SetFilesError};
+use rustenium::evaluate::NodeRef;
 use rustenium::input::{MouseClickOptions, Point};
-use rustenium::nodes::Node;
+use rustenium::locator::LocatorFilter;
+use rustenium::nodes::{ActionabilityOptions, Node};
+use rustenium::pdf::{PageSize, PdfMargins, PdfOptions, PdfOptionsBuilder, PdfOrientation};
+use rustenium::wait::{LoadState, SelectorState, UrlMatcher};
+use rustenium_bidi_definitions::browsing_context::events::Load;
 use rustenium_bidi_definitions::browsing_context::types::ReadinessState;
 use rustenium_bidi_definitions::script::types::{PrimitiveProtocolValue, RemoteValue};
-use rustenium_macros::css;
+use rustenium_macros::{css, role, text};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 
 fn extract_string(value: &RemoteValue) -> Option<String> {
     match value {
@@ -275,6 +295,81 @@ pub async fn test_node_mouse_click(mut browser: impl BidiBrowser) {
     browser.close().await.unwrap();
 }
 
+const ACTIONABILITY_SETUP: &str = "document.body.innerHTML = `
+    <button id=\"covered\">Covered</button>
+    <div id=\"overlay\" style=\"position: fixed; inset: 0; background: white\"></div>
+    <button id=\"disabled\" disabled>Disabled</button>
+    <button id=\"hidden\" style=\"visibility: hidden\">Hidden</button>
+    <button id=\"gone\">Gone</button>`";
+
+pub async fn test_node_actionability_checks(mut browser: impl BidiBrowser) {
+    browser
+        .navigate_with_options("https://example.com", nav_opts())
+        .await
+        .unwrap();
+    browser
+        .evaluate_script(ACTIONABILITY_SETUP.to_string(), false)
+        .await
+        .unwrap();
+    let short = ActionabilityOptions::default().timeout(Duration::from_millis(300));
+
+    let mut covered = browser.find_node(css!("#covered")).await.unwrap().unwrap();
+    covered.set_actionability(short.clone());
+    let result = covered.mouse_click().await;
+    assert!(
+        matches!(
+            result,
+            Err(NodeMouseError::Action(NodeActionError::Obscured { ref by })) if by == "div#overlay"
+        ),
+        "got {result:?}"
+    );
+
+    // The disabled and hidden buttons are covered too, but fail their earlier checks first
+    let mut disabled = browser.find_node(css!("#disabled")).await.unwrap().unwrap();
+    disabled.set_actionability(short.clone());
+    let result = disabled.mouse_click().await;
+    assert!(
+        matches!(
+            result,
+            Err(NodeMouseError::Action(NodeActionError::Disabled))
+        ),
+        "got {result:?}"
+    );
+    let mut hidden = browser.find_node(css!("#hidden")).await.unwrap().unwrap();
+    hidden.set_actionability(short.clone());
+    let result = hidden.mouse_click().await;
+    assert!(
+        matches!(
+            result,
+            Err(NodeMouseError::Action(NodeActionError::NotVisible))
+        ),
+        "got {result:?}"
+    );
+
+    let mut gone = browser.find_node(css!("#gone")).await.unwrap().unwrap();
+    gone.delete().await.unwrap();
+    let result = gone.mouse_click().await;
+    assert!(
+        matches!(
+            result,
+            Err(NodeMouseError::Action(NodeActionError::Detached))
+        ),
+        "got {result:?}"
+    );
+
+    // Clicks wait for the overlay to go away
+    browser
+        .evaluate_script(
+            "setTimeout(() => document.getElementById('overlay').remove(), 200)".to_string(),
+            false,
+        )
+        .await
+        .unwrap();
+    covered.set_actionability(ActionabilityOptions::default());
+    covered.mouse_click().await.unwrap();
+    browser.close().await.unwrap();
+}
+
 pub async fn test_mouse_move_to_point(mut browser: impl BidiBrowser) {
     browser
         .navigate_with_options("https://example.com", nav_opts())
@@ -412,6 +507,892 @@ pub async fn test_get_active_context_id(browser: impl BidiBrowser) {
     browser.close().await.unwrap();
 }
 
+pub async fn test_pages_navigate_concurrently(mut browser: impl BidiBrowser) {
+    let first = browser.active_page().unwrap();
+    let second = browser.create_context(false).await.unwrap();
+    let ids: Vec<_> = browser.pages().iter().map(|p| p.id().clone()).collect();
+    assert!(ids.contains(first.id()) && ids.contains(second.id()));
+
+    let (a, b) = tokio::join!(
+        first.navigate_with_options("https://example.com", nav_opts()),
+        second.navigate_with_options("https://httpbin.org/html", nav_opts()),
+    );
+    a.unwrap();
+    b.unwrap();
+    let first_url = first.evaluate_script("location.href", false).await.unwrap();
+    let second_url = second
+        .evaluate_script("location.href", false)
+        .await
+        .unwrap();
+    assert!(
+        extract_string(&first_url.result)
+            .unwrap()
+            .contains("example.com")
+    );
+    assert!(
+        extract_string(&second_url.result)
+            .unwrap()
+            .contains("httpbin.org")
+    );
+    assert!(!second.find_nodes(css!("h1")).await.unwrap().is_empty());
+
+    second.close().await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    assert!(!browser.pages().iter().any(|p| p.id() == second.id()));
+    browser.close().await.unwrap();
+}
+
+pub async fn test_wait_for_popup(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options("https://example.com", nav_opts())
+        .await
+        .unwrap();
+    let popup = page
+        .wait_for_popup(
+            async {
+                page.evaluate_script_with_options(
+                    "window.open('https://example.com')",
+                    false,
+                    EvaluateScriptOptionsBuilder::default()
+                        .user_activation(true)
+                        .build(),
+                )
+                .await
+                .unwrap();
+            },
+            std::time::Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert_ne!(popup.id(), page.id());
+    popup.close().await.unwrap();
+
+    // The timeout covers a trigger that never finishes
+    let err = page
+        .wait_for_popup(std::future::pending::<()>(), Duration::from_millis(300))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, PopupError::Timeout(_)));
+    browser.close().await.unwrap();
+}
+
+pub async fn test_wait_helpers(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate("https://example.com").await.unwrap();
+    page.wait_for_load_state(LoadState::Load, Duration::from_secs(10))
+        .await
+        .unwrap();
+    page.wait_for_load_state(LoadState::NetworkIdle, Duration::from_secs(10))
+        .await
+        .unwrap();
+
+    // History API and fragment navigations finish without a new document
+    let url = page
+        .wait_for_navigation(
+            async {
+                page.evaluate_script("history.pushState({}, '', '/spa/step-2')", false)
+                    .await
+                    .unwrap();
+            },
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert_eq!(url, "https://example.com/spa/step-2");
+    page.wait_for_url("https://example.com/spa/*", Duration::from_secs(1))
+        .await
+        .unwrap();
+
+    let waiting = page.wait_for_url(UrlMatcher::contains("#done"), Duration::from_secs(5));
+    let (waited, _) = tokio::join!(waiting, async {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        page.evaluate_script("location.hash = 'done'", false)
+            .await
+            .unwrap();
+    });
+    waited.unwrap();
+
+    let load = page
+        .wait_for_event::<Load, _, _>(
+            async {
+                page.navigate("https://example.com/?reloaded")
+                    .await
+                    .unwrap();
+            },
+            |load| load.params.base_navigation_info.url.contains("reloaded"),
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(&load.params.base_navigation_info.context, page.id());
+
+    // The timeout covers a trigger that never finishes
+    let err = page
+        .wait_for_navigation(std::future::pending::<()>(), Duration::from_millis(300))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, WaitError::Timeout(_, _)));
+
+    let err = page
+        .wait_for_url("https://never.example/**", Duration::from_millis(300))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, WaitError::Timeout(_, _)));
+    browser.close().await.unwrap();
+}
+
+const DELAYED_PAGE: &str = "data:text/html,<p id=spinner>Loading</p><script>setTimeout(() => { document.getElementById('spinner').style.visibility = 'hidden'; const button = document.createElement('button'); button.id = 'done'; button.textContent = 'Continue'; document.body.append(button); }, 300)</script>";
+
+pub async fn test_wait_for_selector(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate(DELAYED_PAGE).await.unwrap();
+    let button = page
+        .wait_for_selector(
+            css!("#done"),
+            SelectorState::Visible,
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert!(button.is_some());
+    let spinner = page
+        .wait_for_selector(
+            css!("#spinner"),
+            SelectorState::Hidden,
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert!(spinner.is_none());
+    let continue_text = page
+        .wait_for_selector(
+            text!("Continue", exact),
+            SelectorState::Attached,
+            Duration::from_secs(1),
+        )
+        .await
+        .unwrap();
+    assert!(continue_text.is_some());
+
+    // Accessibility locators are re-run by the browser when the DOM changes
+    page.navigate(DELAYED_PAGE).await.unwrap();
+    let by_role = page
+        .wait_for_selector(
+            role!("button", name = "Continue"),
+            SelectorState::Attached,
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert!(by_role.is_some());
+
+    page.evaluate_script("document.getElementById('done').remove()", false)
+        .await
+        .unwrap();
+    let gone = page
+        .wait_for_selector(
+            css!("#done"),
+            SelectorState::Detached,
+            Duration::from_secs(1),
+        )
+        .await
+        .unwrap();
+    assert!(gone.is_none());
+
+    let Err(err) = page
+        .wait_for_selector(
+            css!("#never"),
+            SelectorState::Attached,
+            Duration::from_millis(300),
+        )
+        .await
+    else {
+        panic!("expected the wait to time out");
+    };
+    assert!(matches!(err, WaitError::Timeout(_, _)));
+    assert!(err.to_string().contains("#never to be attached"));
+    browser.close().await.unwrap();
+}
+
+#[derive(Debug, serde::Deserialize, PartialEq)]
+struct Heading {
+    tag: String,
+    words: Vec<String>,
+}
+
+pub async fn test_evaluate(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options("data:text/html,<h1>Hello typed world</h1>", nav_opts())
+        .await
+        .unwrap();
+
+    let sum: i64 = page.evaluate("(a, b) => a + b", (2, 3)).await.unwrap();
+    assert_eq!(sum, 5);
+    let delayed: String = page
+        .evaluate(
+            "(name) => new Promise((resolve) => setTimeout(() => resolve('hi ' + name), 10))",
+            ("rustenium",),
+        )
+        .await
+        .unwrap();
+    assert_eq!(delayed, "hi rustenium");
+
+    let heading = page.find_node(css!("h1")).await.unwrap().unwrap();
+    let element = NodeRef::from_node(&heading).unwrap();
+    let parsed: Heading = page
+        .evaluate(
+            "({ element }) => ({ tag: element.tagName, words: element.textContent.split(' ') })",
+            (serde_json::json!({ "element": element }),),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        parsed,
+        Heading {
+            tag: "H1".to_string(),
+            words: vec!["Hello".into(), "typed".into(), "world".into()],
+        }
+    );
+    let returned: NodeRef = page
+        .evaluate("() => document.querySelector('h1')", ())
+        .await
+        .unwrap();
+    assert_eq!(returned.shared_id(), element.shared_id());
+
+    let err = page
+        .evaluate::<(), _>("() => { throw new Error('boom') }", ())
+        .await
+        .unwrap_err();
+    let EvaluateError::Exception(exception) = &err else {
+        panic!("expected a JavaScript exception, got {err:?}");
+    };
+    assert!(exception.message.contains("boom"));
+    let err = page
+        .evaluate::<i64, _>("() => 'not a number'", ())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, EvaluateError::DeserializeError(_)));
+    browser.close().await.unwrap();
+}
+
+pub async fn test_expose_function(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options(FRAMES_PAGE, nav_opts())
+        .await
+        .unwrap();
+    let adds = Arc::new(AtomicUsize::new(0));
+    let counted = adds.clone();
+    page.expose_function("rustAdd", move |args: Vec<serde_json::Value>| {
+        counted.fetch_add(1, Ordering::SeqCst);
+        async move {
+            match (args[0].as_i64(), args[1].as_i64()) {
+                (Some(a), Some(b)) => Ok(serde_json::Value::from(a + b)),
+                _ => Err("expected two integers"),
+            }
+        }
+    })
+    .await
+    .unwrap();
+
+    let sum: i64 = page
+        .evaluate("() => window.rustAdd(2, 3)", ())
+        .await
+        .unwrap();
+    assert_eq!(sum, 5);
+    let rejected: String = page
+        .evaluate(
+            "() => window.rustAdd('a', 3).catch((err) => err.message)",
+            (),
+        )
+        .await
+        .unwrap();
+    assert_eq!(rejected, "expected two integers");
+    let outer = page.frame_by_name("outer").await.unwrap().unwrap();
+    let from_frame: i64 = outer
+        .evaluate("() => window.rustAdd(20, 22)", ())
+        .await
+        .unwrap();
+    assert_eq!(from_frame, 42);
+
+    // The preload script installs it again in new documents
+    page.navigate_with_options("data:text/html,<p>next</p>", nav_opts())
+        .await
+        .unwrap();
+    let sum: i64 = page
+        .evaluate("() => window.rustAdd(1, 1)", ())
+        .await
+        .unwrap();
+    assert_eq!(sum, 2);
+
+    // Exposing the name again replaces the function instead of answering twice
+    let calls = adds.load(Ordering::SeqCst);
+    page.expose_function("rustAdd", |args: Vec<serde_json::Value>| async move {
+        match (args[0].as_i64(), args[1].as_i64()) {
+            (Some(a), Some(b)) => Ok::<_, &str>(serde_json::Value::from(a * b)),
+            _ => Err("expected two integers"),
+        }
+    })
+    .await
+    .unwrap();
+    let product: i64 = page
+        .evaluate("() => window.rustAdd(6, 7)", ())
+        .await
+        .unwrap();
+    assert_eq!(product, 42);
+    page.navigate_with_options("data:text/html,<p>again</p>", nav_opts())
+        .await
+        .unwrap();
+    let product: i64 = page
+        .evaluate("() => window.rustAdd(2, 4)", ())
+        .await
+        .unwrap();
+    assert_eq!(product, 8);
+    assert_eq!(adds.load(Ordering::SeqCst), calls);
+
+    // A frame exposes on its top-level page, since preload scripts only take top-level contexts
+    page.navigate_with_options(FRAMES_PAGE, nav_opts())
+        .await
+        .unwrap();
+    let nested = page.frame_by_name("nested").await.unwrap().unwrap();
+    nested
+        .expose_function("rustSub", |args: Vec<serde_json::Value>| async move {
+            match (args[0].as_i64(), args[1].as_i64()) {
+                (Some(a), Some(b)) => Ok::<_, &str>(serde_json::Value::from(a - b)),
+                _ => Err("expected two integers"),
+            }
+        })
+        .await
+        .unwrap();
+    let difference: i64 = nested
+        .evaluate("() => window.rustSub(9, 4)", ())
+        .await
+        .unwrap();
+    assert_eq!(difference, 5);
+    let difference: i64 = page
+        .evaluate("() => window.rustSub(3, 1)", ())
+        .await
+        .unwrap();
+    assert_eq!(difference, 2);
+    browser.close().await.unwrap();
+}
+
+pub async fn test_console(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    let mut messages = page.console_messages().await.unwrap();
+    let mut errors = page.page_errors().await.unwrap();
+    let console = page.collect_console().await.unwrap();
+
+    page.evaluate::<(), _>("() => console.warn('careful', 2, { a: 1 })", ())
+        .await
+        .unwrap();
+    let message = tokio::time::timeout(Duration::from_secs(5), messages.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(message.level, ConsoleLevel::Warning);
+    assert!(message.text.starts_with("careful 2"), "{}", message.text);
+    assert_eq!(
+        message.args[..2],
+        [serde_json::json!("careful"), serde_json::json!(2)]
+    );
+
+    page.evaluate::<(), _>(
+        "() => { setTimeout(() => { throw new Error('boom') }) }",
+        (),
+    )
+    .await
+    .unwrap();
+    let error = tokio::time::timeout(Duration::from_secs(5), errors.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(error.message.contains("boom"), "{}", error.message);
+
+    page.evaluate::<(), _>("() => console.error('failed to load')", ())
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(console.has_errors());
+    assert_eq!(console.page_errors().len(), 1);
+    assert_eq!(console.console_errors()[0].text, "failed to load");
+    assert!(format!("{console}").contains("[warning] careful 2"));
+    browser.close().await.unwrap();
+}
+
+pub async fn test_dialogs(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options("data:text/html,<p>dialogs</p>", nav_opts())
+        .await
+        .unwrap();
+
+    // The script returns only once the prompt is answered
+    let dialog = page
+        .wait_for_dialog(
+            page.evaluate_script(
+                "setTimeout(() => { window.answer = prompt('Name?', 'guest') })",
+                false,
+            ),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert_eq!(dialog.kind(), DialogKind::Prompt);
+    assert_eq!(dialog.message(), "Name?");
+    assert_eq!(dialog.default_value(), Some("guest"));
+    dialog.accept(Some("rustenium")).await.unwrap();
+    assert!(matches!(
+        dialog.dismiss().await,
+        Err(DialogError::AlreadyHandled)
+    ));
+    let answer: String = page.evaluate("() => window.answer", ()).await.unwrap();
+    assert_eq!(answer, "rustenium");
+
+    // A trigger blocked by its own dialog is dropped once the dialog opens
+    let dialog = page
+        .wait_for_dialog(
+            page.evaluate_script("confirm('Delete?')", false),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert_eq!(dialog.kind(), DialogKind::Confirm);
+    dialog.dismiss().await.unwrap();
+
+    let answered = Arc::new(AtomicUsize::new(0));
+    let counted = answered.clone();
+    let registration = page
+        .on_dialog(move |dialog| {
+            counted.fetch_add(1, Ordering::SeqCst);
+            async move {
+                dialog.accept(None).await.unwrap();
+            }
+        })
+        .await
+        .unwrap();
+    let confirmed: bool = page.evaluate("() => confirm('Sure?')", ()).await.unwrap();
+    assert!(confirmed);
+
+    // Once unregistered, the handler no longer sees dialogs
+    registration.unregister().await;
+    let dialog = page
+        .wait_for_dialog(
+            page.evaluate_script("alert('unhandled')", false),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    dialog.accept(None).await.unwrap();
+    assert_eq!(answered.load(Ordering::SeqCst), 1);
+
+    let err = page
+        .wait_for_dialog(async {}, Duration::from_millis(200))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DialogError::Timeout(_)));
+    browser.close().await.unwrap();
+}
+
+const FILES_PAGE: &str =
+    "data:text/html,<input type=file id=one><input type=file id=many multiple><p id=text>text</p>";
+
+/// Writes `name` into the temp directory, returning its path.
+fn upload_file(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, name).unwrap();
+    path
+}
+
+const PICKED_FILES: &str =
+    "(selector) => [...document.querySelector(selector).files].map((f) => f.name)";
+
+pub async fn test_file_inputs(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options(FILES_PAGE, nav_opts())
+        .await
+        .unwrap();
+    let (a, b) = (
+        upload_file("rustenium-upload-a.txt"),
+        upload_file("rustenium-upload-b.txt"),
+    );
+
+    let many = page.find_node(css!("#many")).await.unwrap().unwrap();
+    many.set_input_files(&[&a, &b]).await.unwrap();
+    let picked: Vec<String> = page.evaluate(PICKED_FILES, ("#many",)).await.unwrap();
+    assert_eq!(picked, ["rustenium-upload-a.txt", "rustenium-upload-b.txt"]);
+
+    let one = page.find_node(css!("#one")).await.unwrap().unwrap();
+    assert!(matches!(
+        one.set_input_files(&[&a, &b]).await,
+        Err(SetFilesError::NotMultiple(2))
+    ));
+    assert!(matches!(
+        one.set_input_files(&["no/such/file.txt"]).await,
+        Err(SetFilesError::FileNotFound(_))
+    ));
+    let text = page.find_node(css!("#text")).await.unwrap().unwrap();
+    assert!(matches!(
+        text.set_input_files(&[&a]).await,
+        Err(SetFilesError::NotFileInput)
+    ));
+
+    // Clicking the input opens a native chooser, which is intercepted
+    let mut one = page.find_node(css!("#one")).await.unwrap().unwrap();
+    let chooser = page
+        .wait_for_file_chooser(
+            async { one.mouse_click().await.unwrap() },
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert!(!chooser.is_multiple());
+    assert!(matches!(
+        chooser.set_files(&[&a, &b]).await,
+        Err(SetFilesError::NotMultiple(2))
+    ));
+    chooser.set_files(&[&b]).await.unwrap();
+    let picked: Vec<String> = page.evaluate(PICKED_FILES, ("#one",)).await.unwrap();
+    assert_eq!(picked, ["rustenium-upload-b.txt"]);
+
+    let err = page
+        .wait_for_file_chooser(async {}, Duration::from_millis(200))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, FileChooserError::Timeout(_)));
+    browser.close().await.unwrap();
+}
+
+pub const REPORT: &str = "a,b\n1,2\n";
+
+/// Serves a page linking to `/report.csv`, which is sent as an attachment, until the test
+/// process exits. Returns the page's URL.
+pub fn serve_download() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+            }
+            let (headers, body) = if request_line.contains("/report.csv") {
+                (
+                    "Content-Type: text/csv\r\nContent-Disposition: attachment; filename=\"report.csv\"",
+                    REPORT,
+                )
+            } else {
+                (
+                    "Content-Type: text/html",
+                    "<a id=download href=/report.csv>Download</a>",
+                )
+            };
+            let mut stream = reader.into_inner();
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\n{headers}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+        }
+    });
+    url
+}
+
+pub async fn test_downloads(mut browser: impl BidiBrowser) {
+    let dir = std::env::temp_dir().join(format!("rustenium-downloads-{}", std::process::id()));
+    browser
+        .set_download_behavior(DownloadBehavior::Allow { dir: dir.clone() })
+        .await
+        .unwrap();
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options(&serve_download(), nav_opts())
+        .await
+        .unwrap();
+
+    let mut link = page.find_node(css!("#download")).await.unwrap().unwrap();
+    let download = page
+        .wait_for_download(
+            async { link.mouse_click().await.unwrap() },
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(download.state, DownloadState::Completed);
+    assert_eq!(download.suggested_filename, "report.csv");
+    assert!(download.url.ends_with("/report.csv"));
+    let copy = dir.join("copy.csv");
+    download.save_as(&copy).await.unwrap();
+    assert_eq!(std::fs::read_to_string(copy).unwrap(), REPORT);
+
+    browser
+        .set_download_behavior(DownloadBehavior::Deny)
+        .await
+        .unwrap();
+    let download = page
+        .wait_for_download(
+            async { link.mouse_click().await.unwrap() },
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(download.state, DownloadState::Canceled);
+    assert!(matches!(
+        download.save_as(dir.join("denied.csv")).await,
+        Err(FileDownloadError::NotCompleted(DownloadState::Canceled))
+    ));
+
+    let err = page
+        .wait_for_download(async {}, Duration::from_millis(200))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, FileDownloadError::Timeout(_)));
+    browser.close().await.unwrap();
+}
+
+pub const PDF_PAGE: &str = "data:text/html,<h1 style='background:navy;color:white'>Invoice 42</h1><div style='height:3000px'>Total: 10 EUR</div>";
+
+pub async fn test_pdf(mut browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options(PDF_PAGE, nav_opts())
+        .await
+        .unwrap();
+
+    let pdf = page.pdf(PdfOptions::default()).await.unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+
+    let path = std::env::temp_dir().join(format!("rustenium-invoice-{}.pdf", std::process::id()));
+    let options = PdfOptionsBuilder::default()
+        .page_size(PageSize::A4)
+        .margins(PdfMargins::uniform(1.5))
+        .orientation(PdfOrientation::Landscape)
+        .scale(0.8)
+        .page_range("1")
+        .background(true)
+        .shrink_to_fit(true)
+        .save_path(path.to_string_lossy())
+        .build();
+    let first_page = browser.pdf(options).await.unwrap();
+    assert!(first_page.starts_with(b"%PDF"));
+    assert_eq!(std::fs::read(&path).unwrap(), first_page);
+    let _ = std::fs::remove_file(path);
+    browser.close().await.unwrap();
+}
+
+const FRAMES_PAGE: &str = "data:text/html,<iframe name=\"outer\" srcdoc=\"<p id=inner>outer</p><iframe name=nested srcdoc='<b>nested</b>'></iframe>\"></iframe>";
+
+pub async fn test_frames(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options(FRAMES_PAGE, nav_opts())
+        .await
+        .unwrap();
+
+    let frames = page.frames().await.unwrap();
+    assert_eq!(frames.len(), 2, "expected the outer and the nested frame");
+    assert_eq!(frames[0].parent(), page.id());
+    assert_eq!(frames[1].parent(), frames[0].id());
+
+    let outer = page.frame_by_name("outer").await.unwrap().unwrap();
+    assert_eq!(outer.id(), frames[0].id());
+    let inner = outer.find_node(css!("#inner")).await.unwrap();
+    assert!(inner.is_some(), "should locate nodes inside the frame");
+    let text = outer
+        .evaluate_script("document.querySelector('p').textContent", false)
+        .await
+        .unwrap();
+    assert_eq!(extract_string(&text.result).as_deref(), Some("outer"));
+
+    assert!(page.frame_by_url("srcdoc").await.unwrap().is_some());
+    assert!(page.frame_by_name("missing").await.unwrap().is_none());
+    browser.close().await.unwrap();
+}
+
+pub async fn test_frame_locator(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options(FRAMES_PAGE, nav_opts())
+        .await
+        .unwrap();
+
+    let nested = page
+        .frame_locator(css!("iframe[name=outer]"))
+        .frame_locator(css!("iframe[name=nested]"));
+    let text = nested
+        .evaluate_script("document.querySelector('b').textContent", false)
+        .await
+        .unwrap();
+    assert_eq!(extract_string(&text.result).as_deref(), Some("nested"));
+    assert_eq!(nested.find_nodes(css!("b")).await.unwrap().len(), 1);
+
+    let not_a_frame = page.frame_locator(css!("body")).frame().await;
+    assert!(matches!(not_a_frame, Err(FrameError::NotAFrame(_))));
+    let missing = page.frame_locator(css!("iframe.missing")).frame().await;
+    assert!(matches!(missing, Err(FrameError::NotFound(_))));
+    browser.close().await.unwrap();
+}
+
+/// Attaches an open shadow root with a nested open root, and a closed root, each holding
+/// one `.inside` element.
+const SHADOW_SETUP: &str = "
+    const open = document.body.appendChild(document.createElement('div'))
+        .attachShadow({ mode: 'open' });
+    open.innerHTML = '<span class=\"inside\">open</span><div></div>';
+    open.querySelector('div').attachShadow({ mode: 'open' })
+        .innerHTML = '<span class=\"inside\">nested</span>';
+    document.body.appendChild(document.createElement('div'))
+        .attachShadow({ mode: 'closed' })
+        .innerHTML = '<span class=\"inside\">closed</span>';
+";
+
+pub async fn test_find_nodes_pierces_shadow_roots(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options("https://example.com", nav_opts())
+        .await
+        .unwrap();
+    page.evaluate_script(SHADOW_SETUP, false).await.unwrap();
+
+    let light = page.find_nodes(css!(".inside")).await.unwrap();
+    assert!(
+        light.is_empty(),
+        "CSS should not cross shadow roots by default"
+    );
+
+    let open = page
+        .find_nodes_with_options(
+            css!(".inside"),
+            FindNodesOptionsBuilder::default()
+                .pierce_shadow(ShadowPierce::Open)
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(open.len(), 2, "open and nested open roots");
+
+    let all = page
+        .find_nodes_with_options(
+            css!(".inside"),
+            FindNodesOptionsBuilder::default()
+                .pierce_shadow(ShadowPierce::All)
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        all.len(),
+        3,
+        "closed roots are included with ShadowPierce::All"
+    );
+
+    let first = page
+        .find_nodes_with_options(
+            css!(".inside"),
+            FindNodesOptionsBuilder::default()
+                .pierce_shadow(ShadowPierce::All)
+                .max_node_count(1u64)
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(first.len(), 1);
+    browser.close().await.unwrap();
+}
+
+const PRODUCTS_SETUP: &str = "document.body.innerHTML = `
+    <ul>
+        <li class=\"product\">Mouse <button>Add</button></li>
+        <li class=\"product\">Keyboard <span class=\"sold-out\">Sold out</span></li>
+        <li class=\"product\">Monitor <button>Add</button></li>
+    </ul>`";
+
+pub async fn test_locator_chaining_and_filters(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options("https://example.com", nav_opts())
+        .await
+        .unwrap();
+    let products = page.locator(css!(".product"));
+    assert_eq!(
+        products.count().await.unwrap(),
+        0,
+        "nothing matches before setup"
+    );
+
+    // The same locator sees the nodes added after it was built
+    page.evaluate_script(PRODUCTS_SETUP, false).await.unwrap();
+    assert_eq!(products.count().await.unwrap(), 3);
+    assert_eq!(products.locator(css!("button")).count().await.unwrap(), 2);
+
+    let first = products.first().inner_text().await.unwrap();
+    assert!(first.starts_with("Mouse"), "got {first:?}");
+    let last = products.last().inner_text().await.unwrap();
+    assert!(last.starts_with("Monitor"), "got {last:?}");
+    let second = products.nth(1).inner_text().await.unwrap();
+    assert!(second.starts_with("Keyboard"), "got {second:?}");
+
+    let filtered = products.filter(LocatorFilter::has_text("Monitor"));
+    assert_eq!(filtered.count().await.unwrap(), 1);
+    let sold_out = products.filter(LocatorFilter::has(css!(".sold-out")));
+    assert_eq!(sold_out.count().await.unwrap(), 1);
+    let in_stock = products.filter(LocatorFilter::has_not(css!(".sold-out")));
+    assert_eq!(in_stock.count().await.unwrap(), 2);
+
+    let missing = products.nth(5).click().await;
+    assert!(matches!(missing, Err(LocatorError::NotFound(_))));
+
+    // Replacing the list does not leave the locator pointing at detached nodes
+    page.evaluate_script(
+        "document.querySelector('ul').outerHTML = '<ul><li class=\"product\">Desk</li></ul>'",
+        false,
+    )
+    .await
+    .unwrap();
+    let text = products.first().inner_text().await.unwrap();
+    assert_eq!(text, "Desk");
+    browser.close().await.unwrap();
+}
+
+pub(crate) const FORM_SETUP: &str = "document.body.innerHTML = `
+    <form>
+        <label>Email <input type=\"email\"></label>
+        <button type=\"submit\">Submit</button>
+        <button type=\"button\">Cancel</button>
+        <a href=\"#\">Sign in</a>
+        <p>Already signed in elsewhere?</p>
+    </form>`";
+
+pub async fn test_find_nodes_by_role_and_text(browser: impl BidiBrowser) {
+    let page = browser.active_page().unwrap();
+    page.navigate_with_options("https://example.com", nav_opts())
+        .await
+        .unwrap();
+    page.evaluate_script(FORM_SETUP, false).await.unwrap();
+
+    let buttons = page.find_nodes(role!("button")).await.unwrap();
+    assert_eq!(buttons.len(), 2);
+    let submit = page
+        .find_nodes(role!("button", name = "Submit"))
+        .await
+        .unwrap();
+    assert_eq!(submit.len(), 1);
+    let named = page.find_nodes(role!(name = "Cancel")).await.unwrap();
+    assert!(!named.is_empty());
+
+    let sign_in = page.find_nodes(text!("Sign in", exact)).await.unwrap();
+    assert_eq!(sign_in.len(), 1);
+    let partial = page.find_nodes(text!("sign", ignore_case)).await.unwrap();
+    assert_eq!(
+        partial.len(),
+        2,
+        "the link and the paragraph both mention it"
+    );
+
+    // The new locators work wherever a locator is accepted
+    let cancel = page.locator(role!("button", name = "Cancel"));
+    assert_eq!(cancel.count().await.unwrap(), 1);
+    assert_eq!(cancel.inner_text().await.unwrap(), "Cancel");
+    browser.close().await.unwrap();
+}
+
 pub async fn test_screenshot_returns_base64(mut browser: impl BidiBrowser) {
     browser
         .navigate_with_options("https://example.com", nav_opts())
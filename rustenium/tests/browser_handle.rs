@@ -0,0 +1,66 @@
+use rustenium::browsers::{BrowserHandle, ChromeBrowser};
+use rustenium::error::chrome::AttachError;
+
+fn handle() -> BrowserHandle {
+    BrowserHandle {
+        host: "localhost".to_string(),
+        chrome_pid: Some(4242),
+        remote_debugging_port: Some(9222),
+        cdp_ws_url: Some("ws://localhost:9222/devtools/page/ABC".to_string()),
+        user_data_dir: Some("/tmp/rustenium-chrome-9222".to_string()),
+        driver_pid: Some(4243),
+        driver_port: Some(9515),
+        driver_url: None,
+        session_id: Some("f00d".to_string()),
+        bidi_ws_url: Some("ws://localhost:9515/session".to_string()),
+        enable_bidi: true,
+        enable_cdp: false,
+    }
+}
+
+#[test]
+fn handle_round_trips_through_json() {
+    let handle = handle();
+    let json = serde_json::to_string(&handle).unwrap();
+    let parsed: BrowserHandle = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, handle);
+
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["remote_debugging_port"], 9222);
+    assert_eq!(value["driver_url"], serde_json::Value::Null);
+}
+
+#[tokio::test]
+async fn attach_to_stopped_browser_is_unreachable() {
+    let port = rustenium_core::find_free_port().unwrap();
+    let handle = BrowserHandle {
+        remote_debugging_port: Some(port),
+        ..handle()
+    };
+    let err = ChromeBrowser::attach(handle).await.unwrap_err();
+    assert!(matches!(err, AttachError::Unreachable(_)), "got {err:?}");
+}
+
+#[tokio::test]
+async fn attach_without_debugging_port_is_rejected() {
+    let handle = BrowserHandle {
+        remote_debugging_port: None,
+        ..handle()
+    };
+    let err = ChromeBrowser::attach(handle).await.unwrap_err();
+    assert!(matches!(
+        err,
+        AttachError::MissingField("remote_debugging_port")
+    ));
+}
+
+#[tokio::test]
+async fn attach_remote_driver_without_session_is_rejected() {
+    let handle = BrowserHandle {
+        driver_url: Some("http://localhost:4444".to_string()),
+        session_id: None,
+        ..handle()
+    };
+    let err = ChromeBrowser::attach(handle).await.unwrap_err();
+    assert!(matches!(err, AttachError::MissingField("session_id")));
+}
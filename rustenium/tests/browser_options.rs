@@ -1,4 +1,6 @@
-use rustenium::browsers::{BrowserScreenshotOptionsBuilder, NavigateOptionsBuilder};
+use rustenium::browsers::{
+    BrowserScreenshotOptionsBuilder, FindNodesOptionsBuilder, NavigateOptionsBuilder, ShadowPierce,
+};
 use rustenium_bidi_definitions::browsing_context::types::ReadinessState;
 
 // ── NavigateOptions ───────────────────────────────────────────────────────────
@@ -71,3 +73,21 @@ fn screenshot_options_builder_chains_multiple_fields() {
     assert!(opts.context_id.is_some());
     assert_eq!(opts.save_path.as_deref(), Some("/tmp/out.png"));
 }
+
+// ── FindNodesOptions ──────────────────────────────────────────────────────────
+
+#[test]
+fn find_nodes_options_default_does_not_pierce() {
+    let opts = FindNodesOptionsBuilder::default().build();
+    assert!(opts.pierce_shadow.is_none());
+}
+
+#[test]
+fn find_nodes_options_builder_sets_pierce_shadow() {
+    let opts = FindNodesOptionsBuilder::default()
+        .pierce_shadow(ShadowPierce::All)
+        .max_node_count(3u64)
+        .build();
+    assert_eq!(opts.pierce_shadow, Some(ShadowPierce::All));
+    assert_eq!(opts.max_node_count, Some(3));
+}
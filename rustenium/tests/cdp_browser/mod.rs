@@ -1,9 +1,25 @@
+use crate::bidi_browser::{PDF_PAGE, REPORT, serve_download};
 use rustenium::browsers::{
-    BidiBrowser, ChromeBrowser,
-    cdp_browser::{AddPreloadScriptOptions, CdpBrowser, FetchNodeOptions},
+    BidiBrowser, ChromeBrowser, ShadowPierce,
+    cdp_browser::{AddPreloadScriptOptions, CdpBrowser, FetchNodeOptions, Selector},
 };
+use rustenium::console::ConsoleLevel;
+use rustenium::dialog::DialogKind;
+use rustenium::download::{DownloadBehavior, DownloadEvent, DownloadState};
+use rustenium::error::cdp::{EvaluateError, LocateError, WaitError};
+use rustenium::error::dialog::{DialogError, FileChooserError};
+use rustenium::error::node::SetFilesError;
+use rustenium::evaluate::NodeRef;
 use rustenium::nodes::{AXNode, Node};
+use rustenium::pdf::{PageSize, PdfOptions, PdfOptionsBuilder, PdfOrientation};
+use rustenium::wait::{LoadState, SelectorState, UrlMatcher};
 use rustenium_cdp_definitions::browser_protocol::dom::types::BackendNodeId;
+use rustenium_cdp_definitions::browser_protocol::page::events::LifecycleEvent;
+use rustenium_core::CdpEventManagement;
+use rustenium_macros::{role, text, xpath};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 
 async fn first_backend_node_id(browser: &mut ChromeBrowser, url: &str) -> BackendNodeId {
     <ChromeBrowser as CdpBrowser>::navigate(browser, url)
@@ -220,3 +236,757 @@ pub async fn test_preload_script_add_runs_and_remove(mut browser: ChromeBrowser)
 
     browser.close().await.unwrap();
 }
+
+pub async fn test_frames_and_locate_in_frame(mut browser: ChromeBrowser) {
+    <ChromeBrowser as CdpBrowser>::navigate(
+        &mut browser,
+        "data:text/html,<iframe name=\"outer\" srcdoc=\"<p id=inner>outer</p>\"></iframe>",
+    )
+    .await
+    .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    let frames = browser.frames().await.unwrap();
+    assert_eq!(frames.len(), 1, "expected one child frame");
+    let frame = browser.frame_by_name("outer").await.unwrap().unwrap();
+    assert_eq!(frame.id, frames[0].id);
+
+    let owner = browser
+        .content_frame(Selector::css("iframe"))
+        .await
+        .unwrap();
+    assert_eq!(owner.as_ref(), Some(&frame.id));
+
+    let inner = browser
+        .locate_in_frame(&frame.id, Selector::css("#inner"))
+        .await
+        .unwrap();
+    assert!(inner.is_some(), "should locate nodes inside the frame");
+    assert!(
+        <ChromeBrowser as CdpBrowser>::locate(&mut browser, Selector::css("#inner"))
+            .await
+            .unwrap()
+            .is_none(),
+        "the main document should not see into the frame"
+    );
+
+    let text = browser
+        .evaluate_in_frame(&frame.id, "document.querySelector('p').textContent", false)
+        .await
+        .unwrap();
+    assert_eq!(text.result.value, Some(serde_json::json!("outer")));
+
+    // Evaluations share one isolated world per frame
+    for expected in [1, 2] {
+        let count = browser
+            .evaluate_in_frame(
+                &frame.id,
+                "globalThis.__count = (globalThis.__count ?? 0) + 1",
+                false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(count.result.value, Some(serde_json::json!(expected)));
+    }
+    browser.close().await.unwrap();
+}
+
+pub async fn test_locate_pierces_shadow_roots(mut browser: ChromeBrowser) {
+    <ChromeBrowser as CdpBrowser>::navigate(&mut browser, "https://example.com")
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    <ChromeBrowser as CdpBrowser>::evaluate_script(
+        &mut browser,
+        "const open = document.body.appendChild(document.createElement('div'))
+            .attachShadow({ mode: 'open' });
+        open.innerHTML = '<span class=\"inside\">open</span>';
+        document.body.appendChild(document.createElement('div'))
+            .attachShadow({ mode: 'closed' })
+            .innerHTML = '<span class=\"inside\">closed</span>';",
+        false,
+    )
+    .await
+    .unwrap();
+
+    let light = <ChromeBrowser as CdpBrowser>::locate_all(&mut browser, ".inside".into())
+        .await
+        .unwrap();
+    assert!(
+        light.is_empty(),
+        "querySelector should not cross shadow roots"
+    );
+
+    let open = browser
+        .locate_all_piercing(".inside".into(), ShadowPierce::Open)
+        .await
+        .unwrap();
+    assert_eq!(open.len(), 1);
+    let all = browser
+        .locate_all_piercing(".inside".into(), ShadowPierce::All)
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 2);
+    let first = browser
+        .locate_piercing(".inside".into(), ShadowPierce::All)
+        .await
+        .unwrap();
+    assert!(first.is_some());
+}
+
+pub async fn test_locate_by_role_and_text(mut browser: ChromeBrowser) {
+    <ChromeBrowser as CdpBrowser>::navigate(&mut browser, "https://example.com")
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    <ChromeBrowser as CdpBrowser>::evaluate_script(
+        &mut browser,
+        crate::bidi_browser::FORM_SETUP,
+        false,
+    )
+    .await
+    .unwrap();
+
+    let buttons = <ChromeBrowser as CdpBrowser>::locate_all(&mut browser, role!("button").into())
+        .await
+        .unwrap();
+    assert_eq!(buttons.len(), 2);
+    let submit = <ChromeBrowser as CdpBrowser>::locate(
+        &mut browser,
+        role!("button", name = "Submit").into(),
+    )
+    .await
+    .unwrap();
+    assert!(submit.is_some());
+
+    let sign_in =
+        <ChromeBrowser as CdpBrowser>::locate_all(&mut browser, text!("Sign in", exact).into())
+            .await
+            .unwrap();
+    assert_eq!(sign_in.len(), 1);
+    let case_sensitive =
+        <ChromeBrowser as CdpBrowser>::locate_all(&mut browser, Selector::text("sign"))
+            .await
+            .unwrap();
+    assert_eq!(
+        case_sensitive.len(),
+        1,
+        "only the paragraph has lowercase text"
+    );
+    let ignore_case =
+        <ChromeBrowser as CdpBrowser>::locate_all(&mut browser, text!("sign", ignore_case).into())
+            .await
+            .unwrap();
+    assert_eq!(ignore_case.len(), 2);
+
+    let xpath = <ChromeBrowser as CdpBrowser>::locate_all(&mut browser, xpath!("//button").into())
+        .await
+        .unwrap();
+    assert_eq!(xpath.len(), 2);
+
+    let missing = <ChromeBrowser as CdpBrowser>::wait_for(
+        &mut browser,
+        role!("dialog").into(),
+        std::time::Duration::from_millis(200),
+    )
+    .await;
+    assert!(matches!(missing, Err(LocateError::Timeout(s)) if s == "role=dialog"));
+    browser.close().await.unwrap();
+}
+
+pub async fn test_wait_helpers(mut browser: ChromeBrowser) {
+    <ChromeBrowser as CdpBrowser>::navigate(&mut browser, "https://example.com")
+        .await
+        .unwrap();
+    browser
+        .wait_for_load_state(LoadState::Load, Duration::from_secs(10))
+        .await
+        .unwrap();
+    browser
+        .wait_for_load_state(LoadState::NetworkIdle, Duration::from_secs(10))
+        .await
+        .unwrap();
+
+    let mut adapter = browser.adapter().clone();
+    let url = browser
+        .wait_for_navigation(
+            async {
+                adapter
+                    .evaluate_script("history.pushState({}, '', '/spa/step-2')", false)
+                    .await
+                    .unwrap();
+            },
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert_eq!(url, "https://example.com/spa/step-2");
+    browser
+        .wait_for_url("https://example.com/spa/*", Duration::from_secs(1))
+        .await
+        .unwrap();
+
+    let lifecycle = browser
+        .wait_for_event::<LifecycleEvent, _, _>(
+            async {
+                adapter
+                    .evaluate_script("location.href = 'https://example.com/?reloaded'", false)
+                    .await
+                    .unwrap();
+            },
+            |event| event.params.name == "DOMContentLoaded",
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(lifecycle.params.name, "DOMContentLoaded");
+    browser
+        .wait_for_url(UrlMatcher::contains("reloaded"), Duration::from_secs(10))
+        .await
+        .unwrap();
+
+    // The timeout covers a trigger that never finishes
+    let err = browser
+        .wait_for_navigation(std::future::pending::<()>(), Duration::from_millis(300))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, WaitError::Timeout(_, _)));
+
+    let err = browser
+        .wait_for_url("https://never.example/**", Duration::from_millis(300))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, WaitError::Timeout(_, _)));
+    browser.close().await.unwrap();
+}
+
+const DELAYED_PAGE: &str = "data:text/html,<p id=spinner>Loading</p><script>setTimeout(() => { document.getElementById('spinner').style.visibility = 'hidden'; const button = document.createElement('button'); button.id = 'done'; button.textContent = 'Continue'; document.body.append(button); }, 300)</script>";
+
+pub async fn test_wait_for_selector(mut browser: ChromeBrowser) {
+    <ChromeBrowser as CdpBrowser>::navigate(&mut browser, DELAYED_PAGE)
+        .await
+        .unwrap();
+    let button = browser
+        .wait_for_selector(
+            "#done".into(),
+            SelectorState::Visible,
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert!(button.is_some());
+    let spinner = browser
+        .wait_for_selector(
+            "#spinner".into(),
+            SelectorState::Hidden,
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert!(spinner.is_none());
+    let continue_text = browser
+        .wait_for_selector(
+            text!("Continue", exact).into(),
+            SelectorState::Attached,
+            Duration::from_secs(1),
+        )
+        .await
+        .unwrap();
+    assert!(continue_text.is_some());
+
+    // Role selectors are re-located when the DOM changes
+    <ChromeBrowser as CdpBrowser>::navigate(&mut browser, DELAYED_PAGE)
+        .await
+        .unwrap();
+    let by_role = browser
+        .wait_for_selector(
+            Selector::role_named("button", "Continue"),
+            SelectorState::Attached,
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert!(by_role.is_some());
+
+    browser
+        .adapter()
+        .clone()
+        .evaluate_script("document.getElementById('done').remove()", false)
+        .await
+        .unwrap();
+    let gone = browser
+        .wait_for_selector(
+            "#done".into(),
+            SelectorState::Detached,
+            Duration::from_secs(1),
+        )
+        .await
+        .unwrap();
+    assert!(gone.is_none());
+
+    let err = browser
+        .wait_for_selector(
+            "#never".into(),
+            SelectorState::Attached,
+            Duration::from_millis(300),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, WaitError::Timeout(_, _)));
+    assert!(err.to_string().contains("#never to be attached"));
+    browser.close().await.unwrap();
+}
+
+pub async fn test_evaluate(mut browser: ChromeBrowser) {
+    <ChromeBrowser as CdpBrowser>::navigate(
+        &mut browser,
+        "data:text/html,<ul><li>one</li><li>two</li></ul>",
+    )
+    .await
+    .unwrap();
+    let sum: i64 = <ChromeBrowser as CdpBrowser>::evaluate(&browser, "(a, b) => a + b", (2, 3))
+        .await
+        .unwrap();
+    assert_eq!(sum, 5);
+
+    let items = browser.locate_all("li".into()).await.unwrap();
+    let refs: Vec<NodeRef> = items
+        .iter()
+        .map(|item| NodeRef::cdp(item.node_id()))
+        .collect();
+    let texts: Vec<String> = <ChromeBrowser as CdpBrowser>::evaluate(
+        &browser,
+        "async ({ items }) => items.map((item) => item.textContent)",
+        (serde_json::json!({ "items": refs }),),
+    )
+    .await
+    .unwrap();
+    assert_eq!(texts, vec!["one", "two"]);
+
+    let err = <ChromeBrowser as CdpBrowser>::evaluate::<(), _>(
+        &browser,
+        "() => Promise.reject(new TypeError('nope'))",
+        (),
+    )
+    .await
+    .unwrap_err();
+    let EvaluateError::Exception(exception) = &err else {
+        panic!("expected a JavaScript exception, got {err:?}");
+    };
+    assert!(exception.message.contains("nope"));
+    browser.close().await.unwrap();
+}
+
+pub async fn test_expose_function(mut browser: ChromeBrowser) {
+    <ChromeBrowser as CdpBrowser>::navigate(
+        &mut browser,
+        "data:text/html,<iframe srcdoc='<p>inner</p>'></iframe>",
+    )
+    .await
+    .unwrap();
+    let adds = Arc::new(AtomicUsize::new(0));
+    let counted = adds.clone();
+    <ChromeBrowser as CdpBrowser>::expose_function(
+        &mut browser,
+        "rustAdd",
+        move |args: Vec<serde_json::Value>| {
+            counted.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match (args[0].as_i64(), args[1].as_i64()) {
+                    (Some(a), Some(b)) => Ok(serde_json::Value::from(a + b)),
+                    _ => Err("expected two integers"),
+                }
+            }
+        },
+    )
+    .await
+    .unwrap();
+
+    let sum: i64 =
+        <ChromeBrowser as CdpBrowser>::evaluate(&browser, "() => window.rustAdd(2, 3)", ())
+            .await
+            .unwrap();
+    assert_eq!(sum, 5);
+    let from_frame: i64 = <ChromeBrowser as CdpBrowser>::evaluate(
+        &browser,
+        "() => window.frames[0].rustAdd(20, 22)",
+        (),
+    )
+    .await
+    .unwrap();
+    assert_eq!(from_frame, 42);
+    let rejected: String = <ChromeBrowser as CdpBrowser>::evaluate(
+        &browser,
+        "() => window.rustAdd('a', 3).catch((err) => err.message)",
+        (),
+    )
+    .await
+    .unwrap();
+    assert_eq!(rejected, "expected two integers");
+
+    // The binding and its install script carry over to new documents
+    <ChromeBrowser as CdpBrowser>::navigate(&mut browser, "data:text/html,<p>next</p>")
+        .await
+        .unwrap();
+    let sum: i64 =
+        <ChromeBrowser as CdpBrowser>::evaluate(&browser, "() => window.rustAdd(1, 1)", ())
+            .await
+            .unwrap();
+    assert_eq!(sum, 2);
+
+    // Exposing the name again replaces the function instead of answering twice
+    let calls = adds.load(Ordering::SeqCst);
+    <ChromeBrowser as CdpBrowser>::expose_function(
+        &mut browser,
+        "rustAdd",
+        |args: Vec<serde_json::Value>| async move {
+            match (args[0].as_i64(), args[1].as_i64()) {
+                (Some(a), Some(b)) => Ok::<_, &str>(serde_json::Value::from(a * b)),
+                _ => Err("expected two integers"),
+            }
+        },
+    )
+    .await
+    .unwrap();
+    let product: i64 =
+        <ChromeBrowser as CdpBrowser>::evaluate(&browser, "() => window.rustAdd(6, 7)", ())
+            .await
+            .unwrap();
+    assert_eq!(product, 42);
+    <ChromeBrowser as CdpBrowser>::navigate(&mut browser, "data:text/html,<p>again</p>")
+        .await
+        .unwrap();
+    let product: i64 =
+        <ChromeBrowser as CdpBrowser>::evaluate(&browser, "() => window.rustAdd(2, 4)", ())
+            .await
+            .unwrap();
+    assert_eq!(product, 8);
+    assert_eq!(adds.load(Ordering::SeqCst), calls);
+    browser.close().await.unwrap();
+}
+
+pub async fn test_dialogs(mut browser: ChromeBrowser) {
+    let dialog = <ChromeBrowser as CdpBrowser>::wait_for_dialog(
+        &mut browser,
+        async {},
+        Duration::from_millis(200),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(dialog, DialogError::Timeout(_)));
+
+    // The script returns only once the prompt is answered
+    let mut adapter = browser.adapter().clone();
+    let trigger = async move {
+        let _ = adapter
+            .evaluate("() => prompt('Name?', 'guest')", Vec::new())
+            .await;
+    };
+    let dialog = <ChromeBrowser as CdpBrowser>::wait_for_dialog(
+        &mut browser,
+        trigger,
+        Duration::from_secs(5),
+    )
+    .await
+    .unwrap();
+    assert_eq!(dialog.kind(), DialogKind::Prompt);
+    assert_eq!(dialog.message(), "Name?");
+    assert_eq!(dialog.default_value(), Some("guest"));
+    dialog.dismiss().await.unwrap();
+
+    let answered = Arc::new(AtomicUsize::new(0));
+    let counted = answered.clone();
+    let registration = <ChromeBrowser as CdpBrowser>::on_dialog(&mut browser, move |dialog| {
+        counted.fetch_add(1, Ordering::SeqCst);
+        async move {
+            dialog.accept(Some("rustenium")).await.unwrap();
+        }
+    })
+    .await
+    .unwrap();
+    let answer: String =
+        <ChromeBrowser as CdpBrowser>::evaluate(&browser, "() => prompt('Name?')", ())
+            .await
+            .unwrap();
+    assert_eq!(answer, "rustenium");
+
+    // Once unregistered, the handler no longer sees dialogs
+    registration.unregister().await;
+    let mut adapter = browser.adapter().clone();
+    let dialog = <ChromeBrowser as CdpBrowser>::wait_for_dialog(
+        &mut browser,
+        async move {
+            let _ = adapter
+                .evaluate("() => alert('unhandled')", Vec::new())
+                .await;
+        },
+        Duration::from_secs(5),
+    )
+    .await
+    .unwrap();
+    dialog.accept(None).await.unwrap();
+    assert_eq!(answered.load(Ordering::SeqCst), 1);
+    browser.close().await.unwrap();
+}
+
+pub async fn test_file_inputs(mut browser: ChromeBrowser) {
+    <ChromeBrowser as CdpBrowser>::navigate(
+        &mut browser,
+        "data:text/html,<input type=file id=one><input type=file id=many multiple><p id=text>text</p>",
+    )
+    .await
+    .unwrap();
+    let dir = std::env::temp_dir();
+    let (a, b) = (
+        dir.join("rustenium-cdp-a.txt"),
+        dir.join("rustenium-cdp-b.txt"),
+    );
+    std::fs::write(&a, "a").unwrap();
+    std::fs::write(&b, "b").unwrap();
+    let picked = "(selector) => [...document.querySelector(selector).files].map((f) => f.name)";
+
+    let many = <ChromeBrowser as CdpBrowser>::locate(&mut browser, Selector::css("#many"))
+        .await
+        .unwrap()
+        .unwrap();
+    many.set_input_files(&[&a, &b]).await.unwrap();
+    let names: Vec<String> = <ChromeBrowser as CdpBrowser>::evaluate(&browser, picked, ("#many",))
+        .await
+        .unwrap();
+    assert_eq!(names, ["rustenium-cdp-a.txt", "rustenium-cdp-b.txt"]);
+
+    let text = <ChromeBrowser as CdpBrowser>::locate(&mut browser, Selector::css("#text"))
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        text.set_input_files(&[&a]).await,
+        Err(SetFilesError::NotFileInput)
+    ));
+
+    // The click opens a native chooser, which is intercepted
+    let mut one = <ChromeBrowser as CdpBrowser>::locate(&mut browser, Selector::css("#one"))
+        .await
+        .unwrap()
+        .unwrap();
+    let chooser = <ChromeBrowser as CdpBrowser>::wait_for_file_chooser(
+        &mut browser,
+        async move { one.mouse_click().await.unwrap() },
+        Duration::from_secs(5),
+    )
+    .await
+    .unwrap();
+    assert!(!chooser.is_multiple());
+    chooser.set_files(&[&a]).await.unwrap();
+    let names: Vec<String> = <ChromeBrowser as CdpBrowser>::evaluate(&browser, picked, ("#one",))
+        .await
+        .unwrap();
+    assert_eq!(names, ["rustenium-cdp-a.txt"]);
+
+    let err = <ChromeBrowser as CdpBrowser>::wait_for_file_chooser(
+        &mut browser,
+        async {},
+        Duration::from_millis(200),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, FileChooserError::Timeout(_)));
+
+    // A registered handler sees choosers alongside a wait, and stops once unregistered
+    let seen = Arc::new(AtomicUsize::new(0));
+    let counted = seen.clone();
+    let registration = <ChromeBrowser as CdpBrowser>::on_file_chooser(&mut browser, move |_| {
+        counted.fetch_add(1, Ordering::SeqCst);
+        async {}
+    })
+    .await
+    .unwrap();
+    let mut one = <ChromeBrowser as CdpBrowser>::locate(&mut browser, Selector::css("#one"))
+        .await
+        .unwrap()
+        .unwrap();
+    <ChromeBrowser as CdpBrowser>::wait_for_file_chooser(
+        &mut browser,
+        async move { one.mouse_click().await.unwrap() },
+        Duration::from_secs(5),
+    )
+    .await
+    .unwrap();
+
+    // The wait leaves interception on for the handler, and unregistering turns it off
+    registration.unregister().await;
+    let mut one = <ChromeBrowser as CdpBrowser>::locate(&mut browser, Selector::css("#one"))
+        .await
+        .unwrap()
+        .unwrap();
+    <ChromeBrowser as CdpBrowser>::wait_for_file_chooser(
+        &mut browser,
+        async move { one.mouse_click().await.unwrap() },
+        Duration::from_secs(5),
+    )
+    .await
+    .unwrap();
+    assert_eq!(seen.load(Ordering::SeqCst), 1);
+    browser.close().await.unwrap();
+}
+
+pub async fn test_downloads(mut browser: ChromeBrowser) {
+    let dir = std::env::temp_dir().join(format!("rustenium-cdp-downloads-{}", std::process::id()));
+    <ChromeBrowser as CdpBrowser>::set_download_behavior(
+        &mut browser,
+        DownloadBehavior::Allow { dir: dir.clone() },
+    )
+    .await
+    .unwrap();
+    let mut events = <ChromeBrowser as CdpBrowser>::download_events(&mut browser)
+        .await
+        .unwrap();
+    <ChromeBrowser as CdpBrowser>::navigate(&mut browser, &serve_download())
+        .await
+        .unwrap();
+
+    let mut link = <ChromeBrowser as CdpBrowser>::locate(&mut browser, Selector::css("#download"))
+        .await
+        .unwrap()
+        .unwrap();
+    let download = <ChromeBrowser as CdpBrowser>::wait_for_download(
+        &mut browser,
+        async move { link.mouse_click().await.unwrap() },
+        Duration::from_secs(10),
+    )
+    .await
+    .unwrap();
+    assert_eq!(download.state, DownloadState::Completed);
+    assert_eq!(download.suggested_filename, "report.csv");
+    let copy = dir.join("copy.csv");
+    download.save_as(&copy).await.unwrap();
+    assert_eq!(std::fs::read_to_string(copy).unwrap(), REPORT);
+
+    // The stream saw the same download begin, progress and end
+    let mut seen = Vec::new();
+    while let Some(event) = events.recv().await {
+        let end = matches!(event, DownloadEvent::End(_));
+        seen.push(event);
+        if end {
+            break;
+        }
+    }
+    assert!(matches!(seen.first(), Some(DownloadEvent::WillBegin(_))));
+    assert!(
+        seen.iter()
+            .any(|event| matches!(event, DownloadEvent::Progress(_)))
+    );
+    assert!(matches!(
+        seen.last(),
+        Some(DownloadEvent::End(end)) if end.state == DownloadState::Completed
+    ));
+    browser.close().await.unwrap();
+}
+
+pub async fn test_pdf(mut browser: ChromeBrowser) {
+    <ChromeBrowser as CdpBrowser>::navigate(&mut browser, PDF_PAGE)
+        .await
+        .unwrap();
+    let pdf = <ChromeBrowser as CdpBrowser>::pdf(&mut browser, PdfOptions::default())
+        .await
+        .unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+
+    // A directory save path gets a timestamped file
+    let dir = std::env::temp_dir().join(format!("rustenium-cdp-pdf-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let options = PdfOptionsBuilder::default()
+        .page_size(PageSize::LETTER)
+        .orientation(PdfOrientation::Landscape)
+        .background(true)
+        .save_path(dir.to_string_lossy())
+        .build();
+    let report = <ChromeBrowser as CdpBrowser>::pdf(&mut browser, options)
+        .await
+        .unwrap();
+    assert!(report.starts_with(b"%PDF"));
+    let saved: Vec<_> = std::fs::read_dir(&dir).unwrap().collect();
+    assert_eq!(saved.len(), 1);
+    assert_eq!(
+        std::fs::read(saved[0].as_ref().unwrap().path()).unwrap(),
+        report
+    );
+    let _ = std::fs::remove_dir_all(dir);
+    browser.close().await.unwrap();
+}
+
+/// How many CDP event handlers the browser's session has registered.
+async fn cdp_handler_count(browser: &ChromeBrowser) -> usize {
+    let adapter = browser.adapter();
+    let mut session = adapter.session.lock().await;
+    session.get_events().lock().unwrap().len()
+}
+
+pub async fn test_console(mut browser: ChromeBrowser) {
+    let handlers = cdp_handler_count(&browser).await;
+    let mut messages = <ChromeBrowser as CdpBrowser>::console_messages(&mut browser)
+        .await
+        .unwrap();
+    let mut errors = <ChromeBrowser as CdpBrowser>::page_errors(&mut browser)
+        .await
+        .unwrap();
+    let console = <ChromeBrowser as CdpBrowser>::collect_console(&mut browser)
+        .await
+        .unwrap();
+
+    <ChromeBrowser as CdpBrowser>::evaluate::<(), _>(
+        &browser,
+        "() => console.warn('careful', 2, { a: 1 })",
+        (),
+    )
+    .await
+    .unwrap();
+    let message = tokio::time::timeout(Duration::from_secs(5), messages.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(message.level, ConsoleLevel::Warning);
+    assert_eq!(message.text, "careful 2 Object");
+    assert_eq!(
+        message.args[..2],
+        [serde_json::json!("careful"), serde_json::json!(2)]
+    );
+
+    <ChromeBrowser as CdpBrowser>::evaluate::<(), _>(
+        &browser,
+        "() => { setTimeout(() => { throw new Error('boom') }) }",
+        (),
+    )
+    .await
+    .unwrap();
+    let error = tokio::time::timeout(Duration::from_secs(5), errors.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(error.message.contains("Error: boom"), "{}", error.message);
+
+    <ChromeBrowser as CdpBrowser>::evaluate::<(), _>(
+        &browser,
+        "() => console.error('failed to load')",
+        (),
+    )
+    .await
+    .unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(console.has_errors());
+    assert_eq!(console.page_errors().len(), 1);
+    assert_eq!(console.console_errors()[0].text, "failed to load");
+
+    // The handlers go away with the receivers and the collector they fed
+    drop((messages, errors, console));
+    <ChromeBrowser as CdpBrowser>::evaluate::<(), _>(
+        &browser,
+        "() => { console.log('unheard'); setTimeout(() => { throw new Error('unheard') }) }",
+        (),
+    )
+    .await
+    .unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(cdp_handler_count(&browser).await, handlers);
+    browser.close().await.unwrap();
+}
@@ -38,6 +38,37 @@ fn locate_error_timeout_contains_selector() {
     assert!(msg.contains("#foo"));
 }
 
+#[test]
+fn wait_error_timeout_contains_condition() {
+    let err = WaitError::Timeout("network idle".into(), std::time::Duration::from_millis(500));
+    let msg = format!("{}", err);
+    assert!(msg.contains("network idle"));
+    assert!(msg.contains("500ms"));
+}
+
+#[test]
+fn wait_error_wraps_locate_error() {
+    let err: WaitError = LocateError::InvalidSelector("Uncaught SyntaxError".into()).into();
+    assert!(matches!(err, WaitError::LocateError(_)));
+    assert!(format!("{}", err).contains("SyntaxError"));
+}
+
+#[test]
+fn locate_error_frame_not_found_contains_frame_id() {
+    let err = LocateError::FrameNotFound("F1".into());
+    let msg = format!("{}", err);
+    assert!(msg.contains("frame"));
+    assert!(msg.contains("F1"));
+}
+
+#[test]
+fn locate_error_invalid_selector_contains_reason() {
+    let err = LocateError::InvalidSelector("Uncaught SyntaxError".into());
+    let msg = format!("{}", err);
+    assert!(msg.contains("selector"));
+    assert!(msg.contains("SyntaxError"));
+}
+
 #[test]
 fn locate_error_parse_contains_message() {
     let err = LocateError::ParseError("bad json".into());
@@ -73,3 +104,46 @@ fn create_tab_error_wraps_create_target_error() {
     let err = CreateTabError::CreateTargetError(inner);
     assert!(matches!(err, CreateTabError::CreateTargetError(_)));
 }
+
+#[test]
+fn js_exception_reads_message_from_cdp_description() {
+    let details: rustenium_cdp_definitions::js_protocol::runtime::types::ExceptionDetails =
+        serde_json::from_value(serde_json::json!({
+            "exceptionId": 1,
+            "text": "Uncaught",
+            "lineNumber": 0,
+            "columnNumber": 27,
+            "exception": {
+                "type": "object",
+                "subtype": "error",
+                "description": "TypeError: x is not a function\n    at <anonymous>:1:28"
+            },
+            "stackTrace": { "callFrames": [
+                { "functionName": "", "scriptId": "5", "url": "", "lineNumber": 0, "columnNumber": 27 }
+            ]}
+        }))
+        .unwrap();
+    let err = EvaluateError::Exception(rustenium::error::script::JsException::from(&details));
+    let EvaluateError::Exception(exception) = &err else {
+        unreachable!();
+    };
+    assert_eq!(exception.message, "TypeError: x is not a function");
+    assert_eq!(exception.stack, "    at <anonymous> (:0:27)");
+    assert!(format!("{}", err).contains("x is not a function (line 0, column 27)"));
+}
+
+#[test]
+fn expose_function_error_wraps_preload_script_error() {
+    let err: ExposeFunctionError = PreloadScriptError::ParseError("bad source".into()).into();
+    assert!(matches!(err, ExposeFunctionError::PreloadScriptError(_)));
+    assert!(format!("{}", err).contains("bad source"));
+}
+
+#[test]
+fn console_error_wraps_command_result_error() {
+    use rustenium_core::error::CdpCommandResultError;
+    let err = ConsoleError::CommandResultError(CdpCommandResultError::InvalidResultTypeError(
+        serde_json::Value::Null,
+    ));
+    assert!(format!("{}", err).contains("executing command"));
+}
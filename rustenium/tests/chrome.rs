@@ -1,15 +1,35 @@
 mod bidi_browser;
 mod cdp_browser;
 
-use rustenium::browsers::{ChromeBrowser, ChromeConfig, chrome};
+use rustenium::browsers::{
+    BidiBrowser, BrowserEvent, BrowserHandle, ChromeBrowser, ChromeCapabilities, ChromeConfig,
+    ChromeFlavor, ResourceEvent, ResourceThresholds, chrome,
+};
+use rustenium::error::chrome::DetachError;
+use rustenium_bidi_definitions::session::types::{UserPromptHandler, UserPromptHandlerType};
+use std::time::Duration;
+
+/// Set `RUSTENIUM_CHROME_FLAVOR=headless-shell` to run the suite against chrome-headless-shell.
+fn flavor() -> ChromeFlavor {
+    match std::env::var("RUSTENIUM_CHROME_FLAVOR").as_deref() {
+        Ok("headless-shell") => ChromeFlavor::HeadlessShell,
+        _ => ChromeFlavor::Chrome,
+    }
+}
+
+fn browser_flags() -> Option<Vec<String>> {
+    let mut flags = vec!["--window-size=1280,720".to_string()];
+    if flavor() == ChromeFlavor::Chrome {
+        flags.insert(0, "--headless=new".to_string());
+    }
+    Some(flags)
+}
 
 async fn launch() -> ChromeBrowser {
     let config = ChromeConfig {
         remote_debugging_port: Some(0),
-        browser_flags: Some(vec![
-            "--headless=new".to_string(),
-            "--window-size=1280,720".to_string(),
-        ]),
+        flavor: flavor(),
+        browser_flags: browser_flags(),
         ..ChromeConfig::default()
     };
     chrome(Some(config)).await
@@ -19,10 +39,8 @@ async fn launch_cdp() -> ChromeBrowser {
     let config = ChromeConfig {
         enable_bidi: false,
         enable_cdp: true,
-        browser_flags: Some(vec![
-            "--headless=new".to_string(),
-            "--window-size=1280,720".to_string(),
-        ]),
+        flavor: flavor(),
+        browser_flags: browser_flags(),
         ..ChromeConfig::default()
     };
     chrome(Some(config)).await
@@ -117,6 +135,10 @@ async fn node_mouse_click() {
     bidi_browser::test_node_mouse_click(launch().await).await;
 }
 #[tokio::test]
+async fn node_actionability_checks() {
+    bidi_browser::test_node_actionability_checks(launch().await).await;
+}
+#[tokio::test]
 async fn mouse_move_to_point() {
     bidi_browser::test_mouse_move_to_point(launch().await).await;
 }
@@ -161,6 +183,107 @@ async fn get_active_context_id() {
     bidi_browser::test_get_active_context_id(launch().await).await;
 }
 #[tokio::test]
+async fn pages_navigate_concurrently() {
+    bidi_browser::test_pages_navigate_concurrently(launch().await).await;
+}
+#[tokio::test]
+async fn wait_for_popup() {
+    bidi_browser::test_wait_for_popup(launch().await).await;
+}
+
+#[tokio::test]
+async fn wait_helpers() {
+    bidi_browser::test_wait_helpers(launch().await).await;
+}
+
+#[tokio::test]
+async fn wait_for_selector() {
+    bidi_browser::test_wait_for_selector(launch().await).await;
+}
+
+#[tokio::test]
+async fn evaluate() {
+    bidi_browser::test_evaluate(launch().await).await;
+}
+
+#[tokio::test]
+async fn expose_function() {
+    bidi_browser::test_expose_function(launch().await).await;
+}
+
+#[tokio::test]
+async fn console() {
+    bidi_browser::test_console(launch().await).await;
+}
+
+#[tokio::test]
+async fn dialogs() {
+    let mut capabilities = ChromeCapabilities::default();
+    capabilities.unhandled_prompt_behavior(UserPromptHandlerType::Ignore.into());
+    let config = ChromeConfig {
+        remote_debugging_port: Some(0),
+        flavor: flavor(),
+        browser_flags: browser_flags(),
+        capabilities,
+        ..ChromeConfig::default()
+    };
+    bidi_browser::test_dialogs(chrome(Some(config)).await).await;
+}
+
+#[tokio::test]
+async fn file_inputs() {
+    let mut capabilities = ChromeCapabilities::default();
+    capabilities.unhandled_prompt_behavior(
+        UserPromptHandler::builder()
+            .file(UserPromptHandlerType::Ignore)
+            .build()
+            .into(),
+    );
+    let config = ChromeConfig {
+        remote_debugging_port: Some(0),
+        flavor: flavor(),
+        browser_flags: browser_flags(),
+        capabilities,
+        ..ChromeConfig::default()
+    };
+    bidi_browser::test_file_inputs(chrome(Some(config)).await).await;
+}
+
+#[tokio::test]
+async fn downloads() {
+    bidi_browser::test_downloads(launch().await).await;
+}
+
+#[tokio::test]
+async fn pdf() {
+    bidi_browser::test_pdf(launch().await).await;
+}
+
+#[tokio::test]
+async fn frames() {
+    bidi_browser::test_frames(launch().await).await;
+}
+
+#[tokio::test]
+async fn frame_locator() {
+    bidi_browser::test_frame_locator(launch().await).await;
+}
+
+#[tokio::test]
+async fn find_nodes_pierces_shadow_roots() {
+    bidi_browser::test_find_nodes_pierces_shadow_roots(launch().await).await;
+}
+
+#[tokio::test]
+async fn locator_chaining_and_filters() {
+    bidi_browser::test_locator_chaining_and_filters(launch().await).await;
+}
+
+#[tokio::test]
+async fn find_nodes_by_role_and_text() {
+    bidi_browser::test_find_nodes_by_role_and_text(launch().await).await;
+}
+#[tokio::test]
 async fn screenshot_returns_base64() {
     bidi_browser::test_screenshot_returns_base64(launch().await).await;
 }
@@ -226,3 +349,259 @@ async fn cdp_create_tab() {
 async fn cdp_preload_script_add_runs_and_remove() {
     cdp_browser::test_preload_script_add_runs_and_remove(launch_cdp().await).await;
 }
+
+#[tokio::test]
+async fn cdp_frames_and_locate_in_frame() {
+    cdp_browser::test_frames_and_locate_in_frame(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn cdp_locate_pierces_shadow_roots() {
+    cdp_browser::test_locate_pierces_shadow_roots(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn cdp_locate_by_role_and_text() {
+    cdp_browser::test_locate_by_role_and_text(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn cdp_wait_helpers() {
+    cdp_browser::test_wait_helpers(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn cdp_wait_for_selector() {
+    cdp_browser::test_wait_for_selector(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn cdp_evaluate() {
+    cdp_browser::test_evaluate(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn cdp_expose_function() {
+    cdp_browser::test_expose_function(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn cdp_console() {
+    cdp_browser::test_console(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn cdp_dialogs() {
+    cdp_browser::test_dialogs(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn cdp_file_inputs() {
+    cdp_browser::test_file_inputs(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn cdp_downloads() {
+    cdp_browser::test_downloads(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn cdp_pdf() {
+    cdp_browser::test_pdf(launch_cdp().await).await;
+}
+
+#[tokio::test]
+async fn restarts_after_crash_and_restores_preload_scripts() {
+    let config = ChromeConfig {
+        auto_restart: true,
+        flavor: flavor(),
+        browser_flags: browser_flags(),
+        ..ChromeConfig::default()
+    };
+    let mut browser = chrome(Some(config)).await;
+    browser
+        .add_preload_script("() => { window.__restored = 'yes'; }".to_string())
+        .await
+        .unwrap();
+    let mut events = browser.browser_events();
+
+    let process = browser.get_browser_process().as_ref().unwrap().handle();
+    process.kill().unwrap();
+
+    let event = tokio::time::timeout(Duration::from_secs(30), events.recv())
+        .await
+        .expect("no event after killing Chrome")
+        .unwrap();
+    assert!(
+        matches!(event, BrowserEvent::Crashed { .. }),
+        "got {event:?}"
+    );
+    let event = tokio::time::timeout(Duration::from_secs(60), events.recv())
+        .await
+        .expect("Chrome was not restarted")
+        .unwrap();
+    assert_eq!(event, BrowserEvent::Restarted);
+    assert!(process.is_running());
+
+    browser
+        .navigate("data:text/html,<p>back</p>")
+        .await
+        .unwrap();
+    let result = browser
+        .evaluate_script("window.__restored".to_string(), false)
+        .await
+        .unwrap();
+    assert!(format!("{:?}", result.result).contains("yes"));
+    browser.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn resource_sampler_follows_restart() {
+    let config = ChromeConfig {
+        auto_restart: true,
+        flavor: flavor(),
+        browser_flags: browser_flags(),
+        ..ChromeConfig::default()
+    };
+    let browser = chrome(Some(config)).await;
+    let sampler = browser
+        .sample_resources(Duration::from_millis(100), ResourceThresholds::default())
+        .unwrap();
+    let mut samples = sampler.subscribe();
+    let mut events = browser.browser_events();
+
+    let process = browser.get_browser_process().as_ref().unwrap().handle();
+    let old_pid = process.id();
+    process.kill().unwrap();
+
+    let event = tokio::time::timeout(Duration::from_secs(10), samples.recv())
+        .await
+        .expect("sampler did not notice the crash")
+        .unwrap();
+    assert!(
+        matches!(event, ResourceEvent::Interrupted(_)),
+        "got {event:?}"
+    );
+    loop {
+        let event = tokio::time::timeout(Duration::from_secs(60), events.recv())
+            .await
+            .expect("Chrome was not restarted")
+            .unwrap();
+        if event == BrowserEvent::Restarted {
+            break;
+        }
+    }
+
+    let new_pid = process.id();
+    assert_ne!(new_pid, old_pid);
+    let mut resumed = false;
+    for _ in 0..50 {
+        if sampler.latest().map(|stats| stats.pid) == new_pid {
+            resumed = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(resumed, "sampler did not pick up the relaunched Chrome");
+    browser.close().await.unwrap();
+}
+
+async fn launch_detachable() -> ChromeBrowser {
+    let config = ChromeConfig {
+        flavor: flavor(),
+        browser_flags: browser_flags(),
+        detachable: true,
+        ..ChromeConfig::default()
+    };
+    chrome(Some(config)).await
+}
+
+#[tokio::test]
+async fn detach_requires_detachable_output() {
+    let browser = launch().await;
+    assert!(matches!(
+        browser.detach().await,
+        Err(DetachError::PipedOutput)
+    ));
+}
+
+#[tokio::test]
+async fn detach_and_attach_keeps_browser_running() {
+    let mut browser = launch_detachable().await;
+    browser
+        .navigate("data:text/html,<title>kept</title>")
+        .await
+        .unwrap();
+
+    let handle = browser.detach().await.unwrap();
+    let chrome_pid = handle.chrome_pid.expect("spawned Chrome has a pid");
+    let alive = |pid: u32| {
+        std::process::Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .status()
+            .unwrap()
+            .success()
+    };
+    assert!(alive(chrome_pid));
+    assert!(handle.driver_pid.is_some_and(alive));
+
+    let handle: BrowserHandle =
+        serde_json::from_str(&serde_json::to_string(&handle).unwrap()).unwrap();
+    let mut browser = ChromeBrowser::attach(handle).await.unwrap();
+    let result = browser
+        .evaluate_script("document.title".to_string(), false)
+        .await
+        .unwrap();
+    assert!(format!("{:?}", result.result).contains("kept"));
+
+    browser.close().await.unwrap();
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert!(!alive(chrome_pid));
+}
+
+const DETACH_PARENT: &str = "RUSTENIUM_DETACH_PARENT";
+
+/// Runs as the short-lived parent of [`detached_browser_outlives_parent`]: launches Chrome,
+/// detaches it, prints the handle and exits.
+#[tokio::test]
+async fn detach_parent_helper() {
+    if std::env::var_os(DETACH_PARENT).is_none() {
+        return;
+    }
+    let mut browser = launch_detachable().await;
+    browser
+        .navigate("data:text/html,<title>orphaned</title>")
+        .await
+        .unwrap();
+    let handle = browser.detach().await.unwrap();
+    println!("\nhandle={}", serde_json::to_string(&handle).unwrap());
+}
+
+#[tokio::test]
+async fn detached_browser_outlives_parent() {
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "detach_parent_helper", "--nocapture"])
+        .env(DETACH_PARENT, "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let handle: BrowserHandle = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("handle="))
+        .map(|json| serde_json::from_str(json).unwrap())
+        .expect("helper printed the browser handle");
+
+    // Give Chrome time to write into its log now that the parent is gone
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    let mut browser = ChromeBrowser::attach(handle).await.unwrap();
+    browser
+        .navigate("data:text/html,<title>still here</title>")
+        .await
+        .unwrap();
+    let result = browser
+        .evaluate_script("document.title".to_string(), false)
+        .await
+        .unwrap();
+    assert!(format!("{:?}", result.result).contains("still here"));
+    browser.close().await.unwrap();
+}
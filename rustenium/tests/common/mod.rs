@@ -0,0 +1,9 @@
+use std::path::PathBuf;
+
+/// An empty directory under the system temp dir, unique to `name` and this test process.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rustenium-test-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
@@ -1,4 +1,27 @@
-use rustenium::browsers::ChromeConfig;
+#[path = "common/mod.rs"]
+mod common;
+
+use common::temp_dir;
+use rustenium::browsers::{ChromeConfig, ChromeFlavor, ChromeLaunchMode};
+use rustenium::downloader::Downloader;
+use rustenium::error::config::ConfigError;
+use rustenium_bidi_definitions::session::types::ProxyConfiguration;
+use std::sync::Mutex;
+
+/// Serializes tests that touch the process environment.
+static ENV: Mutex<()> = Mutex::new(());
+
+fn with_env<T>(vars: &[(&str, &str)], f: impl FnOnce() -> T) -> T {
+    let _guard = ENV.lock().unwrap_or_else(|e| e.into_inner());
+    for (key, value) in vars {
+        unsafe { std::env::set_var(key, value) };
+    }
+    let result = f();
+    for (key, _) in vars {
+        unsafe { std::env::remove_var(key) };
+    }
+    result
+}
 
 #[tokio::test]
 async fn default_config() {
@@ -12,6 +35,8 @@ async fn default_config() {
     assert!(config.chrome_executable_path.is_none());
     assert!(config.user_data_dir.is_none());
     assert!(config.browser_flags.is_none());
+    assert_eq!(config.flavor, ChromeFlavor::Chrome);
+    assert!(!config.auto_restart);
 }
 
 #[tokio::test]
@@ -43,3 +68,253 @@ async fn config_with_custom_values() {
         &["--headless".to_string()]
     );
 }
+
+#[test]
+fn from_toml_reads_fields_and_keeps_defaults() {
+    let config = ChromeConfig::from_toml(
+        r#"
+        launch_mode = "remote:9222"
+        flavor = "headless-shell"
+        enable_cdp = true
+        browser_flags = ["--window-size=800,600"]
+
+        [proxy]
+        proxyType = "manual"
+        httpProxy = "proxy.local:3128"
+
+        [capabilities]
+        acceptInsecureCerts = true
+
+        [capabilities."goog:chromeOptions"]
+        args = ["--mute-audio"]
+        "#,
+    )
+    .unwrap();
+
+    assert!(matches!(config.launch_mode, ChromeLaunchMode::Remote(9222)));
+    assert_eq!(config.flavor, ChromeFlavor::HeadlessShell);
+    assert!(config.enable_cdp);
+    assert!(config.enable_bidi);
+    assert_eq!(
+        config.browser_flags.as_deref(),
+        Some(&["--window-size=800,600".to_string()][..])
+    );
+    match config.proxy {
+        Some(ProxyConfiguration::ManualProxyConfiguration(proxy)) => {
+            assert_eq!(proxy.http_proxy.as_deref(), Some("proxy.local:3128"))
+        }
+        other => panic!("expected a manual proxy, got {other:?}"),
+    }
+    let caps = &config.capabilities;
+    assert_eq!(caps.base_capabilities.accept_insecure_certs, Some(true));
+    assert_eq!(
+        caps.base_capabilities.browser_name.as_deref(),
+        Some("chrome")
+    );
+    assert_eq!(
+        caps.chrome_options.args.as_deref(),
+        Some(&["--mute-audio".to_string()][..])
+    );
+}
+
+#[test]
+fn from_toml_parses_every_launch_mode() {
+    let mode = |s: &str| {
+        ChromeConfig::from_toml(&format!("launch_mode = {s:?}"))
+            .unwrap()
+            .launch_mode
+    };
+    assert!(matches!(
+        mode("spawn-and-attach"),
+        ChromeLaunchMode::SpawnAndAttach
+    ));
+    assert!(matches!(
+        mode("driver-managed"),
+        ChromeLaunchMode::DriverManaged
+    ));
+    assert!(matches!(
+        mode("remote-driver:http://grid:4444/wd/hub"),
+        ChromeLaunchMode::RemoteDriver(url) if url == "http://grid:4444/wd/hub"
+    ));
+}
+
+#[test]
+fn from_toml_rejects_unknown_keys_and_bad_values() {
+    assert!(matches!(
+        ChromeConfig::from_toml("headles = true"),
+        Err(ConfigError::Toml(_))
+    ));
+    assert!(matches!(
+        ChromeConfig::from_toml(r#"launch_mode = "remote:abc""#),
+        Err(ConfigError::Toml(_))
+    ));
+}
+
+#[test]
+fn from_toml_file_reads_file() {
+    let path = std::env::temp_dir().join(format!("rustenium-config-{}.toml", std::process::id()));
+    std::fs::write(&path, "sandbox = true\nhost = \"0.0.0.0\"\n").unwrap();
+    let config = ChromeConfig::from_toml_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(config.sandbox);
+    assert_eq!(config.host.as_deref(), Some("0.0.0.0"));
+
+    assert!(matches!(
+        ChromeConfig::from_toml_file(&path),
+        Err(ConfigError::Io(_))
+    ));
+}
+
+#[test]
+fn from_env_reads_prefixed_variables() {
+    let config = with_env(
+        &[
+            ("RUSTENIUM_CHROME_HEADLESS", "true"),
+            (
+                "RUSTENIUM_CHROME_ARGS",
+                "--mute-audio  --window-size=800,600",
+            ),
+            ("RUSTENIUM_CHROME_LAUNCH_MODE", "driver-managed"),
+            ("RUSTENIUM_CHROME_DEBUGGING_PORT", "9333"),
+            ("RUSTENIUM_CHROME_ENABLE_CDP", "1"),
+            ("RUSTENIUM_CHROME_ENABLE_BIDI", "off"),
+            ("RUSTENIUM_CHROME_PROXY", "proxy.local:3128"),
+            (
+                "RUSTENIUM_CHROME_CAPABILITIES",
+                r#"{"acceptInsecureCerts":true}"#,
+            ),
+        ],
+        ChromeConfig::from_env,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.browser_flags.unwrap(),
+        vec!["--headless=new", "--mute-audio", "--window-size=800,600"]
+    );
+    assert!(matches!(
+        config.launch_mode,
+        ChromeLaunchMode::DriverManaged
+    ));
+    assert_eq!(config.remote_debugging_port, Some(9333));
+    assert!(config.enable_cdp);
+    assert!(!config.enable_bidi);
+    match config.proxy {
+        Some(ProxyConfiguration::ManualProxyConfiguration(proxy)) => {
+            assert_eq!(proxy.http_proxy.as_deref(), Some("proxy.local:3128"));
+            assert_eq!(proxy.ssl_proxy.as_deref(), Some("proxy.local:3128"));
+        }
+        other => panic!("expected a manual proxy, got {other:?}"),
+    }
+    assert_eq!(
+        config.capabilities.base_capabilities.accept_insecure_certs,
+        Some(true)
+    );
+}
+
+#[test]
+fn env_overrides_file_config() {
+    let file = ChromeConfig::from_toml(
+        r#"
+        browser_flags = ["--headless=new", "--mute-audio"]
+        launch_mode = "driver-managed"
+        "#,
+    )
+    .unwrap();
+    let config = with_env(
+        &[
+            ("RUSTENIUM_CHROME_HEADLESS", "false"),
+            ("RUSTENIUM_LAUNCH_MODE", "remote:9222"),
+        ],
+        || file.with_env(),
+    )
+    .unwrap();
+
+    assert_eq!(config.browser_flags.unwrap(), vec!["--mute-audio"]);
+    assert!(matches!(config.launch_mode, ChromeLaunchMode::Remote(9222)));
+}
+
+#[test]
+fn headless_env_adds_no_flag_for_headless_shell() {
+    let config = with_env(
+        &[
+            ("RUSTENIUM_CHROME_FLAVOR", "headless-shell"),
+            ("RUSTENIUM_CHROME_HEADLESS", "true"),
+        ],
+        ChromeConfig::from_env,
+    )
+    .unwrap();
+    assert_eq!(config.flavor, ChromeFlavor::HeadlessShell);
+    assert_eq!(config.browser_flags.unwrap(), Vec::<String>::new());
+}
+
+#[test]
+fn from_env_reports_invalid_values() {
+    let err = with_env(
+        &[("RUSTENIUM_CHROME_DRIVER_PORT", "not-a-port")],
+        ChromeConfig::from_env,
+    )
+    .unwrap_err();
+    match err {
+        ConfigError::InvalidValue { key, value, .. } => {
+            assert_eq!(key, "RUSTENIUM_CHROME_DRIVER_PORT");
+            assert_eq!(value, "not-a-port");
+        }
+        other => panic!("expected InvalidValue, got {other:?}"),
+    }
+    assert!(
+        with_env(
+            &[("RUSTENIUM_CHROME_SANDBOX", "maybe")],
+            ChromeConfig::from_env
+        )
+        .is_err()
+    );
+}
+
+/// A downloader whose mirrors refuse connections, so any download fails fast.
+fn offline_downloader(name: &str) -> Downloader {
+    Downloader::new()
+        .cache_dir(temp_dir(name))
+        .base_url("http://127.0.0.1:1")
+}
+
+#[test]
+fn resolve_executables_keeps_explicit_paths() {
+    let mut config = ChromeConfig {
+        driver_executable_path: "/usr/bin/chromedriver".to_string(),
+        chrome_executable_path: Some("/usr/bin/google-chrome".to_string()),
+        ..Default::default()
+    };
+    config
+        .resolve_executables(&offline_downloader("chrome-resolve-explicit"))
+        .unwrap();
+    assert_eq!(config.driver_executable_path, "/usr/bin/chromedriver");
+    assert_eq!(
+        config.chrome_executable_path.as_deref(),
+        Some("/usr/bin/google-chrome")
+    );
+}
+
+#[test]
+fn resolve_executables_skips_remote_driver() {
+    let mut config = ChromeConfig {
+        launch_mode: ChromeLaunchMode::RemoteDriver("http://localhost:4444".to_string()),
+        ..Default::default()
+    };
+    config
+        .resolve_executables(&offline_downloader("chrome-resolve-remote"))
+        .unwrap();
+    assert!(config.chrome_executable_path.is_none());
+    assert_eq!(config.driver_executable_path, "");
+}
+
+#[test]
+fn resolve_executables_returns_download_errors() {
+    let mut config = ChromeConfig::default();
+    assert!(
+        config
+            .resolve_executables(&offline_downloader("chrome-resolve-offline"))
+            .is_err()
+    );
+    assert!(config.chrome_executable_path.is_none());
+}
@@ -0,0 +1,173 @@
+use rustenium::console::{
+    ConsoleCollector, ConsoleLevel, ConsoleMessage, PageError, SourceLocation,
+};
+use rustenium_bidi_definitions::log::events::EntryAddedParams;
+use rustenium_bidi_definitions::log::types::Entry;
+use rustenium_cdp_definitions::js_protocol::runtime::events::{
+    ConsoleApiCalledParams, ExceptionThrownParams,
+};
+use serde_json::json;
+
+fn message(level: ConsoleLevel, text: &str) -> ConsoleMessage {
+    ConsoleMessage {
+        level,
+        text: text.to_string(),
+        args: vec![json!(text)],
+        source: None,
+        stack: String::new(),
+    }
+}
+
+// ── BiDi ──────────────────────────────────────────────────────────────────────
+
+#[test]
+fn bidi_console_entries_become_messages() {
+    let params: EntryAddedParams = serde_json::from_value(json!({
+        "type": "console",
+        "level": "warn",
+        "source": { "realm": "realm-1", "context": "ctx-1" },
+        "text": "careful 2",
+        "timestamp": 1,
+        "method": "warn",
+        "args": [
+            { "type": "string", "value": "careful" },
+            { "type": "number", "value": 2 }
+        ],
+        "stackTrace": { "callFrames": [
+            { "columnNumber": 8, "functionName": "check", "lineNumber": 3, "url": "https://example.com/app.js" }
+        ]}
+    }))
+    .unwrap();
+    let Entry::ConsoleLogEntry(entry) = &params.entry else {
+        panic!("expected a console entry, got {:?}", params.entry);
+    };
+    let message = ConsoleMessage::from(entry);
+    assert_eq!(message.level, ConsoleLevel::Warning);
+    assert_eq!(message.text, "careful 2");
+    assert_eq!(message.args, vec![json!("careful"), json!(2)]);
+    assert_eq!(
+        message.source,
+        Some(SourceLocation {
+            url: "https://example.com/app.js".into(),
+            line: 3,
+            column: 8,
+        })
+    );
+    assert_eq!(
+        message.stack,
+        "    at check (https://example.com/app.js:3:8)"
+    );
+}
+
+#[test]
+fn bidi_javascript_entries_become_page_errors() {
+    let params: EntryAddedParams = serde_json::from_value(json!({
+        "type": "javascript",
+        "level": "error",
+        "source": { "realm": "realm-1", "context": "ctx-1" },
+        "text": "Error: boom",
+        "timestamp": 1
+    }))
+    .unwrap();
+    let Entry::JavascriptLogEntry(entry) = &params.entry else {
+        panic!("expected a javascript entry, got {:?}", params.entry);
+    };
+    let error = PageError::from(entry);
+    assert_eq!(error.message, "Error: boom");
+    assert_eq!(error.source, None);
+    assert_eq!(format!("{error}"), "Error: boom");
+}
+
+// ── CDP ───────────────────────────────────────────────────────────────────────
+
+#[test]
+fn cdp_console_calls_become_messages() {
+    let params: ConsoleApiCalledParams = serde_json::from_value(json!({
+        "type": "assert",
+        "args": [
+            { "type": "string", "value": "count" },
+            { "type": "number", "value": 3 },
+            { "type": "undefined" },
+            { "type": "object", "className": "Object", "description": "Object", "objectId": "1" }
+        ],
+        "executionContextId": 1,
+        "timestamp": 1.0
+    }))
+    .unwrap();
+    let message = ConsoleMessage::from(&params);
+    assert_eq!(message.level, ConsoleLevel::Error);
+    assert_eq!(message.text, "count 3 undefined Object");
+    assert_eq!(
+        message.args,
+        vec![json!("count"), json!(3), json!(null), json!("Object")]
+    );
+    assert_eq!(message.source, None);
+    assert!(message.stack.is_empty());
+}
+
+#[test]
+fn cdp_exceptions_become_page_errors() {
+    let params: ExceptionThrownParams = serde_json::from_value(json!({
+        "timestamp": 1.0,
+        "exceptionDetails": {
+            "exceptionId": 1,
+            "text": "Uncaught",
+            "lineNumber": 4,
+            "columnNumber": 10,
+            "url": "https://example.com/app.js",
+            "exception": {
+                "type": "object",
+                "subtype": "error",
+                "description": "Error: boom\n    at run (https://example.com/app.js:5:11)"
+            },
+            "stackTrace": { "callFrames": [
+                { "functionName": "run", "scriptId": "5", "url": "https://example.com/app.js", "lineNumber": 4, "columnNumber": 10 }
+            ]}
+        }
+    }))
+    .unwrap();
+    let error = PageError::from(&params);
+    assert_eq!(error.message, "Error: boom");
+    assert_eq!(
+        format!("{}", error.source.unwrap()),
+        "https://example.com/app.js:4:10"
+    );
+    assert_eq!(error.stack, "    at run (https://example.com/app.js:4:10)");
+}
+
+// ── Collector ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn collector_sorts_what_it_buffers() {
+    let collector = ConsoleCollector::new();
+    collector.push_message(message(ConsoleLevel::Info, "ready"));
+    assert!(!collector.has_errors());
+
+    collector.push_message(message(ConsoleLevel::Error, "failed to load"));
+    collector.push_error(PageError {
+        message: "Error: boom".into(),
+        source: None,
+        stack: String::new(),
+    });
+    assert!(collector.has_errors());
+    assert_eq!(collector.messages().len(), 2);
+    assert_eq!(collector.console_errors()[0].text, "failed to load");
+    assert_eq!(collector.page_errors()[0].message, "Error: boom");
+    assert_eq!(
+        format!("{collector}"),
+        "[info] ready\n[error] failed to load\n[pageerror] Error: boom\n"
+    );
+}
+
+#[test]
+fn collector_clones_share_the_buffer() {
+    let collector = ConsoleCollector::new();
+    collector
+        .clone()
+        .push_message(message(ConsoleLevel::Warning, "careful"));
+    assert_eq!(collector.messages().len(), 1);
+
+    collector.clear();
+    assert!(collector.messages().is_empty());
+    assert_eq!(format!("{collector}"), "");
+}
@@ -0,0 +1,63 @@
+use rustenium::dialog::DialogKind;
+use rustenium::error::bidi::ContextIndexError;
+use rustenium::error::dialog::{DialogError, FileChooserError};
+use rustenium_bidi_definitions::browsing_context::types::UserPromptType;
+use rustenium_cdp_definitions::browser_protocol::page::types::DialogType;
+use rustenium_core::error::CdpCommandResultError;
+use std::time::Duration;
+
+#[test]
+fn dialog_kinds_match_both_protocols() {
+    for (bidi, cdp, kind) in [
+        (UserPromptType::Alert, DialogType::Alert, DialogKind::Alert),
+        (
+            UserPromptType::Confirm,
+            DialogType::Confirm,
+            DialogKind::Confirm,
+        ),
+        (
+            UserPromptType::Prompt,
+            DialogType::Prompt,
+            DialogKind::Prompt,
+        ),
+        (
+            UserPromptType::Beforeunload,
+            DialogType::Beforeunload,
+            DialogKind::BeforeUnload,
+        ),
+    ] {
+        assert_eq!(DialogKind::from(&bidi), kind);
+        assert_eq!(DialogKind::from(&cdp), kind);
+        assert_eq!(
+            serde_json::to_value(&bidi).unwrap(),
+            serde_json::Value::from(kind.to_string())
+        );
+    }
+}
+
+#[test]
+fn dialog_error_variants() {
+    let err: DialogError = ContextIndexError {}.into();
+    assert!(matches!(err, DialogError::ContextIndexError(_)));
+
+    let err: DialogError =
+        CdpCommandResultError::InvalidResultTypeError(serde_json::Value::Null).into();
+    assert!(matches!(err, DialogError::Cdp(_)));
+
+    assert_eq!(
+        DialogError::Timeout(Duration::from_secs(5)).to_string(),
+        "No dialog opened within 5s"
+    );
+    assert!(DialogError::AlreadyHandled.to_string().contains("already"));
+}
+
+#[test]
+fn file_chooser_error_variants() {
+    let err: FileChooserError = ContextIndexError {}.into();
+    assert!(matches!(err, FileChooserError::ContextIndexError(_)));
+
+    assert_eq!(
+        FileChooserError::Timeout(Duration::from_millis(500)).to_string(),
+        "No file chooser opened within 500ms"
+    );
+}
@@ -0,0 +1,92 @@
+use rustenium::discovery::{
+    BrowserKind, CHROME_ENV, SystemBrowser, check_chromedriver_version, find_chrome, parse_version,
+};
+use rustenium::error::discovery::DiscoveryError;
+
+#[test]
+fn parse_version_from_chrome_output() {
+    assert_eq!(
+        parse_version("Google Chrome 146.0.7680.153 ").as_deref(),
+        Some("146.0.7680.153")
+    );
+}
+
+#[test]
+fn parse_version_from_firefox_output() {
+    assert_eq!(
+        parse_version("Mozilla Firefox 149.0").as_deref(),
+        Some("149.0")
+    );
+}
+
+#[test]
+fn parse_version_from_chromedriver_output() {
+    assert_eq!(
+        parse_version("ChromeDriver 146.0.7680.153 (abcdef-refs/branch-heads/7680@{#1})")
+            .as_deref(),
+        Some("146.0.7680.153")
+    );
+}
+
+#[test]
+fn parse_version_without_number_is_none() {
+    assert!(parse_version("Chromium").is_none());
+}
+
+#[cfg(unix)]
+mod fake_binaries {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::PathBuf;
+
+    fn fake_binary(name: &str, output: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rustenium-discovery-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, format!("#!/bin/sh\necho '{}'\n", output)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[test]
+    fn system_browser_reads_version_and_kind() {
+        let path = fake_binary("fake-edge", "Microsoft Edge 120.0.2210.91");
+        let browser = SystemBrowser::from_path(&path);
+        assert_eq!(browser.kind, BrowserKind::Edge);
+        assert_eq!(browser.version.as_deref(), Some("120.0.2210.91"));
+        assert_eq!(browser.major_version(), Some(120));
+    }
+
+    #[test]
+    fn chromedriver_version_check_matches_major() {
+        let chrome =
+            SystemBrowser::from_path(fake_binary("fake-chrome-ok", "Chromium 131.0.6778.85"));
+        let driver = fake_binary("fake-driver-ok", "ChromeDriver 131.0.6778.69 (abc)");
+        assert!(check_chromedriver_version(&chrome, &driver).is_ok());
+    }
+
+    #[test]
+    fn chromedriver_version_check_reports_mismatch() {
+        let chrome =
+            SystemBrowser::from_path(fake_binary("fake-chrome-old", "Google Chrome 120.0.1.2"));
+        let driver = fake_binary("fake-driver-new", "ChromeDriver 146.0.7680.153 (abc)");
+        assert!(matches!(
+            check_chromedriver_version(&chrome, &driver),
+            Err(DiscoveryError::VersionMismatch {
+                browser: 120,
+                driver: 146
+            })
+        ));
+    }
+
+    #[test]
+    fn env_override_takes_precedence() {
+        let path = fake_binary("fake-chrome-env", "Google Chrome 146.0.7680.153");
+        // SAFETY: this is the only test in the binary touching RUSTENIUM_CHROME.
+        unsafe { std::env::set_var(CHROME_ENV, &path) };
+        let browser = find_chrome().expect("env override should be found");
+        unsafe { std::env::remove_var(CHROME_ENV) };
+        assert_eq!(browser.path, path);
+        assert_eq!(browser.kind, BrowserKind::Chrome);
+    }
+}
@@ -0,0 +1,158 @@
+use rustenium::download::{
+    Download, DownloadEnd, DownloadProgress, DownloadState, DownloadWillBegin,
+};
+use rustenium::error::download::FileDownloadError;
+use rustenium_bidi_definitions::browsing_context::events::{
+    DownloadEndParams, DownloadWillBeginParams,
+};
+use rustenium_cdp_definitions::browser_protocol::browser::events::{
+    DownloadProgressParams, DownloadWillBeginParams as CdpDownloadWillBeginParams,
+};
+use serde_json::json;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::temp_dir;
+
+fn download(state: DownloadState, path: Option<PathBuf>) -> Download {
+    Download {
+        url: "http://127.0.0.1/report.csv".into(),
+        suggested_filename: "report.csv".into(),
+        path,
+        state,
+    }
+}
+
+// ── BiDi ──────────────────────────────────────────────────────────────────────
+
+#[test]
+fn bidi_download_events_share_the_navigation_id() {
+    let params: DownloadWillBeginParams = serde_json::from_value(json!({
+        "context": "ctx-1",
+        "navigation": "nav-1",
+        "timestamp": 1,
+        "url": "http://127.0.0.1/report.csv",
+        "suggestedFilename": "report.csv"
+    }))
+    .unwrap();
+    assert_eq!(
+        DownloadWillBegin::from(&params),
+        DownloadWillBegin {
+            id: "nav-1".into(),
+            url: "http://127.0.0.1/report.csv".into(),
+            suggested_filename: "report.csv".into(),
+        }
+    );
+
+    let params: DownloadEndParams = serde_json::from_value(json!({
+        "context": "ctx-1",
+        "navigation": "nav-1",
+        "timestamp": 2,
+        "url": "http://127.0.0.1/report.csv",
+        "status": "complete",
+        "filepath": "/tmp/downloads/report.csv"
+    }))
+    .unwrap();
+    assert_eq!(
+        DownloadEnd::from(&params),
+        DownloadEnd {
+            id: "nav-1".into(),
+            state: DownloadState::Completed,
+            path: Some("/tmp/downloads/report.csv".into()),
+        }
+    );
+}
+
+#[test]
+fn bidi_canceled_downloads_have_no_path() {
+    let params: DownloadEndParams = serde_json::from_value(json!({
+        "context": "ctx-1",
+        "navigation": "nav-2",
+        "timestamp": 2,
+        "url": "http://127.0.0.1/report.csv",
+        "status": "canceled"
+    }))
+    .unwrap();
+    let end = DownloadEnd::from(&params);
+    assert_eq!(end.state, DownloadState::Canceled);
+    assert_eq!(end.path, None);
+}
+
+// ── CDP ───────────────────────────────────────────────────────────────────────
+
+#[test]
+fn cdp_download_events_use_the_guid() {
+    let params: CdpDownloadWillBeginParams = serde_json::from_value(json!({
+        "frameId": "frame-1",
+        "guid": "guid-1",
+        "url": "http://127.0.0.1/report.csv",
+        "suggestedFilename": "report.csv"
+    }))
+    .unwrap();
+    assert_eq!(DownloadWillBegin::from(&params).id, "guid-1");
+
+    let params: DownloadProgressParams = serde_json::from_value(json!({
+        "guid": "guid-1",
+        "totalBytes": 2048.0,
+        "receivedBytes": 512.0,
+        "state": "inProgress"
+    }))
+    .unwrap();
+    assert_eq!(
+        DownloadProgress::from(&params),
+        DownloadProgress {
+            id: "guid-1".into(),
+            received_bytes: 512,
+            total_bytes: 2048,
+            state: DownloadState::InProgress,
+        }
+    );
+}
+
+// ── Download ──────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn save_as_copies_the_downloaded_file() {
+    let dir = temp_dir("save-as");
+    let saved = dir.join("report.csv");
+    std::fs::write(&saved, "a,b\n1,2\n").unwrap();
+
+    let copy = dir.join("copy.csv");
+    download(DownloadState::Completed, Some(saved))
+        .save_as(&copy)
+        .await
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(copy).unwrap(), "a,b\n1,2\n");
+}
+
+#[tokio::test]
+async fn save_as_needs_a_completed_download() {
+    let err = download(DownloadState::Canceled, None)
+        .save_as("copy.csv")
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        FileDownloadError::NotCompleted(DownloadState::Canceled)
+    ));
+    assert_eq!(err.to_string(), "The download was canceled, not completed");
+
+    let err = download(DownloadState::Completed, None)
+        .save_as("copy.csv")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, FileDownloadError::NoPath));
+}
+
+#[test]
+fn file_download_error_variants() {
+    assert_eq!(
+        FileDownloadError::Timeout(Duration::from_secs(30)).to_string(),
+        "No download finished within 30s"
+    );
+    let err: FileDownloadError = std::io::Error::from(std::io::ErrorKind::NotFound).into();
+    assert!(matches!(err, FileDownloadError::Io(_)));
+}
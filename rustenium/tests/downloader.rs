@@ -0,0 +1,270 @@
+#![cfg(target_os = "linux")]
+
+use rustenium::downloader::{Artifact, ChromeChannel, Downloader};
+use rustenium::error::downloader::DownloadError;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::temp_dir;
+
+/// Serves files under `root` over HTTP until the test process exits. Returns the base URL.
+fn serve(root: PathBuf) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+            }
+            let mut parts = request_line.split_whitespace();
+            let method = parts.next().unwrap_or_default();
+            let path = parts.next().unwrap_or_default().trim_start_matches('/');
+
+            let mut stream = reader.into_inner();
+            match std::fs::read(root.join(path)) {
+                Ok(body) => {
+                    write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                        body.len()
+                    )
+                    .unwrap();
+                    if method != "HEAD" {
+                        stream.write_all(&body).unwrap();
+                    }
+                }
+                Err(_) => {
+                    stream
+                        .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                        .unwrap();
+                }
+            }
+        }
+    });
+    url
+}
+
+/// Builds `<root>/<version>/linux64/<tool>-linux64.zip` containing `<tool>-linux64/<tool>`.
+fn chrome_fixture(root: &Path, version: &str, tool: &str) -> PathBuf {
+    let staging = root.join("staging").join(version);
+    let tool_dir = staging.join(format!("{tool}-linux64"));
+    std::fs::create_dir_all(&tool_dir).unwrap();
+    std::fs::write(tool_dir.join(tool), "#!/bin/sh\necho fixture\n").unwrap();
+
+    let dest = root.join(version).join("linux64");
+    std::fs::create_dir_all(&dest).unwrap();
+    let archive = dest.join(format!("{tool}-linux64.zip"));
+    let status = Command::new("zip")
+        .arg("-qr")
+        .arg(&archive)
+        .arg(format!("{tool}-linux64"))
+        .current_dir(&staging)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    archive
+}
+
+fn sha256(path: &Path) -> String {
+    let output = Command::new("sha256sum").arg(path).output().unwrap();
+    String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .next()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn downloads_pinned_version_from_mirror() {
+    let root = temp_dir("mirror");
+    chrome_fixture(&root, "1.2.3.4", "chromedriver");
+    let url = serve(root.clone());
+
+    let path = Downloader::new()
+        .version("1.2.3.4")
+        .base_url(&url)
+        .cache_dir(root.join("cache"))
+        .chromedriver()
+        .unwrap();
+
+    assert!(path.starts_with(root.join("cache")));
+    assert!(path.ends_with("chromedriver-linux64/chromedriver"));
+    assert!(
+        !root
+            .join("cache/rustenium-chromedriver-1.2.3.4-linux64/download_archive")
+            .exists()
+    );
+}
+
+#[test]
+fn cached_download_is_reused() {
+    let root = temp_dir("cached");
+    chrome_fixture(&root, "1.2.3.4", "chromedriver");
+    let url = serve(root.clone());
+    let downloader = Downloader::new()
+        .version("1.2.3.4")
+        .base_url(&url)
+        .cache_dir(root.join("cache"));
+
+    let first = downloader.chromedriver().unwrap();
+    std::fs::remove_dir_all(root.join("1.2.3.4")).unwrap();
+    let second = downloader.chromedriver().unwrap();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn downloads_chrome_headless_shell() {
+    let root = temp_dir("headless-shell");
+    chrome_fixture(&root, "1.2.3.4", "chrome-headless-shell");
+    let url = serve(root.clone());
+
+    let path = Downloader::new()
+        .version("1.2.3.4")
+        .base_url(&url)
+        .cache_dir(root.join("cache"))
+        .chrome_headless_shell()
+        .unwrap();
+    assert!(path.ends_with("chrome-headless-shell-linux64/chrome-headless-shell"));
+}
+
+#[test]
+fn verifies_matching_checksum() {
+    let root = temp_dir("checksum-ok");
+    let archive = chrome_fixture(&root, "1.2.3.4", "chrome");
+    let url = serve(root.clone());
+
+    let path = Downloader::new()
+        .version("1.2.3.4")
+        .base_url(&url)
+        .cache_dir(root.join("cache"))
+        .sha256(Artifact::Chrome, sha256(&archive).to_uppercase())
+        .chrome()
+        .unwrap();
+    assert!(path.ends_with("chrome-linux64/chrome"));
+}
+
+#[test]
+fn rejects_mismatched_checksum() {
+    let root = temp_dir("checksum-bad");
+    let archive = chrome_fixture(&root, "1.2.3.4", "chrome");
+    let url = serve(root.clone());
+
+    let err = Downloader::new()
+        .version("1.2.3.4")
+        .base_url(&url)
+        .cache_dir(root.join("cache"))
+        .sha256(Artifact::Chrome, "00".repeat(32))
+        .chrome()
+        .unwrap_err();
+
+    match err {
+        DownloadError::ChecksumMismatch {
+            expected, actual, ..
+        } => {
+            assert_eq!(expected, "00".repeat(32));
+            assert_eq!(actual, sha256(&archive));
+        }
+        other => panic!("expected ChecksumMismatch, got {other:?}"),
+    }
+}
+
+#[test]
+fn resolves_channel_from_known_good_versions() {
+    let root = temp_dir("channel");
+    chrome_fixture(&root, "9.8.7.6", "chromedriver");
+    std::fs::write(
+        root.join("last-known-good-versions.json"),
+        r#"{"channels":{"Stable":{"channel":"Stable","version":"1.0.0.0"},"Beta":{"channel":"Beta","version":"9.8.7.6"}}}"#,
+    )
+    .unwrap();
+    let url = serve(root.clone());
+
+    let downloader = Downloader::new()
+        .channel(ChromeChannel::Beta)
+        .versions_url(&url)
+        .base_url(&url)
+        .cache_dir(root.join("cache"));
+    assert_eq!(downloader.resolve_chrome_version().unwrap(), "9.8.7.6");
+
+    let path = downloader.chromedriver().unwrap();
+    assert!(path.to_string_lossy().contains("9.8.7.6"));
+}
+
+#[test]
+fn unknown_channel_is_a_resolution_error() {
+    let root = temp_dir("channel-missing");
+    std::fs::write(
+        root.join("last-known-good-versions.json"),
+        r#"{"channels":{"Stable":{"version":"1.0.0.0"}}}"#,
+    )
+    .unwrap();
+    let url = serve(root.clone());
+
+    let err = Downloader::new()
+        .channel(ChromeChannel::Canary)
+        .versions_url(&url)
+        .resolve_chrome_version()
+        .unwrap_err();
+    assert!(matches!(err, DownloadError::VersionResolution(_)));
+}
+
+#[test]
+fn explicit_version_takes_precedence_over_channel() {
+    let version = Downloader::new()
+        .channel(ChromeChannel::Dev)
+        .version("1.2.3.4")
+        .resolve_chrome_version()
+        .unwrap();
+    assert_eq!(version, "1.2.3.4");
+}
+
+#[test]
+fn reports_progress() {
+    let root = temp_dir("progress");
+    let archive = chrome_fixture(&root, "1.2.3.4", "chromedriver");
+    let size = std::fs::metadata(&archive).unwrap().len();
+    let url = serve(root.clone());
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let sink = reports.clone();
+    Downloader::new()
+        .version("1.2.3.4")
+        .base_url(&url)
+        .cache_dir(root.join("cache"))
+        .progress(move |p| sink.lock().unwrap().push(p))
+        .chromedriver()
+        .unwrap();
+
+    let reports = reports.lock().unwrap();
+    let last = reports.last().expect("progress was never reported");
+    assert_eq!(last.artifact, Artifact::Chromedriver);
+    assert_eq!(last.downloaded, size);
+    assert_eq!(last.total, Some(size));
+    assert!(last.url.ends_with("chromedriver-linux64.zip"));
+}
+
+#[test]
+fn missing_archive_returns_error() {
+    let root = temp_dir("missing");
+    let url = serve(root.clone());
+
+    let err = Downloader::new()
+        .version("0.0.0.0")
+        .base_url(&url)
+        .cache_dir(root.join("cache"))
+        .chromedriver()
+        .unwrap_err();
+    assert!(matches!(err, DownloadError::Command(_)));
+}
@@ -53,3 +53,83 @@ async fn screenshot_error_variants() {
     assert!(format!("{}", ScreenshotError::NoSharedId).contains("shared ID"));
     assert!(format!("{}", ScreenshotError::NoContext).contains("context"));
 }
+
+#[tokio::test]
+async fn frame_error_variants() {
+    let not_found = FrameError::NotFound("iframe#pay".into());
+    assert!(format!("{}", not_found).contains("iframe#pay"));
+
+    let not_a_frame = FrameError::NotAFrame("div".into());
+    assert!(format!("{}", not_a_frame).contains("not a frame"));
+
+    let detached = FrameError::Detached("ctx-1".into());
+    assert!(format!("{}", detached).contains("ctx-1"));
+
+    let err: FrameError = FindNodesError::from(ContextIndexError {}).into();
+    assert!(matches!(err, FrameError::FindNodesError(_)));
+}
+
+#[tokio::test]
+async fn wait_error_variants() {
+    let timeout = WaitError::Timeout("load".into(), std::time::Duration::from_secs(2));
+    let msg = format!("{}", timeout);
+    assert!(msg.contains("Timed out"));
+    assert!(msg.contains("load"));
+    assert!(msg.contains("2s"));
+
+    let failed = WaitError::NavigationFailed("https://example.com/404".into());
+    assert!(format!("{}", failed).contains("https://example.com/404"));
+
+    let err: WaitError = EvaluateResultError::from(ContextIndexError {}).into();
+    assert!(matches!(err, WaitError::EvaluateResultError(_)));
+
+    let err: WaitError = FindNodesError::from(ContextIndexError {}).into();
+    assert!(matches!(err, WaitError::FindNodesError(_)));
+}
+
+#[tokio::test]
+async fn evaluate_error_maps_exceptions() {
+    let exception = serde_json::from_value(serde_json::json!({
+        "type": "exception",
+        "realm": "realm-1",
+        "exceptionDetails": {
+            "columnNumber": 14,
+            "lineNumber": 2,
+            "exception": { "type": "error" },
+            "stackTrace": { "callFrames": [
+                { "columnNumber": 14, "functionName": "check", "lineNumber": 2, "url": "" }
+            ]},
+            "text": "Error: boom"
+        }
+    }))
+    .unwrap();
+    let err: EvaluateError = EvaluateResultError::ExceptionError(exception).into();
+    let EvaluateError::Exception(exception) = &err else {
+        panic!("expected a JavaScript exception, got {err:?}");
+    };
+    assert_eq!(exception.message, "Error: boom");
+    assert_eq!(exception.line, 2);
+    assert_eq!(exception.stack, "    at check (:2:14)");
+    assert!(format!("{}", err).contains("Error: boom (line 2, column 14)"));
+
+    let err: EvaluateError = EvaluateResultError::from(ContextIndexError {}).into();
+    assert!(matches!(err, EvaluateError::ContextIndexError(_)));
+}
+
+#[tokio::test]
+async fn expose_function_error_variants() {
+    let err: ExposeFunctionError = ContextIndexError {}.into();
+    assert!(matches!(err, ExposeFunctionError::ContextIndexError(_)));
+
+    let err: ExposeFunctionError = FrameError::Detached("ctx-1".into()).into();
+    assert!(format!("{}", err).contains("ctx-1"));
+
+    let err: ExposeFunctionError = EvaluateResultError::NoSharedId.into();
+    assert!(matches!(err, ExposeFunctionError::EvaluateResultError(_)));
+}
+
+#[tokio::test]
+async fn console_error_variants() {
+    let err: ConsoleError = ContextIndexError {}.into();
+    assert!(matches!(err, ConsoleError::ContextIndexError(_)));
+}
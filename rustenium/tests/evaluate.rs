@@ -0,0 +1,136 @@
+use rustenium::evaluate::{EvaluateArgs, NodeRef, from_remote_value, to_local_value};
+use rustenium_bidi_definitions::script::types::RemoteValue;
+use rustenium_cdp_definitions::browser_protocol::dom::types::NodeId;
+use serde_json::json;
+
+fn remote(value: serde_json::Value) -> RemoteValue {
+    serde_json::from_value(value).unwrap()
+}
+
+// ── Arguments ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn tuples_spread_into_positional_arguments() {
+    assert_eq!(
+        (1, "two").into_args().unwrap(),
+        vec![json!(1), json!("two")]
+    );
+    assert!(().into_args().unwrap().is_empty());
+    assert_eq!(
+        (json!({ "a": 1 }),).into_args().unwrap(),
+        vec![json!({ "a": 1 })]
+    );
+}
+
+#[test]
+fn sequences_in_a_tuple_stay_one_argument() {
+    assert_eq!(
+        (vec![1, 2, 3],).into_args().unwrap(),
+        vec![json!([1, 2, 3])]
+    );
+    assert_eq!(
+        (json!(["a", "b"]), 3).into_args().unwrap(),
+        vec![json!(["a", "b"]), json!(3)]
+    );
+}
+
+#[test]
+fn json_becomes_local_values() {
+    let value = to_local_value(&json!({ "name": "rustenium", "tags": [1, true, null] }));
+    assert_eq!(
+        serde_json::to_value(value).unwrap(),
+        json!({
+            "type": "object",
+            "value": [
+                ["name", { "type": "string", "value": "rustenium" }],
+                ["tags", {
+                    "type": "array",
+                    "value": [
+                        { "type": "number", "value": 1 },
+                        { "type": "boolean", "value": true },
+                        { "type": "null" }
+                    ]
+                }]
+            ]
+        })
+    );
+}
+
+#[test]
+fn node_refs_become_shared_references() {
+    let node = serde_json::to_value(NodeRef::shared("f.1.d.2.e.3")).unwrap();
+    let value = to_local_value(&json!([node]));
+    assert_eq!(
+        serde_json::to_value(value).unwrap(),
+        json!({ "type": "array", "value": [{ "sharedId": "f.1.d.2.e.3" }] })
+    );
+}
+
+#[test]
+fn node_refs_round_trip() {
+    let shared = NodeRef::shared("f.1.d.2.e.3");
+    let json = serde_json::to_value(&shared).unwrap();
+    assert_eq!(serde_json::from_value::<NodeRef>(json).unwrap(), shared);
+    assert_eq!(shared.shared_id(), Some("f.1.d.2.e.3"));
+    assert_eq!(shared.node_id(), None);
+
+    let cdp = NodeRef::cdp(NodeId::new(7));
+    assert_eq!(cdp.node_id(), Some(NodeId::new(7)));
+    assert_eq!(cdp.shared_id(), None);
+}
+
+// ── Results ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn remote_values_become_json() {
+    let value = remote(json!({
+        "type": "object",
+        "value": [
+            ["count", { "type": "number", "value": 3 }],
+            ["items", { "type": "array", "value": [
+                { "type": "string", "value": "a" },
+                { "type": "undefined" }
+            ]}],
+            ["ok", { "type": "boolean", "value": true }]
+        ]
+    }));
+    assert_eq!(
+        from_remote_value(&value),
+        json!({ "count": 3, "items": ["a", null], "ok": true })
+    );
+}
+
+#[test]
+fn special_remote_values_follow_json_stringify() {
+    assert_eq!(
+        from_remote_value(&remote(json!({ "type": "number", "value": "NaN" }))),
+        json!(null)
+    );
+    assert_eq!(
+        from_remote_value(&remote(json!({ "type": "number", "value": "-0" }))),
+        json!(0)
+    );
+    assert_eq!(
+        from_remote_value(&remote(
+            json!({ "type": "bigint", "value": "9007199254740993" })
+        )),
+        json!("9007199254740993")
+    );
+    assert_eq!(
+        from_remote_value(&remote(json!({ "type": "map", "value": [
+            [{ "type": "number", "value": 1 }, { "type": "string", "value": "one" }]
+        ]}))),
+        json!({ "1": "one" })
+    );
+}
+
+#[test]
+fn returned_nodes_deserialize_into_node_refs() {
+    let node = remote(json!({
+        "type": "node",
+        "sharedId": "f.1.d.2.e.3",
+        "value": { "nodeType": 1, "childNodeCount": 0 }
+    }));
+    let node: NodeRef = serde_json::from_value(from_remote_value(&node)).unwrap();
+    assert_eq!(node.shared_id(), Some("f.1.d.2.e.3"));
+}
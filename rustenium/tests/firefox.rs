@@ -1,6 +1,9 @@
 mod bidi_browser;
 
-use rustenium::browsers::{FirefoxBrowser, FirefoxConfig, firefox};
+use rustenium::browsers::{
+    FirefoxBrowser, FirefoxCapabilities, FirefoxConfig, FirefoxLaunchMode, firefox,
+};
+use rustenium_bidi_definitions::session::types::{UserPromptHandler, UserPromptHandlerType};
 
 async fn launch() -> FirefoxBrowser {
     let config = FirefoxConfig {
@@ -19,6 +22,15 @@ async fn navigate_to_url() {
     bidi_browser::test_navigate_to_url(launch().await).await;
 }
 #[tokio::test]
+async fn driver_managed_navigate() {
+    let config = FirefoxConfig {
+        launch_mode: FirefoxLaunchMode::DriverManaged,
+        browser_flags: Some(vec!["--headless".to_string()]),
+        ..FirefoxConfig::default()
+    };
+    bidi_browser::test_navigate_to_url(firefox(Some(config)).await).await;
+}
+#[tokio::test]
 async fn navigate_with_wait_complete() {
     bidi_browser::test_navigate_with_wait_complete(launch().await).await;
 }
@@ -99,6 +111,10 @@ async fn node_mouse_click() {
     bidi_browser::test_node_mouse_click(launch().await).await;
 }
 #[tokio::test]
+async fn node_actionability_checks() {
+    bidi_browser::test_node_actionability_checks(launch().await).await;
+}
+#[tokio::test]
 async fn mouse_move_to_point() {
     bidi_browser::test_mouse_move_to_point(launch().await).await;
 }
@@ -143,6 +159,103 @@ async fn get_active_context_id() {
     bidi_browser::test_get_active_context_id(launch().await).await;
 }
 #[tokio::test]
+async fn pages_navigate_concurrently() {
+    bidi_browser::test_pages_navigate_concurrently(launch().await).await;
+}
+#[tokio::test]
+async fn wait_for_popup() {
+    bidi_browser::test_wait_for_popup(launch().await).await;
+}
+
+#[tokio::test]
+async fn wait_helpers() {
+    bidi_browser::test_wait_helpers(launch().await).await;
+}
+
+#[tokio::test]
+async fn wait_for_selector() {
+    bidi_browser::test_wait_for_selector(launch().await).await;
+}
+
+#[tokio::test]
+async fn evaluate() {
+    bidi_browser::test_evaluate(launch().await).await;
+}
+
+#[tokio::test]
+async fn expose_function() {
+    bidi_browser::test_expose_function(launch().await).await;
+}
+
+#[tokio::test]
+async fn console() {
+    bidi_browser::test_console(launch().await).await;
+}
+
+#[tokio::test]
+async fn dialogs() {
+    let mut capabilities = FirefoxCapabilities::default();
+    capabilities.unhandled_prompt_behavior(UserPromptHandlerType::Ignore.into());
+    let config = FirefoxConfig {
+        browser_flags: Some(vec!["--headless".to_string()]),
+        capabilities,
+        ..FirefoxConfig::default()
+    };
+    bidi_browser::test_dialogs(firefox(Some(config)).await).await;
+}
+
+#[tokio::test]
+async fn file_inputs() {
+    let mut capabilities = FirefoxCapabilities::default();
+    capabilities.unhandled_prompt_behavior(
+        UserPromptHandler::builder()
+            .file(UserPromptHandlerType::Ignore)
+            .build()
+            .into(),
+    );
+    let config = FirefoxConfig {
+        browser_flags: Some(vec!["--headless".to_string()]),
+        capabilities,
+        ..FirefoxConfig::default()
+    };
+    bidi_browser::test_file_inputs(firefox(Some(config)).await).await;
+}
+
+#[tokio::test]
+async fn downloads() {
+    bidi_browser::test_downloads(launch().await).await;
+}
+
+#[tokio::test]
+async fn pdf() {
+    bidi_browser::test_pdf(launch().await).await;
+}
+
+#[tokio::test]
+async fn frames() {
+    bidi_browser::test_frames(launch().await).await;
+}
+
+#[tokio::test]
+async fn frame_locator() {
+    bidi_browser::test_frame_locator(launch().await).await;
+}
+
+#[tokio::test]
+async fn find_nodes_pierces_shadow_roots() {
+    bidi_browser::test_find_nodes_pierces_shadow_roots(launch().await).await;
+}
+
+#[tokio::test]
+async fn locator_chaining_and_filters() {
+    bidi_browser::test_locator_chaining_and_filters(launch().await).await;
+}
+
+#[tokio::test]
+async fn find_nodes_by_role_and_text() {
+    bidi_browser::test_find_nodes_by_role_and_text(launch().await).await;
+}
+#[tokio::test]
 async fn screenshot_returns_base64() {
     bidi_browser::test_screenshot_returns_base64(launch().await).await;
 }
@@ -1,4 +1,27 @@
+#[path = "common/mod.rs"]
+mod common;
+
+use common::temp_dir;
+use rustenium::bidi::drivers::DriverConfiguration;
 use rustenium::browsers::{FirefoxConfig, FirefoxLaunchMode};
+use rustenium::downloader::Downloader;
+use rustenium::error::config::ConfigError;
+use std::sync::Mutex;
+
+/// Serializes tests that touch the process environment.
+static ENV: Mutex<()> = Mutex::new(());
+
+fn with_env<T>(vars: &[(&str, &str)], f: impl FnOnce() -> T) -> T {
+    let _guard = ENV.lock().unwrap_or_else(|e| e.into_inner());
+    for (key, value) in vars {
+        unsafe { std::env::set_var(key, value) };
+    }
+    let result = f();
+    for (key, _) in vars {
+        unsafe { std::env::remove_var(key) };
+    }
+    result
+}
 
 #[test]
 fn default_config() {
@@ -61,6 +84,27 @@ fn launch_mode_remote_stores_port() {
     }
 }
 
+#[test]
+fn driver_managed_flags_include_driver_and_websocket_ports() {
+    let config = FirefoxConfig {
+        launch_mode: FirefoxLaunchMode::DriverManaged,
+        driver_port: Some(4444),
+        remote_debugging_port: Some(9333),
+        driver_flags: vec!["--log=trace"],
+        ..Default::default()
+    };
+    assert_eq!(config.exe_path(), "geckodriver");
+    assert_eq!(
+        config.flags(),
+        vec![
+            "--host=localhost".to_string(),
+            "--port=4444".to_string(),
+            "--websocket-port=9333".to_string(),
+            "--log=trace".to_string(),
+        ]
+    );
+}
+
 #[test]
 fn config_clone_preserves_fields() {
     let config = FirefoxConfig {
@@ -72,3 +116,114 @@ fn config_clone_preserves_fields() {
     assert_eq!(cloned.remote_debugging_port, Some(7777));
     assert_eq!(cloned.browser_flags.as_ref().unwrap().len(), 2);
 }
+
+#[test]
+fn from_toml_reads_fields_and_capabilities() {
+    let config = FirefoxConfig::from_toml(
+        r#"
+        launch_mode = "driver-managed"
+        driver_port = 4444
+        profile_dir = "/tmp/ff-profile"
+
+        [capabilities]
+        acceptInsecureCerts = true
+
+        [capabilities."moz:firefoxOptions"]
+        prefs = { "dom.webnotifications.enabled" = false }
+        "#,
+    )
+    .unwrap();
+
+    assert!(matches!(
+        config.launch_mode,
+        FirefoxLaunchMode::DriverManaged
+    ));
+    assert_eq!(config.driver_port, Some(4444));
+    assert_eq!(config.profile_dir.as_deref(), Some("/tmp/ff-profile"));
+    let caps = &config.capabilities;
+    assert_eq!(caps.base_capabilities.accept_insecure_certs, Some(true));
+    assert_eq!(
+        caps.base_capabilities.browser_name.as_deref(),
+        Some("firefox")
+    );
+    assert_eq!(
+        caps.firefox_options.prefs.as_ref().unwrap()["dom.webnotifications.enabled"],
+        serde_json::Value::Bool(false)
+    );
+}
+
+#[test]
+fn from_toml_rejects_unknown_keys() {
+    assert!(matches!(
+        FirefoxConfig::from_toml("enable_cdp = true"),
+        Err(ConfigError::Toml(_))
+    ));
+}
+
+#[test]
+fn from_env_reads_prefixed_variables() {
+    let config = with_env(
+        &[
+            ("RUSTENIUM_FIREFOX_HEADLESS", "yes"),
+            ("RUSTENIUM_FIREFOX_ARGS", "-private"),
+            ("RUSTENIUM_FIREFOX_LAUNCH_MODE", "remote:2828"),
+            ("RUSTENIUM_FIREFOX_PATH", "/opt/firefox/firefox"),
+            ("RUSTENIUM_FIREFOX_PREFER_SYSTEM_BROWSER", "true"),
+        ],
+        FirefoxConfig::from_env,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.browser_flags.unwrap(),
+        vec!["--headless", "-private"]
+    );
+    assert!(matches!(
+        config.launch_mode,
+        FirefoxLaunchMode::Remote(2828)
+    ));
+    assert_eq!(
+        config.firefox_executable_path.as_deref(),
+        Some("/opt/firefox/firefox")
+    );
+    assert!(config.prefer_system_browser);
+}
+
+#[test]
+fn from_env_reports_invalid_launch_mode() {
+    let err = with_env(
+        &[("RUSTENIUM_FIREFOX_LAUNCH_MODE", "managed")],
+        FirefoxConfig::from_env,
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, ConfigError::InvalidValue { ref key, .. } if key == "RUSTENIUM_FIREFOX_LAUNCH_MODE")
+    );
+}
+
+#[test]
+fn resolve_executables_returns_download_errors() {
+    let downloader = Downloader::new()
+        .cache_dir(temp_dir("firefox-resolve-offline"))
+        .firefox_base_url("http://127.0.0.1:1")
+        .geckodriver_base_url("http://127.0.0.1:1");
+
+    let mut config = FirefoxConfig {
+        launch_mode: FirefoxLaunchMode::Remote(9222),
+        ..Default::default()
+    };
+    config.resolve_executables(&downloader).unwrap();
+    assert!(config.firefox_executable_path.is_none());
+
+    let mut config = FirefoxConfig {
+        firefox_executable_path: Some("/usr/bin/firefox".to_string()),
+        launch_mode: FirefoxLaunchMode::DriverManaged,
+        ..Default::default()
+    };
+    assert!(config.resolve_executables(&downloader).is_err());
+    assert_eq!(
+        config.firefox_executable_path.as_deref(),
+        Some("/usr/bin/firefox")
+    );
+    assert!(config.driver_executable_path.is_none());
+}
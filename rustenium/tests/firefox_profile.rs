@@ -0,0 +1,137 @@
+use rustenium::browsers::FirefoxProfile;
+use rustenium::error::firefox::FirefoxProfileError;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::temp_dir;
+
+#[test]
+fn user_js_contains_remote_agent_prefs() {
+    let profile = FirefoxProfile::new("/tmp/unused");
+    let user_js = profile.user_js();
+    assert!(user_js.contains(r#"user_pref("remote.active-protocols", 1);"#));
+    assert!(user_js.contains(r#"user_pref("browser.shell.checkDefaultBrowser", false);"#));
+}
+
+#[test]
+fn user_prefs_override_required_prefs() {
+    let mut profile = FirefoxProfile::new("/tmp/unused");
+    profile
+        .add_pref("browser.shell.checkDefaultBrowser", true)
+        .add_pref("browser.download.dir", "/tmp/downloads");
+    let user_js = profile.user_js();
+    assert!(user_js.contains(r#"user_pref("browser.shell.checkDefaultBrowser", true);"#));
+    assert!(!user_js.contains(r#"user_pref("browser.shell.checkDefaultBrowser", false);"#));
+    assert!(user_js.contains(r#"user_pref("browser.download.dir", "/tmp/downloads");"#));
+}
+
+#[test]
+fn write_creates_user_js() {
+    let dir = temp_dir("write");
+    let mut profile = FirefoxProfile::new(&dir);
+    profile.add_pref("dom.webnotifications.enabled", false);
+    let written = profile.write().unwrap();
+
+    assert_eq!(written, dir);
+    let user_js = std::fs::read_to_string(dir.join("user.js")).unwrap();
+    assert!(user_js.contains(r#"user_pref("dom.webnotifications.enabled", false);"#));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn write_merges_existing_user_js() {
+    let dir = temp_dir("merge");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("user.js"),
+        "// my settings\nuser_pref(\"browser.startup.page\", 3);\nuser_pref(\"dom.webnotifications.enabled\", true);\n",
+    )
+    .unwrap();
+
+    let mut profile = FirefoxProfile::new(&dir);
+    profile.add_pref("dom.webnotifications.enabled", false);
+    profile.write().unwrap();
+
+    let user_js = std::fs::read_to_string(dir.join("user.js")).unwrap();
+    assert!(user_js.contains("// my settings"));
+    assert!(user_js.contains(r#"user_pref("browser.startup.page", 3);"#));
+    assert!(user_js.contains(r#"user_pref("dom.webnotifications.enabled", false);"#));
+    assert!(!user_js.contains(r#"user_pref("dom.webnotifications.enabled", true);"#));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn missing_extension_errors() {
+    let dir = temp_dir("missing-ext");
+    let mut profile = FirefoxProfile::new(&dir);
+    profile.add_extension(dir.join("nope.xpi"));
+    assert!(matches!(
+        profile.write(),
+        Err(FirefoxProfileError::ExtensionNotFound(_))
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn missing_certificate_errors() {
+    let dir = temp_dir("missing-cert");
+    let mut profile = FirefoxProfile::new(&dir);
+    profile.add_certificate("Test CA", dir.join("nope.pem"));
+    assert!(matches!(
+        profile.write(),
+        Err(FirefoxProfileError::CertificateNotFound(_))
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn extension_installed_under_manifest_id() {
+    let src = temp_dir("ext-src");
+    std::fs::create_dir_all(&src).unwrap();
+    std::fs::write(
+        src.join("manifest.json"),
+        r#"{"manifest_version":2,"name":"t","version":"1","browser_specific_settings":{"gecko":{"id":"test@rustenium"}}}"#,
+    )
+    .unwrap();
+    let status = std::process::Command::new("zip")
+        .args(["-q", "ext.xpi", "manifest.json"])
+        .current_dir(&src)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let dir = temp_dir("ext");
+    let mut profile = FirefoxProfile::new(&dir);
+    profile.add_extension(src.join("ext.xpi"));
+    profile.write().unwrap();
+
+    assert!(dir.join("extensions").join("test@rustenium.xpi").is_file());
+    let user_js = std::fs::read_to_string(dir.join("user.js")).unwrap();
+    assert!(user_js.contains(r#"user_pref("extensions.autoDisableScopes", 0);"#));
+    let _ = std::fs::remove_dir_all(&src);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn zip_and_unzip_round_trip() {
+    let dir = temp_dir("zip-src");
+    let mut profile = FirefoxProfile::new(&dir);
+    profile.add_pref("browser.startup.page", 0);
+    profile.write().unwrap();
+
+    let archive = temp_dir("zip-archive").join("profile.zip");
+    profile.zip(&archive).unwrap();
+
+    let restored_dir = temp_dir("zip-dest");
+    let restored = FirefoxProfile::from_zip(&archive, &restored_dir).unwrap();
+    assert_eq!(restored.dir(), restored_dir.as_path());
+    let user_js = std::fs::read_to_string(restored_dir.join("user.js")).unwrap();
+    assert!(user_js.contains(r#"user_pref("browser.startup.page", 0);"#));
+
+    let _ = std::fs::remove_file(&archive);
+    let _ = std::fs::remove_dir_all(&dir);
+    let _ = std::fs::remove_dir_all(&restored_dir);
+}
@@ -1,14 +1,28 @@
+// Test files pull in `common` themselves so they also build as their own test crates
+#![allow(clippy::duplicate_mod)]
+
+mod browser_handle;
 mod browser_options;
 mod builders;
 mod capabilities;
 mod cdp_errors;
 mod config;
+mod console;
+mod dialog;
+mod discovery;
+mod download;
+mod downloader;
 mod errors;
+mod evaluate;
 mod fetch_node_options;
 mod firefox_capabilities;
 mod firefox_config;
+mod firefox_profile;
 mod human_mouse;
 mod human_touchscreen;
 mod mouse;
 mod node_errors;
 mod node_types;
+mod pdf;
+mod selectors;
+mod wait;
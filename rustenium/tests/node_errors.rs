@@ -93,6 +93,36 @@ fn cdp_node_action_decode_contains_detail() {
     assert!(format!("{}", err).contains("malformed"));
 }
 
+#[test]
+fn node_action_obscured_names_the_covering_element() {
+    let err = NodeActionError::Obscured {
+        by: "div#overlay".into(),
+    };
+    assert!(format!("{}", err).contains("div#overlay"));
+}
+
+#[test]
+fn node_action_actionability_variants() {
+    assert!(format!("{}", NodeActionError::Detached).contains("attached"));
+    assert!(format!("{}", NodeActionError::NotVisible).contains("not visible"));
+    assert!(format!("{}", NodeActionError::Disabled).contains("disabled"));
+    assert!(format!("{}", NodeActionError::NotStable).contains("moving"));
+}
+
+#[test]
+fn actionability_failure_surfaces_through_mouse_and_input_errors() {
+    let err: NodeMouseError = NodeActionError::Disabled.into();
+    assert!(matches!(
+        err,
+        NodeMouseError::Action(NodeActionError::Disabled)
+    ));
+    let err: NodeInputError = NodeActionError::NotVisible.into();
+    assert!(matches!(
+        err,
+        NodeInputError::Action(NodeActionError::NotVisible)
+    ));
+}
+
 #[test]
 fn bidi_node_mouse_touch_already_started() {
     let err = BidiNodeMouseError::TouchAlreadyStarted;
@@ -155,3 +185,36 @@ fn bidi_screenshot_error_no_context_converts() {
         NodeScreenshotError::Bidi(BidiNodeScreenshotError::NoContext)
     ));
 }
+
+#[test]
+fn locator_error_not_found_includes_locator() {
+    let err = LocatorError::NotFound("css=\"button\" >> nth=2".into());
+    let msg = format!("{}", err);
+    assert!(msg.contains("No element matches"));
+    assert!(msg.contains("nth=2"));
+}
+
+#[test]
+fn locator_error_wraps_node_errors() {
+    let err: LocatorError = NodeMouseError::InvalidPosition.into();
+    assert!(matches!(
+        err,
+        LocatorError::Mouse(NodeMouseError::InvalidPosition)
+    ));
+    assert!(format!("{}", err).contains("valid position"));
+}
+
+#[test]
+fn set_files_error_variants() {
+    let err = SetFilesError::FileNotFound("missing/report.pdf".into());
+    assert_eq!(format!("{}", err), "File not found: missing/report.pdf");
+
+    assert!(format!("{}", SetFilesError::NotFileInput).contains("<input type=file>"));
+    assert!(format!("{}", SetFilesError::NotMultiple(3)).contains("got 3"));
+
+    let err: LocatorError = SetFilesError::NotFileInput.into();
+    assert!(matches!(
+        err,
+        LocatorError::SetFiles(SetFilesError::NotFileInput)
+    ));
+}
@@ -0,0 +1,95 @@
+use rustenium::error::pdf::PdfError;
+use rustenium::pdf::{PageSize, PdfMargins, PdfOptions, PdfOptionsBuilder, PdfOrientation};
+use rustenium_core::error::{CdpCommandResultError, CommandResultError};
+use serde_json::json;
+
+fn options() -> PdfOptions {
+    PdfOptionsBuilder::default()
+        .page_size(PageSize::A4)
+        .margins(PdfMargins::uniform(2.54))
+        .orientation(PdfOrientation::Landscape)
+        .scale(0.5)
+        .page_range("1")
+        .page_ranges(["3-5"])
+        .background(true)
+        .shrink_to_fit(false)
+        .save_path("report.pdf")
+        .build()
+}
+
+#[test]
+fn builder_collects_options() {
+    let options = options();
+    assert_eq!(options.page_size, Some(PageSize::A4));
+    assert_eq!(options.margins, Some(PdfMargins::uniform(2.54)));
+    assert_eq!(options.orientation, Some(PdfOrientation::Landscape));
+    assert_eq!(options.page_ranges, vec!["1", "3-5"]);
+    assert!(options.background);
+    assert_eq!(options.save_path.as_deref(), Some("report.pdf"));
+
+    let default = PdfOptionsBuilder::default().build();
+    assert_eq!(default.page_size, None);
+    assert!(default.page_ranges.is_empty());
+    assert!(!default.background);
+}
+
+#[test]
+fn bidi_print_keeps_centimeters() {
+    let print = serde_json::to_value(options().to_bidi("ctx-1".to_string())).unwrap();
+    assert_eq!(print["method"], "browsingContext.print");
+    assert_eq!(
+        print["params"],
+        json!({
+            "context": "ctx-1",
+            "background": true,
+            "margin": { "top": 2.54, "bottom": 2.54, "left": 2.54, "right": 2.54 },
+            "orientation": "landscape",
+            "page": { "width": 21.0, "height": 29.7 },
+            "pageRanges": ["1", "3-5"],
+            "scale": 0.5,
+            "shrinkToFit": false
+        })
+    );
+}
+
+#[test]
+fn cdp_print_converts_to_inches_and_streams() {
+    let print = serde_json::to_value(options().to_cdp()).unwrap();
+    let params = &print["params"];
+    assert_eq!(print["method"], "Page.printToPDF");
+    assert_eq!(params["transferMode"], "ReturnAsStream");
+    assert_eq!(params["landscape"], true);
+    assert_eq!(params["printBackground"], true);
+    assert_eq!(params["pageRanges"], "1,3-5");
+    assert_eq!(params["marginTop"], 1.0);
+    assert_eq!(params["marginRight"], 1.0);
+    assert!((params["paperWidth"].as_f64().unwrap() - 8.27).abs() < 0.01);
+    assert!((params["paperHeight"].as_f64().unwrap() - 11.69).abs() < 0.01);
+}
+
+#[test]
+fn default_options_leave_browser_defaults() {
+    let options = PdfOptions::default();
+    let bidi = serde_json::to_value(options.to_bidi("ctx-1".to_string())).unwrap();
+    assert_eq!(
+        bidi["params"],
+        json!({ "context": "ctx-1", "background": false })
+    );
+    let cdp = serde_json::to_value(options.to_cdp()).unwrap();
+    assert_eq!(
+        cdp["params"],
+        json!({ "printBackground": false, "transferMode": "ReturnAsStream" })
+    );
+}
+
+#[test]
+fn pdf_error_variants() {
+    let bidi: PdfError = CommandResultError::InvalidResultTypeError(json!({})).into();
+    assert!(matches!(bidi, PdfError::Bidi(_)));
+    let cdp: PdfError = CdpCommandResultError::InvalidResultTypeError(json!({})).into();
+    assert!(matches!(cdp, PdfError::Cdp(_)));
+
+    let io: PdfError = std::io::Error::from(std::io::ErrorKind::PermissionDenied).into();
+    assert!(matches!(io, PdfError::Io(_)));
+    assert!(io.to_string().starts_with("PDF file error"));
+}
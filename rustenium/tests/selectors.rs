@@ -0,0 +1,125 @@
+use rustenium::browsers::cdp_browser::Selector;
+use rustenium_bidi_definitions::browsing_context::types::{
+    BrowsingContext, InnerTextLocatorMatchType, Locator,
+};
+use rustenium_cdp_definitions::browser_protocol::page::types::FrameId;
+use rustenium_macros::{context, css, role, text, xpath};
+use std::borrow::Cow;
+
+// ── BiDi locator macros ───────────────────────────────────────────────────────
+
+#[test]
+fn role_macro_sets_role_and_name() {
+    let Locator::AccessibilityLocator(locator) = role!("button", name = "Submit") else {
+        panic!("expected an accessibility locator");
+    };
+    assert_eq!(locator.value.role.as_deref(), Some("button"));
+    assert_eq!(locator.value.name.as_deref(), Some("Submit"));
+}
+
+#[test]
+fn role_macro_accepts_role_or_name_alone() {
+    let Locator::AccessibilityLocator(by_role) = role!("link") else {
+        panic!("expected an accessibility locator");
+    };
+    assert_eq!(by_role.value.role.as_deref(), Some("link"));
+    assert!(by_role.value.name.is_none());
+
+    let Locator::AccessibilityLocator(by_name) = role!(name = "Close") else {
+        panic!("expected an accessibility locator");
+    };
+    assert!(by_name.value.role.is_none());
+    assert_eq!(by_name.value.name.as_deref(), Some("Close"));
+}
+
+#[test]
+fn text_macro_defaults_to_partial_match() {
+    let Locator::InnerTextLocator(locator) = text!("Sign in") else {
+        panic!("expected an inner text locator");
+    };
+    assert_eq!(locator.value, "Sign in");
+    assert_eq!(locator.match_type, Some(InnerTextLocatorMatchType::Partial));
+    assert!(locator.ignore_case.is_none());
+    assert!(locator.max_depth.is_none());
+}
+
+#[test]
+fn text_macro_options_in_any_order() {
+    let Locator::InnerTextLocator(locator) = text!("Sign in", max_depth = 3, ignore_case, exact)
+    else {
+        panic!("expected an inner text locator");
+    };
+    assert_eq!(locator.match_type, Some(InnerTextLocatorMatchType::Full));
+    assert_eq!(locator.ignore_case, Some(true));
+    assert_eq!(locator.max_depth, Some(3));
+}
+
+#[test]
+fn context_macro_wraps_browsing_context() {
+    let Locator::ContextLocator(locator) = context!(BrowsingContext::from("ctx-1".to_string()))
+    else {
+        panic!("expected a context locator");
+    };
+    assert_eq!(locator.value.context.as_ref(), "ctx-1");
+}
+
+// ── CDP selectors ─────────────────────────────────────────────────────────────
+
+#[test]
+fn selector_from_locators() {
+    assert_eq!(Selector::from(css!("a.b")), Selector::css("a.b"));
+    assert_eq!(Selector::from(xpath!("//a")), Selector::xpath("//a"));
+    assert_eq!(
+        Selector::from(role!("button", name = "Submit")),
+        Selector::role_named("button", "Submit")
+    );
+    assert_eq!(Selector::from(text!("Sign in")), Selector::text("Sign in"));
+    assert_eq!(
+        Selector::from(text!("Sign in", exact)),
+        Selector::exact_text("Sign in")
+    );
+    assert_eq!(
+        Selector::from(context!("F1".to_string())),
+        Selector::FrameOwner(FrameId::from("F1".to_string()))
+    );
+}
+
+#[test]
+fn selector_from_string_is_css() {
+    assert_eq!(Selector::from("#id"), Selector::Css("#id".into()));
+    assert_eq!(
+        Selector::from(String::from("#id")),
+        Selector::Css("#id".into())
+    );
+    assert_eq!(
+        Selector::from(&String::from("#id")),
+        Selector::Css("#id".into())
+    );
+    assert_eq!(
+        Selector::from(Cow::Borrowed("#id")),
+        Selector::Css("#id".into())
+    );
+    assert_eq!(
+        Selector::from(Box::<str>::from("#id")),
+        Selector::Css("#id".into())
+    );
+    assert_eq!(Selector::from('p'), Selector::Css("p".into()));
+}
+
+#[test]
+fn selector_display() {
+    assert_eq!(Selector::css("#id").to_string(), "#id");
+    assert_eq!(Selector::xpath("//a").to_string(), "xpath=//a");
+    assert_eq!(
+        Selector::role_named("button", "Submit").to_string(),
+        "role=button[name=\"Submit\"]"
+    );
+    assert_eq!(
+        Selector::exact_text("Sign in").to_string(),
+        "text=\"Sign in\" exact"
+    );
+    assert_eq!(
+        Selector::from(text!("sign", ignore_case)).to_string(),
+        "text=\"sign\" ignore_case"
+    );
+}
@@ -0,0 +1,83 @@
+use rustenium::wait::{BidiEventType, CdpEventType, LoadState, SelectorState, UrlMatcher};
+use rustenium_bidi_definitions::browsing_context::events::{HistoryUpdated, Load};
+use rustenium_cdp_definitions::browser_protocol::page::events::LifecycleEvent;
+
+// ── UrlMatcher ────────────────────────────────────────────────────────────────
+
+#[test]
+fn glob_star_stays_within_a_path_segment() {
+    let matcher = UrlMatcher::from("https://example.com/*/checkout");
+    assert!(matcher.matches("https://example.com/shop/checkout"));
+    assert!(!matcher.matches("https://example.com/shop/cart/checkout"));
+    assert!(!matcher.matches("https://example.com/shop/checkout/done"));
+}
+
+#[test]
+fn glob_double_star_crosses_segments() {
+    let matcher = UrlMatcher::glob("**/checkout");
+    assert!(matcher.matches("https://example.com/shop/cart/checkout"));
+    assert!(!matcher.matches("https://example.com/checkout/done"));
+    assert!(UrlMatcher::from("**").matches("about:blank"));
+}
+
+#[test]
+fn glob_matches_query_and_fragment_literally() {
+    let matcher = UrlMatcher::from(String::from("https://example.com/?step=2#top"));
+    assert!(matcher.matches("https://example.com/?step=2#top"));
+    assert!(!matcher.matches("https://example.com/?step=22#top"));
+}
+
+#[test]
+fn contains_and_predicate_matchers() {
+    assert!(UrlMatcher::contains("#done").matches("https://example.com/app#done"));
+    assert!(!UrlMatcher::contains("#done").matches("https://example.com/app"));
+
+    let https = UrlMatcher::predicate(|url| url.starts_with("https://"));
+    assert!(https.matches("https://example.com"));
+    assert!(!https.matches("http://example.com"));
+    assert_eq!(format!("{:?}", https), "Predicate(\"<fn>\")");
+}
+
+#[test]
+fn url_matcher_display_names_the_pattern() {
+    assert_eq!(
+        UrlMatcher::from("https://example.com/**").to_string(),
+        "URL https://example.com/**"
+    );
+    assert_eq!(
+        UrlMatcher::contains("/done").to_string(),
+        "URL containing /done"
+    );
+}
+
+// ── LoadState and event types ─────────────────────────────────────────────────
+
+#[test]
+fn load_state_display() {
+    assert_eq!(LoadState::DomContentLoaded.to_string(), "DOMContentLoaded");
+    assert_eq!(LoadState::Load.to_string(), "load");
+    assert_eq!(LoadState::NetworkIdle.to_string(), "network idle");
+}
+
+#[test]
+fn event_types_expose_their_identifiers() {
+    assert_eq!(<Load as BidiEventType>::IDENTIFIER, "browsingContext.load");
+    assert_eq!(
+        <HistoryUpdated as BidiEventType>::IDENTIFIER,
+        "browsingContext.historyUpdated"
+    );
+    assert_eq!(
+        <LifecycleEvent as CdpEventType>::IDENTIFIER,
+        "Page.lifecycleEvent"
+    );
+}
+
+// ── SelectorState ─────────────────────────────────────────────────────────────
+
+#[test]
+fn selector_state_display() {
+    assert_eq!(SelectorState::Attached.to_string(), "attached");
+    assert_eq!(SelectorState::Detached.to_string(), "detached");
+    assert_eq!(SelectorState::Visible.to_string(), "visible");
+    assert_eq!(SelectorState::Hidden.to_string(), "hidden");
+}